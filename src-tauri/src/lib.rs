@@ -1,6 +1,6 @@
 mod minecraft;
 
-use minecraft::{versions, downloader, instances, launcher, settings, auth, modrinth, files, fabric, forge, java, logger, discord, secrets, curseforge};
+use minecraft::{versions, downloader, instances, launcher, settings, auth, modrinth, files, fabric, quilt, forge, java, logger, logtail, discord, secrets, curseforge, skin, installqueue, watcher, preflight};
 use serde::{Deserialize, Serialize};
 use sha1::{Digest, Sha1};
 use std::collections::{HashMap, HashSet};
@@ -32,9 +32,16 @@ pub struct RunningProcessInfo {
     pub start_time: u64,
     #[serde(default)]
     pub launch_username: Option<String>,
+    #[serde(default)]
+    pub instance_id: String,
 }
 
+// Keyed by session_key(instance_id, username), so one instance can run under several accounts
 static RUNNING_PROCESSES: LazyLock<Mutex<HashMap<String, RunningProcessInfo>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+// Sessions the user asked to stop, so their non-zero exit isn't reported as a crash
+static USER_STOPPED_INSTANCES: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(|| Mutex::new(HashSet::new()));
+// Earliest start and open session count per instance, so parallel sessions credit playtime once
+static PLAY_SPANS: LazyLock<Mutex<HashMap<String, (u64, usize)>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
 static BOOTSTRAP_START: LazyLock<std::time::Instant> = LazyLock::new(std::time::Instant::now);
 static MANUAL_METADATA_VERSION_CACHE: LazyLock<Mutex<HashMap<String, Option<modrinth::ModrinthVersion>>>> =
     LazyLock::new(|| Mutex::new(HashMap::new()));
@@ -81,6 +88,18 @@ fn set_auth_state(
     Ok(())
 }
 
+/// Signal the process group led by `pid`. False when `pid` leads no group, which is
+/// the case for games started without a wrapper command.
+#[cfg(not(target_os = "windows"))]
+fn signal_process_group(pid: u32, signal: &str) -> bool {
+    std::process::Command::new("kill")
+        .args([signal, "--", &format!("-{}", pid)])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
 fn terminate_pid(pid: u32) -> Result<(), String> {
     #[cfg(target_os = "windows")]
     {
@@ -95,11 +114,13 @@ fn terminate_pid(pid: u32) -> Result<(), String> {
     }
     #[cfg(not(target_os = "windows"))]
     {
+        // Kill the whole group first so Java forked by a wrapper command goes too
+        let group_killed = signal_process_group(pid, "-9");
         let status = std::process::Command::new("kill")
             .args(["-9", &pid.to_string()])
             .status()
             .map_err(|e| format!("Failed to kill process {}: {}", pid, e))?;
-        if !status.success() {
+        if !status.success() && !group_killed {
             return Err(format!("kill -9 failed for PID {}", pid));
         }
     }
@@ -128,6 +149,9 @@ fn request_graceful_pid_stop(pid: u32) -> Result<(), String> {
     }
     #[cfg(not(target_os = "windows"))]
     {
+        if signal_process_group(pid, "-TERM") {
+            return Ok(());
+        }
         let status = std::process::Command::new("kill")
             .args(["-TERM", &pid.to_string()])
             .status()
@@ -169,6 +193,153 @@ fn has_running_processes() -> bool {
         .unwrap_or(false)
 }
 
+fn session_key(instance_id: &str, username: &str) -> String {
+    format!("{}+{}", instance_id, username)
+}
+
+/// Keys of every session of an instance, or of the one session when given a session key
+fn matching_session_keys(processes: &HashMap<String, RunningProcessInfo>, id: &str) -> Vec<String> {
+    processes
+        .iter()
+        .filter(|(key, info)| key.as_str() == id || info.instance_id == id)
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+fn is_instance_running(instance_id: &str) -> bool {
+    RUNNING_PROCESSES
+        .lock()
+        .map(|processes| processes.values().any(|info| info.instance_id == instance_id))
+        .unwrap_or(false)
+}
+
+/// Drop tracked sessions of an instance (or one session key) whose process is gone.
+/// Covers exit threads that never saw the game close, which would otherwise block relaunching.
+fn prune_stale_sessions(id: &str) -> Vec<String> {
+    let stale: Vec<(String, RunningProcessInfo)> = match RUNNING_PROCESSES.lock() {
+        Ok(processes) => matching_session_keys(&processes, id)
+            .into_iter()
+            .filter_map(|key| processes.get(&key).cloned().map(|info| (key, info)))
+            .filter(|(_, info)| !instances::is_process_running(info.pid))
+            .collect(),
+        Err(_) => return Vec::new(),
+    };
+    if stale.is_empty() {
+        return Vec::new();
+    }
+
+    if let Ok(mut processes) = RUNNING_PROCESSES.lock() {
+        for (key, _) in &stale {
+            processes.remove(key);
+        }
+    }
+    sync_discord_presence();
+    let now = SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    for (key, info) in &stale {
+        instances::clear_active_session(key);
+        forget_user_stop(key);
+        // The real exit time is unknown, so close the span without crediting playtime
+        end_play_span(&info.instance_id, info.start_time, now);
+    }
+    stale.into_iter().map(|(key, _)| key).collect()
+}
+
+/// Drop a session's pending stop request once that session is gone
+fn forget_user_stop(process_key: &str) {
+    if let Ok(mut stopped) = USER_STOPPED_INSTANCES.lock() {
+        stopped.remove(process_key);
+    }
+}
+
+/// Exits within this many seconds of launch count as a failed start
+const EARLY_EXIT_WINDOW_SECS: u64 = 60;
+
+/// Pull an instance's play span back to `start_time` without opening a session
+fn widen_play_span(instance_id: &str, start_time: u64) {
+    if let Ok(mut spans) = PLAY_SPANS.lock() {
+        let span = spans.entry(instance_id.to_string()).or_insert((start_time, 0));
+        span.0 = span.0.min(start_time);
+    }
+}
+
+fn begin_play_span(instance_id: &str, start_time: u64) {
+    if let Ok(mut spans) = PLAY_SPANS.lock() {
+        let span = spans.entry(instance_id.to_string()).or_insert((start_time, 0));
+        span.0 = span.0.min(start_time);
+        span.1 += 1;
+    }
+}
+
+/// Close one session and return the seconds to credit: the whole span once the
+/// last parallel session of the instance exits, nothing before that
+fn end_play_span(instance_id: &str, start_time: u64, end_time: u64) -> u64 {
+    let Ok(mut spans) = PLAY_SPANS.lock() else {
+        return end_time.saturating_sub(start_time);
+    };
+    match spans.get_mut(instance_id) {
+        Some(span) if span.1 > 1 => {
+            span.1 -= 1;
+            0
+        }
+        Some(_) => {
+            let span_start = spans.remove(instance_id).map(|span| span.0).unwrap_or(start_time);
+            end_time.saturating_sub(span_start)
+        }
+        None => end_time.saturating_sub(start_time),
+    }
+}
+
+/// Stamp last played and bump the launch count; returns the session's start time
+fn record_launch(instance: &instances::Instance) -> Result<u64, String> {
+    let start_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut updated_instance = instance.clone();
+    updated_instance.last_played = Some(start_time.to_string());
+    updated_instance.total_launches += 1;
+    instances::update_instance(updated_instance)?;
+    Ok(start_time)
+}
+
+/// Track a freshly started game: session record for crash recovery, running process entry
+/// and play span. Returns the session key.
+fn register_session(instance_id: &str, username: &str, pid: u32, start_time: u64) -> Result<String, String> {
+    let process_key = session_key(instance_id, username);
+    let _ = instances::write_active_session(&process_key, instance_id, start_time, Some(pid), Some(username.to_string()));
+    // A stop request left over from an earlier session must not hide this one's crash
+    forget_user_stop(&process_key);
+
+    let mut processes = RUNNING_PROCESSES.lock().map_err(|_| "Process state corrupted")?;
+    // Output left over from an earlier session is dropped unless a parallel one is still writing to it
+    logtail::reset_live_output(instance_id, !matching_session_keys(&processes, instance_id).is_empty());
+    processes.insert(process_key.clone(), RunningProcessInfo {
+        pid,
+        start_time,
+        launch_username: Some(username.to_string()),
+        instance_id: instance_id.to_string(),
+    });
+    drop(processes);
+    begin_play_span(instance_id, start_time);
+    Ok(process_key)
+}
+
+/// Add a finished span to the instance's playtime and activity log
+fn credit_playtime(instance_id: &str, end_time: u64, seconds: u64) {
+    if seconds == 0 {
+        return;
+    }
+    if let Ok(mut inst) = instances::get_instance(instance_id) {
+        let name = inst.name.clone();
+        inst.playtime_seconds += seconds;
+        let _ = instances::update_instance(inst);
+        instances::log_session(instance_id, &name, end_time, seconds);
+    }
+}
+
 fn show_main_window(app: &AppHandle) {
     if let Some(window) = app.get_webview_window("main") {
         let _ = window.show();
@@ -177,6 +348,119 @@ fn show_main_window(app: &AppHandle) {
     }
 }
 
+/// Apply the `on_game_launch` setting to the main window once a game process is up
+fn apply_game_launch_behavior(app: &AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+
+    match settings::get_on_game_launch().as_str() {
+        "minimize" => {
+            let _ = window.minimize();
+        }
+        "close_to_tray" => {
+            let _ = window.hide();
+        }
+        "exit" => {
+            // Quit without touching the game; its active session record stays on disk so the
+            // next startup re-attaches to the process or credits the playtime
+            discord::shutdown();
+            app.exit(0);
+        }
+        _ => {}
+    }
+}
+
+/// Latest.log lines that only appear once the game has a working render context
+const GAME_WINDOW_LOG_MARKERS: &[&str] = &["Created: ", "Sound engine started"];
+const LAUNCH_WATCHDOG_POLL_SECS: u64 = 3;
+
+#[cfg(target_os = "windows")]
+fn process_has_visible_window(pid: u32) -> bool {
+    use windows::Win32::Foundation::{BOOL, HWND, LPARAM};
+    use windows::Win32::UI::WindowsAndMessaging::{EnumWindows, GetWindowThreadProcessId, IsWindowVisible};
+
+    unsafe extern "system" fn check_window(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let state = &mut *(lparam.0 as *mut (u32, bool));
+        let mut window_pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut window_pid as *mut u32));
+        if window_pid == state.0 && IsWindowVisible(hwnd).as_bool() {
+            state.1 = true;
+            return BOOL(0);
+        }
+        BOOL(1)
+    }
+
+    let mut state = (pid, false);
+    unsafe {
+        // Stopping the enumeration early reports an error, which is expected here
+        let _ = EnumWindows(Some(check_window), LPARAM(&mut state as *mut (u32, bool) as isize));
+    }
+    state.1
+}
+
+/// Best-effort check that the game got as far as showing its window
+fn game_window_detected(pid: u32, log_path: &Path, launched_at: SystemTime) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        if process_has_visible_window(pid) {
+            return true;
+        }
+    }
+    #[cfg(not(target_os = "windows"))]
+    let _ = pid;
+
+    // Fall back to the log; an older latest.log belongs to the previous session
+    let fresh = fs::metadata(log_path)
+        .and_then(|m| m.modified())
+        .map(|modified| modified >= launched_at)
+        .unwrap_or(false);
+    fresh
+        && fs::read_to_string(log_path)
+            .map(|content| GAME_WINDOW_LOG_MARKERS.iter().any(|marker| content.contains(marker)))
+            .unwrap_or(false)
+}
+
+/// Watch a freshly launched game and emit `launch-stalled` if it stays alive without a window
+fn spawn_launch_watchdog(app_handle: AppHandle, instance: &instances::Instance, pid: u32) {
+    let Some(timeout_secs) = settings::get_launch_watchdog_secs() else {
+        return;
+    };
+    let instance_id = instance.id.clone();
+    let instance_name = instance.name.clone();
+    let log_path = instance.get_game_directory().join("logs").join("latest.log");
+    let launched_at = SystemTime::now();
+
+    std::thread::spawn(move || {
+        let mut waited = 0;
+        while waited < timeout_secs {
+            std::thread::sleep(std::time::Duration::from_secs(LAUNCH_WATCHDOG_POLL_SECS));
+            waited += LAUNCH_WATCHDOG_POLL_SECS;
+
+            let still_running = RUNNING_PROCESSES
+                .lock()
+                .map(|processes| processes.values().any(|info| info.pid == pid))
+                .unwrap_or(false);
+            if !still_running || game_window_detected(pid, &log_path, launched_at) {
+                return;
+            }
+        }
+
+        log_warn!(&app_handle, "Instance {} is running but no game window appeared after {}s", instance_name, waited);
+        let _ = app_handle.emit("launch-stalled", serde_json::json!({
+            "instance_id": instance_id,
+            "pid": pid,
+            "waited_secs": waited,
+            "hints": [
+                "Update your graphics drivers, or make sure Java uses the dedicated GPU.",
+                "Check the game log for errors about OpenGL, GLFW or missing natives.",
+                "Try removing recently added mods or custom JVM arguments.",
+                "If the game is still loading on a slow machine, raise the watchdog timeout in settings.",
+            ],
+        }));
+    });
+}
+
 fn request_exit_confirmation(app: &AppHandle) {
     show_main_window(app);
     if let Some(window) = app.get_webview_window("main") {
@@ -407,21 +691,30 @@ fn build_tray_menu(app: &AppHandle) -> tauri::Result<tauri::menu::Menu<tauri::Wr
     } else {
         let mut running_rows: Vec<(String, RunningProcessInfo)> = running_infos.into_iter().collect();
         running_rows.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut sessions_per_instance: HashMap<String, usize> = HashMap::new();
+        for (_, info) in &running_rows {
+            *sessions_per_instance.entry(info.instance_id.clone()).or_insert(0) += 1;
+        }
 
-        for (instance_id, info) in running_rows {
-            let instance_name = instance_names
-                .get(&instance_id)
+        for (session_key, info) in running_rows {
+            let mut instance_name = instance_names
+                .get(&info.instance_id)
                 .cloned()
                 .unwrap_or_else(|| "Unknown Instance".to_string());
+            if sessions_per_instance.get(&info.instance_id).copied().unwrap_or(0) > 1 {
+                if let Some(username) = &info.launch_username {
+                    instance_name = format!("{} as {}", instance_name, username);
+                }
+            }
             let running_for = SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_secs()
                 .saturating_sub(info.start_time);
-            let id = format!("{}{}", TRAY_STOP_PREFIX, instance_id);
+            let id = format!("{}{}", TRAY_STOP_PREFIX, session_key);
             let text = format!("Stop {} ({})", instance_name, format_short_playtime(running_for));
             let icon = instances_by_id
-                .get(&instance_id)
+                .get(&info.instance_id)
                 .and_then(load_tray_instance_icon);
             append_tray_item_with_optional_icon(app, &running_submenu, id, text, icon)?;
         }
@@ -490,43 +783,75 @@ fn refresh_tray_menu(app: &AppHandle) {
     }
 }
 
+/// Update Discord Rich Presence from the running process table, featuring the most recently launched instance.
+/// Parallel sessions of one instance count as a single running instance.
+fn sync_discord_presence() {
+    // Snapshot the sessions and let go of the lock before reading instances.json
+    let (running_count, newest) = match RUNNING_PROCESSES.lock() {
+        Ok(processes) => {
+            let running_instances: HashSet<&str> = processes.values().map(|info| info.instance_id.as_str()).collect();
+            let newest = processes
+                .values()
+                .max_by_key(|info| info.start_time)
+                .map(|info| (info.instance_id.clone(), info.start_time));
+            (running_instances.len(), newest)
+        }
+        Err(_) => (0, None),
+    };
+    let active = newest.and_then(|(instance_id, started_at)| {
+        instances::get_instance(&instance_id).ok().map(|instance| discord::PresenceInstance {
+            name: instance.name,
+            version: instance.version_id,
+            mod_loader: instance.mod_loader.to_string(),
+            started_at,
+        })
+    });
+    discord::update_presence(running_count, active);
+}
+
+/// Stop every session of an instance, or a single session when given its session key
 fn stop_running_instance(instance_id: &str, app_handle: &AppHandle, force_immediately: bool) -> Result<String, String> {
-    let process_info = {
+    let sessions: Vec<(String, RunningProcessInfo)> = {
         let processes = RUNNING_PROCESSES.lock().map_err(|_| "Process state corrupted")?;
-        processes.get(instance_id).cloned()
+        matching_session_keys(&processes, instance_id)
+            .into_iter()
+            .filter_map(|key| processes.get(&key).cloned().map(|info| (key, info)))
+            .collect()
     };
 
-    match process_info {
-        Some(info) => {
-            let forced = if force_immediately {
-                if instances::is_process_running(info.pid) {
-                    terminate_pid(info.pid)?;
-                    true
-                } else {
-                    false
-                }
-            } else {
-                stop_pid_with_fallback(info.pid)?
-            };
+    if sessions.is_empty() {
+        return Err("Instance is not running".to_string());
+    }
 
-            {
-                let mut processes = RUNNING_PROCESSES.lock().map_err(|_| "Process state corrupted")?;
-                processes.remove(instance_id);
-                discord::update_presence(processes.len());
-            }
-            instances::clear_active_session(instance_id);
-            refresh_tray_menu(app_handle);
-            let _ = app_handle.emit("refresh-instances", ());
-
-            if force_immediately {
-                Ok(format!("Force-stopped instance {}", instance_id))
-            } else if forced {
-                Ok(format!("Instance {} did not close gracefully, so it was force-stopped", instance_id))
+    let mut forced = false;
+    for (key, info) in sessions {
+        if let Ok(mut stopped) = USER_STOPPED_INSTANCES.lock() {
+            stopped.insert(key.clone());
+        }
+        forced |= if force_immediately {
+            if instances::is_process_running(info.pid) {
+                terminate_pid(info.pid)?;
+                true
             } else {
-                Ok(format!("Stopped instance {}", instance_id))
+                false
             }
-        }
-        None => Err("Instance is not running".to_string()),
+        } else {
+            stop_pid_with_fallback(info.pid)?
+        };
+
+        RUNNING_PROCESSES.lock().map_err(|_| "Process state corrupted")?.remove(&key);
+        sync_discord_presence();
+        instances::clear_active_session(&key);
+    }
+    refresh_tray_menu(app_handle);
+    let _ = app_handle.emit("refresh-instances", ());
+
+    if force_immediately {
+        Ok(format!("Force-stopped instance {}", instance_id))
+    } else if forced {
+        Ok(format!("Instance {} did not close gracefully, so it was force-stopped", instance_id))
+    } else {
+        Ok(format!("Stopped instance {}", instance_id))
     }
 }
 
@@ -537,6 +862,17 @@ pub struct VersionListItem {
     pub release_time: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VersionSummary {
+    pub id: String,
+    pub version_type: String,
+    pub release_time: String,
+    pub is_snapshot: bool,
+    pub is_old: bool,
+    pub required_java: Option<u32>,
+    pub java_warning: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LoaderVersion {
     pub version: String,
@@ -665,6 +1001,22 @@ fn get_global_stats() -> Result<GlobalStats, String> {
     Ok(stats)
 }
 
+#[tauri::command]
+fn get_instance_sessions(instance_id: String) -> Vec<instances::InstanceSession> {
+    instances::get_instance_sessions(&instance_id)
+}
+
+// ----------
+// export_stats_csv
+// Description: Writes every recorded play session to a CSV for charting outside the launcher
+// ----------
+#[tauri::command]
+async fn export_stats_csv(destination_path: String) -> Result<usize, String> {
+    tokio::task::spawn_blocking(move || instances::export_sessions_csv(Path::new(&destination_path)))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
 #[tauri::command]
 fn get_bootstrap_time() -> f64 {
     BOOTSTRAP_START.elapsed().as_secs_f64()
@@ -675,6 +1027,140 @@ fn log_event(level: String, message: String, app_handle: AppHandle) {
     logger::emit_log(&app_handle, &level, &message);
 }
 
+/// Replace known account tokens, and anything following a token flag or key, with a placeholder
+fn redact_secrets(text: &str, secrets: &[String]) -> String {
+    let mut redacted = text.to_string();
+    for secret in secrets.iter().filter(|secret| secret.len() >= 8) {
+        redacted = redacted.replace(secret.as_str(), "<redacted>");
+    }
+
+    // Tokens of accounts that were removed since are still caught by what precedes them
+    for marker in ["--accessToken ", "accessToken=", "\"access_token\":\"", "\"accessToken\":\"", "\"refresh_token\":\""] {
+        let mut output = String::with_capacity(redacted.len());
+        let mut rest = redacted.as_str();
+        while let Some(index) = rest.find(marker) {
+            let value_start = index + marker.len();
+            output.push_str(&rest[..value_start]);
+            let value = &rest[value_start..];
+            let value_len = value
+                .find(|c: char| c.is_whitespace() || matches!(c, '"' | ',' | '&' | ';'))
+                .unwrap_or(value.len());
+            if value_len > 0 && &value[..value_len] != "<redacted>" {
+                output.push_str("<redacted>");
+            } else {
+                output.push_str(&value[..value_len]);
+            }
+            rest = &value[value_len..];
+        }
+        output.push_str(rest);
+        redacted = output;
+    }
+    redacted
+}
+
+// ----------
+// export_debug_bundle
+// Description: Zips the launcher logs, the instance's latest.log, version JSON and metadata,
+//              and a system summary into one file for bug reports. Tokens are stripped.
+// ----------
+#[tauri::command]
+async fn export_debug_bundle(
+    app_handle: AppHandle,
+    destination_path: String,
+    instance_id: Option<String>,
+) -> Result<String, String> {
+    let log_dir = app_handle.path().app_log_dir().ok();
+    let instance = match instance_id.as_deref() {
+        Some(id) => Some(instances::get_instance(id)?),
+        None => None,
+    };
+
+    tokio::task::spawn_blocking(move || {
+        use std::io::Write;
+        use zip::write::SimpleFileOptions;
+
+        let secrets: Vec<String> = auth::load_accounts()
+            .accounts
+            .into_iter()
+            .flat_map(|account| std::iter::once(account.access_token).chain(account.refresh_token))
+            .collect();
+
+        let mut entries: Vec<(String, String)> = Vec::new();
+
+        if let Some(log_dir) = log_dir.as_ref() {
+            if let Ok(read_dir) = fs::read_dir(log_dir) {
+                for entry in read_dir.flatten() {
+                    let path = entry.path();
+                    if path.extension().map_or(false, |ext| ext == "log") {
+                        if let Ok(content) = fs::read(&path) {
+                            let name = entry.file_name().to_string_lossy().to_string();
+                            entries.push((format!("launcher/{}", name), String::from_utf8_lossy(&content).to_string()));
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(instance) = instance.as_ref() {
+            let latest_log = instance.get_game_directory().join("logs").join("latest.log");
+            if let Ok(content) = fs::read(&latest_log) {
+                entries.push(("instance/latest.log".to_string(), String::from_utf8_lossy(&content).to_string()));
+            }
+            if let Ok(json) = serde_json::to_string_pretty(instance) {
+                entries.push(("instance/instance.json".to_string(), json));
+            }
+            let version_json = downloader::get_versions_dir()
+                .join(&instance.version_id)
+                .join(format!("{}.json", &instance.version_id));
+            if let Ok(content) = fs::read_to_string(&version_json) {
+                entries.push((format!("instance/{}.json", instance.version_id), content));
+            }
+        }
+
+        let mut system_info = String::new();
+        system_info.push_str(&format!("Launcher version: {}\n", minecraft::get_launcher_version()));
+        system_info.push_str(&format!("OS: {} ({})\n", std::env::consts::OS, std::env::consts::FAMILY));
+        system_info.push_str(&format!("Arch: {}\n", std::env::consts::ARCH));
+        system_info.push_str(&format!("Data directory: {}\n", downloader::get_minecraft_dir().display()));
+        system_info.push_str(&format!("Log level: {}\n", settings::get_log_level()));
+        system_info.push_str("Java runtimes:\n");
+        let runtimes = launcher::detected_java_runtimes();
+        if runtimes.is_empty() {
+            system_info.push_str("  none found\n");
+        }
+        for (path, major) in runtimes {
+            let version = major.map(|m| format!("Java {}", m)).unwrap_or_else(|| "unknown version".to_string());
+            system_info.push_str(&format!("  {} ({})\n", path.display(), version));
+        }
+        entries.push(("system-info.txt".to_string(), system_info));
+
+        let destination = std::path::PathBuf::from(&destination_path);
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create folder: {}", e))?;
+        }
+        let file = fs::File::create(&destination).map_err(|e| format!("Failed to create bundle: {}", e))?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+        let write_entries = |zip: &mut zip::ZipWriter<fs::File>| -> Result<(), String> {
+            for (name, content) in &entries {
+                zip.start_file(name.as_str(), options).map_err(|e| e.to_string())?;
+                zip.write_all(redact_secrets(content, &secrets).as_bytes()).map_err(|e| e.to_string())?;
+            }
+            Ok(())
+        };
+        let result = write_entries(&mut zip)
+            .and_then(|_| zip.finish().map(|_| ()).map_err(|e| format!("Failed to finalize bundle: {}", e)));
+        if let Err(e) = result {
+            let _ = fs::remove_file(&destination);
+            return Err(e);
+        }
+        Ok(destination.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+}
+
 // ============== VERSION COMMANDS ==============
 
 #[tauri::command]
@@ -682,14 +1168,35 @@ async fn open_url(url: String) -> Result<(), String> {
     open::that(url).map_err(|e| e.to_string())
 }
 
+/// Probe connectivity. While offline, cached data is served and online-only pages are hidden.
 #[tauri::command]
-async fn get_versions() -> Result<Vec<VersionListItem>, String> {
-    let manifest = versions::fetch_version_manifest()
+async fn is_online(app_handle: AppHandle) -> bool {
+    let was_offline = minecraft::is_offline();
+    let online = minecraft::probe_connectivity().await;
+    if was_offline == online {
+        log_info!(&app_handle, "Connectivity changed: {}", if online { "online" } else { "offline" });
+        let _ = app_handle.emit("offline-mode-changed", !online);
+    }
+    online
+}
+
+/// `force` re-downloads the manifest instead of trusting the cached copy
+#[tauri::command]
+async fn get_versions(
+    force: Option<bool>,
+    include_snapshots: Option<bool>,
+    include_old: Option<bool>,
+) -> Result<Vec<VersionListItem>, String> {
+    let manifest = versions::fetch_version_manifest_with(force.unwrap_or(false))
         .await
         .map_err(|e| e.to_string())?;
+    let include_snapshots = include_snapshots.unwrap_or(true);
+    let include_old = include_old.unwrap_or(true);
     
     let versions: Vec<VersionListItem> = manifest.versions
         .into_iter()
+        .filter(|v| include_snapshots || v.version_type != "snapshot")
+        .filter(|v| include_old || !versions::is_old_version_type(&v.version_type))
         .map(|v| VersionListItem {
             id: v.id,
             version_type: v.version_type,
@@ -700,6 +1207,40 @@ async fn get_versions() -> Result<Vec<VersionListItem>, String> {
     Ok(versions)
 }
 
+/// Get release date, type and Java requirement for a single Minecraft version
+#[tauri::command]
+async fn get_version_details(version_id: String) -> Result<VersionSummary, String> {
+    let details = versions::load_or_fetch_version_details(&version_id)
+        .await
+        .map_err(|e| format!("Failed to load version {}: {}", version_id, e))?;
+    let needs_java8 = launcher::needs_java8(&details);
+    let required_java = details
+        .java_version
+        .as_ref()
+        .map(|java| java.major_version as u32)
+        .or(needs_java8.then_some(8));
+    // Most versions up to 1.16.5 declare Java 8 but run fine on newer Java; only warn for
+    // the ones that really break on it
+    let java_warning = needs_java8.then(|| {
+        format!("Minecraft {} needs Java 8 and may crash on newer Java versions", details.id)
+    });
+    Ok(VersionSummary {
+        is_snapshot: details.version_type == "snapshot",
+        is_old: versions::is_old_version_type(&details.version_type),
+        id: details.id,
+        version_type: details.version_type,
+        release_time: details.release_time,
+        required_java,
+        java_warning,
+    })
+}
+
+/// Unix seconds when the version list was last confirmed current with Mojang
+#[tauri::command]
+fn get_versions_updated_at() -> Option<u64> {
+    versions::manifest_cache_checked_at()
+}
+
 #[tauri::command]
 async fn get_latest_release() -> Result<String, String> {
     let manifest = versions::fetch_version_manifest()
@@ -713,11 +1254,36 @@ async fn get_latest_release() -> Result<String, String> {
 
 #[tauri::command]
 fn get_instances() -> Result<Vec<instances::Instance>, String> {
-    instances::load_instances()
+    instances::load_instances_ordered()
 }
 
+/// Save a manual instance order; ids not in the list are kept after the listed ones
 #[tauri::command]
-fn create_instance(name: String, version_id: String, app_handle: AppHandle) -> Result<instances::Instance, String> {
+fn reorder_instances(ordered_ids: Vec<String>, app_handle: AppHandle) -> Result<Vec<instances::Instance>, String> {
+    let result = instances::reorder_instances(&ordered_ids)?;
+    let _ = app_handle.emit("refresh-instances", ());
+    Ok(result)
+}
+
+/// Check an instance name as the user types it; returns the name as it would be saved
+#[tauri::command]
+fn validate_instance_name(name: String) -> Result<String, String> {
+    instances::normalize_instance_name(&name)
+}
+
+#[tauri::command]
+fn create_instance(
+    name: String,
+    version_id: String,
+    lenient: Option<bool>,
+    app_handle: AppHandle,
+) -> Result<instances::Instance, String> {
+    // Names from share codes and modpacks are not typed by the user, so trim them to fit instead of failing
+    let name = if lenient.unwrap_or(false) {
+        instances::fit_instance_name(&name, "New Instance")
+    } else {
+        name
+    };
     let instance = instances::create_instance(name, version_id)?;
     let _ = app_handle.emit("refresh-instances", ());
     Ok(instance)
@@ -726,50 +1292,313 @@ fn create_instance(name: String, version_id: String, app_handle: AppHandle) -> R
 #[tauri::command]
 async fn delete_instance(instance_id: String, app_handle: AppHandle) -> Result<(), String> {
     instances::delete_instance(&instance_id).await?;
+    files::invalidate_instance_summary(&instance_id);
+    if settings::get_last_view().as_deref() == Some(instance_id.as_str()) {
+        let _ = settings::set_last_view(None);
+    }
     let _ = app_handle.emit("refresh-instances", ());
     Ok(())
 }
 
+fn locked_fields_changed(current: &instances::Instance, incoming: &instances::Instance) -> bool {
+    current.version_id != incoming.version_id
+        || current.mod_loader != incoming.mod_loader
+        || current.mod_loader_version != incoming.mod_loader_version
+        || current.memory_min != incoming.memory_min
+        || current.memory_max != incoming.memory_max
+        || current.java_path != incoming.java_path
+        || current.jvm_args != incoming.jvm_args
+        || current.jvm_preset != incoming.jvm_preset
+}
+
 #[tauri::command]
-fn update_instance(instance: instances::Instance, app_handle: AppHandle) -> Result<instances::Instance, String> {
+fn update_instance(mut instance: instances::Instance, app_handle: AppHandle) -> Result<instances::Instance, String> {
+    let current = instances::get_instance(&instance.id)?;
+    if locked_fields_changed(&current, &instance) {
+        current.ensure_unlocked()?;
+    }
+    // The lock can only be toggled through set_instance_lock
+    instance.config_locked = current.config_locked;
+    // Likewise the position only changes through reorder_instances
+    instance.order = current.order;
+    if current.name != instance.name {
+        let normalized = instances::normalize_instance_name(&instance.name)?;
+        instance.name = instances::unique_rename(&instance.id, normalized)?;
+    }
+    if current.jvm_args != instance.jvm_args {
+        if let Some(args) = &instance.jvm_args {
+            let parsed: Vec<String> = args.split_whitespace().map(|arg| arg.to_string()).collect();
+            launcher::validate_jvm_args(&parsed)?;
+        }
+    }
+    if (current.resolution_width, current.resolution_height) != (instance.resolution_width, instance.resolution_height) {
+        if let (Some(width), Some(height)) = (instance.resolution_width, instance.resolution_height) {
+            validate_resolution(&app_handle, width, height)?;
+        }
+    }
+    if current.memory_max != instance.memory_max {
+        if let Some(warning) = instance.memory_max.and_then(memory_allocation_warning) {
+            log_warn!(&app_handle, "{}: {}", instance.name, warning);
+        }
+    }
+
     let result = instances::update_instance(instance)?;
+    if result.separate_loader_mods && !current.separate_loader_mods {
+        // The flat mods/ belongs to the loader the instance had before this save, which may differ from the new one
+        if let Err(e) = files::mark_mods_dir_loader(&result, &current.mod_loader) {
+            log_warn!(&app_handle, "{}: {}", result.name, e);
+        }
+    }
+    if current.mod_loader != result.mod_loader || current.separate_loader_mods != result.separate_loader_mods {
+        // Launch tries again, so a failed swap only needs reporting here
+        match files::sync_loader_mods_dir(&result) {
+            Ok(true) => log_info!(&app_handle, "{}: switched to the {} mods folder", result.name, result.mod_loader),
+            Ok(false) => {}
+            Err(e) => log_warn!(&app_handle, "{}: {}", result.name, e),
+        }
+    }
     let _ = app_handle.emit("refresh-instances", ());
     Ok(result)
 }
 
+/// Why a max heap is risky on this machine, if it is. Only warns; the user may know better.
+fn memory_allocation_warning(memory_max_mb: u32) -> Option<String> {
+    let system = settings::get_system_memory();
+    let memory_max_mb = memory_max_mb as u64;
+    if system.total_mb == 0 {
+        return None;
+    }
+    if memory_max_mb >= system.total_mb {
+        return Some(format!("{} MB max memory is more than the {} MB installed", memory_max_mb, system.total_mb));
+    }
+    if memory_max_mb > system.total_mb * 3 / 4 {
+        return Some(format!("{} MB max memory leaves little of the {} MB installed for the system", memory_max_mb, system.total_mb));
+    }
+    if memory_max_mb > system.available_mb {
+        return Some(format!("{} MB max memory is more than the {} MB currently free", memory_max_mb, system.available_mb));
+    }
+    None
+}
+
 #[tauri::command]
-async fn clone_instance(instance_id: String, new_name: String, app_handle: AppHandle) -> Result<instances::Instance, String> {
-    // 1. Load source instance
-    let source = instances::get_instance(&instance_id)?;
-    
-    // 2. Create new instance with same version
-    let mut cloned = instances::create_instance(new_name, source.version_id.clone())?;
-    
-    // 3. Copy settings from source to cloned
-    cloned.java_path = source.java_path.clone();
-    cloned.jvm_args = source.jvm_args.clone();
-    cloned.memory_min = source.memory_min;
-    cloned.memory_max = source.memory_max;
-    cloned.resolution_width = source.resolution_width;
-    cloned.resolution_height = source.resolution_height;
-    cloned.mod_loader = source.mod_loader.clone();
-    cloned.mod_loader_version = source.mod_loader_version.clone();
-    cloned.console_auto_update = source.console_auto_update;
-    cloned.logo_filename = source.logo_filename.clone();
-    cloned.color_accent = source.color_accent.clone();
-    cloned.category = source.category.clone();
-    cloned.preferred_account = source.preferred_account.clone();
-    cloned.check_mod_updates_on_launch = source.check_mod_updates_on_launch;
-    
-    // Update the saved metadata
-    instances::update_instance(cloned.clone())?;
-    
-    // 4. Copy game directory with progress
-    let source_game_dir = source.get_game_directory();
+fn get_system_memory() -> settings::SystemMemory {
+    settings::get_system_memory()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MonitorResolution {
+    pub name: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub scale_factor: f64,
+    pub is_primary: bool,
+}
+
+/// Sizes of the connected monitors in physical pixels, primary monitor first
+#[tauri::command]
+fn get_monitor_resolutions(app_handle: AppHandle) -> Result<Vec<MonitorResolution>, String> {
+    let primary = app_handle
+        .primary_monitor()
+        .ok()
+        .flatten()
+        .map(|monitor| (monitor.name().cloned(), *monitor.position()));
+    let mut monitors: Vec<MonitorResolution> = app_handle
+        .available_monitors()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|monitor| MonitorResolution {
+            is_primary: primary.as_ref().is_some_and(|(name, position)| name.as_ref() == monitor.name() && position == monitor.position()),
+            name: monitor.name().cloned(),
+            width: monitor.size().width,
+            height: monitor.size().height,
+            scale_factor: monitor.scale_factor(),
+        })
+        .collect();
+    monitors.sort_by_key(|monitor| !monitor.is_primary);
+    Ok(monitors)
+}
+
+/// Reject a game resolution larger than every connected monitor. Skipped when no monitor can be read.
+fn validate_resolution(app_handle: &AppHandle, width: u32, height: u32) -> Result<(), String> {
+    let monitors = get_monitor_resolutions(app_handle.clone()).unwrap_or_default();
+    if monitors.is_empty() || monitors.iter().any(|monitor| width <= monitor.width && height <= monitor.height) {
+        return Ok(());
+    }
+    let largest = monitors.iter().max_by_key(|monitor| monitor.width as u64 * monitor.height as u64);
+    Err(match largest {
+        Some(monitor) => format!("{}x{} is larger than any connected monitor (largest is {}x{})", width, height, monitor.width, monitor.height),
+        None => format!("{}x{} is larger than any connected monitor", width, height),
+    })
+}
+
+#[tauri::command]
+fn import_jvm_args_from_file(instance_id: String, file_path: String, app_handle: AppHandle) -> Result<instances::Instance, String> {
+    let mut instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+
+    let content = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read JVM args file: {}", e))?;
+    let args = launcher::parse_jvm_args_file(&content);
+    launcher::validate_jvm_args(&args)?;
+
+    instance.jvm_args = if args.is_empty() { None } else { Some(args.join(" ")) };
+    let result = instances::update_instance(instance)?;
+    let _ = app_handle.emit("refresh-instances", ());
+    Ok(result)
+}
+
+#[tauri::command]
+fn export_jvm_args(instance_id: String, dest: String) -> Result<(), String> {
+    let instance = instances::get_instance(&instance_id)?;
+    let mut content = format!("# JVM arguments for {}\n", instance.name);
+    if let Some(args) = &instance.jvm_args {
+        for arg in args.split_whitespace() {
+            content.push_str(arg);
+            content.push('\n');
+        }
+    }
+    fs::write(&dest, content).map_err(|e| format!("Failed to write JVM args file: {}", e))
+}
+
+#[tauri::command]
+fn get_jvm_presets() -> Vec<launcher::JvmPreset> {
+    launcher::jvm_presets()
+}
+
+#[tauri::command]
+fn set_instance_lock(instance_id: String, locked: bool, app_handle: AppHandle) -> Result<instances::Instance, String> {
+    let result = instances::set_instance_lock(&instance_id, locked)?;
+    let _ = app_handle.emit("refresh-instances", ());
+    Ok(result)
+}
+
+#[tauri::command]
+fn set_excluded_library(
+    instance_id: String,
+    identity: String,
+    excluded: bool,
+    app_handle: AppHandle,
+) -> Result<instances::Instance, String> {
+    let result = instances::set_excluded_library(&instance_id, &identity, excluded)?;
+    let _ = app_handle.emit("refresh-instances", ());
+    Ok(result)
+}
+
+// ----------
+// CloneOptions
+// Description: Which game-directory content clone_instance copies. Everything is copied by
+//              default; folders outside these groups (logs, shaderpacks, ...) always are.
+// ----------
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloneOptions {
+    #[serde(default = "minecraft::default_true")]
+    pub copy_worlds: bool,
+    #[serde(default = "minecraft::default_true")]
+    pub copy_mods: bool,
+    #[serde(default = "minecraft::default_true")]
+    pub copy_configs: bool,
+    #[serde(default = "minecraft::default_true")]
+    pub copy_resourcepacks: bool,
+    #[serde(default = "minecraft::default_true")]
+    pub copy_screenshots: bool,
+}
+
+impl Default for CloneOptions {
+    fn default() -> Self {
+        Self {
+            copy_worlds: true,
+            copy_mods: true,
+            copy_configs: true,
+            copy_resourcepacks: true,
+            copy_screenshots: true,
+        }
+    }
+}
+
+impl CloneOptions {
+    /// Whether a top-level entry of the game directory is copied
+    fn includes(&self, top_level: &str) -> bool {
+        match top_level {
+            "saves" => self.copy_worlds,
+            // Other loaders' mods parked in mods-<loader>/ go with mods/
+            "mods" => self.copy_mods,
+            parked if parked.starts_with("mods-") => self.copy_mods,
+            "config" | "defaultconfigs" => self.copy_configs,
+            "resourcepacks" | "texturepacks" => self.copy_resourcepacks,
+            "screenshots" => self.copy_screenshots,
+            _ => true,
+        }
+    }
+}
+
+#[tauri::command]
+async fn clone_instance(instance_id: String, new_name: String, options: Option<CloneOptions>, app_handle: AppHandle) -> Result<instances::Instance, String> {
+    let options = options.unwrap_or_default();
+
+    // 1. Load source instance
+    let source = instances::get_instance(&instance_id)?;
+    let source_game_dir = source.get_game_directory();
+    let included_entries: Vec<std::path::PathBuf> = fs::read_dir(&source_game_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| options.includes(&entry.file_name().to_string_lossy()))
+                .map(|entry| entry.path())
+                .collect()
+        })
+        .unwrap_or_default();
+    let clone_size: u64 = included_entries
+        .iter()
+        .map(|p| if p.is_dir() { get_dir_size(p) } else { p.metadata().map(|m| m.len()).unwrap_or(0) })
+        .sum();
+    downloader::ensure_disk_space(&downloader::get_instances_dir(), clone_size)?;
+    
+    // 2. Create new instance with same version
+    let new_name = instances::fit_instance_name(&new_name, &source.name);
+    let mut cloned = instances::create_instance(new_name, source.version_id.clone())?;
+    
+    // 3. Copy settings from source to cloned
+    cloned.java_path = source.java_path.clone();
+    cloned.jvm_args = source.jvm_args.clone();
+    cloned.jvm_preset = source.jvm_preset.clone();
+    cloned.auto_backup_on_launch = source.auto_backup_on_launch;
+    cloned.auto_backup_keep = source.auto_backup_keep;
+    cloned.start_fullscreen = source.start_fullscreen;
+    cloned.wrapper_command = source.wrapper_command.clone();
+    cloned.demo_mode = source.demo_mode;
+    cloned.excluded_libraries = source.excluded_libraries.clone();
+    cloned.separate_loader_mods = source.separate_loader_mods;
+    cloned.memory_min = source.memory_min;
+    cloned.memory_max = source.memory_max;
+    cloned.resolution_width = source.resolution_width;
+    cloned.resolution_height = source.resolution_height;
+    cloned.mod_loader = source.mod_loader.clone();
+    cloned.mod_loader_version = source.mod_loader_version.clone();
+    cloned.console_auto_update = source.console_auto_update;
+    cloned.logo_filename = source.logo_filename.clone();
+    cloned.color_accent = source.color_accent.clone();
+    cloned.category = source.category.clone();
+    cloned.preferred_account = source.preferred_account.clone();
+    cloned.check_mod_updates_on_launch = source.check_mod_updates_on_launch;
+    cloned.modpack_provider = source.modpack_provider.clone();
+    cloned.modpack_project_id = source.modpack_project_id.clone();
+    cloned.modpack_version_id = source.modpack_version_id.clone();
+    cloned.modpack_file_name = source.modpack_file_name.clone();
+    cloned.modpack_title = source.modpack_title.clone();
+    cloned.modpack_author = source.modpack_author.clone();
+    cloned.modpack_url = source.modpack_url.clone();
+    
+    // Update the saved metadata
+    instances::update_instance(cloned.clone())?;
+    
+    // 4. Copy the selected parts of the game directory with progress
     let new_game_dir = cloned.get_game_directory();
     
     if source_game_dir.exists() {
-        let total_files = count_files_recursive(&source_game_dir);
+        let total_files: u32 = included_entries
+            .iter()
+            .map(|p| if p.is_dir() { count_files_recursive(p) } else { 1 })
+            .sum();
         let mut current_count = 0;
         
         // Initial progress event
@@ -782,20 +1611,25 @@ async fn clone_instance(instance_id: String, new_name: String, app_handle: AppHa
             downloaded_bytes: None,
         });
 
-        copy_dir_with_progress(
+        copy_dir_filtered_with_progress(
             &source_game_dir,
             &new_game_dir,
             &app_handle,
             total_files,
             &mut current_count,
-            "Cloning"
+            "Cloning",
+            &|top_level| options.includes(top_level),
         )?;
     }
 
-    // 5. Copy mod loader config files (stored at instance root, not in game directory)
+    // 5. Copy mod loader config files and the installed modpack manifest (stored at instance root, not in game directory)
     let source_dir = source.get_directory();
     let cloned_dir = cloned.get_directory();
-    for loader_file in &["fabric.json", "forge.json", "neoforge.json"] {
+    for loader_file in &["fabric.json", "quilt.json", "forge.json", "neoforge.json", "modpack_manifest.json"] {
+        // The modpack manifest describes the mods folder, so it only makes sense alongside it
+        if *loader_file == "modpack_manifest.json" && !options.copy_mods {
+            continue;
+        }
         let src_path = source_dir.join(loader_file);
         if src_path.exists() {
             let dst_path = cloned_dir.join(loader_file);
@@ -837,7 +1671,8 @@ fn set_instance_logo(instance_id: String, source_path: String) -> Result<instanc
     fs::copy(&source, &destination)
         .map_err(|e| format!("Failed to copy logo: {}", e))?;
 
-    if let Some(old_logo) = &instance.logo_filename {
+    // Stock logos and loader badges are shared, only instance-specific files get removed
+    if let Some(old_logo) = instance.logo_filename.as_ref().filter(|logo| logo.starts_with(&format!("{}_", instance.id))) {
         let old_path = downloader::get_instance_logos_dir().join(old_logo);
         let _ = fs::remove_file(old_path);
     }
@@ -851,40 +1686,10 @@ fn set_instance_logo(instance_id: String, source_path: String) -> Result<instanc
 async fn set_instance_logo_from_url(instance_id: String, logo_url: String, app_handle: AppHandle) -> Result<instances::Instance, String> {
     logger::emit_log(&app_handle, "info", &format!("Setting instance logo from URL: {}", logo_url));
     let mut instance = instances::get_instance(&instance_id)?;
-    downloader::ensure_instance_logos_dir()?;
+    let filename = downloader::download_instance_logo(&instance_id, &logo_url).await?;
 
-    let client = minecraft::http_client();
-    let response = client.get(&logo_url)
-        .header("User-Agent", "PaletheaLauncher/0.1.0")
-        .send()
-        .await
-        .map_err(|e| format!("Failed to download logo: {}", e))?
-        .error_for_status()
-        .map_err(|e| format!("Logo download returned an error status: {}", e))?;
-
-    let bytes = response.bytes().await.map_err(|e| format!("Failed to get logo bytes: {}", e))?;
-
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-    
-    // Better extension detection
-    let url_no_query = logo_url.split('?').next().unwrap_or(&logo_url);
-    let ext = url_no_query.split('.').last().unwrap_or("png").to_lowercase();
-    let safe_ext = if ext == "jpg" || ext == "jpeg" || ext == "png" || ext == "webp" {
-        ext
-    } else {
-        "png".to_string()
-    };
-
-    let filename = format!("{}_{}.{}", instance_id, timestamp, safe_ext);
-    let destination = downloader::get_instance_logos_dir().join(&filename);
-
-    fs::write(&destination, &bytes)
-        .map_err(|e| format!("Failed to save logo: {}", e))?;
-
-    if let Some(old_logo) = &instance.logo_filename {
+    // Stock logos and loader badges are shared, only instance-specific files get removed
+    if let Some(old_logo) = instance.logo_filename.as_ref().filter(|logo| logo.starts_with(&format!("{}_", instance.id))) {
         let old_path = downloader::get_instance_logos_dir().join(old_logo);
         let _ = fs::remove_file(old_path);
     }
@@ -898,7 +1703,8 @@ async fn set_instance_logo_from_url(instance_id: String, logo_url: String, app_h
 fn clear_instance_logo(instance_id: String) -> Result<instances::Instance, String> {
     let mut instance = instances::get_instance(&instance_id)?;
 
-    if let Some(old_logo) = &instance.logo_filename {
+    // Stock logos and loader badges are shared, only instance-specific files get removed
+    if let Some(old_logo) = instance.logo_filename.as_ref().filter(|logo| logo.starts_with(&format!("{}_", instance.id))) {
         let old_path = downloader::get_instance_logos_dir().join(old_logo);
         let _ = fs::remove_file(old_path);
     }
@@ -933,6 +1739,125 @@ fn save_instance_options(instance_id: String, content: String) -> Result<(), Str
     fs::write(options_path, content).map_err(|e| e.to_string())
 }
 
+// Mod config files that only hold client video settings, safe to carry between instances
+const VIDEO_CONFIG_FILES: &[&str] = &[
+    "sodium-options.json",
+    "embeddium-options.json",
+    "iris.properties",
+    "oculus.properties",
+];
+
+/// Merge `key:value` lines from `source` into `target`. Keys already in the target are
+/// updated in place, new keys are appended. With `keys_only` only `key_` lines are taken.
+/// The target keeps its own `version`, which tells the game which data version wrote the file;
+/// the source's is only taken when the target has none.
+fn merge_options_lines(target: &str, source: &str, keys_only: bool) -> String {
+    let mut lines: Vec<String> = target.lines().map(|line| line.to_string()).collect();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for (i, line) in lines.iter().enumerate() {
+        if let Some((key, _)) = line.split_once(':') {
+            index.entry(key.to_string()).or_insert(i);
+        }
+    }
+
+    for line in source.lines() {
+        let Some((key, _)) = line.split_once(':') else {
+            continue;
+        };
+        if key == "version" {
+            if index.contains_key(key) {
+                continue;
+            }
+        } else if keys_only && !key.starts_with("key_") {
+            continue;
+        }
+        match index.get(key) {
+            Some(&i) => lines[i] = line.to_string(),
+            None => {
+                index.insert(key.to_string(), lines.len());
+                lines.push(line.to_string());
+            }
+        }
+    }
+
+    let mut merged = lines.join("\n");
+    merged.push('\n');
+    merged
+}
+
+#[tauri::command]
+fn copy_options_from(
+    source_instance_id: String,
+    target_instance_id: String,
+    selective: Option<bool>,
+) -> Result<Vec<String>, String> {
+    if source_instance_id == target_instance_id {
+        return Err("Source and target instance are the same".to_string());
+    }
+    let source = instances::get_instance(&source_instance_id)?;
+    let target = instances::get_instance(&target_instance_id)?;
+    let keys_only = selective.unwrap_or(false);
+    let source_dir = source.get_game_directory();
+    let target_dir = target.get_game_directory();
+    fs::create_dir_all(&target_dir).map_err(|e| e.to_string())?;
+
+    let mut copied = Vec::new();
+
+    // OptiFine keeps its video settings in optionsof.txt, which has no keybinds
+    let option_files: &[&str] = if keys_only { &["options.txt"] } else { &["options.txt", "optionsof.txt"] };
+    for file_name in option_files {
+        let source_path = source_dir.join(file_name);
+        if !source_path.exists() {
+            continue;
+        }
+        let source_content = fs::read_to_string(&source_path).map_err(|e| e.to_string())?;
+        let target_path = target_dir.join(file_name);
+        let target_content = fs::read_to_string(&target_path).unwrap_or_default();
+        let merged = merge_options_lines(&target_content, &source_content, keys_only);
+        fs::write(&target_path, merged).map_err(|e| e.to_string())?;
+        copied.push(file_name.to_string());
+    }
+
+    if !keys_only {
+        let target_config = target_dir.join("config");
+        for file_name in VIDEO_CONFIG_FILES {
+            let source_path = source_dir.join("config").join(file_name);
+            let target_path = target_config.join(file_name);
+            // These are whole-file configs; keep whatever the target already has
+            if !source_path.exists() || target_path.exists() {
+                continue;
+            }
+            fs::create_dir_all(&target_config).map_err(|e| e.to_string())?;
+            fs::copy(&source_path, &target_path).map_err(|e| e.to_string())?;
+            copied.push(format!("config/{}", file_name));
+        }
+    }
+
+    if copied.is_empty() {
+        return Err(format!("\"{}\" has no options to copy yet", source.name));
+    }
+
+    Ok(copied)
+}
+
+#[tauri::command]
+fn list_instance_configs(instance_id: String) -> Result<Vec<files::ConfigFile>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    Ok(files::list_configs(&instance))
+}
+
+#[tauri::command]
+fn read_instance_config(instance_id: String, rel_path: String) -> Result<String, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    files::read_config(&instance, &rel_path)
+}
+
+#[tauri::command]
+fn write_instance_config(instance_id: String, rel_path: String, content: String) -> Result<(), String> {
+    let instance = instances::get_instance(&instance_id)?;
+    files::write_config(&instance, &rel_path, &content)
+}
+
 #[tauri::command]
 fn open_instance_options_file(instance_id: String) -> Result<(), String> {
     let instance = instances::get_instance(&instance_id)?;
@@ -1012,18 +1937,23 @@ fn count_files_recursive(dir: &std::path::Path) -> u32 {
     count
 }
 
-fn copy_dir_with_progress(
+/// `copy_dir_with_progress`, copying only the top-level entries of `src` whose name passes `include`
+fn copy_dir_filtered_with_progress(
     src: &std::path::Path,
     dst: &std::path::Path,
     app_handle: &AppHandle,
     total_files: u32,
     current_count: &mut u32,
     stage_prefix: &str,
+    include: &dyn Fn(&str) -> bool,
 ) -> Result<(), String> {
     fs::create_dir_all(dst).map_err(|e| e.to_string())?;
 
     for entry in fs::read_dir(src).map_err(|e| e.to_string())? {
         let entry = entry.map_err(|e| e.to_string())?;
+        if !include(&entry.file_name().to_string_lossy()) {
+            continue;
+        }
         let file_type = entry.file_type().map_err(|e| e.to_string())?;
         let src_path = entry.path();
         let dst_path = dst.join(entry.file_name());
@@ -1031,94 +1961,264 @@ fn copy_dir_with_progress(
         if file_type.is_dir() {
             copy_dir_with_progress(&src_path, &dst_path, app_handle, total_files, current_count, stage_prefix)?;
         } else {
-            *current_count += 1;
-            let file_name = entry.file_name().to_string_lossy().to_string();
-            let percentage = if total_files > 0 { (*current_count as f32 / total_files as f32) * 100.0 } else { 100.0 };
-
-            if *current_count % 50 == 0 || *current_count == total_files {
-                let _ = app_handle.emit("download-progress", downloader::DownloadProgress {
-                    stage: format!("{}: {} ({}/{})", stage_prefix, file_name, current_count, total_files),
-                    current: *current_count,
-                    total: total_files,
-                    percentage,
-                    total_bytes: None,
-                    downloaded_bytes: None,
-                });
-            }
-
-            if file_type.is_symlink() {
-                let target = fs::read_link(&src_path).map_err(|e| e.to_string())?;
-                #[cfg(unix)]
-                {
-                    use std::os::unix::fs::symlink;
-                    let _ = symlink(&target, &dst_path);
-                }
-                #[cfg(windows)]
-                {
-                    if target.is_dir() {
-                        let _ = std::os::windows::fs::symlink_dir(&target, &dst_path);
-                    } else {
-                        let _ = std::os::windows::fs::symlink_file(&target, &dst_path);
-                    }
-                }
-            } else {
-                fs::copy(&src_path, &dst_path).map_err(|e| e.to_string())?;
-            }
+            copy_entry_with_progress(&entry, &dst_path, app_handle, total_files, current_count, stage_prefix)?;
         }
     }
-    Ok(())
-}
 
-#[derive(Debug, Deserialize)]
-struct PrismPackComponent {
-    uid: String,
-    version: String,
+    Ok(())
 }
 
-#[derive(Debug, Deserialize)]
-struct PrismPackManifest {
-    components: Vec<PrismPackComponent>,
-}
+fn copy_dir_with_progress(
+    src: &std::path::Path,
+    dst: &std::path::Path,
+    app_handle: &AppHandle,
+    total_files: u32,
+    current_count: &mut u32,
+    stage_prefix: &str,
+) -> Result<(), String> {
+    fs::create_dir_all(dst).map_err(|e| e.to_string())?;
 
-#[derive(Debug, Default)]
-struct PrismIndexMeta {
-    filename: Option<String>,
-    name: Option<String>,
-    project_id: Option<String>,
-    version_id: Option<String>,
-}
+    for entry in fs::read_dir(src).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let file_type = entry.file_type().map_err(|e| e.to_string())?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
 
-fn clean_import_name(custom_name: Option<String>) -> Option<String> {
-    custom_name.and_then(|n| {
-        let trimmed = n.trim();
-        if trimmed.is_empty() {
-            None
+        if file_type.is_dir() {
+            copy_dir_with_progress(&src_path, &dst_path, app_handle, total_files, current_count, stage_prefix)?;
         } else {
-            Some(trimmed.to_string())
+            copy_entry_with_progress(&entry, &dst_path, app_handle, total_files, current_count, stage_prefix)?;
         }
-    })
+    }
+    Ok(())
 }
 
-fn is_prism_instance_folder(path: &std::path::Path) -> bool {
-    path.is_dir()
-        && path.join("instance.cfg").is_file()
-        && path.join("mmc-pack.json").is_file()
-        && path.join("minecraft").is_dir()
-}
+/// Copy one file (or recreate one symlink) and report progress every 50 files
+fn copy_entry_with_progress(
+    entry: &fs::DirEntry,
+    dst_path: &std::path::Path,
+    app_handle: &AppHandle,
+    total_files: u32,
+    current_count: &mut u32,
+    stage_prefix: &str,
+) -> Result<(), String> {
+    let file_type = entry.file_type().map_err(|e| e.to_string())?;
+    let src_path = entry.path();
 
-fn parse_prism_value(raw: &str) -> String {
-    let trimmed = raw.trim();
-    if trimmed.len() >= 2 {
-        let first = trimmed.chars().next().unwrap_or_default();
-        let last = trimmed.chars().last().unwrap_or_default();
-        if (first == '\'' && last == '\'') || (first == '"' && last == '"') {
-            return trimmed[1..trimmed.len() - 1].to_string();
+    *current_count += 1;
+    let file_name = entry.file_name().to_string_lossy().to_string();
+    let percentage = if total_files > 0 { (*current_count as f32 / total_files as f32) * 100.0 } else { 100.0 };
+
+    if *current_count % 50 == 0 || *current_count == total_files {
+        let _ = app_handle.emit("download-progress", downloader::DownloadProgress {
+            stage: format!("{}: {} ({}/{})", stage_prefix, file_name, current_count, total_files),
+            current: *current_count,
+            total: total_files,
+            percentage,
+            total_bytes: None,
+            downloaded_bytes: None,
+        });
+    }
+
+    if file_type.is_symlink() {
+        let target = fs::read_link(&src_path).map_err(|e| e.to_string())?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::symlink;
+            let _ = symlink(&target, dst_path);
         }
+        #[cfg(windows)]
+        {
+            if target.is_dir() {
+                let _ = std::os::windows::fs::symlink_dir(&target, dst_path);
+            } else {
+                let _ = std::os::windows::fs::symlink_file(&target, dst_path);
+            }
+        }
+    } else {
+        fs::copy(&src_path, dst_path).map_err(|e| e.to_string())?;
     }
-    trimmed.to_string()
+    Ok(())
 }
 
-fn read_prism_instance_name_from_content(content: &str) -> Option<String> {
+/// Link `link` to the directory `target`. Windows falls back to a junction, which unlike
+/// a directory symlink needs neither admin rights nor developer mode.
+fn create_dir_link(target: &std::path::Path, link: &std::path::Path) -> Result<(), String> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, link).map_err(|e| e.to_string())
+    }
+    #[cfg(windows)]
+    {
+        if std::os::windows::fs::symlink_dir(target, link).is_ok() {
+            return Ok(());
+        }
+        let status = std::process::Command::new("cmd")
+            .args(["/C", "mklink", "/J"])
+            .arg(link)
+            .arg(target)
+            .creation_flags(CREATE_NO_WINDOW)
+            .status()
+            .map_err(|e| e.to_string())?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(format!("mklink /J exited with {}", status))
+        }
+    }
+}
+
+/// Move a directory, by rename when source and destination share a drive and by copy
+/// then delete otherwise. A failed copy leaves the source untouched.
+fn move_dir_with_progress(src: &std::path::Path, dst: &std::path::Path, app_handle: &AppHandle, stage_prefix: &str) -> Result<(), String> {
+    if fs::rename(src, dst).is_ok() {
+        return Ok(());
+    }
+    let total_files = count_files_recursive(src);
+    let mut copied = 0u32;
+    if let Err(e) = copy_dir_with_progress(src, dst, app_handle, total_files, &mut copied, stage_prefix) {
+        let _ = fs::remove_dir_all(dst);
+        return Err(e);
+    }
+    fs::remove_dir_all(src).map_err(|e| format!("Copied to {} but could not remove {}: {}", dst.display(), src.display(), e))
+}
+
+// ----------
+// relocate_instance_data
+// Description: Moves an instance's game directory to `new_path`/<instance id> and leaves a
+//              symlink at the usual location, so every path lookup keeps working. With no
+//              new_path the data is moved back and the symlink removed.
+// ----------
+#[tauri::command]
+async fn relocate_instance_data(instance_id: String, new_path: Option<String>, app_handle: AppHandle) -> Result<instances::Instance, String> {
+    let mut instance = instances::get_instance(&instance_id)?;
+    if is_instance_running(&instance_id) {
+        return Err(format!("Close {} before moving its data", instance.name));
+    }
+    if instance.game_directory.is_some() {
+        return Err("This instance already uses a custom game directory".to_string());
+    }
+
+    let result = tokio::task::spawn_blocking(move || {
+        let link = instance.get_directory().join("minecraft");
+        let current_real = instance.relocated_game_dir.as_deref().map(std::path::PathBuf::from);
+        let new_path = new_path.map(|p| p.trim().to_string()).filter(|p| !p.is_empty());
+
+        match new_path {
+            None => {
+                let Some(real_dir) = current_real else {
+                    return Ok(instance);
+                };
+                // `link` still resolves to the relocated drive, so measure the instances drive itself
+                downloader::ensure_disk_space(&instance.get_directory(), get_dir_size(&real_dir))?;
+                if fs::symlink_metadata(&link).map(|m| m.file_type().is_symlink()).unwrap_or(false) {
+                    // Directory links and junctions on Windows need remove_dir; neither touches the target
+                    fs::remove_file(&link).or_else(|_| fs::remove_dir(&link))
+                        .map_err(|e| format!("Failed to remove link {}: {}", link.display(), e))?;
+                }
+                if let Err(e) = move_dir_with_progress(&real_dir, &link, &app_handle, "Moving instance data") {
+                    let _ = create_dir_link(&real_dir, &link);
+                    return Err(format!("Failed to move data back: {}", e));
+                }
+                instance.relocated_game_dir = None;
+            }
+            Some(new_path) => {
+                let parent = std::path::PathBuf::from(new_path);
+                if !parent.is_absolute() {
+                    return Err("The new location must be an absolute path".to_string());
+                }
+                let target = parent.join(&instance.id);
+                let source = current_real.clone().unwrap_or_else(|| link.clone());
+                let source_real = fs::canonicalize(&source).unwrap_or_else(|_| source.clone());
+                let target_parent_real = fs::canonicalize(&parent).unwrap_or_else(|_| parent.clone());
+                if target_parent_real.starts_with(&source_real) || target_parent_real.starts_with(instance.get_directory()) {
+                    return Err("The new location cannot be inside the instance's own folder".to_string());
+                }
+                if target.exists() {
+                    return Err(format!("{} already exists", target.display()));
+                }
+
+                fs::create_dir_all(&source).map_err(|e| e.to_string())?;
+                downloader::ensure_disk_space(&parent, get_dir_size(&source_real))?;
+                fs::create_dir_all(&parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+
+                if current_real.is_some() {
+                    // Already relocated: move the real data and re-point the link
+                    move_dir_with_progress(&source_real, &target, &app_handle, "Moving instance data")?;
+                    fs::remove_file(&link).or_else(|_| fs::remove_dir(&link)).map_err(|e| e.to_string())?;
+                } else {
+                    move_dir_with_progress(&link, &target, &app_handle, "Moving instance data")?;
+                }
+                if let Err(e) = create_dir_link(&target, &link) {
+                    // Without the link the instance would look empty, so undo the move
+                    let _ = move_dir_with_progress(&target, &link, &app_handle, "Restoring instance data");
+                    instance.relocated_game_dir = None;
+                    let _ = instances::update_instance(instance);
+                    return Err(format!("Failed to link the new location: {}", e));
+                }
+                instance.relocated_game_dir = Some(target.to_string_lossy().to_string());
+            }
+        }
+        instances::update_instance(instance)
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    files::invalidate_instance_summary(&instance_id);
+    let _ = app_handle.emit("refresh-instances", ());
+    Ok(result)
+}
+
+#[derive(Debug, Deserialize)]
+struct PrismPackComponent {
+    uid: String,
+    version: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PrismPackManifest {
+    components: Vec<PrismPackComponent>,
+}
+
+#[derive(Debug, Default)]
+struct PrismIndexMeta {
+    filename: Option<String>,
+    name: Option<String>,
+    project_id: Option<String>,
+    version_id: Option<String>,
+}
+
+fn clean_import_name(custom_name: Option<String>) -> Option<String> {
+    custom_name.and_then(|n| {
+        let trimmed = n.trim();
+        if trimmed.is_empty() {
+            None
+        } else {
+            Some(trimmed.to_string())
+        }
+    })
+}
+
+fn is_prism_instance_folder(path: &std::path::Path) -> bool {
+    path.is_dir()
+        && path.join("instance.cfg").is_file()
+        && path.join("mmc-pack.json").is_file()
+        && path.join("minecraft").is_dir()
+}
+
+fn parse_prism_value(raw: &str) -> String {
+    let trimmed = raw.trim();
+    if trimmed.len() >= 2 {
+        let first = trimmed.chars().next().unwrap_or_default();
+        let last = trimmed.chars().last().unwrap_or_default();
+        if (first == '\'' && last == '\'') || (first == '"' && last == '"') {
+            return trimmed[1..trimmed.len() - 1].to_string();
+        }
+    }
+    trimmed.to_string()
+}
+
+fn read_prism_instance_name_from_content(content: &str) -> Option<String> {
     for line in content.lines() {
         let trimmed = line.trim();
         if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('[') {
@@ -1164,6 +2264,12 @@ fn parse_prism_pack_info_from_content(
             continue;
         }
 
+        if uid.contains("quilt-loader") {
+            mod_loader = instances::ModLoader::Quilt;
+            mod_loader_version = Some(component.version.clone());
+            continue;
+        }
+
         if uid.contains("neoforge") || uid.contains("neoforged") {
             mod_loader = instances::ModLoader::NeoForge;
             mod_loader_version = Some(component.version.clone());
@@ -1386,6 +2492,8 @@ async fn import_prism_mod_metadata(
             icon_url: project.and_then(|p| p.icon_url.clone()),
             version_name,
             categories,
+            source_url: None,
+            pinned: false,
         };
 
         if files::write_meta_for_entry(&dest_mods_dir, &entry.filename, &meta).is_ok() {
@@ -1595,6 +2703,8 @@ async fn enrich_non_mod_modrinth_metadata(
             icon_url: entry.icon_url.clone().or_else(|| project.and_then(|p| p.icon_url.clone())),
             version_name,
             categories,
+            source_url: None,
+            pinned: false,
         };
 
         if files::write_meta_for_entry(&entry.parent_dir, &entry.filename, &meta).is_ok() {
@@ -1649,6 +2759,7 @@ fn set_instance_logo_from_local_file(
 fn prism_loader_label(loader: &instances::ModLoader) -> &'static str {
     match loader {
         instances::ModLoader::Fabric => "Fabric",
+        instances::ModLoader::Quilt => "Quilt",
         instances::ModLoader::Forge => "Forge",
         instances::ModLoader::NeoForge => "NeoForge",
         instances::ModLoader::Vanilla => "Vanilla",
@@ -1692,6 +2803,7 @@ async fn import_prism_instance_folder(
         .or_else(|| source_dir.file_name().and_then(|n| n.to_str()).map(|s| s.to_string()))
         .unwrap_or_else(|| "Imported Prism Instance".to_string());
     let instance_name = clean_import_name(custom_name).unwrap_or_else(|| format!("{} (Imported)", default_name));
+    let instance_name = instances::fit_instance_name(&instance_name, "Imported Prism Instance");
 
     logger::emit_log(
         app_handle,
@@ -1766,19 +2878,25 @@ async fn import_prism_instance_folder(
         match new_instance.mod_loader {
             instances::ModLoader::Fabric => {
                 logger::emit_log(app_handle, "info", &format!("Installing Fabric {} for imported Prism instance", loader_version));
-                fabric::install_fabric(&new_instance, loader_version)
+                fabric::install_fabric(&new_instance, loader_version, None)
                     .await
                     .map_err(|e| format!("Failed to install Fabric: {}", e))?;
             }
+            instances::ModLoader::Quilt => {
+                logger::emit_log(app_handle, "info", &format!("Installing Quilt {} for imported Prism instance", loader_version));
+                quilt::install_quilt(&new_instance, loader_version, None)
+                    .await
+                    .map_err(|e| format!("Failed to install Quilt: {}", e))?;
+            }
             instances::ModLoader::Forge => {
                 logger::emit_log(app_handle, "info", &format!("Installing Forge {} for imported Prism instance", loader_version));
-                forge::install_forge(&new_instance, loader_version)
+                forge::install_forge(&new_instance, loader_version, None)
                     .await
                     .map_err(|e| format!("Failed to install Forge: {}", e))?;
             }
             instances::ModLoader::NeoForge => {
                 logger::emit_log(app_handle, "info", &format!("Installing NeoForge {} for imported Prism instance", loader_version));
-                forge::install_neoforge(&new_instance, loader_version)
+                forge::install_neoforge(&new_instance, loader_version, None)
                     .await
                     .map_err(|e| format!("Failed to install NeoForge: {}", e))?;
             }
@@ -1796,6 +2914,74 @@ async fn import_prism_instance_folder(
     Ok(new_instance)
 }
 
+/// Recursively add a directory to a zip under `prefix`.
+/// `progress` carries the app handle and total file count when zip progress should be emitted.
+fn add_dir_to_zip<W: std::io::Write + std::io::Seek>(
+    zip: &mut zip::ZipWriter<W>,
+    base_path: &std::path::Path,
+    current_path: &std::path::Path,
+    prefix: &str,
+    options: zip::write::SimpleFileOptions,
+    progress: Option<(&AppHandle, u32)>,
+    current_count: &mut u32,
+) -> Result<(), String> {
+    add_dir_to_zip_filtered(zip, base_path, current_path, prefix, options, progress, current_count, &|_| false)
+}
+
+/// `add_dir_to_zip`, leaving out entries (files or whole folders) whose path relative to
+/// `base_path` matches `skip`
+#[allow(clippy::too_many_arguments)]
+fn add_dir_to_zip_filtered<W: std::io::Write + std::io::Seek>(
+    zip: &mut zip::ZipWriter<W>,
+    base_path: &std::path::Path,
+    current_path: &std::path::Path,
+    prefix: &str,
+    options: zip::write::SimpleFileOptions,
+    progress: Option<(&AppHandle, u32)>,
+    current_count: &mut u32,
+    skip: &dyn Fn(&std::path::Path) -> bool,
+) -> Result<(), String> {
+    for entry in fs::read_dir(current_path).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        let relative = path.strip_prefix(base_path).map_err(|e| e.to_string())?;
+        if skip(relative) {
+            continue;
+        }
+        let name = format!("{}/{}", prefix, relative.to_string_lossy().replace("\\", "/"));
+        
+        if path.is_dir() {
+            zip.add_directory(&name, options)
+                .map_err(|e| format!("Failed to add directory {}: {}", name, e))?;
+            add_dir_to_zip_filtered(zip, base_path, &path, prefix, options, progress, current_count, skip)?;
+        } else {
+            *current_count += 1;
+
+            // Only emit every 50 files or so to avoid overwhelming the frontend
+            if let Some((app_handle, total_files)) = progress {
+                if *current_count % 50 == 0 || *current_count == total_files {
+                    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+                    let percentage = (*current_count as f32 / total_files as f32) * 100.0;
+                    let _ = app_handle.emit("download-progress", downloader::DownloadProgress {
+                        stage: format!("Zipping: {} ({}/{})", file_name, current_count, total_files),
+                        current: *current_count,
+                        total: total_files,
+                        percentage,
+                        total_bytes: None,
+                        downloaded_bytes: None,
+                    });
+                }
+            }
+
+            zip.start_file(&name, options)
+                .map_err(|e| format!("Failed to start file {}: {}", name, e))?;
+            let mut file = fs::File::open(&path).map_err(|e| e.to_string())?;
+            std::io::copy(&mut file, zip).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
 #[tauri::command]
 async fn export_instance_zip(instance_id: String, destination_path: String, app_handle: AppHandle) -> Result<String, String> {
     use std::io::Write;
@@ -1822,8 +3008,11 @@ async fn export_instance_zip(instance_id: String, destination_path: String, app_
     let total_files = count_files_recursive(&game_dir);
     let mut current_count = 0;
     
-    // Create the zip file
+    // Compression usually shrinks the export, but the uncompressed size is a safe upper bound
     let zip_path = std::path::PathBuf::from(&destination_path);
+    downloader::ensure_disk_space(&zip_path, get_dir_size(&game_dir))?;
+
+    // Create the zip file
     let file = fs::File::create(&zip_path)
         .map_err(|e| format!("Failed to create zip file: {}", e))?;
     let mut zip = zip::ZipWriter::new(file);
@@ -1845,6 +3034,13 @@ async fn export_instance_zip(instance_id: String, destination_path: String, app_
         "resolution_height": instance.resolution_height,
         "color_accent": instance.color_accent,
         "category": instance.category,
+        "modpack_provider": instance.modpack_provider,
+        "modpack_project_id": instance.modpack_project_id,
+        "modpack_version_id": instance.modpack_version_id,
+        "modpack_file_name": instance.modpack_file_name,
+        "modpack_title": instance.modpack_title,
+        "modpack_author": instance.modpack_author,
+        "modpack_url": instance.modpack_url,
         "exported_at": SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap_or_default()
@@ -1860,52 +3056,7 @@ async fn export_instance_zip(instance_id: String, destination_path: String, app_
         .map_err(|e| format!("Failed to write metadata: {}", e))?;
     
     // Recursively add all files from game directory
-    fn add_dir_to_zip<W: Write + std::io::Seek>(
-        zip: &mut zip::ZipWriter<W>,
-        base_path: &std::path::Path,
-        current_path: &std::path::Path,
-        options: SimpleFileOptions,
-        app_handle: &AppHandle,
-        total_files: u32,
-        current_count: &mut u32,
-    ) -> Result<(), String> {
-        for entry in fs::read_dir(current_path).map_err(|e| e.to_string())? {
-            let entry = entry.map_err(|e| e.to_string())?;
-            let path = entry.path();
-            let relative = path.strip_prefix(base_path).map_err(|e| e.to_string())?;
-            let name = format!("minecraft/{}", relative.to_string_lossy().replace("\\", "/"));
-            
-            if path.is_dir() {
-                zip.add_directory(&name, options)
-                    .map_err(|e| format!("Failed to add directory {}: {}", name, e))?;
-                add_dir_to_zip(zip, base_path, &path, options, app_handle, total_files, current_count)?;
-            } else {
-                *current_count += 1;
-                let file_name = path.file_name().unwrap_or_default().to_string_lossy();
-                let percentage = (*current_count as f32 / total_files as f32) * 100.0;
-                
-                // Only emit every 50 files or so to avoid overwhelming the frontend
-                if *current_count % 50 == 0 || *current_count == total_files {
-                    let _ = app_handle.emit("download-progress", downloader::DownloadProgress {
-                        stage: format!("Zipping: {} ({}/{})", file_name, current_count, total_files),
-                        current: *current_count,
-                        total: total_files,
-                        percentage,
-                        total_bytes: None,
-                        downloaded_bytes: None,
-                    });
-                }
-
-                zip.start_file(&name, options)
-                    .map_err(|e| format!("Failed to start file {}: {}", name, e))?;
-                let mut file = fs::File::open(&path).map_err(|e| e.to_string())?;
-                std::io::copy(&mut file, zip).map_err(|e| e.to_string())?;
-            }
-        }
-        Ok(())
-    }
-    
-    add_dir_to_zip(&mut zip, &game_dir, &game_dir, options, &app_handle, total_files, &mut current_count)?;
+    add_dir_to_zip(&mut zip, &game_dir, &game_dir, "minecraft", options, Some((&app_handle, total_files)), &mut current_count)?;
     
     zip.finish().map_err(|e| format!("Failed to finalize zip: {}", e))?;
     
@@ -1914,85 +3065,411 @@ async fn export_instance_zip(instance_id: String, destination_path: String, app_
     Ok(destination_path)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CurseForgeExportResult {
+    pub path: String,
+    pub referenced_mods: usize, // listed in manifest.json by project and file id
+    pub bundled_mods: usize,    // copied into overrides/mods
+}
+
+/// Game-directory folders that are runtime output rather than pack content
+const CURSEFORGE_EXPORT_SKIPPED_DIRS: &[&str] = &["logs", "crash-reports", "screenshots"];
+
+/// Loader id in the form CurseForge manifests use, e.g. "forge-47.2.0"
+fn curseforge_loader_id(instance: &instances::Instance) -> Option<String> {
+    let loader = normalize_loader_key(&instance.mod_loader)?;
+    let version = instance.mod_loader_version.as_deref()?.trim();
+    if version.is_empty() {
+        return None;
+    }
+    Some(format!("{}-{}", loader, version))
+}
+
 // ----------
-// peek_instance_zip
-// Description: Peeks into a zip file to see if it's a valid Palethea instance export
-//              and returns the metadata if it is.
+// export_instance_curseforge
+// Description: Writes a CurseForge modpack zip. Mods with CurseForge metadata are referenced in
+//              manifest.json; everything else in the game directory goes into overrides/.
 // ----------
 #[tauri::command]
-async fn peek_instance_zip(zip_path: String) -> Result<serde_json::Value, String> {
-    use std::io::Read;
-    
-    let zip_file = fs::File::open(&zip_path)
-        .map_err(|e| format!("Failed to open zip file: {}", e))?;
-    let mut archive = zip::ZipArchive::new(zip_file)
-        .map_err(|e| format!("Failed to read zip archive: {}", e))?;
-    
-    let mut metadata_file = archive.by_name("palethea_instance.json")
-        .map_err(|_| "This doesn't appear to be a valid Palethea instance export (missing palethea_instance.json)")?;
-    let mut contents = String::new();
-    metadata_file.read_to_string(&mut contents)
-        .map_err(|e| format!("Failed to read metadata: {}", e))?;
-    let metadata: serde_json::Value = serde_json::from_str(&contents)
-        .map_err(|e| format!("Failed to parse metadata: {}", e))?;
-        
-    Ok(metadata)
-}
+async fn export_instance_curseforge(instance_id: String, destination_path: String, app_handle: AppHandle) -> Result<CurseForgeExportResult, String> {
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
 
-fn detect_prism_root_prefix_in_zip<R: std::io::Read + std::io::Seek>(
-    archive: &mut zip::ZipArchive<R>,
-) -> Result<Option<String>, String> {
-    let mut names: Vec<String> = Vec::new();
-    for i in 0..archive.len() {
-        let file = archive.by_index(i).map_err(|e| format!("Failed to read zip entry: {}", e))?;
-        names.push(file.name().replace('\\', "/"));
+    let instance = instances::get_instance(&instance_id)?;
+    let game_dir = instance.get_game_directory();
+    if !game_dir.exists() {
+        return Err("Instance game directory not found".to_string());
     }
+    logger::emit_log(&app_handle, "info", &format!("Exporting instance {} as a CurseForge pack to {}", instance.name, destination_path));
 
-    let name_set: HashSet<String> = names.iter().cloned().collect();
-    for name in &names {
-        let Some(prefix) = name.strip_suffix("instance.cfg") else {
-            continue;
-        };
-        let mmc_pack = format!("{}mmc-pack.json", prefix);
-        let minecraft_prefix = format!("{}minecraft/", prefix);
-        if name_set.contains(&mmc_pack) && names.iter().any(|n| n.starts_with(&minecraft_prefix)) {
-            return Ok(Some(prefix.to_string()));
+    // Mods with a numeric project and file id came from CurseForge and can be referenced
+    let mut manifest_files = Vec::new();
+    let mut referenced_filenames: HashSet<String> = HashSet::new();
+    let mut bundled_mods = 0;
+    for m in files::list_mods(&instance) {
+        let ids = m.project_id.as_deref().zip(m.version_id.as_deref()).and_then(|(project, file)| {
+            Some((project.trim().parse::<u64>().ok()?, file.trim().parse::<u64>().ok()?))
+        });
+        match ids {
+            Some((project_id, file_id)) => {
+                manifest_files.push(serde_json::json!({
+                    "projectID": project_id,
+                    "fileID": file_id,
+                    "required": m.enabled,
+                }));
+                referenced_filenames.insert(m.filename);
+            }
+            None => bundled_mods += 1,
         }
     }
 
-    Ok(None)
-}
+    let mut mod_loaders = Vec::new();
+    if let Some(id) = curseforge_loader_id(&instance) {
+        mod_loaders.push(serde_json::json!({ "id": id, "primary": true }));
+    }
+    let manifest = serde_json::json!({
+        "minecraft": {
+            "version": instance.version_id,
+            "modLoaders": mod_loaders,
+        },
+        "manifestType": "minecraftModpack",
+        "manifestVersion": 1,
+        "name": instance.name,
+        "version": "1.0.0",
+        "author": instance.modpack_author.clone().unwrap_or_default(),
+        "files": manifest_files,
+        "overrides": "overrides",
+    });
 
-fn read_zip_entry_string<R: std::io::Read + std::io::Seek>(
-    archive: &mut zip::ZipArchive<R>,
-    entry_name: &str,
-) -> Result<String, String> {
-    use std::io::Read;
+    // Walking and compressing the game directory is blocking work
+    let referenced_mods = referenced_filenames.len();
+    let zip_destination = destination_path.clone();
+    let progress_handle = app_handle.clone();
+    tokio::task::spawn_blocking(move || -> Result<(), String> {
+        let zip_path = std::path::PathBuf::from(&zip_destination);
+        downloader::ensure_disk_space(&zip_path, get_dir_size(&game_dir))?;
+        let file = fs::File::create(&zip_path).map_err(|e| format!("Failed to create zip file: {}", e))?;
+        let mut zip = zip::ZipWriter::new(file);
+        let options = SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .compression_level(Some(6));
+
+        zip.start_file("manifest.json", options)
+            .map_err(|e| format!("Failed to add manifest: {}", e))?;
+        let manifest_json = serde_json::to_string_pretty(&manifest)
+            .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+        zip.write_all(manifest_json.as_bytes())
+            .map_err(|e| format!("Failed to write manifest: {}", e))?;
+
+        let mods_metadata_dir = std::path::Path::new("mods").join("metadata");
+        let loader_marker = std::path::Path::new("mods").join(files::ACTIVE_LOADER_MARKER);
+        let skip = |relative: &std::path::Path| {
+            let mut components = relative.components();
+            let first = components.next().map(|c| c.as_os_str().to_string_lossy().to_string()).unwrap_or_default();
+            if CURSEFORGE_EXPORT_SKIPPED_DIRS.contains(&first.as_str()) || relative == mods_metadata_dir || relative == loader_marker {
+                return true;
+            }
+            // Mods parked for another loader (mods-<loader>/) do not belong to the exported pack
+            if first.starts_with("mods-") {
+                return true;
+            }
+            // Referenced mods are downloaded by the importer, so they stay out of overrides
+            first == "mods"
+                && relative.parent() == Some(std::path::Path::new("mods"))
+                && relative
+                    .file_name()
+                    .is_some_and(|name| referenced_filenames.contains(name.to_string_lossy().as_ref()))
+        };
 
-    let mut entry = archive
-        .by_name(entry_name)
-        .map_err(|_| format!("Missing required Prism file in zip: {}", entry_name))?;
-    let mut content = String::new();
-    entry
-        .read_to_string(&mut content)
-        .map_err(|e| format!("Failed to read {}: {}", entry_name, e))?;
-    Ok(content)
+        let total_files = count_files_recursive(&game_dir);
+        let mut current_count = 0;
+        add_dir_to_zip_filtered(&mut zip, &game_dir, &game_dir, "overrides", options, Some((&progress_handle, total_files)), &mut current_count, &skip)?;
+        zip.finish().map_err(|e| format!("Failed to finalize zip: {}", e))?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))??;
+
+    logger::emit_log(
+        &app_handle,
+        "info",
+        &format!(
+            "Exported {} as a CurseForge pack: {} mod(s) referenced, {} bundled in overrides",
+            instance.name, referenced_mods, bundled_mods
+        ),
+    );
+
+    Ok(CurseForgeExportResult {
+        path: destination_path,
+        referenced_mods,
+        bundled_mods,
+    })
 }
 
-fn peek_prism_instance_zip(zip_path: &str) -> Result<serde_json::Value, String> {
-    let zip_file = fs::File::open(zip_path).map_err(|e| format!("Failed to open zip file: {}", e))?;
-    let mut archive = zip::ZipArchive::new(zip_file).map_err(|e| format!("Failed to read zip archive: {}", e))?;
+// ============== WORLD BACKUPS ==============
 
-    let prism_prefix = detect_prism_root_prefix_in_zip(&mut archive)?.ok_or_else(|| {
-        "This zip is not a supported import. Expected a Palethea export or Prism instance zip.".to_string()
-    })?;
-    let instance_cfg = read_zip_entry_string(&mut archive, &format!("{}instance.cfg", prism_prefix))?;
-    let mmc_pack = read_zip_entry_string(&mut archive, &format!("{}mmc-pack.json", prism_prefix))?;
-    let (version_id, loader, loader_version) = parse_prism_pack_info_from_content(&mmc_pack)?;
+const DEFAULT_WORLD_BACKUP_KEEP: u32 = 5;
 
-    let fallback_name = std::path::Path::new(zip_path)
-        .file_stem()
-        .and_then(|n| n.to_str())
+#[derive(Debug, Serialize)]
+struct WorldBackup {
+    filename: String,
+    world_name: String,
+    created_at: u64,
+    size: u64,
+}
+
+fn world_backups_dir(instance: &instances::Instance) -> std::path::PathBuf {
+    instance.get_directory().join("backups")
+}
+
+fn validate_world_name(world_name: &str) -> Result<(), String> {
+    if world_name.is_empty() || world_name.contains('/') || world_name.contains('\\') || world_name.contains("..") {
+        return Err("Invalid world name".to_string());
+    }
+    Ok(())
+}
+
+/// Zip `saves/<world_name>` into `backups/<world_name>__<timestamp>.zip`, returning the backup filename
+fn create_world_backup(instance: &instances::Instance, world_name: &str) -> Result<String, String> {
+    validate_world_name(world_name)?;
+    let world_dir = files::get_saves_dir(instance).join(world_name);
+    if !world_dir.is_dir() {
+        return Err(format!("World \"{}\" not found", world_name));
+    }
+
+    let backups_dir = world_backups_dir(instance);
+    fs::create_dir_all(&backups_dir).map_err(|e| format!("Failed to create backups folder: {}", e))?;
+    downloader::ensure_disk_space(&backups_dir, get_dir_size(&world_dir))?;
+
+    let mut filename = format!("{}__{}.zip", world_name, chrono::Local::now().format("%Y%m%d-%H%M%S"));
+    let mut suffix = 1;
+    while backups_dir.join(&filename).exists() {
+        suffix += 1;
+        filename = format!("{}__{}-{}.zip", world_name, chrono::Local::now().format("%Y%m%d-%H%M%S"), suffix);
+    }
+    let backup_path = backups_dir.join(&filename);
+
+    let file = fs::File::create(&backup_path).map_err(|e| format!("Failed to create backup: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated)
+        .compression_level(Some(6));
+
+    let mut count = 0;
+    let result = add_dir_to_zip(&mut zip, &world_dir, &world_dir, world_name, options, None, &mut count)
+        .and_then(|_| zip.finish().map(|_| ()).map_err(|e| format!("Failed to finalize backup: {}", e)));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&backup_path);
+        return Err(e);
+    }
+
+    Ok(filename)
+}
+
+fn list_backups_for_world(instance: &instances::Instance, world_name: Option<&str>) -> Vec<WorldBackup> {
+    let mut backups = Vec::new();
+    if let Ok(entries) = fs::read_dir(world_backups_dir(instance)) {
+        for entry in entries.flatten() {
+            let filename = entry.file_name().to_string_lossy().to_string();
+            let Some(stem) = filename.strip_suffix(".zip") else {
+                continue;
+            };
+            let Some((backup_world, _)) = stem.rsplit_once("__") else {
+                continue;
+            };
+            if world_name.is_some_and(|name| name != backup_world) {
+                continue;
+            }
+
+            let metadata = entry.metadata().ok();
+            backups.push(WorldBackup {
+                world_name: backup_world.to_string(),
+                created_at: metadata
+                    .as_ref()
+                    .and_then(|m| m.modified().ok())
+                    .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0),
+                size: metadata.map(|m| m.len()).unwrap_or(0),
+                filename,
+            });
+        }
+    }
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at).then_with(|| b.filename.cmp(&a.filename)));
+    backups
+}
+
+/// Keep only the `keep` newest backups of a world
+fn prune_world_backups(instance: &instances::Instance, world_name: &str, keep: u32) {
+    let backups_dir = world_backups_dir(instance);
+    for backup in list_backups_for_world(instance, Some(world_name)).into_iter().skip(keep as usize) {
+        let _ = fs::remove_file(backups_dir.join(&backup.filename));
+    }
+}
+
+/// Snapshot every world before launch when the instance opted in
+fn auto_backup_worlds(instance: &instances::Instance, app_handle: &AppHandle) {
+    let keep = instance.auto_backup_keep.unwrap_or(DEFAULT_WORLD_BACKUP_KEEP).max(1);
+    for world in files::list_worlds(instance) {
+        match create_world_backup(instance, &world.folder_name) {
+            Ok(filename) => {
+                log_info!(app_handle, "Backed up world {} to {}", world.folder_name, filename);
+                prune_world_backups(instance, &world.folder_name, keep);
+            }
+            Err(e) => log_warn!(app_handle, "Failed to back up world {}: {}", world.folder_name, e),
+        }
+    }
+}
+
+#[tauri::command]
+async fn backup_world(instance_id: String, world_name: String) -> Result<String, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    tauri::async_runtime::spawn_blocking(move || create_world_backup(&instance, &world_name))
+        .await
+        .map_err(|e| format!("Failed to join backup task: {}", e))?
+}
+
+#[tauri::command]
+fn list_world_backups(instance_id: String, world_name: String) -> Result<Vec<WorldBackup>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    Ok(list_backups_for_world(&instance, Some(&world_name)))
+}
+
+#[tauri::command]
+async fn restore_world_backup(instance_id: String, backup_filename: String) -> Result<String, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    if is_instance_running(&instance_id) {
+        return Err("Stop the instance before restoring a world backup".to_string());
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        validate_world_name(&backup_filename)?;
+        let backup_path = world_backups_dir(&instance).join(&backup_filename);
+        let world_name = backup_filename
+            .strip_suffix(".zip")
+            .and_then(|stem| stem.rsplit_once("__"))
+            .map(|(world, _)| world.to_string())
+            .ok_or_else(|| "Not a world backup file".to_string())?;
+
+        let file = fs::File::open(&backup_path).map_err(|e| format!("Failed to open backup: {}", e))?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|e| format!("Failed to read backup: {}", e))?;
+
+        let saves_dir = files::get_saves_dir(&instance);
+        let world_dir = saves_dir.join(&world_name);
+
+        // Keep the current state around in case the restore was a mistake
+        if world_dir.is_dir() {
+            create_world_backup(&instance, &world_name)
+                .map_err(|e| format!("Failed to back up current world before restoring: {}", e))?;
+            fs::remove_dir_all(&world_dir).map_err(|e| format!("Failed to remove current world: {}", e))?;
+        }
+        fs::create_dir_all(&saves_dir).map_err(|e| e.to_string())?;
+
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).map_err(|e| e.to_string())?;
+            let Some(relative) = entry.enclosed_name() else {
+                continue;
+            };
+            if !relative.starts_with(&world_name) {
+                continue;
+            }
+            let out_path = saves_dir.join(relative);
+            if entry.is_dir() {
+                fs::create_dir_all(&out_path).map_err(|e| e.to_string())?;
+            } else {
+                if let Some(parent) = out_path.parent() {
+                    fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+                }
+                let mut out = fs::File::create(&out_path).map_err(|e| e.to_string())?;
+                std::io::copy(&mut entry, &mut out).map_err(|e| e.to_string())?;
+            }
+        }
+
+        Ok(world_name)
+    })
+    .await
+    .map_err(|e| format!("Failed to join restore task: {}", e))?
+}
+
+// ----------
+// peek_instance_zip
+// Description: Peeks into a zip file to see if it's a valid Palethea instance export
+//              and returns the metadata if it is.
+// ----------
+#[tauri::command]
+async fn peek_instance_zip(zip_path: String) -> Result<serde_json::Value, String> {
+    use std::io::Read;
+    
+    let zip_file = fs::File::open(&zip_path)
+        .map_err(|e| format!("Failed to open zip file: {}", e))?;
+    let mut archive = zip::ZipArchive::new(zip_file)
+        .map_err(|e| format!("Failed to read zip archive: {}", e))?;
+    
+    let mut metadata_file = archive.by_name("palethea_instance.json")
+        .map_err(|_| "This doesn't appear to be a valid Palethea instance export (missing palethea_instance.json)")?;
+    let mut contents = String::new();
+    metadata_file.read_to_string(&mut contents)
+        .map_err(|e| format!("Failed to read metadata: {}", e))?;
+    let metadata: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse metadata: {}", e))?;
+        
+    Ok(metadata)
+}
+
+fn detect_prism_root_prefix_in_zip<R: std::io::Read + std::io::Seek>(
+    archive: &mut zip::ZipArchive<R>,
+) -> Result<Option<String>, String> {
+    let mut names: Vec<String> = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive.by_index(i).map_err(|e| format!("Failed to read zip entry: {}", e))?;
+        names.push(file.name().replace('\\', "/"));
+    }
+
+    let name_set: HashSet<String> = names.iter().cloned().collect();
+    for name in &names {
+        let Some(prefix) = name.strip_suffix("instance.cfg") else {
+            continue;
+        };
+        let mmc_pack = format!("{}mmc-pack.json", prefix);
+        let minecraft_prefix = format!("{}minecraft/", prefix);
+        if name_set.contains(&mmc_pack) && names.iter().any(|n| n.starts_with(&minecraft_prefix)) {
+            return Ok(Some(prefix.to_string()));
+        }
+    }
+
+    Ok(None)
+}
+
+fn read_zip_entry_string<R: std::io::Read + std::io::Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    entry_name: &str,
+) -> Result<String, String> {
+    use std::io::Read;
+
+    let mut entry = archive
+        .by_name(entry_name)
+        .map_err(|_| format!("Missing required Prism file in zip: {}", entry_name))?;
+    let mut content = String::new();
+    entry
+        .read_to_string(&mut content)
+        .map_err(|e| format!("Failed to read {}: {}", entry_name, e))?;
+    Ok(content)
+}
+
+fn peek_prism_instance_zip(zip_path: &str) -> Result<serde_json::Value, String> {
+    let zip_file = fs::File::open(zip_path).map_err(|e| format!("Failed to open zip file: {}", e))?;
+    let mut archive = zip::ZipArchive::new(zip_file).map_err(|e| format!("Failed to read zip archive: {}", e))?;
+
+    let prism_prefix = detect_prism_root_prefix_in_zip(&mut archive)?.ok_or_else(|| {
+        "This zip is not a supported import. Expected a Palethea export or Prism instance zip.".to_string()
+    })?;
+    let instance_cfg = read_zip_entry_string(&mut archive, &format!("{}instance.cfg", prism_prefix))?;
+    let mmc_pack = read_zip_entry_string(&mut archive, &format!("{}mmc-pack.json", prism_prefix))?;
+    let (version_id, loader, loader_version) = parse_prism_pack_info_from_content(&mmc_pack)?;
+
+    let fallback_name = std::path::Path::new(zip_path)
+        .file_stem()
+        .and_then(|n| n.to_str())
         .unwrap_or("Prism Instance")
         .to_string();
     let instance_name = read_prism_instance_name_from_content(&instance_cfg).unwrap_or(fallback_name);
@@ -2006,18 +3483,6 @@ fn peek_prism_instance_zip(zip_path: &str) -> Result<serde_json::Value, String>
     }))
 }
 
-fn is_safe_relative_zip_path(path: &std::path::Path) -> bool {
-    if path.is_absolute() {
-        return false;
-    }
-    path.components().all(|component| {
-        matches!(
-            component,
-            std::path::Component::Normal(_) | std::path::Component::CurDir
-        )
-    })
-}
-
 async fn import_prism_instance_zip(
     zip_path: String,
     custom_name: Option<String>,
@@ -2083,12 +3548,12 @@ async fn import_prism_instance_zip(
                 continue;
             }
 
-            let relative_path = std::path::Path::new(relative_name);
-            if !is_safe_relative_zip_path(relative_path) {
+            let Some(destination_path) =
+                downloader::safe_extract_path(&temp_import_dir, std::path::Path::new(relative_name))
+            else {
+                log_warn!(&app_handle, "Skipping archive entry {} that would be written outside the instance", entry_name);
                 continue;
-            }
-
-            let destination_path = temp_import_dir.join(relative_path);
+            };
             if file.is_dir() {
                 fs::create_dir_all(&destination_path)
                     .map_err(|e| format!("Failed to create directory {}: {}", destination_path.display(), e))?;
@@ -2232,6 +3697,7 @@ async fn import_instance_zip(zip_path: String, custom_name: Option<String>, app_
     
     // Use custom name if provided, otherwise use original name
     let instance_name = custom_name.unwrap_or_else(|| format!("{} (Imported)", original_name));
+    let instance_name = instances::fit_instance_name(&instance_name, "Imported Instance");
     
     // Create new instance
     let mut new_instance = instances::create_instance(instance_name.clone(), version_id.clone())?;
@@ -2239,6 +3705,7 @@ async fn import_instance_zip(zip_path: String, custom_name: Option<String>, app_
     // Set mod loader
     new_instance.mod_loader = match mod_loader_str {
         "Fabric" => instances::ModLoader::Fabric,
+        "Quilt" => instances::ModLoader::Quilt,
         "Forge" => instances::ModLoader::Forge,
         "NeoForge" => instances::ModLoader::NeoForge,
         _ => instances::ModLoader::Vanilla,
@@ -2272,6 +3739,19 @@ async fn import_instance_zip(zip_path: String, custom_name: Option<String>, app_
             Some(trimmed.to_string())
         };
     }
+    let metadata_string = |key: &str| {
+        metadata[key]
+            .as_str()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    new_instance.modpack_provider = metadata_string("modpack_provider");
+    new_instance.modpack_project_id = metadata_string("modpack_project_id");
+    new_instance.modpack_version_id = metadata_string("modpack_version_id");
+    new_instance.modpack_file_name = metadata_string("modpack_file_name");
+    new_instance.modpack_title = metadata_string("modpack_title");
+    new_instance.modpack_author = metadata_string("modpack_author");
+    new_instance.modpack_url = metadata_string("modpack_url");
     
     // Extract game files to the new instance's minecraft directory
     let game_dir = new_instance.get_game_directory();
@@ -2318,7 +3798,10 @@ async fn import_instance_zip(zip_path: String, custom_name: Option<String>, app_
                 continue;
             }
             
-            let dest_path = game_dir.join(relative_path);
+            let Some(dest_path) = downloader::safe_extract_path(&game_dir, std::path::Path::new(relative_path)) else {
+                log_warn!(&app_handle, "Skipping archive entry {} that would be written outside the instance", name);
+                continue;
+            };
             
             if file.is_dir() {
                 fs::create_dir_all(&dest_path).map_err(|e| e.to_string())?;
@@ -2345,21 +3828,30 @@ async fn import_instance_zip(zip_path: String, custom_name: Option<String>, app_
         match new_instance.mod_loader {
             instances::ModLoader::Fabric => {
                 logger::emit_log(&app_handle, "info", &format!("Installing Fabric {} for imported instance", loader_version_clone));
-                fabric::install_fabric(&new_instance, &loader_version_clone)
+                let info = fabric::install_fabric(&new_instance, &loader_version_clone, None)
                     .await
                     .map_err(|e| format!("Failed to install Fabric: {}", e))?;
+                new_instance.mod_loader_version = Some(info.loader.version);
+            }
+            instances::ModLoader::Quilt => {
+                logger::emit_log(&app_handle, "info", &format!("Installing Quilt {} for imported instance", loader_version_clone));
+                quilt::install_quilt(&new_instance, &loader_version_clone, None)
+                    .await
+                    .map_err(|e| format!("Failed to install Quilt: {}", e))?;
             }
             instances::ModLoader::Forge => {
                 logger::emit_log(&app_handle, "info", &format!("Installing Forge {} for imported instance", loader_version_clone));
-                forge::install_forge(&new_instance, &loader_version_clone)
+                let info = forge::install_forge(&new_instance, &loader_version_clone, None)
                     .await
                     .map_err(|e| format!("Failed to install Forge: {}", e))?;
+                new_instance.mod_loader_version = Some(info.forge_version);
             }
             instances::ModLoader::NeoForge => {
                 logger::emit_log(&app_handle, "info", &format!("Installing NeoForge {} for imported instance", loader_version_clone));
-                forge::install_neoforge(&new_instance, &loader_version_clone)
+                let info = forge::install_neoforge(&new_instance, &loader_version_clone, None)
                     .await
                     .map_err(|e| format!("Failed to install NeoForge: {}", e))?;
+                new_instance.mod_loader_version = Some(info.forge_version);
             }
             instances::ModLoader::Vanilla => {
                 // No mod loader to install
@@ -2476,6 +3968,7 @@ fn get_instance_share_code(instance_id: String) -> Result<String, String> {
         loader: match instance.mod_loader {
             instances::ModLoader::Vanilla => "vanilla".to_string(),
             instances::ModLoader::Fabric => "fabric".to_string(),
+            instances::ModLoader::Quilt => "quilt".to_string(),
             instances::ModLoader::Forge => "forge".to_string(),
             instances::ModLoader::NeoForge => "neoforge".to_string(),
         },
@@ -2519,6 +4012,7 @@ fn get_instance_mods_share_code(instance_id: String) -> Result<String, String> {
         loader: match instance.mod_loader {
             instances::ModLoader::Vanilla => "vanilla".to_string(),
             instances::ModLoader::Fabric => "fabric".to_string(),
+            instances::ModLoader::Quilt => "quilt".to_string(),
             instances::ModLoader::Forge => "forge".to_string(),
             instances::ModLoader::NeoForge => "neoforge".to_string(),
         },
@@ -2545,13 +4039,255 @@ fn decode_instance_share_code(code: String) -> Result<InstanceShareCode, String>
     Ok(share_data)
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareItemCheck {
+    pub kind: String, // "mod", "resourcepack", "shader" or "datapack"
+    #[serde(flatten)]
+    pub item: ShareItem,
+    pub checked: bool,    // false for CurseForge entries, which can't be looked up on Modrinth
+    pub available: bool,  // the project still exists on Modrinth
+    pub compatible: bool, // some version fits the code's game version (and loader, for mods)
+    pub compatible_version_id: Option<String>, // the pinned version when it fits, otherwise the newest one that does
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareCodeValidation {
+    pub name: String,
+    pub version: String,
+    pub loader: String,
+    pub items: Vec<ShareItemCheck>,
+    pub unavailable_count: usize,
+    pub incompatible_count: usize,
+    pub unchecked_count: usize,
+}
+
+/// Whether a Modrinth version declares a loader whose mods run under `loader` on `game_version`
+fn version_fits_loader(version: &modrinth::ModrinthVersion, loader: &str, game_version: &str) -> bool {
+    let accepted = files::loaders_accepted_by(loader, game_version);
+    version.loaders.iter().any(|l| accepted.contains(&l.as_str()))
+}
+
+/// Check every item of a share code against Modrinth before anything gets installed
+#[tauri::command]
+async fn validate_share_code(code: String) -> Result<ShareCodeValidation, String> {
+    use futures::stream::StreamExt;
+
+    let share = decode_instance_share_code(code)?;
+    let InstanceShareCode { name, version, loader, mods, resourcepacks, shaders, datapacks, .. } = share;
+
+    let items: Vec<(String, ShareItem)> = [("mod", mods), ("resourcepack", resourcepacks), ("shader", shaders), ("datapack", datapacks)]
+        .into_iter()
+        .flat_map(|(kind, list)| list.into_iter().map(move |item| (kind.to_string(), item)))
+        .collect();
+
+    // CurseForge entries carry numeric ids and can't be looked up on Modrinth; they are
+    // reported as unchecked rather than left out
+    let mut project_ids: Vec<String> = items
+        .iter()
+        .map(|(_, item)| item.project_id.clone())
+        .filter(|id| !is_numeric_project_id(id))
+        .collect();
+    project_ids.sort();
+    project_ids.dedup();
+    let known: HashSet<String> = modrinth::get_projects_without_authors(project_ids)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .flat_map(|project| [project.project_id, project.slug])
+        .collect();
+
+    let mod_loader = if loader == "vanilla" { None } else { Some(loader.clone()) };
+    let checks: Vec<ShareItemCheck> = futures::stream::iter(items)
+        .map(|(kind, item)| {
+            let game_version = version.clone();
+            let loader_filter = if kind == "mod" { mod_loader.clone() } else { None };
+            let checked = !is_numeric_project_id(&item.project_id);
+            let available = known.contains(&item.project_id);
+            async move {
+                let mut compatible_version_id = None;
+                if available {
+                    if let Ok(versions) = modrinth::get_project_versions(&item.project_id, Some(&game_version), None).await {
+                        let versions: Vec<modrinth::ModrinthVersion> = versions
+                            .into_iter()
+                            .filter(|v| loader_filter.as_deref().map_or(true, |loader| version_fits_loader(v, loader, &game_version)))
+                            .collect();
+                        let pinned = item.version_id.as_deref().filter(|pinned| versions.iter().any(|v| v.id == *pinned));
+                        compatible_version_id = pinned.map(str::to_string).or_else(|| versions.first().map(|v| v.id.clone()));
+                    }
+                }
+                ShareItemCheck {
+                    kind,
+                    item,
+                    checked,
+                    available,
+                    compatible: compatible_version_id.is_some(),
+                    compatible_version_id,
+                }
+            }
+        })
+        .buffered(8)
+        .collect()
+        .await;
+
+    let unchecked_count = checks.iter().filter(|check| !check.checked).count();
+    let unavailable_count = checks.iter().filter(|check| check.checked && !check.available).count();
+    let incompatible_count = checks.iter().filter(|check| check.available && !check.compatible).count();
+
+    Ok(ShareCodeValidation {
+        name,
+        version,
+        loader,
+        items: checks,
+        unavailable_count,
+        incompatible_count,
+        unchecked_count,
+    })
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareApplyFailure {
+    pub kind: String,
+    pub project_id: String,
+    pub name: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareApplyResult {
+    pub installed: usize,
+    pub skipped: usize, // already present in the instance
+    pub failed: Vec<ShareApplyFailure>,
+}
+
+/// Pick the version of a share item to install: the pinned one while it still fits the
+/// instance, otherwise the newest compatible one
+async fn resolve_share_item_version(item: &ShareItem, game_version: &str, loader: Option<&str>) -> Result<modrinth::ModrinthVersion, String> {
+    if let Some(pinned) = item.version_id.as_deref().filter(|id| !id.trim().is_empty()) {
+        if let Ok(version) = modrinth::get_version(pinned).await {
+            let loader_fits = loader.map_or(true, |wanted| version_fits_loader(&version, wanted, game_version));
+            if loader_fits && version.game_versions.iter().any(|v| v == game_version) {
+                return Ok(version);
+            }
+        }
+    }
+
+    modrinth::get_project_versions(&item.project_id, Some(game_version), None)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|version| loader.map_or(true, |wanted| version_fits_loader(version, wanted, game_version)))
+        .ok_or_else(|| format!("No version available for Minecraft {}", game_version))
+}
+
+// ----------
+// apply_share_code
+// Description: Installs the mods, resource packs, shaders and datapacks of a share code into an
+//              existing instance. Datapacks go into `world_name` and are skipped without one.
+// ----------
+#[tauri::command]
+async fn apply_share_code(app_handle: AppHandle, instance_id: String, code: String, world_name: Option<String>) -> Result<ShareApplyResult, String> {
+    let share = decode_instance_share_code(code)?;
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    let loader = normalize_loader_key(&instance.mod_loader);
+
+    let mut present: HashSet<String> = files::list_mods(&instance)
+        .into_iter()
+        .filter_map(|m| m.project_id)
+        .chain(files::list_resourcepacks(&instance).into_iter().filter_map(|p| p.project_id))
+        .chain(files::list_shaderpacks(&instance).into_iter().filter_map(|s| s.project_id))
+        .collect();
+    if let Some(world) = &world_name {
+        present.extend(files::list_datapacks(&instance, world).into_iter().filter_map(|d| d.project_id));
+    }
+
+    let items: Vec<(&str, ShareItem)> = [("mod", share.mods), ("resourcepack", share.resourcepacks), ("shader", share.shaders), ("datapack", share.datapacks)]
+        .into_iter()
+        .flat_map(|(kind, list)| list.into_iter().map(move |item| (kind, item)))
+        .collect();
+    let total = items.len();
+
+    let mut result = ShareApplyResult { installed: 0, skipped: 0, failed: Vec::new() };
+    for (index, (kind, item)) in items.into_iter().enumerate() {
+        if present.contains(&item.project_id) {
+            result.skipped += 1;
+            continue;
+        }
+
+        let label = item.name.clone().unwrap_or_else(|| item.project_id.clone());
+        downloader::emit_download_progress(&app_handle, downloader::DownloadProgress {
+            stage: format!("Applying share code: {} ({}/{})", label, index + 1, total),
+            current: index as u32,
+            total: total as u32,
+            percentage: (index as f32 / total as f32) * 100.0,
+            total_bytes: None,
+            downloaded_bytes: None,
+        }, Some(&instance_id));
+
+        let outcome: Result<(), String> = async {
+            if is_numeric_project_id(&item.project_id) {
+                return Err("CurseForge entries can't be applied from a share code".to_string());
+            }
+            if kind == "datapack" && world_name.is_none() {
+                return Err("No world selected for datapacks".to_string());
+            }
+
+            let version = resolve_share_item_version(&item, &instance.version_id, if kind == "mod" { loader } else { None }).await?;
+            let file = version.files.iter().find(|f| f.primary).or_else(|| version.files.first())
+                .ok_or_else(|| "Version has no files".to_string())?;
+
+            install_content_item(&app_handle, ContentInstallItem {
+                instance_id: instance_id.clone(),
+                file_url: file.url.clone(),
+                filename: file.filename.clone(),
+                file_type: kind.to_string(),
+                project_id: Some(item.project_id.clone()),
+                version_id: Some(version.id.clone()),
+                world_name: if kind == "datapack" { world_name.clone() } else { None },
+                name: item.name.clone(),
+                author: None,
+                icon_url: item.icon_url.clone(),
+                version_name: Some(version.version_number.clone()),
+                categories: None,
+            }, None).await
+        }
+        .await;
+
+        match outcome {
+            Ok(()) => result.installed += 1,
+            Err(reason) => {
+                log_warn!(&app_handle, "Share code item {} was not installed: {}", label, reason);
+                result.failed.push(ShareApplyFailure {
+                    kind: kind.to_string(),
+                    project_id: item.project_id,
+                    name: item.name,
+                    reason,
+                });
+            }
+        }
+    }
+
+    downloader::emit_download_progress(&app_handle, downloader::DownloadProgress {
+        stage: "Share code applied".to_string(),
+        current: total as u32,
+        total: total as u32,
+        percentage: 100.0,
+        total_bytes: None,
+        downloaded_bytes: None,
+    }, Some(&instance_id));
+    let _ = app_handle.emit("refresh-instances", ());
+
+    Ok(result)
+}
+
 // ============== FABRIC/MOD LOADER COMMANDS ==============
 
 #[tauri::command]
-async fn install_fabric(instance_id: String, loader_version: String) -> Result<String, String> {
+async fn install_fabric(instance_id: String, loader_version: String, app_handle: AppHandle) -> Result<String, String> {
     let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
     
-    fabric::install_fabric(&instance, &loader_version)
+    fabric::install_fabric(&instance, &loader_version, None)
         .await
         .map_err(|e| e.to_string())?;
     
@@ -2559,16 +4295,45 @@ async fn install_fabric(instance_id: String, loader_version: String) -> Result<S
     let mut updated = instance.clone();
     updated.mod_loader = instances::ModLoader::Fabric;
     updated.mod_loader_version = Some(loader_version.clone());
-    instances::update_instance(updated)?;
+    updated.apply_loader_badge();
+    let updated = instances::update_instance(updated)?;
+    // The loader is installed either way; launch tries the folder swap again
+    if let Err(e) = files::sync_loader_mods_dir(&updated) {
+        log_warn!(&app_handle, "{}: {}", updated.name, e);
+    }
     
     Ok(format!("Fabric {} installed successfully", loader_version))
 }
 
 #[tauri::command]
-async fn install_forge(instance_id: String, loader_version: String) -> Result<String, String> {
+async fn install_quilt(instance_id: String, loader_version: String, app_handle: AppHandle) -> Result<String, String> {
     let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
     
-    forge::install_forge(&instance, &loader_version)
+    quilt::install_quilt(&instance, &loader_version, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    
+    // Update instance with mod loader info
+    let mut updated = instance.clone();
+    updated.mod_loader = instances::ModLoader::Quilt;
+    updated.mod_loader_version = Some(loader_version.clone());
+    updated.apply_loader_badge();
+    let updated = instances::update_instance(updated)?;
+    // The loader is installed either way; launch tries the folder swap again
+    if let Err(e) = files::sync_loader_mods_dir(&updated) {
+        log_warn!(&app_handle, "{}: {}", updated.name, e);
+    }
+    
+    Ok(format!("Quilt {} installed successfully", loader_version))
+}
+
+#[tauri::command]
+async fn install_forge(instance_id: String, loader_version: String, app_handle: AppHandle) -> Result<String, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    
+    forge::install_forge(&instance, &loader_version, None)
         .await
         .map_err(|e| e.to_string())?;
     
@@ -2576,16 +4341,22 @@ async fn install_forge(instance_id: String, loader_version: String) -> Result<St
     let mut updated = instance.clone();
     updated.mod_loader = instances::ModLoader::Forge;
     updated.mod_loader_version = Some(loader_version.clone());
-    instances::update_instance(updated)?;
+    updated.apply_loader_badge();
+    let updated = instances::update_instance(updated)?;
+    // The loader is installed either way; launch tries the folder swap again
+    if let Err(e) = files::sync_loader_mods_dir(&updated) {
+        log_warn!(&app_handle, "{}: {}", updated.name, e);
+    }
     
     Ok(format!("Forge {} installed successfully", loader_version))
 }
 
 #[tauri::command]
-async fn install_neoforge(instance_id: String, loader_version: String) -> Result<String, String> {
+async fn install_neoforge(instance_id: String, loader_version: String, app_handle: AppHandle) -> Result<String, String> {
     let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
     
-    forge::install_neoforge(&instance, &loader_version)
+    forge::install_neoforge(&instance, &loader_version, None)
         .await
         .map_err(|e| e.to_string())?;
     
@@ -2593,7 +4364,12 @@ async fn install_neoforge(instance_id: String, loader_version: String) -> Result
     let mut updated = instance.clone();
     updated.mod_loader = instances::ModLoader::NeoForge;
     updated.mod_loader_version = Some(loader_version.clone());
-    instances::update_instance(updated)?;
+    updated.apply_loader_badge();
+    let updated = instances::update_instance(updated)?;
+    // The loader is installed either way; launch tries the folder swap again
+    if let Err(e) = files::sync_loader_mods_dir(&updated) {
+        log_warn!(&app_handle, "{}: {}", updated.name, e);
+    }
     
     Ok(format!("NeoForge {} installed successfully", loader_version))
 }
@@ -2618,22 +4394,424 @@ fn is_version_downloaded(version_id: String) -> bool {
 
 // ============== LAUNCH COMMANDS ==============
 
+// ----------
+// LaunchOutcome
+// Description: What launch_instance did - launched the game, or stopped because the
+//              required download is over the confirmation threshold
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LaunchOutcome {
+    Launched { message: String },
+    ConfirmationRequired { bytes: u64 },
+}
+
+/// Resolve the exact command a launch would run, without starting the game.
+/// This is a dry run: nothing is downloaded or written, so it is safe while the instance is running.
+#[tauri::command]
+async fn get_launch_command(
+    instance_id: String,
+    state: State<'_, AppState>,
+) -> Result<launcher::LaunchCommand, String> {
+    let instance = instances::get_instance(&instance_id)?;
+
+    let versions_dir = downloader::get_versions_dir();
+    let json_path = versions_dir.join(&instance.version_id).join(format!("{}.json", &instance.version_id));
+    let json_content = std::fs::read_to_string(&json_path)
+        .map_err(|e| format!("Failed to read version JSON: {}", e))?;
+    let version_details: versions::VersionDetails = serde_json::from_str(&json_content)
+        .map_err(|e| format!("Failed to parse version JSON: {}", e))?;
+
+    // Same account resolution as launch_instance, minus the token refresh
+    let preferred = instance.preferred_account.as_ref().and_then(|preferred_username| {
+        auth::load_accounts()
+            .accounts
+            .into_iter()
+            .find(|a| &a.username == preferred_username)
+    });
+    let (username, uuid) = match preferred {
+        Some(account) => (account.username, account.uuid),
+        None => (
+            state.username.lock().map_err(|_| "Auth state corrupted")?.clone(),
+            state.uuid.lock().map_err(|_| "Auth state corrupted")?.clone(),
+        ),
+    };
+
+    // The real token never enters the pipeline; describe() redacts the placeholder as well
+    let prepared = launcher::prepare_launch(
+        &instance,
+        &version_details,
+        &username,
+        "<redacted>",
+        &uuid,
+        None,
+        Some(&instance_id),
+        None,
+        true,
+    )
+    .await?;
+
+    Ok(prepared.describe())
+}
+
+// ----------
+// RepairReport
+// Description: What repair_instance had to fix, plus a readable line per fix for the UI
+// ----------
+#[derive(Debug, Serialize, Default)]
+struct RepairReport {
+    version_json_redownloaded: bool,
+    client_jar_redownloaded: bool,
+    libraries_redownloaded: u32,
+    natives_extracted: u32,
+    fixed: Vec<String>,
+}
+
+fn emit_repair_stage(app_handle: &AppHandle, instance_id: &str, stage: &str, percentage: f32) {
+    downloader::emit_download_progress(app_handle, downloader::DownloadProgress {
+        stage: stage.to_string(),
+        current: 0,
+        total: 0,
+        percentage,
+        total_bytes: None,
+        downloaded_bytes: None,
+    }, Some(instance_id));
+}
+
+// ----------
+// repair_instance
+// Description: Fixes the usual launch failures - unreadable version JSON, corrupt client jar,
+//              missing or corrupt libraries (mod loader ones included) and assets - and always re-extracts natives,
+//              since a half-written native only shows up as an UnsatisfiedLinkError in game
+// ----------
+#[tauri::command]
+async fn repair_instance(app_handle: AppHandle, instance_id: String) -> Result<RepairReport, String> {
+    if is_instance_running(&instance_id) {
+        return Err("Stop the instance before repairing it".to_string());
+    }
+    let instance = instances::get_instance(&instance_id)?;
+    let mut report = RepairReport::default();
+
+    emit_repair_stage(&app_handle, &instance_id, "Checking version JSON...", 5.0);
+    let version_dir = downloader::get_versions_dir().join(&instance.version_id);
+    let json_path = version_dir.join(format!("{}.json", &instance.version_id));
+    let version_details = match versions::load_version_details(&json_path) {
+        Ok(details) => details,
+        Err(e) => {
+            log_warn!(&app_handle, "Version JSON for {} is unreadable ({}), fetching it again", instance.version_id, e);
+            let manifest = versions::fetch_version_manifest()
+                .await
+                .map_err(|e| format!("Failed to fetch version manifest: {}", e))?;
+            let info = manifest
+                .versions
+                .iter()
+                .find(|v| v.id == instance.version_id)
+                .ok_or_else(|| format!("Version {} is not in the version manifest", instance.version_id))?;
+            let details = versions::fetch_version_details(&info.url)
+                .await
+                .map_err(|e| format!("Failed to fetch version details: {}", e))?;
+            fs::create_dir_all(&version_dir).map_err(|e| e.to_string())?;
+            let json = serde_json::to_string_pretty(&details).map_err(|e| e.to_string())?;
+            fs::write(&json_path, json).map_err(|e| format!("Failed to write version JSON: {}", e))?;
+            report.version_json_redownloaded = true;
+            report.fixed.push(format!("Re-downloaded the {} version JSON", instance.version_id));
+            details
+        }
+    };
+
+    emit_repair_stage(&app_handle, &instance_id, "Verifying client JAR...", 15.0);
+    if let Some(downloads) = &version_details.downloads {
+        let client_path = version_dir.join(format!("{}.jar", &instance.version_id));
+        if !downloader::verify_sha1(&client_path, &downloads.client.sha1) {
+            let _ = fs::remove_file(&client_path);
+            downloader::download_client(&version_details)
+                .await
+                .map_err(|e| format!("Failed to re-download client JAR: {}", e))?;
+            report.client_jar_redownloaded = true;
+            report.fixed.push("Re-downloaded the corrupt or missing client JAR".to_string());
+        }
+    }
+
+    // Libraries, assets and natives are checked against the same loader-merged details a launch uses
+    let version_details = launcher::resolve_version_details(&instance, &version_details)?;
+
+    emit_repair_stage(&app_handle, &instance_id, "Verifying libraries...", 25.0);
+    let details_for_check = version_details.clone();
+    let broken = tokio::task::spawn_blocking(move || downloader::find_broken_libraries(&details_for_check))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?;
+    if !broken.is_empty() {
+        for path in &broken {
+            let _ = fs::remove_file(path);
+        }
+        downloader::download_libraries(&version_details, Some(&app_handle), Some(&instance_id))
+            .await
+            .map_err(|e| format!("Failed to re-download libraries: {}", e))?;
+        report.libraries_redownloaded = broken.len() as u32;
+        report.fixed.push(format!("Re-downloaded {} missing or corrupt libraries", broken.len()));
+    }
+
+    emit_repair_stage(&app_handle, &instance_id, "Checking assets...", 35.0);
+    downloader::download_assets(&version_details, Some(&app_handle), Some(&instance_id))
+        .await
+        .map_err(|e| format!("Failed to restore assets: {}", e))?;
+
+    emit_repair_stage(&app_handle, &instance_id, "Re-extracting natives...", 99.0);
+    let instance_for_natives = instance.clone();
+    let details_for_natives = version_details.clone();
+    report.natives_extracted = tokio::task::spawn_blocking(move || {
+        launcher::reextract_natives(&instance_for_natives, &details_for_natives)
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))??;
+    if report.natives_extracted > 0 {
+        report.fixed.push(format!("Re-extracted {} native files", report.natives_extracted));
+    }
+
+    emit_repair_stage(&app_handle, &instance_id, "Repair complete", 100.0);
+    log_info!(&app_handle, "Repaired {}: {}", instance.name, if report.fixed.is_empty() { "nothing to fix".to_string() } else { report.fixed.join("; ") });
+    Ok(report)
+}
+
+/// Forget tracked sessions of an instance whose game process is no longer alive
+#[tauri::command]
+fn clear_stale_process(instance_id: String, app_handle: AppHandle) -> Result<usize, String> {
+    let stale = prune_stale_sessions(&instance_id);
+    if !stale.is_empty() {
+        refresh_tray_menu(&app_handle);
+        let _ = app_handle.emit("refresh-instances", ());
+    }
+    Ok(stale.len())
+}
+
 #[tauri::command]
 async fn launch_instance(
     instance_id: String,
     server_address: Option<String>,
+    confirm: Option<bool>,
     state: State<'_, AppState>,
     app_handle: tauri::AppHandle,
-) -> Result<String, String> {
+) -> Result<LaunchOutcome, String> {
+    launch_instance_session(instance_id, None, server_address, confirm, state, app_handle).await
+}
+
+/// Launch an extra session of an instance with a specific saved account, alongside any
+/// sessions already running under other accounts (e.g. to test multiplayer locally)
+#[tauri::command]
+async fn launch_instance_as(
+    instance_id: String,
+    account_username: String,
+    server_address: Option<String>,
+    confirm: Option<bool>,
+    state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<LaunchOutcome, String> {
+    launch_instance_session(instance_id, Some(account_username), server_address, confirm, state, app_handle).await
+}
+
+// ----------
+// LaunchAccount
+// Description: The account a launch runs as, after falling back from an explicit account to the
+//              instance's bound account to the active one, and after any token refresh
+// ----------
+struct LaunchAccount {
+    username: String,
+    uuid: String,
+    access_token: String,
+    refresh_token: Option<String>,
+    is_microsoft: bool,
+    source: LaunchAccountSource,
+    refreshed: bool, // the stored token had expired and was replaced
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LaunchAccountSource {
+    Explicit,
+    Preferred,
+    Active,
+}
+
+/// Log to the launcher UI, or to stdout for a headless `--launch` run
+fn launch_log(app_handle: Option<&AppHandle>, level: &str, message: &str) {
+    match app_handle {
+        Some(app_handle) => logger::emit_log(app_handle, level, message),
+        None => println!("{}", message),
+    }
+}
+
+/// Pick the account for a launch and make sure it can be used: `explicit`, then the instance's
+/// bound account, then `active`. Demo mode is refused for Microsoft accounts, and Microsoft
+/// tokens are validated and refreshed. Shared by GUI launches and `--launch`.
+async fn resolve_launch_account(
+    instance: &instances::Instance,
+    explicit: Option<&str>,
+    active: Option<auth::SavedAccount>,
+    app_handle: Option<&AppHandle>,
+) -> Result<LaunchAccount, String> {
+    let saved_accounts = auth::load_accounts().accounts;
+    let find_saved = |username: &str| saved_accounts.iter().find(|a| a.username == username).cloned();
+
+    let (saved, source) = if let Some(explicit) = explicit {
+        let account = find_saved(explicit)
+            .ok_or_else(|| format!("Account '{}' is not saved in the launcher", explicit))?;
+        launch_log(app_handle, "info", &format!("Using account {} for {}", account.username, instance.name));
+        (account, LaunchAccountSource::Explicit)
+    } else if let Some(account) = instance.preferred_account.as_deref().and_then(find_saved) {
+        launch_log(app_handle, "info", &format!("Launching {} with instance-bound account {}", instance.name, account.username));
+        (account, LaunchAccountSource::Preferred)
+    } else {
+        if let Some(preferred) = &instance.preferred_account {
+            launch_log(
+                app_handle,
+                "warn",
+                &format!("Preferred account '{}' for instance {} was not found. Falling back to active account.", preferred, instance.name),
+            );
+            // Clear stale preferred binding if account no longer exists
+            let mut rebound_instance = instance.clone();
+            rebound_instance.preferred_account = None;
+            if instances::update_instance(rebound_instance).is_ok() {
+                if let Some(app_handle) = app_handle {
+                    let _ = app_handle.emit("refresh-instances", ());
+                }
+            }
+        }
+        let account = active.ok_or("No account given and no active account is saved; pass --account <username>")?;
+        (account, LaunchAccountSource::Active)
+    };
+
+    // Demo mode is for unlicensed play only, so a paid account never ends up in it
+    if instance.demo_mode && saved.is_microsoft {
+        return Err(format!(
+            "{} is set to demo mode, which can't be used with the Microsoft account {}. Switch to an offline account or turn off demo mode.",
+            instance.name, saved.username
+        ));
+    }
+
+    let mut account = LaunchAccount {
+        username: saved.username,
+        uuid: saved.uuid,
+        access_token: saved.access_token,
+        refresh_token: saved.refresh_token,
+        is_microsoft: saved.is_microsoft,
+        source,
+        refreshed: false,
+    };
+    if !account.is_microsoft {
+        return Ok(account);
+    }
+
+    // Offline there is nothing to validate against, so the stored token is used as-is.
+    // The offline flag may be stale, so probe again before trusting it.
+    if minecraft::is_offline() && !minecraft::probe_connectivity().await {
+        launch_log(app_handle, "warn", &format!("Launcher is offline; skipping session validation for {}", account.username));
+        return Ok(account);
+    }
+
+    if let Some(app_handle) = app_handle {
+        downloader::emit_download_progress(app_handle, downloader::DownloadProgress {
+            stage: "Validating session...".to_string(),
+            percentage: 5.0,
+            total_bytes: None,
+            downloaded_bytes: None,
+            current: 0,
+            total: 0,
+        }, Some(&instance.id));
+    }
+
+    match auth::check_token_with_retry(&account.access_token).await {
+        auth::TokenState::Valid => {}
+        auth::TokenState::Unreachable => {
+            launch_log(
+                app_handle,
+                "warn",
+                &format!(
+                    "Could not reach Minecraft services to validate the session for {}; launching with the stored token",
+                    account.username
+                ),
+            );
+        }
+        auth::TokenState::Invalid => {
+            launch_log(app_handle, "info", &format!("Access token expired for {}, attempting refresh...", account.username));
+
+            let tok = account.refresh_token.clone().ok_or_else(|| {
+                format!("The session for {} has expired and no refresh token is stored. Please log in again.", account.username)
+            })?;
+            let new_account = auth::refresh_session(&tok).await.map_err(|e| {
+                format!("The session for {} has expired and could not be refreshed ({}). Please log in again.", account.username, e)
+            })?;
+
+            launch_log(app_handle, "info", &format!("Token refreshed successfully for {}", new_account.username));
+            let _ = auth::add_account(auth::SavedAccount {
+                username: new_account.username.clone(),
+                uuid: new_account.uuid.clone(),
+                access_token: new_account.access_token.clone(),
+                refresh_token: new_account.refresh_token.clone(),
+                is_microsoft: true,
+                needs_relogin: false,
+            });
+
+            // Keep preferred binding aligned if account username changed.
+            if source == LaunchAccountSource::Preferred && instance.preferred_account.as_deref() != Some(&new_account.username) {
+                let mut rebound_instance = instance.clone();
+                rebound_instance.preferred_account = Some(new_account.username.clone());
+                if instances::update_instance(rebound_instance).is_ok() {
+                    if let Some(app_handle) = app_handle {
+                        let _ = app_handle.emit("refresh-instances", ());
+                    }
+                }
+            }
+
+            account.username = new_account.username;
+            account.uuid = new_account.uuid;
+            account.access_token = new_account.access_token;
+            account.refresh_token = new_account.refresh_token;
+            account.refreshed = true;
+        }
+    }
+
+    Ok(account)
+}
+
+async fn launch_instance_session(
+    instance_id: String,
+    account_username: Option<String>,
+    server_address: Option<String>,
+    confirm: Option<bool>,
+    state: State<'_, AppState>,
+    app_handle: tauri::AppHandle,
+) -> Result<LaunchOutcome, String> {
     let instance = instances::get_instance(&instance_id)?;
     println!("Launching instance: {}", instance.name);
     
-    // Check if this instance is already running
+    // A normal launch needs the instance idle; a per-account launch only needs that account free
     {
+        let stale = match account_username.as_deref() {
+            Some(account) => prune_stale_sessions(&session_key(&instance_id, account)),
+            None => prune_stale_sessions(&instance_id),
+        };
+        if !stale.is_empty() {
+            log_warn!(&app_handle, "Cleared {} stale session(s) of {} whose process had already exited", stale.len(), instance.name);
+            refresh_tray_menu(&app_handle);
+        }
+
         let processes = RUNNING_PROCESSES.lock().map_err(|_| "Process state corrupted")?;
-        if processes.contains_key(&instance_id) {
+        let already_running = match account_username.as_deref() {
+            Some(account) => processes.contains_key(&session_key(&instance_id, account)),
+            None => processes.values().any(|info| info.instance_id == instance_id),
+        };
+        if already_running {
             log_warn!(&app_handle, "Instance {} is already running", instance.name);
-            return Err("This instance is already running".to_string());
+            return Err(match account_username.as_deref() {
+                Some(account) => format!("This instance is already running as {}", account),
+                None => "This instance is already running".to_string(),
+            });
+        }
+    }
+    // Games started with `--launch` run in another process and are only known by their session record
+    if account_username.is_none() {
+        if let Some(pid) = instances::find_running_session(&instance_id) {
+            log_warn!(&app_handle, "Instance {} is already running from the command line (PID {})", instance.name, pid);
+            return Err("This instance is already running from the command line".to_string());
         }
     }
 
@@ -2653,160 +4831,78 @@ async fn launch_instance(
         .map_err(|e| format!("Failed to read version JSON: {}", e))?;
     let version_details: versions::VersionDetails = serde_json::from_str(&json_content)
         .map_err(|e| format!("Failed to parse version JSON: {}", e))?;
-    
-    let mut using_preferred_account = false;
-    let (mut username, mut uuid, mut access_token, is_microsoft, refresh_token) =
-        if let Some(preferred_username) = instance.preferred_account.clone() {
-            let accounts = auth::load_accounts();
-            if let Some(account) = accounts
-                .accounts
-                .into_iter()
-                .find(|a| a.username == preferred_username)
-            {
-                using_preferred_account = true;
+
+    // Let the UI ask before a launch quietly pulls a large download (metered connections)
+    if !confirm.unwrap_or(false) {
+        if let Some(threshold) = settings::get_launch_confirm_threshold_bytes() {
+            let pending_bytes = downloader::estimate_version_download_size(&version_details);
+            if pending_bytes > threshold {
                 log_info!(
                     &app_handle,
-                    "Launching {} with instance-bound account {}",
+                    "Launch of {} needs {} of downloads, asking for confirmation",
                     instance.name,
-                    account.username
-                );
-                (
-                    account.username,
-                    account.uuid,
-                    account.access_token,
-                    account.is_microsoft,
-                    account.refresh_token,
-                )
-            } else {
-                log_warn!(
-                    &app_handle,
-                    "Preferred account '{}' for instance {} was not found. Falling back to active account.",
-                    preferred_username,
-                    instance.name
+                    downloader::format_bytes(pending_bytes)
                 );
-
-                // Clear stale preferred binding if account no longer exists
-                let mut rebound_instance = instance.clone();
-                rebound_instance.preferred_account = None;
-                if instances::update_instance(rebound_instance).is_ok() {
-                    let _ = app_handle.emit("refresh-instances", ());
-                }
-
-                (
-                    state.username.lock().map_err(|_| "Auth state corrupted")?.clone(),
-                    state.uuid.lock().map_err(|_| "Auth state corrupted")?.clone(),
-                    state.access_token.lock().map_err(|_| "Auth state corrupted")?.clone(),
-                    *state
-                        .is_microsoft_auth
-                        .lock()
-                        .map_err(|_| "Auth state corrupted")?,
-                    state
-                        .refresh_token
-                        .lock()
-                        .map_err(|_| "Auth state corrupted")?
-                        .clone(),
-                )
+                return Ok(LaunchOutcome::ConfirmationRequired { bytes: pending_bytes });
             }
-        } else {
-            (
-                state.username.lock().map_err(|_| "Auth state corrupted")?.clone(),
-                state.uuid.lock().map_err(|_| "Auth state corrupted")?.clone(),
-                state.access_token.lock().map_err(|_| "Auth state corrupted")?.clone(),
-                *state
-                    .is_microsoft_auth
-                    .lock()
-                    .map_err(|_| "Auth state corrupted")?,
-                state
-                    .refresh_token
-                    .lock()
-                    .map_err(|_| "Auth state corrupted")?
-                    .clone(),
-            )
-        };
+        }
+    }
 
-    // Validate and refresh Microsoft token before launch
-    if is_microsoft {
+    // Running with another loader's mods in mods/ would only crash, so a blocked swap
+    // (mods-<loader>/ already there) stops the launch until the user sorts it out
+    if files::sync_loader_mods_dir(&instance)? {
+        log_info!(&app_handle, "{}: switched to the {} mods folder", instance.name, instance.mod_loader);
+    }
+
+    if instance.auto_backup_on_launch {
         downloader::emit_download_progress(&app_handle, downloader::DownloadProgress {
-            stage: "Validating session...".to_string(),
+            stage: "Backing up worlds...".to_string(),
             percentage: 5.0,
             total_bytes: None,
             downloaded_bytes: None,
             current: 0,
             total: 0,
         }, Some(&instance_id));
-
-        if !auth::validate_token(&access_token).await {
-            println!("[Auth] Access token expired for {}, attempting refresh...", username);
-
-            if let Some(ref tok) = refresh_token {
-                match auth::refresh_token(tok).await {
-                    Ok(token_response) => {
-                        if let Some(new_ms_token) = token_response.access_token {
-                            match auth::complete_authentication(
-                                &new_ms_token,
-                                token_response.refresh_token.or(refresh_token.clone()),
-                            ).await {
-                                Ok(new_account) => {
-                                    println!("[Auth] Token refreshed successfully for {}", new_account.username);
-                                    username = new_account.username.clone();
-                                    uuid = new_account.uuid.clone();
-                                    access_token = new_account.access_token.clone();
-
-                                    let saved = auth::SavedAccount {
-                                        username: new_account.username.clone(),
-                                        uuid: new_account.uuid.clone(),
-                                        access_token: new_account.access_token.clone(),
-                                        refresh_token: new_account.refresh_token.clone(),
-                                        is_microsoft: true,
-                                    };
-                                    let _ = auth::add_account(saved);
-
-                                    // Only update global active auth state when launching with active account.
-                                    if !using_preferred_account {
-                                        set_auth_state(
-                                            &state,
-                                            new_account.username,
-                                            new_account.uuid,
-                                            new_account.access_token,
-                                            true,
-                                            new_account.refresh_token,
-                                        )?;
-                                    } else if instance.preferred_account.as_deref() != Some(&username) {
-                                        // Keep preferred binding aligned if account username changed.
-                                        let mut rebound_instance = instance.clone();
-                                        rebound_instance.preferred_account = Some(username.clone());
-                                        if instances::update_instance(rebound_instance).is_ok() {
-                                            let _ = app_handle.emit("refresh-instances", ());
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    return Err(format!("Session expired and refresh failed: {}. Please log out and log in again.", e));
-                                }
-                            }
-                        } else {
-                            return Err("Session expired and no new token received. Please log out and log in again.".to_string());
-                        }
-                    }
-                    Err(e) => {
-                        return Err(format!("Session expired and refresh failed: {}. Please log out and log in again.", e));
-                    }
-                }
-            } else {
-                return Err("Session expired and no refresh token available. Please log out and log in again.".to_string());
-            }
+        let backup_instance = instance.clone();
+        let backup_handle = app_handle.clone();
+        let _ = tauri::async_runtime::spawn_blocking(move || auto_backup_worlds(&backup_instance, &backup_handle)).await;
+    }
+
+    let active_account = auth::SavedAccount {
+        username: state.username.lock().map_err(|_| "Auth state corrupted")?.clone(),
+        uuid: state.uuid.lock().map_err(|_| "Auth state corrupted")?.clone(),
+        access_token: state.access_token.lock().map_err(|_| "Auth state corrupted")?.clone(),
+        refresh_token: state.refresh_token.lock().map_err(|_| "Auth state corrupted")?.clone(),
+        is_microsoft: *state.is_microsoft_auth.lock().map_err(|_| "Auth state corrupted")?,
+        needs_relogin: false,
+    };
+    let account = resolve_launch_account(
+        &instance,
+        account_username.as_deref(),
+        Some(active_account),
+        Some(&app_handle),
+    )
+    .await?;
+    // Keep the launcher's active session in step with a token refreshed for it
+    if account.refreshed && account.source == LaunchAccountSource::Active {
+        set_auth_state(
+            &state,
+            account.username.clone(),
+            account.uuid.clone(),
+            account.access_token.clone(),
+            true,
+            account.refresh_token.clone(),
+        )?;
+    }
+    let (username, uuid, access_token) = (account.username, account.uuid, account.access_token);
+
+    if !account.is_microsoft {
+        if let Some(address) = server_address.clone() {
+            spawn_online_mode_warning(app_handle.clone(), instance_id.clone(), username.clone(), address);
         }
     }
 
-    // Update last played and launch count
-    let mut updated_instance = instance.clone();
-    let start_time = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs();
-    updated_instance.last_played = Some(start_time.to_string());
-    updated_instance.total_launches += 1;
-    instances::update_instance(updated_instance)?;
+    let start_time = record_launch(&instance)?;
     
     downloader::emit_download_progress(&app_handle, downloader::DownloadProgress {
         stage: format!("Preparing to launch {}...", instance.name),
@@ -2824,7 +4920,7 @@ async fn launch_instance(
         &username,
         &access_token,
         &uuid,
-        &app_handle,
+        Some(&app_handle),
         Some(&instance_id),
         server_address.as_deref(),
     ).await?;
@@ -2832,61 +4928,93 @@ async fn launch_instance(
     // Store the process ID
     let process_id = child.id();
     
-    // Write session file for crash recovery (include PID)
-    let _ = instances::write_active_session(
-        &instance_id,
-        start_time,
-        Some(process_id),
-        Some(username.clone()),
-    );
-    
-    {
-        let mut processes = RUNNING_PROCESSES.lock().map_err(|_| "Process state corrupted")?;
-        processes.insert(instance_id.clone(), RunningProcessInfo {
-            pid: process_id,
-            start_time,
-            launch_username: Some(username.clone()),
-        });
-        discord::update_presence(processes.len());
-    }
+    let process_key = register_session(&instance_id, &username, process_id, start_time)?;
+    sync_discord_presence();
     refresh_tray_menu(&app_handle);
+    spawn_launch_watchdog(app_handle.clone(), &instance, process_id);
     
     // Spawn a background thread to track playtime
     let instance_id_clone = instance_id.clone();
     let app_handle_clone = app_handle.clone();
     let instance_name = instance.name.clone();
+    #[cfg_attr(target_os = "windows", allow(unused_variables))]
+    let uses_wrapper = instance.wrapper_command.as_deref().map_or(false, |w| !w.trim().is_empty());
     std::thread::spawn(move || {
         // Wait for the game to exit
         if let Ok(status) = child.wait() {
+            // A wrapper may fork Java and exit first; the session lasts until its process group is gone
+            #[cfg(not(target_os = "windows"))]
+            if uses_wrapper {
+                while instances::is_process_running(process_id) {
+                    std::thread::sleep(std::time::Duration::from_secs(1));
+                }
+            }
             let end_time = std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_secs();
             let session_duration = end_time.saturating_sub(start_time);
             
-            // Update playtime
-            if let Ok(mut inst) = instances::get_instance(&instance_id_clone) {
-                inst.playtime_seconds += session_duration;
-                let _ = instances::update_instance(inst);
-            }
-
-            // Log session for activity tracking
-            instances::log_session(&instance_id_clone, &instance_name, end_time, session_duration);
-            instances::clear_active_session(&instance_id_clone);
+            // Update playtime and log the session once no parallel session of the instance remains
+            credit_playtime(&instance_id_clone, end_time, end_play_span(&instance_id_clone, start_time, end_time));
+            instances::clear_active_session(&process_key);
             
             // Remove from running processes
             if let Ok(mut processes) = RUNNING_PROCESSES.lock() {
-                processes.remove(&instance_id_clone);
-                discord::update_presence(processes.len());
+                processes.remove(&process_key);
+                if matching_session_keys(&processes, &instance_id_clone).is_empty() {
+                    logtail::clear_live_output(&instance_id_clone);
+                }
             }
+            sync_discord_presence();
             refresh_tray_menu(&app_handle_clone);
             let _ = app_handle_clone.emit("refresh-instances", ());
 
             log_info!(&app_handle_clone, "Instance {} exited with status: {:?}, session duration: {}s", instance_name, status, session_duration);
+
+            let stopped_by_user = USER_STOPPED_INSTANCES
+                .lock()
+                .map(|mut stopped| stopped.remove(&process_key))
+                .unwrap_or(false);
+
+            if !status.success() && !stopped_by_user {
+                let crashed_instance = instances::get_instance(&instance_id_clone).ok();
+                let crash_report = crashed_instance
+                    .as_ref()
+                    .and_then(|inst| files::latest_crash_report_since(inst, start_time));
+                let early_exit = session_duration <= EARLY_EXIT_WINDOW_SECS;
+
+                // Without a report, only an exit right after launch is treated as a failed start
+                if crash_report.is_some() || early_exit {
+                    // The window may have been minimized or sent to the tray on launch
+                    if settings::get_on_game_launch() != "keep_open" {
+                        show_main_window(&app_handle_clone);
+                    }
+                    match &crash_report {
+                        Some(report) => log_warn!(&app_handle_clone, "Instance {} crashed, report: {}", instance_name, report.path),
+                        None => log_warn!(&app_handle_clone, "Instance {} exited {}s after launch without a crash report", instance_name, session_duration),
+                    }
+                    let _ = app_handle_clone.emit("game-crashed", serde_json::json!({
+                        "instance_id": instance_id_clone,
+                        "exit_code": status.code(),
+                        "early_exit": early_exit,
+                        "report_path": crash_report.as_ref().map(|r| r.path.clone()),
+                        "filename": crash_report.as_ref().map(|r| r.filename.clone()),
+                        "description": crash_report.as_ref().and_then(|r| r.description.clone()),
+                        "crash_reports_dir": crashed_instance
+                            .as_ref()
+                            .map(|inst| files::get_crash_reports_dir(inst).to_string_lossy().to_string()),
+                    }));
+                }
+            }
         }
     });
+
+    apply_game_launch_behavior(&app_handle);
     
-    Ok(format!("Launched {} with version {}", instance.name, instance.version_id))
+    Ok(LaunchOutcome::Launched {
+        message: format!("Launched {} with version {}", instance.name, instance.version_id),
+    })
 }
 
 #[tauri::command]
@@ -2913,8 +5041,25 @@ async fn force_kill_game(
     .map_err(|e| format!("Failed to join stop task: {}", e))?
 }
 
+/// Running instances keyed by instance id; with parallel sessions the earliest one is reported
 #[tauri::command]
 fn get_running_instances() -> Result<HashMap<String, RunningProcessInfo>, String> {
+    let processes = RUNNING_PROCESSES.lock().map_err(|_| "Process state corrupted")?;
+    let mut by_instance: HashMap<String, RunningProcessInfo> = HashMap::new();
+    for info in processes.values() {
+        match by_instance.get(&info.instance_id) {
+            Some(existing) if existing.start_time <= info.start_time => {}
+            _ => {
+                by_instance.insert(info.instance_id.clone(), info.clone());
+            }
+        }
+    }
+    Ok(by_instance)
+}
+
+/// Every running session, keyed by session key, for views that list parallel sessions
+#[tauri::command]
+fn get_running_sessions() -> Result<HashMap<String, RunningProcessInfo>, String> {
     let processes = RUNNING_PROCESSES.lock().map_err(|_| "Process state corrupted")?;
     Ok(processes.clone())
 }
@@ -2954,8 +5099,156 @@ fn get_settings() -> settings::LauncherSettings {
 }
 
 #[tauri::command]
-fn save_settings(new_settings: settings::LauncherSettings) -> Result<(), String> {
-    settings::save_settings(&new_settings)
+fn get_privacy_settings() -> settings::PrivacySettings {
+    settings::get_privacy_settings()
+}
+
+#[tauri::command]
+fn save_privacy_settings(privacy: settings::PrivacySettings) -> Result<(), String> {
+    settings::save_privacy_settings(&privacy)?;
+
+    // Connect or disconnect Discord right away
+    sync_discord_presence();
+    Ok(())
+}
+
+// ----------
+// get_last_view
+// Description: The instance id or route the user was last looking at, read by the frontend on mount
+// ----------
+#[tauri::command]
+fn get_last_view() -> Option<String> {
+    settings::get_last_view()
+}
+
+// ----------
+// set_last_view
+// Description: Remembers the instance or route the user is looking at. Pass None to reset.
+// ----------
+#[tauri::command]
+fn set_last_view(view: Option<String>) -> Result<(), String> {
+    settings::set_last_view(view)
+}
+
+#[tauri::command]
+fn save_settings(mut new_settings: settings::LauncherSettings) -> Result<(), String> {
+    // The data directory only changes through migrate_data_directory, never from a stale settings form
+    let previous = settings::load_settings();
+    new_settings.custom_data_dir = previous.custom_data_dir;
+    // Likewise the last view is only written by set_last_view
+    new_settings.last_view = previous.last_view;
+    settings::save_settings(&new_settings)?;
+
+    if previous.force_ipv4.unwrap_or(false) != new_settings.force_ipv4.unwrap_or(false) {
+        minecraft::rebuild_http_client();
+    }
+
+    // Apply the Discord presence toggle right away
+    sync_discord_presence();
+    Ok(())
+}
+
+const LAUNCHER_CONFIG_FORMAT: &str = "palethea-launcher-config";
+const LAUNCHER_CONFIG_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LauncherConfigExport {
+    format: String,
+    version: u32,
+    exported_at: u64,
+    launcher_version: String,
+    settings: serde_json::Value,
+    #[serde(default)]
+    accounts: Option<auth::EncryptedAccounts>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LauncherConfigImport {
+    settings_imported: bool,
+    accounts: Option<auth::AccountsImportResult>,
+}
+
+/// Write settings, and optionally passphrase-encrypted accounts, to a file for another machine.
+/// Paths and tokens that only work here are never written in plain text.
+#[tauri::command]
+async fn export_launcher_config(destination_path: String, include_accounts: bool, passphrase: Option<String>) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || write_launcher_config(&destination_path, include_accounts, passphrase))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
+fn write_launcher_config(destination_path: &str, include_accounts: bool, passphrase: Option<String>) -> Result<(), String> {
+    // Key derivation takes a moment by design, hence the blocking task
+    let accounts = if include_accounts {
+        let passphrase = passphrase.ok_or("A passphrase is required to export accounts")?;
+        Some(auth::export_accounts(&passphrase)?)
+    } else {
+        None
+    };
+    let export = LauncherConfigExport {
+        format: LAUNCHER_CONFIG_FORMAT.to_string(),
+        version: LAUNCHER_CONFIG_VERSION,
+        exported_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        launcher_version: minecraft::get_launcher_version(),
+        settings: settings::export_portable_settings()?,
+        accounts,
+    };
+    let json = serde_json::to_string_pretty(&export).map_err(|e| e.to_string())?;
+    fs::write(destination_path, json).map_err(|e| format!("Failed to write {}: {}", destination_path, e))
+}
+
+/// Restore an exported launcher config. Settings are merged over the current ones; accounts are
+/// only added when missing here, and need the passphrase they were exported with.
+#[tauri::command]
+async fn import_launcher_config(path: String, passphrase: Option<String>) -> Result<LauncherConfigImport, String> {
+    tokio::task::spawn_blocking(move || read_launcher_config(&path, passphrase.as_deref()))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))?
+}
+
+fn read_launcher_config(path: &str, passphrase: Option<&str>) -> Result<LauncherConfigImport, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let export: LauncherConfigExport =
+        serde_json::from_str(&content).map_err(|_| "This is not a Palethea launcher config file".to_string())?;
+    if export.format != LAUNCHER_CONFIG_FORMAT {
+        return Err("This is not a Palethea launcher config file".to_string());
+    }
+    if export.version > LAUNCHER_CONFIG_VERSION {
+        return Err("This config was exported by a newer launcher version; update the launcher first".to_string());
+    }
+
+    // Decrypt and merge both in memory first so a wrong passphrase or bad settings block
+    // doesn't leave a half import
+    let accounts = match (&export.accounts, passphrase) {
+        (Some(_), None) => return Err("This file contains accounts; enter the passphrase it was exported with".to_string()),
+        (Some(encrypted), Some(passphrase)) => Some(auth::merge_imported_accounts(encrypted, passphrase)?),
+        (None, _) => None,
+    };
+    let merged_settings = settings::merge_portable_settings(&export.settings)?;
+
+    let accounts = match accounts {
+        Some((data, result)) => {
+            auth::save_accounts(&data)?;
+            Some(result)
+        }
+        None => None,
+    };
+    settings::save_settings(&merged_settings)?;
+    minecraft::rebuild_http_client();
+
+    Ok(LauncherConfigImport {
+        settings_imported: true,
+        accounts,
+    })
+}
+
+/// Check which address families reach the game and mod download hosts
+#[tauri::command]
+async fn diagnose_connectivity() -> Vec<minecraft::HostConnectivity> {
+    minecraft::diagnose_connectivity().await
 }
 
 fn copy_dir_recursive_simple(src: &Path, dst: &Path) -> Result<(), String> {
@@ -3016,9 +5309,9 @@ fn has_curseforge_api_key() -> bool {
 }
 
 #[tauri::command]
-async fn download_java_for_instance(instance_id: String, version: u32) -> Result<instances::Instance, String> {
+async fn download_java_for_instance(instance_id: String, version: u32, vendor: Option<String>) -> Result<instances::Instance, String> {
     let instance = instances::get_instance(&instance_id)?;
-    let java_path = java::download_java(version)
+    let java_path = java::download_java(version, vendor.as_deref(), None, None)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -3028,25 +5321,114 @@ async fn download_java_for_instance(instance_id: String, version: u32) -> Result
 }
 
 #[tauri::command]
-async fn download_java_global(version: u32) -> Result<String, String> {
-    let java_path = java::download_java(version)
+async fn download_java_global(version: u32, vendor: Option<String>) -> Result<String, String> {
+    let java_path = java::download_java(version, vendor.as_deref(), None, None)
         .await
         .map_err(|e| e.to_string())?;
     Ok(java_path.to_string_lossy().to_string())
 }
 
 #[tauri::command]
-async fn is_java_version_installed(version: u32) -> Result<bool, String> {
-    let mc_dir = minecraft::downloader::get_minecraft_dir();
-    let install_dir = mc_dir.join("java").join(format!("temurin-{}", version));
-    Ok(install_dir.exists())
+async fn is_java_version_installed(version: u32, vendor: Option<String>) -> Result<bool, String> {
+    java::is_java_installed(vendor.as_deref(), version)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InstalledJavaRuntime {
+    pub path: String,      // runtime folder under java/
+    pub java_path: String, // the java binary inside it
+    pub version: u32,
+    pub vendor: String,
+    pub size: u64,
+    pub in_use: bool, // referenced by an instance or the global Java path
+}
+
+/// Java paths currently configured on instances and in the launcher settings
+fn configured_java_paths() -> Vec<std::path::PathBuf> {
+    let mut paths: Vec<std::path::PathBuf> = instances::load_instances()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|instance| instance.java_path)
+        .map(std::path::PathBuf::from)
+        .collect();
+    paths.extend(settings::get_java_path().map(std::path::PathBuf::from));
+    paths
+}
+
+fn is_runtime_in_use(runtime_dir: &std::path::Path, configured: &[std::path::PathBuf]) -> bool {
+    let runtime_dir = runtime_dir.canonicalize().unwrap_or_else(|_| runtime_dir.to_path_buf());
+    configured.iter().any(|java_path| {
+        java_path
+            .canonicalize()
+            .unwrap_or_else(|_| java_path.clone())
+            .starts_with(&runtime_dir)
+    })
+}
+
+#[tauri::command]
+async fn list_installed_java_runtimes() -> Result<Vec<InstalledJavaRuntime>, String> {
+    tokio::task::spawn_blocking(|| {
+        let configured = configured_java_paths();
+        java::list_downloaded_runtimes()
+            .into_iter()
+            .map(|runtime| InstalledJavaRuntime {
+                path: runtime.dir.to_string_lossy().to_string(),
+                java_path: runtime.binary.to_string_lossy().to_string(),
+                version: runtime.version,
+                vendor: runtime.vendor,
+                size: get_dir_size(&runtime.dir),
+                in_use: is_runtime_in_use(&runtime.dir, &configured),
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+// ----------
+// delete_java_runtime
+// Description: Removes a downloaded runtime folder. Only folders directly under java/ are accepted,
+//              and runtimes still set as an instance's or the launcher's Java path are refused.
+// ----------
+#[tauri::command]
+async fn delete_java_runtime(path: String) -> Result<(), String> {
+    let java_root = downloader::get_minecraft_dir().join("java");
+    let java_root = java_root.canonicalize().map_err(|_| "No Java runtimes are installed".to_string())?;
+    let runtime_dir = std::path::PathBuf::from(&path)
+        .canonicalize()
+        .map_err(|_| format!("Java runtime not found: {}", path))?;
+    if runtime_dir.parent() != Some(java_root.as_path()) || !runtime_dir.is_dir() {
+        return Err("Only runtimes downloaded by the launcher can be deleted".to_string());
+    }
+
+    let configured = configured_java_paths();
+    if is_runtime_in_use(&runtime_dir, &configured) {
+        let users: Vec<String> = instances::load_instances()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|instance| {
+                instance
+                    .java_path
+                    .as_ref()
+                    .is_some_and(|p| is_runtime_in_use(&runtime_dir, &[std::path::PathBuf::from(p)]))
+            })
+            .map(|instance| instance.name)
+            .collect();
+        return Err(if users.is_empty() {
+            "This runtime is set as the global Java path".to_string()
+        } else {
+            format!("This runtime is used by: {}", users.join(", "))
+        });
+    }
+
+    fs::remove_dir_all(&runtime_dir).map_err(|e| format!("Failed to delete Java runtime: {}", e))
 }
 
 // ----------
 // GitHub Release Info
 // Description: Struct to hold release information from GitHub API
 // ----------
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GitHubRelease {
     pub tag_name: String,
     pub name: Option<String>,
@@ -3056,40 +5438,78 @@ pub struct GitHubRelease {
     pub html_url: String,
 }
 
+const GITHUB_RELEASES_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(600);
+
+static GITHUB_RELEASES_CACHE: LazyLock<Mutex<Option<(std::time::Instant, Vec<GitHubRelease>)>>> =
+    LazyLock::new(|| Mutex::new(None));
+
 // ----------
 // get_github_releases
-// Description: Fetches releases from GitHub API, returns both stable and prerelease versions
+// Description: Fetches releases from GitHub API, returns both stable and prerelease versions.
+//              Responses are cached for 10 minutes to stay under the unauthenticated rate limit.
 // ----------
 #[tauri::command]
 async fn get_github_releases(include_prerelease: bool) -> Result<Vec<GitHubRelease>, String> {
+    let cached = GITHUB_RELEASES_CACHE
+        .lock()
+        .ok()
+        .and_then(|cache| cache.as_ref().filter(|(at, _)| at.elapsed() < GITHUB_RELEASES_CACHE_TTL).map(|(_, r)| r.clone()));
+
+    let releases = match cached {
+        Some(releases) => releases,
+        None => {
+            let releases = fetch_github_releases().await?;
+            if let Ok(mut cache) = GITHUB_RELEASES_CACHE.lock() {
+                *cache = Some((std::time::Instant::now(), releases.clone()));
+            }
+            releases
+        }
+    };
+    
+    // Filter based on include_prerelease flag
+    let filtered: Vec<GitHubRelease> = if include_prerelease {
+        releases
+    } else {
+        releases.into_iter().filter(|r| !r.prerelease).collect()
+    };
+    
+    Ok(filtered)
+}
+
+async fn fetch_github_releases() -> Result<Vec<GitHubRelease>, String> {
     let client = minecraft::http_client();
     let url = "https://api.github.com/repos/mwsk75996/palethea-launcher/releases";
     
-    let response = client
+    let mut request = client
         .get(url)
-        .header("User-Agent", "PaletheaLauncher/0.1.0")
-        .header("Accept", "application/vnd.github.v3+json")
+        .header("Accept", "application/vnd.github.v3+json");
+    if let Some(token) = settings::get_github_token() {
+        request = request.bearer_auth(token);
+    }
+
+    let response = request
         .send()
         .await
         .map_err(|e| format!("Failed to fetch releases: {}", e))?;
     
-    if !response.status().is_success() {
-        return Err(format!("GitHub API returned status: {}", response.status()));
+    let status = response.status();
+    let rate_limited = response
+        .headers()
+        .get("x-ratelimit-remaining")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.trim() == "0");
+    if rate_limited && (status == reqwest::StatusCode::FORBIDDEN || status == reqwest::StatusCode::TOO_MANY_REQUESTS) {
+        return Err("GitHub rate limit reached, try again later".to_string());
+    }
+
+    if !status.is_success() {
+        return Err(format!("GitHub API returned status: {}", status));
     }
     
-    let releases: Vec<GitHubRelease> = response
+    response
         .json()
         .await
-        .map_err(|e| format!("Failed to parse releases: {}", e))?;
-    
-    // Filter based on include_prerelease flag
-    let filtered: Vec<GitHubRelease> = if include_prerelease {
-        releases
-    } else {
-        releases.into_iter().filter(|r| !r.prerelease).collect()
-    };
-    
-    Ok(filtered)
+        .map_err(|e| format!("Failed to parse releases: {}", e))
 }
 
 // ----------
@@ -3166,6 +5586,61 @@ fn is_prerelease_version(version: String) -> bool {
     ver.contains('-')
 }
 
+// ----------
+// UpdateCheckResult
+// Description: Outcome of check_for_update for the configured update channel
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UpdateCheckResult {
+    pub checked: bool, // false when automatic checks are turned off
+    pub update_available: bool,
+    pub current_version: String,
+    pub channel: String,
+    pub latest: Option<GitHubRelease>,
+}
+
+// ----------
+// check_for_update
+// Description: Looks for a newer release on the user's update channel. Stable users are never
+//              offered prerelease tags. Automatic checks are skipped when disabled in settings;
+//              pass manual = true for a user-initiated check.
+// ----------
+#[tauri::command]
+async fn check_for_update(manual: Option<bool>) -> Result<UpdateCheckResult, String> {
+    let channel = settings::get_update_channel();
+    let current_version = minecraft::get_launcher_version();
+
+    if !manual.unwrap_or(false) && !settings::is_auto_update_check_enabled() {
+        return Ok(UpdateCheckResult {
+            checked: false,
+            update_available: false,
+            current_version,
+            channel,
+            latest: None,
+        });
+    }
+
+    let include_prerelease = channel == "prerelease";
+    let releases = get_github_releases(include_prerelease).await?;
+
+    let latest = releases
+        .into_iter()
+        .filter(|r| include_prerelease || !is_prerelease_version(r.tag_name.clone()))
+        .max_by(|a, b| compare_versions(a.tag_name.clone(), b.tag_name.clone()).cmp(&0));
+
+    let update_available = latest
+        .as_ref()
+        .is_some_and(|r| compare_versions(r.tag_name.clone(), current_version.clone()) > 0);
+
+    Ok(UpdateCheckResult {
+        checked: true,
+        update_available,
+        current_version,
+        channel,
+        latest,
+    })
+}
+
 // ----------
 // download_and_run_installer
 // Description: Downloads a release installer from GitHub and runs it directly.
@@ -3202,7 +5677,6 @@ async fn download_and_run_installer(version: String, window: tauri::Window) -> R
     let client = minecraft::http_client();
     let response = client
         .get(&download_url)
-        .header("User-Agent", "PaletheaLauncher/0.1.0")
         .send()
         .await
         .map_err(|e| format!("Failed to download installer: {}", e))?;
@@ -3222,6 +5696,7 @@ async fn download_and_run_installer(version: String, window: tauri::Window) -> R
     
     while let Some(chunk) = stream.next().await {
         let chunk = chunk.map_err(|e| format!("Download error: {}", e))?;
+        downloader::throttle_download(chunk.len() as u64).await;
         file.write_all(&chunk)
             .await
             .map_err(|e| format!("Failed to write installer: {}", e))?;
@@ -3320,6 +5795,7 @@ async fn poll_microsoft_login(device_code: String, state: State<'_, AppState>) -
         access_token: account.access_token,
         refresh_token: account.refresh_token,
         is_microsoft: true,
+        needs_relogin: false,
     };
     auth::add_account(saved)?;
     auth::set_active_account(&account.username)?;
@@ -3448,19 +5924,7 @@ async fn refresh_account(username: String, state: State<'_, AppState>) -> Result
     let refresh_tok = account.refresh_token.clone()
         .ok_or("No refresh token available")?;
     
-    // Try to refresh the token
-    let token_response = auth::refresh_token(&refresh_tok)
-        .await
-        .map_err(|e| e.to_string())?;
-    
-    let new_access_token = token_response.access_token
-        .ok_or("No access token in refresh response")?;
-    
-    // Complete authentication with new token
-    let new_account = auth::complete_authentication(
-        &new_access_token,
-        token_response.refresh_token.or(Some(refresh_tok))
-    )
+    let new_account = auth::refresh_session(&refresh_tok)
         .await
         .map_err(|e| e.to_string())?;
     
@@ -3471,6 +5935,7 @@ async fn refresh_account(username: String, state: State<'_, AppState>) -> Result
         access_token: new_account.access_token.clone(),
         refresh_token: new_account.refresh_token.clone(),
         is_microsoft: true,
+        needs_relogin: false,
     };
     auth::add_account(saved)?;
     
@@ -3489,6 +5954,28 @@ async fn refresh_account(username: String, state: State<'_, AppState>) -> Result
     Ok(true)
 }
 
+/// Refresh every saved Microsoft account ahead of time. `on_startup` calls respect the
+/// launcher setting and do nothing when it is off.
+#[tauri::command]
+async fn refresh_all_accounts(on_startup: Option<bool>, state: State<'_, AppState>) -> Result<Vec<auth::AccountRefreshResult>, String> {
+    if on_startup.unwrap_or(false) && !settings::is_refresh_accounts_on_startup_enabled() {
+        return Ok(Vec::new());
+    }
+    if minecraft::is_offline() {
+        return Err("The launcher is offline".to_string());
+    }
+    let results = auth::refresh_all_accounts().await?;
+
+    // The active session may have been one of the refreshed accounts
+    let active_uuid = state.uuid.lock().map_err(|_| "Auth state corrupted")?.clone();
+    if results.iter().any(|r| r.status == "refreshed" && r.uuid == active_uuid) {
+        if let Some(account) = auth::load_accounts().accounts.into_iter().find(|a| a.is_microsoft && a.uuid == active_uuid) {
+            set_auth_state(&state, account.username, account.uuid, account.access_token, true, account.refresh_token)?;
+        }
+    }
+    Ok(results)
+}
+
 #[tauri::command]
 async fn switch_account(username: String, state: State<'_, AppState>) -> Result<(), String> {
     let data = auth::load_accounts();
@@ -3524,6 +6011,7 @@ async fn switch_account(username: String, state: State<'_, AppState>) -> Result<
                                         access_token: new_account.access_token,
                                         refresh_token: new_account.refresh_token,
                                         is_microsoft: true,
+                                        needs_relogin: false,
                                     };
                                     let _ = auth::add_account(saved);
                                 }
@@ -3565,7 +6053,7 @@ async fn switch_account(username: String, state: State<'_, AppState>) -> Result<
 
 #[tauri::command]
 fn set_offline_user(username: String, state: State<'_, AppState>) -> Result<(), String> {
-    let uuid = uuid::Uuid::new_v4().to_string().replace("-", "");
+    let uuid = auth::offline_uuid(&username);
     
     set_auth_state(
         &state,
@@ -3583,6 +6071,7 @@ fn set_offline_user(username: String, state: State<'_, AppState>) -> Result<(),
         access_token: "0".to_string(),
         refresh_token: None,
         is_microsoft: false,
+        needs_relogin: false,
     };
     auth::add_account(saved)?;
     auth::set_active_account(&username)?;
@@ -3590,14 +6079,199 @@ fn set_offline_user(username: String, state: State<'_, AppState>) -> Result<(),
     Ok(())
 }
 
-#[tauri::command]
-fn get_current_user(state: State<'_, AppState>) -> Result<String, String> {
-    Ok(state.username.lock().map_err(|_| "Auth state corrupted")?.clone())
-}
+#[derive(Debug, Serialize)]
+pub struct OfflineUuidMigration {
+    pub uuid: String,
+    pub changed: bool,
+    pub moved_player_files: usize,
+}
+
+/// Rename per-player world files (inventory, stats, advancements) from one UUID to another
+/// in every world of every instance. Files already present under the new UUID are left alone.
+fn move_player_world_files(old_uuid: &str, new_uuid: &str) -> usize {
+    let hyphenated = |raw: &str| {
+        uuid::Uuid::parse_str(raw)
+            .map(|parsed| parsed.hyphenated().to_string())
+            .unwrap_or_else(|_| raw.to_string())
+    };
+    let (old_uuid, new_uuid) = (hyphenated(old_uuid), hyphenated(new_uuid));
+    let player_files = [
+        ("playerdata", "dat"),
+        ("playerdata", "dat_old"),
+        ("stats", "json"),
+        ("advancements", "json"),
+    ];
+
+    let mut moved = 0;
+    for instance in instances::load_instances().unwrap_or_default() {
+        let Ok(worlds) = fs::read_dir(instance.get_game_directory().join("saves")) else { continue };
+        for world in worlds.flatten() {
+            let world_dir = world.path();
+            for (folder, extension) in player_files {
+                let from = world_dir.join(folder).join(format!("{}.{}", old_uuid, extension));
+                let to = world_dir.join(folder).join(format!("{}.{}", new_uuid, extension));
+                if from.is_file() && !to.exists() && fs::rename(&from, &to).is_ok() {
+                    moved += 1;
+                }
+            }
+        }
+    }
+    moved
+}
+
+/// Opt an existing offline account into the standard offline UUID, carrying its
+/// singleplayer inventories and stats over so worlds still recognise the player.
+#[tauri::command]
+fn migrate_offline_account_uuid(username: String, state: State<'_, AppState>) -> Result<OfflineUuidMigration, String> {
+    if has_running_processes() {
+        return Err("Close all running instances before changing an account's UUID".to_string());
+    }
+    let Some(old_uuid) = auth::migrate_offline_uuid(&username)? else {
+        return Ok(OfflineUuidMigration { uuid: auth::offline_uuid(&username), changed: false, moved_player_files: 0 });
+    };
+    let new_uuid = auth::offline_uuid(&username);
+    let moved_player_files = move_player_world_files(&old_uuid, &new_uuid);
+
+    let is_active = *state.username.lock().map_err(|_| "Auth state corrupted")? == username;
+    let is_microsoft = *state.is_microsoft_auth.lock().map_err(|_| "Auth state corrupted")?;
+    if is_active && !is_microsoft {
+        *state.uuid.lock().map_err(|_| "Auth state corrupted")? = new_uuid.clone();
+    }
+
+    Ok(OfflineUuidMigration { uuid: new_uuid, changed: true, moved_player_files })
+}
+
+#[tauri::command]
+fn get_current_user(state: State<'_, AppState>) -> Result<String, String> {
+    Ok(state.username.lock().map_err(|_| "Auth state corrupted")?.clone())
+}
+
+#[tauri::command]
+fn get_data_directory() -> String {
+    downloader::get_minecraft_dir().to_string_lossy().to_string()
+}
+
+// ----------
+// migrate_data_directory
+// Description: Moves instances, versions, libraries, assets and Java runtimes to a new
+//              folder and points custom_data_dir at it. settings.json stays where it is.
+// ----------
+#[tauri::command]
+async fn migrate_data_directory(app_handle: AppHandle, new_path: String) -> Result<String, String> {
+    if has_running_processes() {
+        return Err("Close all running instances before moving the data directory".to_string());
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let old_dir = downloader::get_minecraft_dir();
+        let new_dir = std::path::PathBuf::from(new_path.trim());
+        if !new_dir.is_absolute() {
+            return Err("The data directory must be an absolute path".to_string());
+        }
+        if new_dir == old_dir {
+            return Ok(old_dir.to_string_lossy().to_string());
+        }
+        if new_dir.starts_with(&old_dir) || old_dir.starts_with(&new_dir) {
+            return Err("The new data directory cannot be inside the current one (or contain it)".to_string());
+        }
+        // settings.json always stays in the default directory, so moving back there finds it
+        let default_dir = downloader::get_default_data_dir();
+        let settings_path = default_dir.join("settings.json");
+        if new_dir.exists() {
+            let has_entries = fs::read_dir(&new_dir)
+                .map_err(|e| format!("Cannot read {}: {}", new_dir.display(), e))?
+                .flatten()
+                .any(|entry| entry.path() != settings_path);
+            if has_entries {
+                return Err("The new data directory must be empty".to_string());
+            }
+        }
+
+        let entries: Vec<std::path::PathBuf> = fs::read_dir(&old_dir)
+            .map(|rd| rd.flatten().map(|e| e.path()).collect())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|p| *p != settings_path)
+            .collect();
+
+        let required: u64 = entries
+            .iter()
+            .map(|p| if p.is_dir() { get_dir_size(p) } else { p.metadata().map(|m| m.len()).unwrap_or(0) })
+            .sum();
+        downloader::ensure_disk_space(&new_dir, required)?;
+        fs::create_dir_all(&new_dir).map_err(|e| format!("Failed to create {}: {}", new_dir.display(), e))?;
+
+        let total_files: u32 = entries
+            .iter()
+            .map(|p| if p.is_dir() { count_files_recursive(p) } else { 1 })
+            .sum();
+        let mut copied = 0u32;
+        let mut written: Vec<std::path::PathBuf> = Vec::new();
+        for entry in &entries {
+            let Some(name) = entry.file_name() else { continue };
+            let target = new_dir.join(name);
+            written.push(target.clone());
+            let result = if entry.is_dir() {
+                copy_dir_with_progress(entry, &target, &app_handle, total_files, &mut copied, "Moving data")
+            } else {
+                copied += 1;
+                fs::copy(entry, &target).map(|_| ()).map_err(|e| e.to_string())
+            };
+            if let Err(e) = result {
+                // Leave the old directory untouched and drop only what this move wrote; the
+                // target may be the default directory, whose settings.json must survive
+                for path in written.iter().filter(|p| **p != settings_path) {
+                    let _ = if path.is_dir() { fs::remove_dir_all(path) } else { fs::remove_file(path) };
+                }
+                return Err(format!("Failed to copy {}: {}", entry.display(), e));
+            }
+        }
+
+        // Java paths pointing into the old directory have to follow the move
+        let old_prefix = old_dir.to_string_lossy().to_string();
+        let new_prefix = new_dir.to_string_lossy().to_string();
+        // Component-wise, so /data/mc2 is not taken to be inside /data/mc
+        let rebase = |path: &mut Option<String>| {
+            if let Some(p) = path.as_mut() {
+                if let Ok(rest) = std::path::Path::new(p.as_str()).strip_prefix(&old_dir) {
+                    *p = new_dir.join(rest).to_string_lossy().to_string();
+                }
+            }
+        };
+
+        let mut launcher_settings = settings::load_settings();
+        rebase(&mut launcher_settings.java_path);
+        launcher_settings.custom_data_dir = if new_dir == default_dir { None } else { Some(new_prefix.clone()) };
+        settings::save_settings(&launcher_settings)?;
+        downloader::set_data_dir_override(launcher_settings.custom_data_dir.as_ref().map(std::path::PathBuf::from));
 
-#[tauri::command]
-fn get_data_directory() -> String {
-    downloader::get_minecraft_dir().to_string_lossy().to_string()
+        if let Ok(mut all_instances) = instances::load_instances() {
+            all_instances.iter_mut().for_each(|instance| rebase(&mut instance.java_path));
+            if let Err(e) = instances::save_instances(&all_instances) {
+                log_warn!(&app_handle, "Failed to update instance Java paths after move: {}", e);
+            }
+        }
+
+        for entry in &entries {
+            let removed = if entry.is_dir() { fs::remove_dir_all(entry) } else { fs::remove_file(entry) };
+            if let Err(e) = removed {
+                log_warn!(&app_handle, "Could not remove old data at {}: {}", entry.display(), e);
+            }
+        }
+
+        log_info!(&app_handle, "Moved data directory from {} to {}", old_prefix, new_prefix);
+        let _ = app_handle.emit("download-progress", downloader::DownloadProgress {
+            stage: "Data directory moved".to_string(),
+            current: total_files,
+            total: total_files,
+            percentage: 100.0,
+            total_bytes: None,
+            downloaded_bytes: None,
+        });
+        Ok(new_prefix)
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
 }
 
 #[tauri::command]
@@ -3657,7 +6331,9 @@ async fn add_to_skin_collection(name: String, source_path: String, variant: Stri
     let dest_path = skins_dir.join(&filename);
     
     if source_path.starts_with("http") {
-        let response = reqwest::get(&source_path)
+        let response = minecraft::http_client()
+            .get(&source_path)
+            .send()
             .await
             .map_err(|e| format!("Failed to download skin: {}", e))?;
         let bytes = response.bytes()
@@ -3709,6 +6385,22 @@ fn delete_skin_from_collection(id: String) -> Result<(), String> {
     Ok(())
 }
 
+/// Render a front-facing preview of a skin PNG and return the cached image path
+#[tauri::command]
+async fn render_skin_preview(file_path: String, variant: String) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        skin::render_skin_preview(Path::new(&file_path), &variant).map(|path| path.to_string_lossy().to_string())
+    })
+    .await
+    .map_err(|e| format!("Failed to join preview task: {}", e))?
+}
+
+/// Get a cached head avatar for an account UUID and return the image path
+#[tauri::command]
+async fn get_account_head(uuid: String) -> Result<String, String> {
+    skin::account_head(&uuid).await.map(|path| path.to_string_lossy().to_string())
+}
+
 #[tauri::command]
 fn get_skin_file_path(filename: String) -> String {
     downloader::get_skins_dir().join(filename).to_string_lossy().to_string()
@@ -3732,22 +6424,22 @@ struct DownloadedVersion {
     size: u64,
 }
 
+/// Total size of the files under `path`, walked in parallel on the rayon pool. Symlinks
+/// are not followed, so links back up the tree can't recurse forever or count twice.
 fn get_dir_size(path: &std::path::Path) -> u64 {
-    if !path.exists() {
-        return 0;
-    }
-    let mut size = 0;
-    if let Ok(entries) = fs::read_dir(path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_dir() {
-                size += get_dir_size(&path);
-            } else if let Ok(meta) = path.metadata() {
-                size += meta.len();
-            }
-        }
-    }
-    size
+    use rayon::prelude::*;
+
+    let Ok(entries) = fs::read_dir(path) else { return 0 };
+    entries
+        .flatten()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|entry| match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => get_dir_size(&entry.path()),
+            Ok(file_type) if file_type.is_file() => entry.metadata().map(|m| m.len()).unwrap_or(0),
+            _ => 0,
+        })
+        .sum()
 }
 
 #[tauri::command]
@@ -3755,11 +6447,14 @@ async fn get_disk_usage() -> Result<DiskUsageInfo, String> {
     tokio::task::spawn_blocking(|| {
         let base_dir = downloader::get_minecraft_dir();
         
-        let versions_size = get_dir_size(&base_dir.join("versions"));
-        let libraries_size = get_dir_size(&base_dir.join("libraries"));
-        let assets_size = get_dir_size(&base_dir.join("assets"));
-        let instances_size = get_dir_size(&base_dir.join("instances"));
-        let java_size = get_dir_size(&base_dir.join("java"));
+        use rayon::prelude::*;
+
+        let sizes: Vec<u64> = ["versions", "libraries", "assets", "instances", "java"]
+            .par_iter()
+            .map(|folder| get_dir_size(&base_dir.join(folder)))
+            .collect();
+        let (versions_size, libraries_size, assets_size, instances_size, java_size) =
+            (sizes[0], sizes[1], sizes[2], sizes[3], sizes[4]);
         
         Ok(DiskUsageInfo {
             versions: versions_size,
@@ -3800,6 +6495,7 @@ async fn get_downloaded_versions() -> Result<Vec<DownloadedVersion>, String> {
                     if let Some(ref loader_ver) = inst.mod_loader_version {
                         let loader_name = match inst.mod_loader {
                             instances::ModLoader::Fabric => "Fabric",
+                            instances::ModLoader::Quilt => "Quilt",
                             instances::ModLoader::Forge => "Forge",
                             instances::ModLoader::NeoForge => "NeoForge",
                             _ => "ModLoader",
@@ -3848,7 +6544,7 @@ async fn get_downloaded_versions() -> Result<Vec<DownloadedVersion>, String> {
 #[tauri::command]
 fn delete_version(version_id: String) -> Result<String, String> {
     // Check if this is a synthesized mod loader version
-    if version_id.contains("Fabric") || version_id.contains("Forge") || version_id.contains("NeoForge") {
+    if version_id.contains("Fabric") || version_id.contains("Quilt") || version_id.contains("Forge") || version_id.contains("NeoForge") {
         return Err("Mod loader versions cannot be deleted from here. Modify or delete the instance that uses it instead.".to_string());
     }
 
@@ -3879,6 +6575,231 @@ fn clear_assets_cache() -> Result<String, String> {
     Ok("Assets cache cleared".to_string())
 }
 
+#[derive(Debug, Serialize)]
+struct LibraryDedupeReport {
+    scanned_files: u32,
+    duplicate_groups: u32,
+    duplicate_files: u32,
+    reclaimable_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct LibraryPruneResult {
+    scanned_files: u32,
+    removed_files: u32,
+    freed_bytes: u64,
+}
+
+fn collect_files_recursive(dir: &std::path::Path, out: &mut Vec<std::path::PathBuf>) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_files_recursive(&path, out);
+            } else if path.is_file() {
+                out.push(path);
+            }
+        }
+    }
+}
+
+fn remove_empty_dirs(dir: &std::path::Path) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                remove_empty_dirs(&path);
+                let _ = fs::remove_dir(&path);
+            }
+        }
+    }
+}
+
+/// Version JSON ids an instance can launch from (vanilla plus any loader-specific id)
+fn instance_version_json_ids(inst: &instances::Instance) -> Vec<String> {
+    let mut ids = vec![inst.version_id.clone()];
+    let loader_version = inst.mod_loader_version.clone().unwrap_or_default();
+
+    match inst.mod_loader {
+        instances::ModLoader::Forge => {
+            if let Some(vid) = forge::load_forge_info(inst).and_then(|info| info.version_id) {
+                ids.push(vid);
+            }
+            ids.push(format!("{}-forge-{}", inst.version_id, loader_version));
+            ids.push(format!("{}-forge{}", inst.version_id, loader_version));
+        }
+        instances::ModLoader::NeoForge => {
+            ids.push(format!("neoforge-{}", loader_version));
+            ids.push(loader_version.clone());
+            ids.push(format!("{}-neoforge-{}", inst.version_id, loader_version));
+        }
+        _ => {}
+    }
+
+    ids
+}
+
+fn collect_version_library_paths(details: &versions::VersionDetails, libraries_dir: &std::path::Path, out: &mut HashSet<std::path::PathBuf>) {
+    for library in &details.libraries {
+        match &library.downloads {
+            Some(downloads) => {
+                if let Some(artifact) = &downloads.artifact {
+                    out.insert(libraries_dir.join(&artifact.path));
+                }
+                if let Some(classifiers) = &downloads.classifiers {
+                    for native_artifact in classifiers.values() {
+                        out.insert(libraries_dir.join(&native_artifact.path));
+                    }
+                }
+            }
+            None => {
+                out.insert(libraries_dir.join(versions::library_name_to_path(&library.name)));
+            }
+        }
+    }
+}
+
+#[tauri::command]
+async fn dedupe_libraries() -> Result<LibraryDedupeReport, String> {
+    tokio::task::spawn_blocking(|| {
+        let mut files = Vec::new();
+        collect_files_recursive(&downloader::get_libraries_dir(), &mut files);
+
+        // Only hash files that share a size with at least one other file
+        let mut by_size: HashMap<u64, Vec<std::path::PathBuf>> = HashMap::new();
+        for path in &files {
+            if let Ok(meta) = fs::metadata(path) {
+                by_size.entry(meta.len()).or_default().push(path.clone());
+            }
+        }
+
+        let mut duplicate_groups = 0u32;
+        let mut duplicate_files = 0u32;
+        let mut reclaimable_bytes = 0u64;
+
+        for (size, paths) in by_size {
+            if paths.len() < 2 || size == 0 {
+                continue;
+            }
+
+            let mut by_hash: HashMap<String, u32> = HashMap::new();
+            for path in &paths {
+                if let Ok(hash) = compute_file_sha1(path) {
+                    *by_hash.entry(hash).or_insert(0) += 1;
+                }
+            }
+
+            for count in by_hash.values().filter(|count| **count > 1) {
+                duplicate_groups += 1;
+                duplicate_files += count - 1;
+                reclaimable_bytes += size * (*count as u64 - 1);
+            }
+        }
+
+        Ok(LibraryDedupeReport {
+            scanned_files: files.len() as u32,
+            duplicate_groups,
+            duplicate_files,
+            reclaimable_bytes,
+        })
+    }).await.map_err(|e| e.to_string())?
+}
+
+#[tauri::command]
+async fn prune_unused_libraries() -> Result<LibraryPruneResult, String> {
+    if has_running_processes() {
+        return Err("Close all running instances before pruning libraries".to_string());
+    }
+
+    tokio::task::spawn_blocking(|| {
+        let libraries_dir = downloader::get_libraries_dir();
+        let versions_dir = downloader::get_versions_dir();
+        let all_instances = instances::load_instances()?;
+
+        let mut referenced: HashSet<std::path::PathBuf> = HashSet::new();
+        let mut has_forge_like = false;
+
+        for inst in &all_instances {
+            let mut found_base = false;
+            for id in instance_version_json_ids(inst) {
+                let json_path = versions_dir.join(&id).join(format!("{}.json", id));
+                if !json_path.exists() {
+                    continue;
+                }
+                let details = versions::load_version_details(&json_path)
+                    .map_err(|e| format!("Failed to read {}: {}", json_path.display(), e))?;
+                collect_version_library_paths(&details, &libraries_dir, &mut referenced);
+                if id == inst.version_id {
+                    found_base = true;
+                }
+            }
+
+            // Without the base manifest we cannot tell what this instance needs
+            if !found_base {
+                return Err(format!(
+                    "Version {} used by '{}' is not downloaded; launch it once before pruning",
+                    inst.version_id, inst.name
+                ));
+            }
+
+            match inst.mod_loader {
+                instances::ModLoader::Fabric => {
+                    if let Some(fabric_info) = fabric::load_fabric_info(inst) {
+                        for (_, path) in fabric::get_fabric_classpath(&fabric_info) {
+                            referenced.insert(std::path::PathBuf::from(path));
+                        }
+                    }
+                }
+                instances::ModLoader::Quilt => {
+                    if let Some(quilt_info) = quilt::load_quilt_info(inst) {
+                        for (_, path) in quilt::get_quilt_classpath(&quilt_info) {
+                            referenced.insert(std::path::PathBuf::from(path));
+                        }
+                    }
+                }
+                instances::ModLoader::Forge | instances::ModLoader::NeoForge => {
+                    has_forge_like = true;
+                }
+                _ => {}
+            }
+        }
+
+        // Forge/NeoForge installers generate patched jars that no version JSON lists
+        let protected_prefixes: Vec<std::path::PathBuf> = if has_forge_like {
+            ["net/minecraftforge", "net/neoforged", "net/minecraft", "de/oceanlabs/mcp"]
+                .iter()
+                .map(|prefix| libraries_dir.join(prefix))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut files = Vec::new();
+        collect_files_recursive(&libraries_dir, &mut files);
+
+        let mut removed_files = 0u32;
+        let mut freed_bytes = 0u64;
+        for path in &files {
+            if referenced.contains(path) || protected_prefixes.iter().any(|prefix| path.starts_with(prefix)) {
+                continue;
+            }
+            let size = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            if fs::remove_file(path).is_ok() {
+                removed_files += 1;
+                freed_bytes += size;
+            }
+        }
+
+        remove_empty_dirs(&libraries_dir);
+
+        Ok(LibraryPruneResult {
+            scanned_files: files.len() as u32,
+            removed_files,
+            freed_bytes,
+        })
+    }).await.map_err(|e| e.to_string())?
+}
+
 // ============== MOD LOADER COMMANDS ==============
 
 #[tauri::command]
@@ -3893,7 +6814,7 @@ async fn get_loader_versions(loader: String, game_version: String) -> Result<Vec
             );
             let response = client
                 .get(&url)
-                .header("User-Agent", "PaletheaLauncher/0.1.3")
+                .header("User-Agent", format!("PaletheaLauncher/{}", minecraft::get_launcher_version()))
                 .send()
                 .await
                 .map_err(|e| format!("Network error connecting to Fabric API: {}", e))?;
@@ -3938,6 +6859,26 @@ async fn get_loader_versions(loader: String, game_version: String) -> Result<Vec
                 }
             }).collect())
         }
+        "quilt" => {
+            let loaders = quilt::get_quilt_loader_versions(&game_version)
+                .await
+                .map_err(|e| e.to_string())?;
+            
+            Ok(loaders.into_iter().map(|loader| {
+                let lower_v = loader.version.to_lowercase();
+                let v_type = if lower_v.contains("beta") || lower_v.contains("alpha") || lower_v.contains("rc") || lower_v.contains("pre") {
+                    "snapshot".to_string()
+                } else {
+                    "release".to_string()
+                };
+                
+                LoaderVersion {
+                    version: loader.version,
+                    release_time: None,
+                    version_type: v_type,
+                }
+            }).collect())
+        }
         "forge" => {
             // Fetch Forge versions
             let client = minecraft::http_client();
@@ -3946,7 +6887,7 @@ async fn get_loader_versions(loader: String, game_version: String) -> Result<Vec
             );
             let response = client
                 .get(&url)
-                .header("User-Agent", "PaletheaLauncher/0.1.3")
+                .header("User-Agent", format!("PaletheaLauncher/{}", minecraft::get_launcher_version()))
                 .send()
                 .await
                 .map_err(|e| e.to_string())?
@@ -3994,7 +6935,7 @@ async fn get_loader_versions(loader: String, game_version: String) -> Result<Vec
             let url = "https://maven.neoforged.net/api/maven/versions/releases/net/neoforged/neoforge";
             let response = client
                 .get(url)
-                .header("User-Agent", "PaletheaLauncher/0.1.3")
+                .header("User-Agent", format!("PaletheaLauncher/{}", minecraft::get_launcher_version()))
                 .send()
                 .await
                 .map_err(|e| e.to_string())?
@@ -4065,8 +7006,8 @@ async fn search_modrinth(
 }
 
 #[tauri::command]
-async fn get_modpack_total_size(version_id: String) -> Result<u64, String> {
-    modrinth::get_modpack_total_size(&version_id)
+async fn get_modpack_total_size(version_id: String, file_name: Option<String>) -> Result<u64, String> {
+    modrinth::get_modpack_total_size(&version_id, file_name.as_deref())
         .await
         .map_err(|e| e.to_string())
 }
@@ -4124,15 +7065,63 @@ async fn get_curseforge_modpack_total_size(
         .map_err(|e| e.to_string())
 }
 
+// ----------
+// plan_modpack_install
+// Description: Dry run of a modpack install - fetches only the pack index/manifest and reports
+//              the resolved versions, files, sizes and overrides without writing to the instance
+// ----------
+#[tauri::command]
+async fn plan_modpack_install(
+    source: String,
+    instance_id: Option<String>,
+    version_id: String,
+    project_id: Option<String>,
+    file_name: Option<String>,
+) -> Result<downloader::ModpackInstallPlan, String> {
+    let instance = match instance_id.as_deref() {
+        Some(id) => Some(instances::get_instance(id)?),
+        None => None,
+    };
+    let game_dir = instance.as_ref().map(|inst| inst.get_game_directory());
+
+    match source.trim().to_ascii_lowercase().as_str() {
+        "curseforge" => {
+            let pack_project_id = project_id
+                .or_else(|| instance.as_ref().and_then(|inst| inst.modpack_project_id.clone()))
+                .ok_or_else(|| "A CurseForge project id is required to plan this install".to_string())?;
+            curseforge::plan_modpack_install(&pack_project_id, &version_id, game_dir.as_deref())
+                .await
+                .map_err(|e| e.to_string())
+        }
+        "modrinth" => {
+            let file_name = file_name.or_else(|| instance.as_ref().and_then(|inst| inst.modpack_file_name.clone()));
+            modrinth::plan_modpack_install(&version_id, file_name.as_deref(), game_dir.as_deref())
+                .await
+                .map_err(|e| e.to_string())
+        }
+        other => Err(format!("Unknown modpack source: {}", other)),
+    }
+}
+
 #[tauri::command]
 async fn install_modpack(
     instance_id: String,
     version_id: String,
+    file_name: Option<String>,
     app_handle: tauri::AppHandle,
-) -> Result<(), String> {
-    modrinth::install_modpack(&app_handle, &instance_id, &version_id)
-        .await
-        .map_err(|e| e.to_string())
+) -> Result<downloader::ModpackInstallSummary, String> {
+    instances::get_instance(&instance_id)?.ensure_unlocked()?;
+    run_queued_modpack_install(
+        &app_handle,
+        &instance_id,
+        modrinth::install_modpack(&app_handle, &instance_id, &version_id, file_name.as_deref()),
+    )
+    .await
+}
+
+#[tauri::command]
+async fn get_modpack_files(version_id: String) -> Result<Vec<modrinth::ModpackFileOption>, String> {
+    modrinth::get_modpack_files(&version_id).await.map_err(|e| e.to_string())
 }
 
 #[tauri::command]
@@ -4141,10 +7130,9 @@ async fn install_curseforge_modpack(
     project_id: String,
     file_id: String,
     app_handle: tauri::AppHandle,
-) -> Result<(), String> {
-    curseforge::install_modpack(&app_handle, &instance_id, &project_id, &file_id)
-        .await
-        .map_err(|e| e.to_string())
+) -> Result<downloader::ModpackInstallSummary, String> {
+    instances::get_instance(&instance_id)?.ensure_unlocked()?;
+    run_queued_modpack_install(&app_handle, &instance_id, curseforge::install_modpack(&app_handle, &instance_id, &project_id, &file_id)).await
 }
 
 #[tauri::command]
@@ -4153,8 +7141,9 @@ async fn switch_instance_modpack_version(
     target_version_id: String,
     force_reinstall: Option<bool>,
     app_handle: tauri::AppHandle,
-) -> Result<(), String> {
+) -> Result<downloader::ModpackInstallSummary, String> {
     let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
     let provider = instance
         .modpack_provider
         .clone()
@@ -4166,7 +7155,7 @@ async fn switch_instance_modpack_version(
     let current_version = instance.modpack_version_id.clone().unwrap_or_default();
     let should_force_reinstall = force_reinstall.unwrap_or(false);
     if !should_force_reinstall && !current_version.is_empty() && current_version == target_version_id {
-        return Ok(());
+        return Ok(downloader::ModpackInstallSummary::default());
     }
 
     let game_dir = instance.get_game_directory();
@@ -4209,24 +7198,27 @@ async fn switch_instance_modpack_version(
             .await
             .map_err(|e| e.to_string())
     } else {
-        modrinth::install_modpack(&app_handle, &instance_id, &target_version_id)
+        modrinth::install_modpack(&app_handle, &instance_id, &target_version_id, None)
             .await
             .map_err(|e| e.to_string())
     };
 
-    if let Err(install_error) = install_result {
-        if mods_dir.exists() {
-            let _ = fs::remove_dir_all(&mods_dir);
+    let summary = match install_result {
+        Ok(summary) => summary,
+        Err(install_error) => {
+            if mods_dir.exists() {
+                let _ = fs::remove_dir_all(&mods_dir);
+            }
+            let _ = restore_path_if_exists(&backup_mods, &mods_dir);
+            let _ = restore_path_if_exists(&backup_config, &config_dir);
+            let _ = restore_path_if_exists(&backup_options, &options_txt);
+            let _ = restore_path_if_exists(&backup_options_of, &options_of_txt);
+            let _ = restore_path_if_exists(&backup_options_shader, &options_shader_txt);
+            let _ = restore_path_if_exists(&backup_servers, &servers_dat);
+            let _ = fs::remove_dir_all(&backup_root);
+            return Err(format!("Failed to switch modpack version: {}", install_error));
         }
-        let _ = restore_path_if_exists(&backup_mods, &mods_dir);
-        let _ = restore_path_if_exists(&backup_config, &config_dir);
-        let _ = restore_path_if_exists(&backup_options, &options_txt);
-        let _ = restore_path_if_exists(&backup_options_of, &options_of_txt);
-        let _ = restore_path_if_exists(&backup_options_shader, &options_shader_txt);
-        let _ = restore_path_if_exists(&backup_servers, &servers_dat);
-        let _ = fs::remove_dir_all(&backup_root);
-        return Err(format!("Failed to switch modpack version: {}", install_error));
-    }
+    };
 
     restore_path_if_exists(&backup_config, &config_dir)?;
     restore_path_if_exists(&backup_options, &options_txt)?;
@@ -4239,7 +7231,151 @@ async fn switch_instance_modpack_version(
     instances::update_instance(updated_instance)?;
 
     let _ = fs::remove_dir_all(&backup_root);
-    Ok(())
+    Ok(summary)
+}
+
+// ----------
+// update_modpack
+// Description: Moves a Modrinth modpack instance to another version in place, keeping
+//              user-added mods and edited configs instead of reinstalling from scratch
+// ----------
+#[tauri::command]
+async fn update_modpack(
+    instance_id: String,
+    new_version_id: String,
+    file_name: Option<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<downloader::ModpackInstallSummary, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    if is_instance_running(&instance_id) {
+        return Err("Close the game before updating the modpack".to_string());
+    }
+
+    let provider = instance.modpack_provider.clone().unwrap_or_default().trim().to_ascii_lowercase();
+    if provider == "curseforge" {
+        return Err("In-place updates are only available for Modrinth modpacks".to_string());
+    }
+
+    // Without an explicit pick, the file chosen at install time guides the choice again
+    let file_name = file_name.or_else(|| instance.modpack_file_name.clone());
+    let summary = modrinth::update_modpack(&app_handle, &instance_id, &new_version_id, file_name.as_deref())
+        .await
+        .map_err(|e| format!("Failed to update modpack: {}", e))?;
+
+    let mut updated_instance = instances::get_instance(&instance_id)?;
+    updated_instance.modpack_version_id = Some(new_version_id);
+    instances::update_instance(updated_instance)?;
+    files::invalidate_instance_summary(&instance_id);
+
+    Ok(summary)
+}
+
+// preflight_check results per instance, keyed by what they depend on: the mods folder's
+// modification time (any add, remove or enable/disable rename changes it), game version and loader
+type PreflightFingerprint = (Option<std::time::SystemTime>, String, String);
+static PREFLIGHT_CACHE: LazyLock<Mutex<HashMap<String, (PreflightFingerprint, Vec<preflight::ModCompatibilityIssue>)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Enabled mods that look built for another loader or game version. Advisory: the UI warns
+/// before launching but nothing is blocked. Runs on every launch, so results are cached
+/// until the mods folder, game version or loader changes.
+#[tauri::command]
+async fn preflight_check(instance_id: String) -> Result<Vec<preflight::ModCompatibilityIssue>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    if instance.mod_loader == instances::ModLoader::Vanilla {
+        return Ok(Vec::new());
+    }
+    let fingerprint: PreflightFingerprint = (
+        fs::metadata(files::get_mods_dir(&instance)).and_then(|meta| meta.modified()).ok(),
+        instance.version_id.clone(),
+        instance.mod_loader.slug(),
+    );
+    let cached = PREFLIGHT_CACHE.lock().ok().and_then(|cache| {
+        cache
+            .get(&instance_id)
+            .filter(|(key, _)| *key == fingerprint)
+            .map(|(_, issues)| issues.clone())
+    });
+    if let Some(issues) = cached {
+        return Ok(issues);
+    }
+    let mods: Vec<files::InstalledMod> = files::list_mods(&instance).into_iter().filter(|m| m.enabled).collect();
+
+    let version_ids: Vec<String> = mods
+        .iter()
+        .filter(|m| m.provider == "Modrinth")
+        .filter_map(|m| m.version_id.clone())
+        .collect();
+    // Offline or on API errors every mod falls back to inspecting its jar
+    let modrinth_versions: HashMap<String, modrinth::ModrinthVersion> = if version_ids.is_empty() || minecraft::is_offline() {
+        HashMap::new()
+    } else {
+        modrinth::get_versions_bulk(version_ids)
+            .await
+            .map(|versions| versions.into_iter().map(|v| (v.id.clone(), v)).collect())
+            .unwrap_or_default()
+    };
+
+    let issues = tokio::task::spawn_blocking(move || preflight::check_mods(&instance, &mods, &modrinth_versions))
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Ok(mut cache) = PREFLIGHT_CACHE.lock() {
+        cache.insert(instance_id, (fingerprint, issues.clone()));
+    }
+    Ok(issues)
+}
+
+#[tauri::command]
+async fn verify_modpack(instance_id: String) -> Result<Vec<downloader::ModpackFileFailure>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    let version_id = instance
+        .modpack_version_id
+        .clone()
+        .ok_or_else(|| "This instance was not installed from a modpack".to_string())?;
+    let provider = instance.modpack_provider.clone().unwrap_or_default().trim().to_ascii_lowercase();
+
+    if provider == "curseforge" {
+        let project_id = instance
+            .modpack_project_id
+            .clone()
+            .ok_or_else(|| "This instance is missing modpack project metadata".to_string())?;
+        curseforge::verify_modpack(&instance, &project_id, &version_id)
+            .await
+            .map_err(|e| e.to_string())
+    } else {
+        modrinth::verify_modpack(&instance, &version_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[tauri::command]
+async fn retry_failed_modpack_files(instance_id: String, app_handle: AppHandle) -> Result<Vec<downloader::ModpackFileFailure>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    let version_id = instance
+        .modpack_version_id
+        .clone()
+        .ok_or_else(|| "This instance was not installed from a modpack".to_string())?;
+    let provider = instance.modpack_provider.clone().unwrap_or_default().trim().to_ascii_lowercase();
+
+    let remaining = if provider == "curseforge" {
+        let project_id = instance
+            .modpack_project_id
+            .clone()
+            .ok_or_else(|| "This instance is missing modpack project metadata".to_string())?;
+        curseforge::repair_modpack_files(&app_handle, &instance, &project_id, &version_id)
+            .await
+            .map_err(|e| e.to_string())?
+    } else {
+        modrinth::repair_modpack_files(&app_handle, &instance, &version_id)
+            .await
+            .map_err(|e| e.to_string())?
+    };
+
+    let _ = app_handle.emit("refresh-instances", ());
+    Ok(remaining)
 }
 
 #[tauri::command]
@@ -4276,6 +7412,15 @@ async fn get_modrinth_version(
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+async fn get_version_dependencies_detailed(
+    version_id: String,
+) -> Result<Vec<modrinth::DetailedDependency>, String> {
+    modrinth::get_version_dependencies_detailed(&version_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 struct ManualMetadataResolveReport {
     pub scanned: u32,
@@ -4416,6 +7561,8 @@ fn mod_meta_from_modrinth_project(
         icon_url: project.icon_url.clone(),
         version_name,
         categories,
+        source_url: None,
+        pinned: false,
     }
 }
 
@@ -4443,6 +7590,8 @@ fn mod_meta_from_curseforge_project(
         } else {
             Some(project.categories.clone())
         },
+        source_url: None,
+        pinned: false,
     }
 }
 
@@ -4974,34 +8123,179 @@ async fn resolve_manual_modrinth_metadata(
     .await
 }
 
+// ----------
+// identify_mods_by_hash
+// Description: Matches metadata-less mod jars against Modrinth by SHA1 in bulk and writes
+//              ModMeta sidecars, so manually added mods get update checks and share codes
+// ----------
 #[tauri::command]
-async fn install_modrinth_file(
+async fn identify_mods_by_hash(
     app_handle: AppHandle,
     instance_id: String,
+) -> Result<ManualMetadataResolveReport, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    let mods_dir = files::get_mods_dir(&instance);
+
+    let candidates: Vec<(String, std::path::PathBuf)> = files::list_mods(&instance)
+        .into_iter()
+        .filter(|item| item.provider == "Manual")
+        .map(|item| {
+            let path = mods_dir.join(&item.filename);
+            (item.filename.trim_end_matches(".disabled").to_string(), path)
+        })
+        .filter(|(_, path)| path.is_file())
+        .collect();
+
+    let mut report = ManualMetadataResolveReport {
+        scanned: candidates.len() as u32,
+        ..Default::default()
+    };
+    if candidates.is_empty() {
+        return Ok(report);
+    }
+
+    let hashed = tokio::task::spawn_blocking(move || {
+        candidates
+            .into_iter()
+            .map(|(filename, path)| (filename, compute_file_sha1(&path)))
+            .collect::<Vec<_>>()
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?;
+
+    let mut by_hash: Vec<(String, String)> = Vec::new();
+    for (filename, sha1) in hashed {
+        match sha1 {
+            Ok(sha1) => by_hash.push((filename, sha1)),
+            Err(error) => {
+                report.errors += 1;
+                logger::emit_log(&app_handle, "warn", &format!("Failed to hash file: {}", error));
+            }
+        }
+    }
+
+    // Only ask Modrinth about hashes this session hasn't seen yet
+    let unknown: Vec<String> = match MANUAL_METADATA_VERSION_CACHE.lock() {
+        Ok(cache) => by_hash
+            .iter()
+            .filter(|(_, sha1)| !cache.contains_key(sha1))
+            .map(|(_, sha1)| sha1.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect(),
+        Err(_) => by_hash.iter().map(|(_, sha1)| sha1.clone()).collect(),
+    };
+    let fetched = modrinth::get_versions_by_file_sha1_bulk(unknown.clone())
+        .await
+        .map_err(|e| format!("Modrinth hash lookup failed: {}", e))?;
+    if let Ok(mut cache) = MANUAL_METADATA_VERSION_CACHE.lock() {
+        for sha1 in unknown {
+            let version = fetched.get(&sha1).cloned();
+            cache.insert(sha1, version);
+        }
+    }
+
+    let found: Vec<(String, modrinth::ModrinthVersion)> = match MANUAL_METADATA_VERSION_CACHE.lock() {
+        Ok(cache) => by_hash
+            .into_iter()
+            .filter_map(|(filename, sha1)| cache.get(&sha1).cloned().flatten().map(|v| (filename, v)))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    report.unresolved = report.scanned - report.errors - found.len() as u32;
+    if found.is_empty() {
+        return Ok(report);
+    }
+
+    let missing_projects: Vec<String> = match MANUAL_METADATA_PROJECT_CACHE.lock() {
+        Ok(cache) => found
+            .iter()
+            .map(|(_, version)| version.project_id.clone())
+            .filter(|id| !matches!(cache.get(id), Some(Some(_))))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    match modrinth::get_projects(missing_projects).await {
+        Ok(projects) => {
+            if let Ok(mut cache) = MANUAL_METADATA_PROJECT_CACHE.lock() {
+                for project in projects {
+                    cache.insert(project.project_id.clone(), Some(project));
+                }
+            }
+        }
+        Err(error) => logger::emit_log(&app_handle, "warn", &format!("Failed to fetch Modrinth projects: {}", error)),
+    }
+
+    for (filename, version) in found {
+        let project = MANUAL_METADATA_PROJECT_CACHE
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(&version.project_id).cloned().flatten());
+        let Some(project) = project else {
+            report.errors += 1;
+            continue;
+        };
+
+        report.matched += 1;
+        let meta = mod_meta_from_modrinth_project(&project, Some(&version), Some(filename.trim_end_matches(".jar").to_string()));
+        match files::write_meta_for_entry(&mods_dir, &filename, &meta) {
+            Ok(_) => report.updated += 1,
+            Err(error) => {
+                report.errors += 1;
+                logger::emit_log(&app_handle, "warn", &format!("Failed to write metadata for {}: {}", filename, error));
+            }
+        }
+    }
+
+    log_info!(&app_handle, "Identified {}/{} manual mods by hash for {}", report.updated, report.scanned, instance.name);
+    Ok(report)
+}
+
+// ----------
+// ContentInstallItem
+// Description: A single mod/resource pack/shader/datapack download, as taken by
+//              install_modrinth_file and queued in batches by queue_install
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct ContentInstallItem {
+    instance_id: String,
     file_url: String,
     filename: String,
     file_type: String, // "mod", "resourcepack", "shader", "datapack"
+    #[serde(default)]
     project_id: Option<String>,
+    #[serde(default)]
     version_id: Option<String>,
+    #[serde(default)]
     world_name: Option<String>,
+    #[serde(default)]
     name: Option<String>,
+    #[serde(default)]
     author: Option<String>,
+    #[serde(default)]
     icon_url: Option<String>,
+    #[serde(default)]
     version_name: Option<String>,
-    // ----------
-    // Categories parameter
-    // Description: Modrinth category tags for filtering installed items
-    // ----------
+    #[serde(default)]
     categories: Option<Vec<String>>,
-) -> Result<(), String> {
-    let instance = instances::get_instance(&instance_id)?;
+}
+
+/// Download one content file. With a queue task id, cancelling the task stops the
+/// download at the next chunk; a partial file is never left behind.
+async fn install_content_item(app_handle: &AppHandle, item: ContentInstallItem, task_id: Option<&str>) -> Result<(), String> {
+    let instance = instances::get_instance(&item.instance_id)?;
+    if item.file_type == "mod" {
+        instance.ensure_unlocked()?;
+    }
     
-    let dest_dir = match file_type.as_str() {
+    let dest_dir = match item.file_type.as_str() {
         "mod" => files::get_mods_dir(&instance),
         "resourcepack" => files::get_resourcepacks_dir(&instance),
         "shader" => files::get_shaderpacks_dir(&instance),
         "datapack" => {
-            if let Some(wname) = world_name {
+            if let Some(wname) = &item.world_name {
                 files::get_saves_dir(&instance).join(wname).join("datapacks")
             } else {
                 return Err("World name required for datapack installation".to_string());
@@ -5010,12 +8304,13 @@ async fn install_modrinth_file(
         _ => return Err("Invalid file type".to_string()),
     };
     
+    let filename = item.filename.clone();
     let dest_path = dest_dir.join(&filename);
     
     // Download the file with progress
     let client = minecraft::http_client();
     let response = client
-        .get(&file_url)
+        .get(&item.file_url)
         .header("User-Agent", format!("PaletheaLauncher/{}", minecraft::get_launcher_version()))
         .send()
         .await
@@ -5036,41 +8331,354 @@ async fn install_modrinth_file(
     let mut stream = response.bytes_stream();
     use futures::StreamExt;
     
+    let streamed: Result<(), String> = async {
+        while let Some(item_chunk) = stream.next().await {
+            if task_id.is_some_and(installqueue::is_cancelled) {
+                return Err("Installation cancelled".to_string());
+            }
+            let chunk = item_chunk.map_err(|e| format!("Download error: {}", e))?;
+            downloader::throttle_download(chunk.len() as u64).await;
+            std::io::Write::write_all(&mut file, &chunk).map_err(|e| format!("Write error: {}", e))?;
+            downloaded += chunk.len() as u64;
+            
+            // Emit progress every 100ms or so
+            if last_emit.elapsed().as_millis() > 100 || total_size.map_or(false, |ts| downloaded == ts) {
+                let percentage = total_size.map_or(0.0, |ts| (downloaded as f32 / ts as f32) * 100.0);
+                let _ = app_handle.emit("download-progress", downloader::DownloadProgress {
+                    stage: format!("Downloading {}...", item.name.as_ref().unwrap_or(&filename)),
+                    percentage,
+                    current: 1,
+                    total: 1,
+                    total_bytes: total_size,
+                    downloaded_bytes: Some(downloaded),
+                });
+                if let Some(task_id) = task_id {
+                    installqueue::report_bytes(app_handle, task_id, downloaded, total_size);
+                }
+                last_emit = std::time::Instant::now();
+            }
+        }
+        Ok(())
+    }
+    .await;
+    if let Err(e) = streamed {
+        drop(file);
+        let _ = std::fs::remove_file(&dest_path);
+        return Err(e);
+    }
+    
+    // Save metadata with project_id if provided
+    if let Some(pid) = item.project_id {
+        let meta = files::ModMeta { 
+            project_id: pid,
+            version_id: item.version_id,
+            name: item.name,
+            author: item.author,
+            icon_url: item.icon_url,
+            version_name: item.version_name,
+            categories: item.categories,
+            source_url: None,
+            pinned: false,
+        };
+        let _ = files::write_meta_for_entry(&dest_dir, &filename, &meta);
+    }
+    
+    Ok(())
+}
+
+#[tauri::command]
+async fn install_modrinth_file(
+    app_handle: AppHandle,
+    instance_id: String,
+    file_url: String,
+    filename: String,
+    file_type: String, // "mod", "resourcepack", "shader", "datapack"
+    project_id: Option<String>,
+    version_id: Option<String>,
+    world_name: Option<String>,
+    name: Option<String>,
+    author: Option<String>,
+    icon_url: Option<String>,
+    version_name: Option<String>,
+    // ----------
+    // Categories parameter
+    // Description: Modrinth category tags for filtering installed items
+    // ----------
+    categories: Option<Vec<String>>,
+) -> Result<(), String> {
+    let item = ContentInstallItem {
+        instance_id,
+        file_url,
+        filename,
+        file_type,
+        project_id,
+        version_id,
+        world_name,
+        name,
+        author,
+        icon_url,
+        version_name,
+        categories,
+    };
+    let label = item.name.clone().unwrap_or_else(|| item.filename.clone());
+    let task_id = installqueue::register(&app_handle, label, Some(item.instance_id.clone()), None);
+    run_queued_content_install(&app_handle, &task_id, item).await
+}
+
+/// Run one queued content install, keeping its queue entry's status in step
+async fn run_queued_content_install(app_handle: &AppHandle, task_id: &str, item: ContentInstallItem) -> Result<(), String> {
+    if installqueue::is_cancelled(task_id) {
+        return Err("Installation cancelled".to_string());
+    }
+    installqueue::set_status(app_handle, task_id, "downloading", None);
+    match install_content_item(app_handle, item, Some(task_id)).await {
+        Ok(()) => {
+            installqueue::set_status(app_handle, task_id, "done", None);
+            Ok(())
+        }
+        Err(e) if installqueue::is_cancelled(task_id) => {
+            installqueue::set_status(app_handle, task_id, "cancelled", None);
+            Err(e)
+        }
+        Err(e) => {
+            log_warn!(app_handle, "Queued install {} failed: {}", task_id, e);
+            installqueue::set_status(app_handle, task_id, "failed", Some(e.clone()));
+            Err(e)
+        }
+    }
+}
+
+// ----------
+// queue_install
+// Description: Queues several content downloads and installs them one after another in the
+//              background. Returns the task ids for cancel_install.
+// ----------
+#[tauri::command]
+async fn queue_install(app_handle: AppHandle, items: Vec<ContentInstallItem>) -> Result<Vec<String>, String> {
+    if items.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let tasks: Vec<(String, ContentInstallItem)> = items
+        .into_iter()
+        .map(|item| {
+            let label = item.name.clone().unwrap_or_else(|| item.filename.clone());
+            let task_id = installqueue::register(&app_handle, label, Some(item.instance_id.clone()), None);
+            (task_id, item)
+        })
+        .collect();
+    let task_ids = tasks.iter().map(|(task_id, _)| task_id.clone()).collect();
+
+    let handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        for (task_id, item) in tasks {
+            let _ = run_queued_content_install(&handle, &task_id, item).await;
+        }
+        let _ = handle.emit("refresh-instances", ());
+    });
+
+    Ok(task_ids)
+}
+
+#[tauri::command]
+fn cancel_install(app_handle: AppHandle, task_id: String) -> Result<bool, String> {
+    Ok(installqueue::cancel(&app_handle, &task_id))
+}
+
+#[tauri::command]
+fn get_install_queue() -> Vec<installqueue::InstallTask> {
+    installqueue::snapshot()
+}
+
+/// Track a modpack install in the queue under its instance id so it can be cancelled
+async fn run_queued_modpack_install<F>(app_handle: &AppHandle, instance_id: &str, install: F) -> Result<downloader::ModpackInstallSummary, String>
+where
+    F: std::future::Future<Output = Result<downloader::ModpackInstallSummary, Box<dyn std::error::Error + Send + Sync>>>,
+{
+    let label = instances::get_instance(instance_id)
+        .map(|instance| instance.name)
+        .unwrap_or_else(|_| "Modpack".to_string());
+    installqueue::register(app_handle, label, Some(instance_id.to_string()), Some(instance_id.to_string()));
+    installqueue::set_status(app_handle, instance_id, "downloading", None);
+
+    match install.await {
+        Ok(summary) => {
+            installqueue::set_status(app_handle, instance_id, "done", None);
+            Ok(summary)
+        }
+        Err(_) if installqueue::is_cancelled(instance_id) => {
+            installqueue::set_status(app_handle, instance_id, "cancelled", None);
+            Err("Modpack installation cancelled".to_string())
+        }
+        Err(e) => {
+            installqueue::set_status(app_handle, instance_id, "failed", Some(e.to_string()));
+            Err(e.to_string())
+        }
+    }
+}
+
+/// Filename for a direct download: Content-Disposition first, then the last URL path segment.
+/// Returns only a bare file name, never a path.
+fn download_filename(response: &reqwest::Response, requested_url: &str) -> Option<String> {
+    let from_header = response
+        .headers()
+        .get(reqwest::header::CONTENT_DISPOSITION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| {
+            let mut plain = None;
+            for part in value.split(';').map(str::trim) {
+                if let Some(encoded) = part.strip_prefix("filename*=") {
+                    // RFC 5987: charset'lang'percent-encoded-name
+                    let encoded = encoded.rsplit('\'').next().unwrap_or(encoded);
+                    return urlencoding::decode(encoded).ok().map(|n| n.into_owned());
+                }
+                if let Some(name) = part.strip_prefix("filename=") {
+                    plain = Some(name.trim_matches('"').to_string());
+                }
+            }
+            plain
+        });
+
+    let from_url = |url: &str| {
+        reqwest::Url::parse(url).ok().and_then(|parsed| {
+            parsed
+                .path_segments()
+                .and_then(|mut segments| segments.next_back().map(str::to_string))
+                .filter(|segment| !segment.is_empty())
+                .and_then(|segment| urlencoding::decode(&segment).ok().map(|n| n.into_owned()))
+        })
+    };
+
+    [from_header, from_url(requested_url), from_url(response.url().as_str())]
+        .into_iter()
+        .flatten()
+        .map(|name| {
+            name.rsplit(['/', '\\'])
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .map(|c| if c.is_control() || matches!(c, '<' | '>' | ':' | '"' | '|' | '?' | '*') { '_' } else { c })
+                .collect::<String>()
+                .trim()
+                .to_string()
+        })
+        .find(|name| name.to_ascii_lowercase().ends_with(".jar"))
+}
+
+// ----------
+// install_mod_from_url
+// Description: Installs a mod that only exists as a direct link (GitHub release, author site)
+//              and records a ModMeta with its name and source URL so it stays tracked
+// ----------
+#[tauri::command]
+async fn install_mod_from_url(
+    app_handle: AppHandle,
+    instance_id: String,
+    url: String,
+    name: Option<String>,
+) -> Result<String, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+
+    let url = url.trim().to_string();
+    let parsed = reqwest::Url::parse(&url).map_err(|e| format!("Invalid URL: {}", e))?;
+    if parsed.scheme() != "https" && parsed.scheme() != "http" {
+        return Err("Only http and https links are supported".to_string());
+    }
+    let name = name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+
+    let client = minecraft::http_client();
+    let response = client
+        .get(&url)
+        .header("User-Agent", format!("PaletheaLauncher/{}", minecraft::get_launcher_version()))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?
+        .error_for_status()
+        .map_err(|e| format!("HTTP error: {}", e))?;
+
+    let filename = download_filename(&response, &url)
+        .or_else(|| {
+            // Links like .../download carry no name; fall back to the name the user gave
+            name.as_ref().map(|n| format!("{}.jar", n.replace(['/', '\\', ':'], "_")))
+        })
+        .ok_or_else(|| "Could not work out a .jar filename from this link. Enter a name for the mod.".to_string())?;
+
+    let mods_dir = files::get_mods_dir(&instance);
+    let dest_path = mods_dir.join(&filename);
+    if dest_path.exists() || mods_dir.join(format!("{}.disabled", filename)).exists() {
+        return Err(format!("{} is already installed", filename));
+    }
+    std::fs::create_dir_all(&mods_dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+
+    let total_size = response.content_length();
+    let part_path = mods_dir.join(format!("{}.part", filename));
+    let mut file = std::fs::File::create(&part_path).map_err(|e| format!("Failed to create file: {}", e))?;
+    let mut downloaded: u64 = 0;
+    let mut last_emit = std::time::Instant::now();
+    let label = name.clone().unwrap_or_else(|| filename.clone());
+
+    let mut stream = response.bytes_stream();
+    use futures::StreamExt;
+
     while let Some(item) = stream.next().await {
-        let chunk = item.map_err(|e| format!("Download error: {}", e))?;
-        std::io::Write::write_all(&mut file, &chunk).map_err(|e| format!("Write error: {}", e))?;
+        let chunk = match item {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                let _ = std::fs::remove_file(&part_path);
+                return Err(format!("Download error: {}", e));
+            }
+        };
+        downloader::throttle_download(chunk.len() as u64).await;
+        if let Err(e) = std::io::Write::write_all(&mut file, &chunk) {
+            let _ = std::fs::remove_file(&part_path);
+            return Err(format!("Write error: {}", e));
+        }
         downloaded += chunk.len() as u64;
-        
-        // Emit progress every 100ms or so
+
         if last_emit.elapsed().as_millis() > 100 || total_size.map_or(false, |ts| downloaded == ts) {
             let percentage = total_size.map_or(0.0, |ts| (downloaded as f32 / ts as f32) * 100.0);
             let _ = app_handle.emit("download-progress", downloader::DownloadProgress {
-                stage: format!("Downloading {}...", name.as_ref().unwrap_or(&filename)),
+                stage: format!("Downloading {}...", label),
                 percentage,
                 current: 1,
                 total: 1,
                 total_bytes: total_size,
-                downloaded_bytes: Some(downloaded),
-            });
-            last_emit = std::time::Instant::now();
-        }
-    }
-    
-    // Save metadata with project_id if provided
-    if let Some(pid) = project_id {
-        let meta = files::ModMeta { 
-            project_id: pid,
-            version_id,
-            name,
-            author,
-            icon_url,
-            version_name,
-            categories,
-        };
-        let _ = files::write_meta_for_entry(&dest_dir, &filename, &meta);
+                downloaded_bytes: Some(downloaded),
+            });
+            last_emit = std::time::Instant::now();
+        }
     }
-    
-    Ok(())
+    drop(file);
+
+    // A login page or error page saved as .jar would only fail later inside the game
+    let is_zip = std::fs::File::open(&part_path)
+        .and_then(|mut f| {
+            let mut magic = [0u8; 2];
+            std::io::Read::read_exact(&mut f, &mut magic).map(|_| magic == *b"PK")
+        })
+        .unwrap_or(false);
+    if !is_zip {
+        let _ = std::fs::remove_file(&part_path);
+        return Err("The link did not return a mod .jar file".to_string());
+    }
+    std::fs::rename(&part_path, &dest_path).map_err(|e| format!("Failed to save {}: {}", filename, e))?;
+
+    let meta = files::ModMeta {
+        project_id: String::new(),
+        version_id: None,
+        name: name.or_else(|| Some(filename.trim_end_matches(".jar").to_string())),
+        author: None,
+        icon_url: None,
+        version_name: None,
+        categories: None,
+        source_url: Some(url.clone()),
+        pinned: false,
+    };
+    files::write_meta_for_entry(&mods_dir, &filename, &meta)?;
+
+    log_info!(&app_handle, "Installed {} from {} into {}", filename, url, instance.name);
+    Ok(filename)
 }
 
 #[tauri::command]
@@ -5090,6 +8698,9 @@ async fn install_curseforge_file(
     categories: Option<Vec<String>>,
 ) -> Result<(), String> {
     let instance = instances::get_instance(&instance_id)?;
+    if file_type == "mod" {
+        instance.ensure_unlocked()?;
+    }
 
     let dest_dir = match file_type.as_str() {
         "mod" => files::get_mods_dir(&instance),
@@ -5144,6 +8755,7 @@ async fn install_curseforge_file(
     let mut stream = response.bytes_stream();
     while let Some(item) = stream.next().await {
         let chunk = item.map_err(|e| format!("Download error: {}", e))?;
+        downloader::throttle_download(chunk.len() as u64).await;
         std::io::Write::write_all(&mut file, &chunk).map_err(|e| format!("Write error: {}", e))?;
         downloaded += chunk.len() as u64;
 
@@ -5172,6 +8784,8 @@ async fn install_curseforge_file(
         icon_url,
         version_name,
         categories,
+        source_url: None,
+        pinned: false,
     };
     let _ = files::write_meta_for_entry(&dest_dir, &resolved_filename, &meta);
 
@@ -5239,6 +8853,7 @@ async fn install_curseforge_world(
     let mut stream = response.bytes_stream();
     while let Some(item) = stream.next().await {
         let chunk = item.map_err(|e| format!("Download error: {}", e))?;
+        downloader::throttle_download(chunk.len() as u64).await;
         std::io::Write::write_all(&mut file, &chunk).map_err(|e| format!("Write error: {}", e))?;
         downloaded += chunk.len() as u64;
 
@@ -5304,7 +8919,7 @@ async fn save_remote_file(url: String, path: String) -> Result<(), String> {
     }
 
     let mut file = std::fs::File::create(&dest_path).map_err(|e| format!("Failed to create file: {}", e))?;
-    let content = response.bytes().await.map_err(|e| format!("Failed to read body: {}", e))?;
+    let content = downloader::read_body_throttled(response).await.map_err(|e| format!("Failed to read body: {}", e))?;
     std::io::Write::write_all(&mut file, &content).map_err(|e| format!("Write error: {}", e))?;
     
     Ok(())
@@ -5333,6 +8948,9 @@ struct InstanceModUpdate {
     pub latest_version: Option<modrinth::ModrinthVersion>,
     #[serde(default)]
     pub latest_curseforge_version: Option<curseforge::CurseForgeModpackVersion>,
+    // Pinned mods are listed without a latest version and must not be updated
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 #[tauri::command]
@@ -5360,6 +8978,7 @@ async fn get_instance_mod_updates(
         author: Option<String>,
         icon_url: Option<String>,
         categories: Option<Vec<String>>,
+        pinned: bool,
     }
 
     let installed_rows: Vec<InstalledModRow> = match normalized_file_type.as_str() {
@@ -5379,6 +8998,7 @@ async fn get_instance_mod_updates(
                     author: m.author,
                     icon_url: m.icon_url,
                     categories: m.categories,
+                    pinned: m.pinned,
                 })
             })
             .collect(),
@@ -5397,6 +9017,7 @@ async fn get_instance_mod_updates(
                     author: pack.author,
                     icon_url: pack.icon_url,
                     categories: pack.categories,
+                    pinned: false,
                 })
             })
             .collect(),
@@ -5415,6 +9036,7 @@ async fn get_instance_mod_updates(
                     author: shader.author,
                     icon_url: shader.icon_url,
                     categories: shader.categories,
+                    pinned: false,
                 })
             })
             .collect(),
@@ -5436,6 +9058,7 @@ async fn get_instance_mod_updates(
                         author: pack.author,
                         icon_url: pack.icon_url,
                         categories: None,
+                        pinned: false,
                     })
                 })
                 .collect()
@@ -5479,6 +9102,23 @@ async fn get_instance_mod_updates(
         let loader_key = loader_key.clone();
         let file_type_for_updates = file_type_for_updates.clone();
         async move {
+            if row.pinned {
+                return Some(InstanceModUpdate {
+                    provider: row.provider,
+                    project_id: row.project_id,
+                    installed_filename: row.filename,
+                    installed_version_id: row.version_id,
+                    installed_version_name: row.version_name,
+                    installed_name: row.name,
+                    installed_author: row.author,
+                    installed_icon_url: row.icon_url,
+                    installed_categories: row.categories,
+                    latest_version: None,
+                    latest_curseforge_version: None,
+                    pinned: true,
+                });
+            }
+
             if row.provider.eq_ignore_ascii_case("CurseForge") || is_numeric_project_id(&row.project_id) {
                 let mut versions = match curseforge::get_modpack_versions(&row.project_id).await {
                     Ok(v) => v,
@@ -5522,6 +9162,7 @@ async fn get_instance_mod_updates(
                     installed_categories: row.categories,
                     latest_version: None,
                     latest_curseforge_version: Some(latest_version),
+                    pinned: false,
                 });
             }
 
@@ -5565,6 +9206,7 @@ async fn get_instance_mod_updates(
                 installed_categories: row.categories,
                 latest_version: Some(latest_version),
                 latest_curseforge_version: None,
+                pinned: false,
             })
         }
     }))
@@ -5590,6 +9232,143 @@ async fn get_instance_mod_updates(
     Ok(updates)
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct IncompatibleMod {
+    pub filename: String,
+    pub name: Option<String>,
+    pub provider: String,
+    pub project_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct InstanceVersionChange {
+    pub instance: instances::Instance,
+    pub previous_version_id: String,
+    // Tracked mods with no release for the new game version and loader
+    pub incompatible_mods: Vec<IncompatibleMod>,
+    // Only filled when the update check was requested
+    pub mod_updates: Vec<InstanceModUpdate>,
+}
+
+/// Find enabled, provider-tracked mods that have no file for the given game version and loader.
+/// Mods whose lookup fails are left out rather than reported as incompatible.
+async fn find_incompatible_mods(instance: &instances::Instance, game_version: &str) -> Vec<IncompatibleMod> {
+    use futures::stream::{self, StreamExt};
+
+    let loader_key = normalize_loader_key(&instance.mod_loader).map(str::to_string);
+    let mods: Vec<files::InstalledMod> = files::list_mods(instance)
+        .into_iter()
+        .filter(|m| m.enabled && m.project_id.is_some())
+        .collect();
+
+    let mut incompatible: Vec<IncompatibleMod> = stream::iter(mods.into_iter().map(|m| {
+        let loader_key = loader_key.clone();
+        async move {
+            let project_id = m.project_id.clone()?;
+            let provider = normalize_provider_label(Some(m.provider.as_str()), &project_id);
+            let compatible = if provider == "CurseForge" {
+                let versions = curseforge::get_modpack_versions(&project_id).await.ok()?;
+                versions
+                    .iter()
+                    .any(|v| curseforge_file_matches_instance(v, game_version, loader_key.as_deref(), "mod"))
+            } else {
+                !modrinth::get_project_versions(&project_id, Some(game_version), loader_key.as_deref())
+                    .await
+                    .ok()?
+                    .is_empty()
+            };
+            (!compatible).then(|| IncompatibleMod {
+                filename: m.filename,
+                name: m.name,
+                provider,
+                project_id,
+            })
+        }
+    }))
+    .buffer_unordered(12)
+    .filter_map(async |item| item)
+    .collect()
+    .await;
+
+    incompatible.sort_by_key(|m| m.name.as_deref().unwrap_or(&m.filename).to_lowercase());
+    incompatible
+}
+
+/// Move an instance to another Minecraft version: download the game, reinstall the mod loader
+/// for it and report mods that have no compatible release yet. Worlds and configs are untouched,
+/// and the instance is only saved once everything installed.
+#[tauri::command]
+async fn change_instance_version(
+    instance_id: String,
+    new_version_id: String,
+    new_loader_version: Option<String>,
+    check_updates: Option<bool>,
+    app_handle: AppHandle,
+) -> Result<InstanceVersionChange, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    if is_instance_running(&instance_id) {
+        return Err("Stop the instance before changing its Minecraft version".to_string());
+    }
+    let new_version_id = new_version_id.trim().to_string();
+    if new_version_id.is_empty() {
+        return Err("No Minecraft version given".to_string());
+    }
+    let previous_version_id = instance.version_id.clone();
+
+    // Fabric and Quilt loader versions are independent of the game version; Forge builds are not
+    let loader_version = match (&instance.mod_loader, new_loader_version) {
+        (instances::ModLoader::Vanilla, _) => None,
+        (_, Some(version)) if !version.trim().is_empty() => Some(version.trim().to_string()),
+        (instances::ModLoader::Fabric | instances::ModLoader::Quilt, _) => instance.mod_loader_version.clone(),
+        (loader, _) => {
+            return Err(format!("Pick a {} version for Minecraft {}", loader, new_version_id));
+        }
+    };
+
+    downloader::download_version(&new_version_id, Some(&app_handle), None)
+        .await
+        .map_err(|e| format!("Failed to download Minecraft {}: {}", new_version_id, e))?;
+
+    let mut updated = instance.clone();
+    updated.version_id = new_version_id.clone();
+    if let Some(loader_version) = &loader_version {
+        // Store the version the installer resolved, not the request ("latest", a bare build number, ...)
+        let installed = match updated.mod_loader {
+            instances::ModLoader::Fabric => fabric::install_fabric(&updated, loader_version, None).await.map(|info| info.loader.version),
+            instances::ModLoader::Quilt => quilt::install_quilt(&updated, loader_version, None).await.map(|info| info.loader.version),
+            instances::ModLoader::Forge => forge::install_forge(&updated, loader_version, None).await.map(|info| info.forge_version),
+            instances::ModLoader::NeoForge => forge::install_neoforge(&updated, loader_version, None).await.map(|info| info.forge_version),
+            instances::ModLoader::Vanilla => Ok(loader_version.clone()),
+        };
+        let resolved = installed.map_err(|e| format!("Failed to install {} {} for Minecraft {}: {}", updated.mod_loader, loader_version, new_version_id, e))?;
+        updated.mod_loader_version = Some(resolved);
+    }
+    instances::update_instance(updated.clone())?;
+    log_info!(&app_handle, "Changed {} from Minecraft {} to {}", updated.name, previous_version_id, new_version_id);
+    let _ = app_handle.emit("refresh-instances", ());
+
+    let incompatible_mods = if updated.mod_loader == instances::ModLoader::Vanilla || minecraft::is_offline() {
+        Vec::new()
+    } else {
+        find_incompatible_mods(&updated, &new_version_id).await
+    };
+    let mod_updates = if check_updates.unwrap_or(false) && updated.mod_loader != instances::ModLoader::Vanilla {
+        get_instance_mod_updates(instance_id, Some("mod".to_string()), None)
+            .await
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    Ok(InstanceVersionChange {
+        instance: updated,
+        previous_version_id,
+        incompatible_mods,
+        mod_updates,
+    })
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct ModConflictIssue {
     pub id: String,
@@ -5613,6 +9392,7 @@ fn normalize_loader_key(loader: &instances::ModLoader) -> Option<&'static str> {
     match loader {
         instances::ModLoader::Vanilla => None,
         instances::ModLoader::Fabric => Some("fabric"),
+        instances::ModLoader::Quilt => Some("quilt"),
         instances::ModLoader::Forge => Some("forge"),
         instances::ModLoader::NeoForge => Some("neoforge"),
     }
@@ -5629,6 +9409,42 @@ fn is_modrinth_like_version_id(version_id: &str) -> bool {
         && trimmed.chars().all(|c| c.is_ascii_alphanumeric())
 }
 
+// ----------
+// check_curseforge_mod_updates
+// Description: Newest CurseForge file per installed CurseForge mod, filtered to the
+//              instance's game version and loader
+// ----------
+#[tauri::command]
+async fn check_curseforge_mod_updates(instance_id: String) -> Result<Vec<curseforge::CurseForgeModUpdate>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    let mods = files::list_mods(&instance);
+    let pinned: HashSet<String> = mods.iter().filter(|m| m.pinned).map(|m| m.filename.clone()).collect();
+    let installed: Vec<(String, Option<String>, String)> = mods
+        .into_iter()
+        .filter_map(|m| {
+            let project_id = m.project_id?;
+            (normalize_provider_label(Some(m.provider.as_str()), &project_id) == "CurseForge")
+                .then_some((project_id, m.version_id, m.filename))
+        })
+        .collect();
+    if installed.is_empty() {
+        return Ok(Vec::new());
+    }
+    if !secrets::has_curseforge_api_key() {
+        return Err("CurseForge update checks need a CurseForge API key. Set CURSEFORGE_API_KEY and restart the launcher.".to_string());
+    }
+
+    let mut updates = curseforge::check_mod_updates(&installed, &instance.version_id, normalize_loader_key(&instance.mod_loader))
+        .await
+        .map_err(|e| e.to_string())?;
+    // Pinned mods stay listed so the UI can mark them, but are never offered as updates
+    for update in updates.iter_mut().filter(|u| pinned.contains(&u.installed_filename)) {
+        update.pinned = true;
+        update.update_available = false;
+    }
+    Ok(updates)
+}
+
 fn normalize_provider_label(provider: Option<&str>, project_id: &str) -> String {
     if let Some(value) = provider {
         if value.eq_ignore_ascii_case("curseforge") {
@@ -5725,7 +9541,7 @@ fn curseforge_file_matches_instance(
         .collect();
 
     let loader_match = |token: &str| match loader.as_str() {
-        "fabric" => token.contains("fabric") || token.contains("quilt"),
+        "fabric" | "quilt" => token.contains("fabric") || token.contains("quilt"),
         "forge" => token == "forge" || (token.contains("forge") && !token.contains("neo")),
         "neoforge" => token.contains("neoforge") || token.contains("neo forge"),
         _ => true,
@@ -5770,7 +9586,7 @@ fn curseforge_file_matches_instance(
     let hints_forge = fallback_text.contains("forge") && !hints_neoforge;
 
     match loader.as_str() {
-        "fabric" => hints_fabric && !hints_forge && !hints_neoforge,
+        "fabric" | "quilt" => hints_fabric && !hints_forge && !hints_neoforge,
         "forge" => hints_forge && !hints_fabric && !hints_neoforge,
         "neoforge" => hints_neoforge && !hints_fabric,
         _ => true,
@@ -5926,7 +9742,7 @@ async fn scan_mod_conflicts(instance_id: String) -> Result<Vec<ModConflictIssue>
                 && !version
                     .loaders
                     .iter()
-                    .any(|l| l.eq_ignore_ascii_case(loader_key))
+                    .any(|l| l.eq_ignore_ascii_case(loader_key) || (loader_key == "quilt" && l.eq_ignore_ascii_case("fabric")))
             {
                 issues.push(ModConflictIssue {
                     id: format!("loader:{}:{}", row.project_id, version.id),
@@ -6055,18 +9871,71 @@ fn get_instance_mods(instance_id: String) -> Result<Vec<files::InstalledMod>, St
     Ok(files::list_mods(&instance))
 }
 
+#[tauri::command]
+fn detect_mod_conflicts(instance_id: String) -> Result<Vec<files::ModConflictGroup>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    Ok(files::detect_mod_conflicts(&instance))
+}
+
+#[tauri::command]
+async fn search_installed_content(query: String) -> Result<Vec<files::InstalledContentMatch>, String> {
+    tokio::task::spawn_blocking(move || {
+        let all_instances = instances::load_instances()?;
+        Ok(files::search_installed_content(&all_instances, &query))
+    })
+    .await
+    .map_err(|e| format!("Search task failed: {}", e))?
+}
+
 #[tauri::command]
 fn toggle_instance_mod(instance_id: String, filename: String) -> Result<bool, String> {
     let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
     files::toggle_mod(&instance, &filename)
 }
 
 #[tauri::command]
 fn delete_instance_mod(instance_id: String, filename: String) -> Result<(), String> {
     let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
     files::delete_mod(&instance, &filename)
 }
 
+#[tauri::command]
+fn toggle_mods(instance_id: String, filenames: Vec<String>, enabled: bool) -> Result<Vec<files::BulkModResult>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    Ok(files::set_mods_enabled(&instance, &filenames, enabled))
+}
+
+/// Pin a mod so update checks and update-all leave it at its current version
+#[tauri::command]
+fn set_mod_pinned(instance_id: String, filename: String, pinned: bool) -> Result<(), String> {
+    let instance = instances::get_instance(&instance_id)?;
+    files::set_mod_pinned(&instance, &filename, pinned)
+}
+
+#[tauri::command]
+fn delete_mods(instance_id: String, filenames: Vec<String>) -> Result<Vec<files::BulkModResult>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    Ok(files::delete_mods(&instance, &filenames))
+}
+
+#[tauri::command]
+fn disable_all_mods(instance_id: String) -> Result<Vec<files::BulkModResult>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    Ok(files::set_all_mods_enabled(&instance, false))
+}
+
+#[tauri::command]
+fn enable_all_mods(instance_id: String) -> Result<Vec<files::BulkModResult>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    instance.ensure_unlocked()?;
+    Ok(files::set_all_mods_enabled(&instance, true))
+}
+
 #[tauri::command]
 fn get_instance_resourcepacks(instance_id: String) -> Result<Vec<files::ResourcePack>, String> {
     let instance = instances::get_instance(&instance_id)?;
@@ -6176,6 +10045,14 @@ fn get_instance_screenshots(instance_id: String) -> Result<Vec<files::Screenshot
     Ok(files::list_screenshots(&instance))
 }
 
+#[tauri::command]
+async fn get_screenshot_thumbnail(instance_id: String, filename: String) -> Result<String, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    tokio::task::spawn_blocking(move || files::screenshot_thumbnail(&instance, &filename))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
 #[tauri::command]
 fn delete_instance_screenshot(instance_id: String, filename: String) -> Result<(), String> {
     let instance = instances::get_instance(&instance_id)?;
@@ -6252,6 +10129,58 @@ fn get_instance_log(instance_id: String) -> Result<String, String> {
     files::get_latest_log(&instance)
 }
 
+#[tauri::command]
+fn start_log_stream(instance_id: String, app_handle: AppHandle) -> Result<(), String> {
+    let instance = instances::get_instance(&instance_id)?;
+    let log_path = files::get_logs_dir(&instance).join("latest.log");
+    logtail::start_log_stream(app_handle, instance_id, log_path);
+    Ok(())
+}
+
+#[tauri::command]
+fn start_watching_instance(instance_id: String, app_handle: AppHandle) -> Result<(), String> {
+    let instance = instances::get_instance(&instance_id)?;
+    watcher::start_watching(app_handle, instance_id, instance.get_game_directory())
+}
+
+#[tauri::command]
+fn stop_watching_instance(instance_id: String) -> bool {
+    watcher::stop_watching(&instance_id)
+}
+
+#[tauri::command]
+fn stop_log_stream(instance_id: String) -> bool {
+    logtail::stop_log_stream(&instance_id)
+}
+
+/// Recent stdout/stderr of a running instance, kept in memory and cleared when it exits
+#[tauri::command]
+fn get_instance_live_output(instance_id: String) -> Vec<logtail::LogLinePayload> {
+    logtail::live_output(&instance_id)
+}
+
+/// Walking the instance folder for its size can take a while on big instances, so the
+/// summary is built off the async runtime
+#[tauri::command]
+async fn get_instance_summary(instance_id: String) -> Result<files::InstanceSummary, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    tokio::task::spawn_blocking(move || files::get_instance_summary(&instance))
+        .await
+        .map_err(|e| format!("Task join error: {}", e))
+}
+
+#[tauri::command]
+fn get_instance_crash_reports(instance_id: String) -> Result<Vec<files::CrashReport>, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    Ok(files::list_crash_reports(&instance))
+}
+
+#[tauri::command]
+fn get_crash_report(instance_id: String, filename: String) -> Result<String, String> {
+    let instance = instances::get_instance(&instance_id)?;
+    files::read_crash_report(&instance, &filename)
+}
+
 #[tauri::command]
 fn clear_instance_log(instance_id: String) -> Result<(), String> {
     let instance = instances::get_instance(&instance_id)?;
@@ -6336,11 +10265,83 @@ fn set_server_resource_packs(instance_id: String, index: usize, mode: i8) -> Res
     files::save_servers(&instance, servers)
 }
 
+/// Offline sessions have no Mojang-signed profile, so online-mode servers reject them
+fn can_join_online_servers(state: &AppState) -> bool {
+    state.is_microsoft_auth.lock().map(|v| *v).unwrap_or(false)
+}
+
+/// Best guess from a status ping: vanilla only advertises enforcesSecureChat when the
+/// server also runs in online mode. None when the server doesn't say.
+fn likely_online_mode(ping: &minecraft::ping::PingResponse) -> Option<bool> {
+    ping.enforces_secure_chat
+}
+
+fn online_mode_warning(username: &str, address: &str) -> String {
+    format!(
+        "{} looks like an online-mode server, which offline accounts like {} usually can't join. Sign in with a Microsoft account to play there.",
+        address, username
+    )
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerJoinWarning {
+    pub instance_id: String,
+    pub address: String,
+    pub message: String,
+}
+
+/// Ping the target of an offline launch in the background and emit server-join-warning
+/// if it looks online-mode. The launch itself is never held up or blocked by this.
+fn spawn_online_mode_warning(app_handle: AppHandle, instance_id: String, username: String, address: String) {
+    tauri::async_runtime::spawn(async move {
+        let Ok(ping) = minecraft::ping::ping_server(&address).await else { return };
+        if likely_online_mode(&ping) != Some(true) {
+            return;
+        }
+        let message = online_mode_warning(&username, &address);
+        log_warn!(&app_handle, "{}", message);
+        let _ = app_handle.emit("server-join-warning", ServerJoinWarning { instance_id, address, message });
+    });
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServerJoinCheck {
+    pub can_join_online_servers: bool,
+    pub likely_online_mode: Option<bool>,
+    pub warning: Option<String>,
+}
+
+/// Whether the active account is likely to get into `address`. Microsoft sessions skip the ping.
+#[tauri::command]
+async fn check_server_join(address: String, state: State<'_, AppState>) -> Result<ServerJoinCheck, String> {
+    if can_join_online_servers(&state) {
+        return Ok(ServerJoinCheck { can_join_online_servers: true, likely_online_mode: None, warning: None });
+    }
+    let username = state.username.lock().map_err(|_| "Auth state corrupted")?.clone();
+    let likely = minecraft::ping::ping_server(&address).await.ok().and_then(|ping| likely_online_mode(&ping));
+    Ok(ServerJoinCheck {
+        can_join_online_servers: false,
+        likely_online_mode: likely,
+        warning: (likely == Some(true)).then(|| online_mode_warning(&username, &address)),
+    })
+}
+
+/// "microsoft" or "offline" for the launcher's active session
+#[tauri::command]
+fn get_auth_type(state: State<'_, AppState>) -> String {
+    if can_join_online_servers(&state) { "microsoft" } else { "offline" }.to_string()
+}
+
 #[tauri::command]
 async fn ping_server(address: String) -> Result<minecraft::ping::PingResponse, String> {
     minecraft::ping::ping_server(&address).await
 }
 
+#[tauri::command]
+async fn query_server(address: String) -> Result<Option<minecraft::ping::QueryResponse>, String> {
+    minecraft::ping::query_server(&address).await
+}
+
 #[tauri::command]
 fn import_instance_file(instance_id: String, source_path: String, folder_type: String, world_name: Option<String>) -> Result<(), String> {
     let instance = instances::get_instance(&instance_id)?;
@@ -6586,6 +10587,18 @@ fn create_instance_shortcut(instance_id: String, _app: AppHandle) -> Result<Stri
     }
 }
 
+#[tauri::command]
+fn open_crash_reports_folder(instance_id: String) -> Result<(), String> {
+    let instance = instances::get_instance(&instance_id)?;
+    let path = files::get_crash_reports_dir(&instance);
+
+    if !path.exists() {
+        std::fs::create_dir_all(&path).map_err(|e| e.to_string())?;
+    }
+
+    open_path_native(&path)
+}
+
 #[tauri::command]
 async fn open_instance_folder(_app: AppHandle, instance_id: String, folder_type: String) -> Result<(), String> {
     let instance = instances::get_instance(&instance_id)?;
@@ -6693,22 +10706,123 @@ fn get_instance_details(instance_id: String) -> Result<instances::Instance, Stri
 #[tauri::command]
 fn exit_app_fully(app: AppHandle) {
     let process_infos = if let Ok(mut processes) = RUNNING_PROCESSES.lock() {
-        processes.drain().map(|(_, info)| info).collect::<Vec<_>>()
+        processes.drain().collect::<Vec<_>>()
     } else {
         Vec::new()
     };
 
-    for info in process_infos {
+    for (process_key, info) in process_infos {
         if let Err(err) = terminate_pid(info.pid) {
+            // Leave the session record so the next startup can pick the process back up
             log::warn!("Failed to fully terminate PID {}: {}", info.pid, err);
+            continue;
         }
+        instances::clear_active_session(&process_key);
     }
-    // Clear session file
-    instances::clear_all_active_sessions();
     discord::shutdown();
     app.exit(0);
 }
 
+// ----------
+// run_cli_launch
+// Description: Headless entry point for `--launch <instance_id> [--account <username>]`. Shares
+//              launch_instance_session's account selection and token refresh, running-session
+//              guard, session record, launch count and playtime; a missing game version is
+//              downloaded. The session record names this process as its owner, so a GUI started
+//              meanwhile shows the game as running but leaves crediting its playtime to us.
+//              The mod loader and a suitable Java must already be installed, as for a GUI launch;
+//              auto backups and mod update checks are left to the GUI. Streams the game's output
+//              to stdout and returns its exit code (1 when the launch fails).
+// ----------
+pub fn run_cli_launch(instance_id: &str, account: Option<&str>) -> i32 {
+    minecraft::set_launcher_version(env!("CARGO_PKG_VERSION").to_string());
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to start async runtime: {}", e);
+            return 1;
+        }
+    };
+
+    match runtime.block_on(cli_launch(instance_id, account)) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Launch failed: {}", e);
+            1
+        }
+    }
+}
+
+async fn cli_launch(instance_id: &str, account: Option<&str>) -> Result<i32, String> {
+    let instance = instances::get_instance(instance_id)?;
+    if let Some(pid) = instances::find_running_session(instance_id) {
+        return Err(format!("{} is already running (PID {})", instance.name, pid));
+    }
+
+    let json_path = downloader::get_versions_dir()
+        .join(&instance.version_id)
+        .join(format!("{}.json", &instance.version_id));
+    let version_details: versions::VersionDetails = match fs::read_to_string(&json_path) {
+        Ok(json_content) => serde_json::from_str(&json_content)
+            .map_err(|e| format!("Failed to parse version JSON: {}", e))?,
+        Err(_) => {
+            println!("Downloading Minecraft {}...", instance.version_id);
+            downloader::download_version(&instance.version_id, None, Some(instance_id))
+                .await
+                .map_err(|e| format!("Minecraft {} is not installed and could not be downloaded: {}", instance.version_id, e))?
+        }
+    };
+    if instance.mod_loader != instances::ModLoader::Vanilla && instance.mod_loader_version.is_none() {
+        return Err(format!(
+            "{} uses {} but no loader version is installed; open the launcher to install it",
+            instance.name, instance.mod_loader
+        ));
+    }
+    if files::sync_loader_mods_dir(&instance)? {
+        println!("Switched to the {} mods folder", instance.mod_loader);
+    }
+
+    // Explicit account, then the instance's bound account, then the launcher's active one
+    let accounts = auth::load_accounts();
+    let active = accounts
+        .active_account
+        .as_deref()
+        .and_then(|active| accounts.accounts.iter().find(|a| a.username == active).cloned());
+    let account = resolve_launch_account(&instance, account, active, None).await?;
+    let (username, uuid, access_token) = (account.username, account.uuid, account.access_token);
+
+    let start_time = record_launch(&instance)?;
+
+    println!("Launching {} as {}", instance.name, username);
+    let mut child = launcher::launch_game(
+        &instance,
+        &version_details,
+        &username,
+        &access_token,
+        &uuid,
+        None,
+        Some(instance_id),
+        None,
+    )
+    .await?;
+
+    let process_key = register_session(instance_id, &username, child.id(), start_time)?;
+
+    let status = tokio::task::spawn_blocking(move || child.wait()).await.map_err(|e| e.to_string());
+
+    let end_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    credit_playtime(instance_id, end_time, end_play_span(instance_id, start_time, end_time));
+    instances::clear_active_session(&process_key);
+    if let Ok(mut processes) = RUNNING_PROCESSES.lock() {
+        processes.remove(&process_key);
+    }
+
+    let status = status?.map_err(|e| format!("Failed to wait for the game: {}", e))?;
+    println!("{} exited with {}", instance.name, status);
+    Ok(status.code().unwrap_or(1))
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     // Note: WebView2 args are set in main.rs (must be before any Tauri init)
@@ -6747,7 +10861,14 @@ pub fn run() {
             if let tauri::WindowEvent::CloseRequested { api, .. } = event {
                 if window.label() == "main" {
                     if let Ok(processes) = RUNNING_PROCESSES.lock() {
-                        if !processes.is_empty() {
+                        let hide_to_tray = settings::get_on_game_launch() == "close_to_tray"
+                            || settings::is_minimize_to_tray_on_close_enabled();
+                        if !processes.is_empty() && hide_to_tray {
+                            // Keep the launcher alive in the tray while the game runs; playtime
+                            // tracking and Discord presence carry on in their own threads
+                            api.prevent_close();
+                            let _ = window.hide();
+                        } else if !processes.is_empty() {
                             api.prevent_close();
                             let _ = window.emit("show-exit-confirm", ());
                         } else {
@@ -6771,7 +10892,7 @@ pub fn run() {
 
             app.handle().plugin(
                 tauri_plugin_log::Builder::default()
-                    .level(if cfg!(debug_assertions) { log::LevelFilter::Debug } else { log::LevelFilter::Info })
+                    .level(settings::get_log_level())
                     .level_for("reqwest", log::LevelFilter::Off)
                     .level_for("hyper", log::LevelFilter::Off)
                     .build()
@@ -6836,20 +10957,34 @@ pub fn run() {
                 let handle = app.handle().clone();
                 let _ = handle.emit("refresh-instances", ());
 
-                for (instance_id, start_time_or_credited, pid, current_playtime, launch_username) in recovered_sessions {
-                    if let Some(pid_val) = pid {
+                let live_instances: HashSet<String> = recovered_sessions
+                    .iter()
+                    .filter(|session| session.pid.is_some() && !session.owned_elsewhere)
+                    .map(|session| session.instance_id.clone())
+                    .collect();
+
+                for session in recovered_sessions {
+                    let instance_id = session.instance_id;
+                    let process_key = session.session_key;
+                    let start_time = session.start_time;
+                    let owned_elsewhere = session.owned_elsewhere;
+                    if let Some(pid_val) = session.pid {
                         // Re-register in the global map
                         {
                             if let Ok(mut processes) = RUNNING_PROCESSES.lock() {
-                                processes.insert(instance_id.clone(), RunningProcessInfo {
+                                processes.insert(process_key.clone(), RunningProcessInfo {
                                     pid: pid_val,
-                                    start_time: start_time_or_credited,
-                                    launch_username: launch_username.clone(),
+                                    start_time,
+                                    launch_username: session.launch_username.clone(),
+                                    instance_id: instance_id.clone(),
                                 });
                             } else {
                                 log::warn!("Process state corrupted while recovering PID {}", pid_val);
                             }
                         }
+                        if !owned_elsewhere {
+                            begin_play_span(&instance_id, start_time);
+                        }
 
                         let handle_clone = handle.clone();
                         let instance_id_clone = instance_id.clone();
@@ -6860,28 +10995,21 @@ pub fn run() {
                                 std::thread::sleep(std::time::Duration::from_secs(5));
                             }
 
-                            // Finalize playtime
-                            let end_time = std::time::SystemTime::now()
-                                .duration_since(std::time::UNIX_EPOCH)
-                                .unwrap_or_default()
-                                .as_secs();
-                            let session_duration = end_time.saturating_sub(start_time_or_credited);
-
-                            if let Ok(mut inst) = instances::get_instance(&instance_id_clone) {
-                                let inst_name = inst.name.clone();
-                                inst.playtime_seconds = current_playtime + session_duration;
-                                let _ = instances::update_instance(inst);
-
-                                // Log session for activity tracking
-                                instances::log_session(&instance_id_clone, &inst_name, end_time, session_duration);
+                            // Finalize playtime, unless the `--launch` process that started the game does it
+                            if !owned_elsewhere {
+                                let end_time = std::time::SystemTime::now()
+                                    .duration_since(std::time::UNIX_EPOCH)
+                                    .unwrap_or_default()
+                                    .as_secs();
+                                credit_playtime(&instance_id_clone, end_time, end_play_span(&instance_id_clone, start_time, end_time));
+                                instances::clear_active_session(&process_key);
                             }
 
-                            instances::clear_active_session(&instance_id_clone);
-
                             if let Ok(mut processes) = RUNNING_PROCESSES.lock() {
-                                processes.remove(&instance_id_clone);
-                                discord::update_presence(processes.len());
+                                processes.remove(&process_key);
                             }
+                            sync_discord_presence();
+                            forget_user_stop(&process_key);
                             refresh_tray_menu(&handle_clone);
 
                             let _ = handle_clone.emit("refresh-instances", ());
@@ -6889,11 +11017,14 @@ pub fn run() {
 
                         log::info!("Re-registered running instance {} with PID {}", instance_id, pid_val);
                     } else {
-                        let credited_duration = start_time_or_credited;
+                        if live_instances.contains(&instance_id) {
+                            // A parallel session is still running; its span now starts here
+                            widen_play_span(&instance_id, start_time);
+                        }
                         log::info!(
                             "Recovered orphaned session for instance {}: {}s credited",
                             instance_id,
-                            credited_duration
+                            session.credited_seconds
                         );
                     }
                 }
@@ -6904,13 +11035,7 @@ pub fn run() {
             discord::init();
 
             // Update Discord presence with any recovered running instances
-            {
-                if let Ok(processes) = RUNNING_PROCESSES.lock() {
-                    discord::update_presence(processes.len());
-                } else {
-                    discord::update_presence(0);
-                }
-            }
+            sync_discord_presence();
 
             // Store launch args if app was opened directly with a shortcut target.
             let startup_args: Vec<String> = std::env::args().collect();
@@ -6922,17 +11047,34 @@ pub fn run() {
             open_url,
             get_bootstrap_time,
             // Version commands
+            is_online,
             get_versions,
+            get_versions_updated_at,
+            get_version_details,
+            get_monitor_resolutions,
+            get_system_memory,
             get_latest_release,
             // Instance commands
             get_instances,
+            reorder_instances,
             create_instance,
+            validate_instance_name,
             create_instance_shortcut,
             delete_instance,
             update_instance,
+            set_instance_lock,
+            set_excluded_library,
+            get_jvm_presets,
+            import_jvm_args_from_file,
+            export_jvm_args,
             clone_instance,
+            relocate_instance_data,
             get_instance_options,
             save_instance_options,
+            copy_options_from,
+            list_instance_configs,
+            read_instance_config,
+            write_instance_config,
             open_instance_options_file,
             get_instance_details,
             get_available_logos,
@@ -6941,6 +11083,10 @@ pub fn run() {
             set_instance_logo_from_url,
             clear_instance_logo,
             export_instance_zip,
+            export_instance_curseforge,
+            backup_world,
+            list_world_backups,
+            restore_world_backup,
             import_instance_zip,
             peek_instance_zip,
             import_instance_source,
@@ -6950,22 +11096,37 @@ pub fn run() {
             is_version_downloaded,
             // Launch commands
             launch_instance,
+            clear_stale_process,
+            launch_instance_as,
+            get_launch_command,
+            repair_instance,
             kill_game,
             force_kill_game,
             get_running_instances,
+            get_running_sessions,
             check_java,
             // Settings commands
             set_java_path,
             get_java_path,
             get_settings,
             save_settings,
+            diagnose_connectivity,
+            export_launcher_config,
+            import_launcher_config,
+            get_last_view,
+            set_last_view,
+            get_privacy_settings,
+            save_privacy_settings,
             has_curseforge_api_key,
             download_java_for_instance,
             download_java_global,
             is_java_version_installed,
+            list_installed_java_runtimes,
+            delete_java_runtime,
             // Update/version comparison commands
             get_github_releases,
             compare_versions,
+            check_for_update,
             is_prerelease_version,
             download_and_run_installer,
             // Auth commands
@@ -6979,18 +11140,22 @@ pub fn run() {
             validate_account,
             refresh_account,
             switch_account,
+            refresh_all_accounts,
             get_mc_profile_full,
             get_mc_profile_full_with_token,
             upload_skin,
             reset_skin,
             // User commands
             set_offline_user,
+            migrate_offline_account_uuid,
             get_current_user,
             get_data_directory,
+            migrate_data_directory,
             take_pending_shortcut_launch,
             // Mod loader commands
             get_loader_versions,
             install_fabric,
+            install_quilt,
             install_forge,
             install_neoforge,
             // Disk cleanup commands
@@ -6998,6 +11163,8 @@ pub fn run() {
             get_downloaded_versions,
             delete_version,
             clear_assets_cache,
+            dedupe_libraries,
+            prune_unused_libraries,
             // Modrinth commands
             search_modrinth,
             search_curseforge_modpacks,
@@ -7009,21 +11176,42 @@ pub fn run() {
             get_modrinth_projects,
             get_modrinth_versions,
             get_modrinth_version,
+            get_version_dependencies_detailed,
             get_modpack_total_size,
             install_modpack,
+            get_modpack_files,
+            plan_modpack_install,
             install_curseforge_modpack,
             switch_instance_modpack_version,
+            update_modpack,
+            verify_modpack,
+            preflight_check,
+            retry_failed_modpack_files,
             install_modrinth_file,
+            queue_install,
+            cancel_install,
+            get_install_queue,
+            install_mod_from_url,
             install_curseforge_file,
             install_curseforge_world,
             resolve_manual_modrinth_metadata,
+            identify_mods_by_hash,
             save_remote_file,
             // File management commands
             get_instance_mods,
+            detect_mod_conflicts,
+            search_installed_content,
             get_instance_mod_updates,
+            change_instance_version,
+            check_curseforge_mod_updates,
             scan_mod_conflicts,
             toggle_instance_mod,
             delete_instance_mod,
+            toggle_mods,
+            set_mod_pinned,
+            delete_mods,
+            disable_all_mods,
+            enable_all_mods,
             get_instance_resourcepacks,
             toggle_instance_resourcepack,
             delete_instance_resourcepack,
@@ -7041,11 +11229,21 @@ pub fn run() {
             toggle_instance_datapack,
             delete_instance_datapack,
             get_instance_screenshots,
+            get_screenshot_thumbnail,
             delete_instance_screenshot,
             rename_instance_screenshot,
             open_instance_screenshot,
             save_instance_edited_screenshot,
             get_instance_log,
+            start_log_stream,
+            stop_log_stream,
+            start_watching_instance,
+            stop_watching_instance,
+            get_instance_live_output,
+            get_instance_summary,
+            get_instance_crash_reports,
+            get_crash_report,
+            open_crash_reports_folder,
             clear_instance_log,
             get_instance_servers,
             add_instance_server,
@@ -7053,17 +11251,27 @@ pub fn run() {
             update_instance_server,
             set_server_resource_packs,
             ping_server,
+            check_server_join,
+            get_auth_type,
+            query_server,
             open_instance_folder,
             get_instance_share_code,
             get_instance_mods_share_code,
             decode_instance_share_code,
+            validate_share_code,
+            apply_share_code,
             // Skin collection commands
             get_skin_collection,
             add_to_skin_collection,
             delete_skin_from_collection,
             get_skin_file_path,
+            render_skin_preview,
+            get_account_head,
             get_global_stats,
+            get_instance_sessions,
+            export_stats_csv,
             log_event,
+            export_debug_bundle,
             get_bootstrap_time,
             exit_app_fully,
         ])
@@ -63,10 +63,33 @@ fn load_local_dev_env() {
   }
 }
 
+/// `--launch <instance_id> [--account <username>]` starts a game without the GUI
+fn parse_cli_launch(args: &[String]) -> Option<(String, Option<String>)> {
+  let value_of = |flag: &str| {
+    args
+      .iter()
+      .position(|arg| arg == flag)
+      .and_then(|i| args.get(i + 1))
+      .filter(|value| !value.starts_with("--"))
+      .cloned()
+  };
+  Some((value_of("--launch")?, value_of("--account")))
+}
+
 fn main() {
   #[cfg(debug_assertions)]
   load_local_dev_env();
 
+  let args: Vec<String> = std::env::args().collect();
+  if let Some((instance_id, account)) = parse_cli_launch(&args) {
+    // Release builds use the GUI subsystem, so borrow the calling terminal for output
+    #[cfg(target_os = "windows")]
+    let _ = unsafe {
+      windows::Win32::System::Console::AttachConsole(windows::Win32::System::Console::ATTACH_PARENT_PROCESS)
+    };
+    std::process::exit(app_lib::run_cli_launch(&instance_id, account.as_deref()));
+  }
+
   // CRITICAL: Set WebView2 args BEFORE any Tauri initialization
   // This bypasses the 20-second Windows proxy auto-detection hang
   #[cfg(target_os = "windows")]
@@ -108,6 +108,9 @@ pub struct SavedAccount {
     pub access_token: String,
     pub refresh_token: Option<String>,
     pub is_microsoft: bool,
+    // Set when Microsoft rejected the refresh token; cleared by logging in again
+    #[serde(default)]
+    pub needs_relogin: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -460,6 +463,116 @@ pub fn save_accounts(data: &AccountsData) -> Result<(), String> {
     Ok(())
 }
 
+// Key stretching for exported account bundles; high enough to make guessing a passphrase slow
+const EXPORT_KDF_ROUNDS: u32 = 600_000;
+const MIN_EXPORT_PASSPHRASE_CHARS: usize = 8;
+// Rounds accepted from an imported file; more than this would hang the import on a crafted file
+const IMPORT_KDF_ROUNDS: std::ops::RangeInclusive<u32> = 100_000..=10_000_000;
+
+// ----------
+// EncryptedAccounts
+// Description: Saved accounts sealed with AES-256-GCM under a key derived from a passphrase,
+//              so exported files never hold readable access or refresh tokens
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EncryptedAccounts {
+    pub kdf: String,
+    pub rounds: u32,
+    pub salt: String,
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct AccountsImportResult {
+    pub added: Vec<String>,
+    // Already present here; the local copy is kept since its tokens may be newer
+    pub skipped: Vec<String>,
+}
+
+fn export_key(passphrase: &str, salt: &[u8], rounds: u32) -> aes_gcm::Key<aes_gcm::Aes256Gcm> {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<sha2::Sha256>(passphrase.as_bytes(), salt, rounds, &mut key);
+    key.into()
+}
+
+/// Encrypt all saved accounts with a passphrase
+pub fn export_accounts(passphrase: &str) -> Result<EncryptedAccounts, String> {
+    use aes_gcm::aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng};
+    use base64::{engine::general_purpose, Engine as _};
+
+    if passphrase.chars().count() < MIN_EXPORT_PASSPHRASE_CHARS {
+        return Err(format!("Use a passphrase of at least {} characters", MIN_EXPORT_PASSPHRASE_CHARS));
+    }
+    let plaintext = serde_json::to_vec(&load_accounts()).map_err(|e| e.to_string())?;
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = aes_gcm::Aes256Gcm::new(&export_key(passphrase, &salt, EXPORT_KDF_ROUNDS));
+    let nonce = aes_gcm::Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_ref())
+        .map_err(|_| "Failed to encrypt accounts".to_string())?;
+    Ok(EncryptedAccounts {
+        kdf: "pbkdf2-sha256".to_string(),
+        rounds: EXPORT_KDF_ROUNDS,
+        salt: general_purpose::STANDARD.encode(salt),
+        nonce: general_purpose::STANDARD.encode(nonce),
+        ciphertext: general_purpose::STANDARD.encode(ciphertext),
+    })
+}
+
+/// Decrypt exported accounts and add the ones not saved here yet to the current accounts.
+/// Existing accounts and the current active account are left as they are. Nothing is saved;
+/// the caller writes the returned data with `save_accounts`.
+pub fn merge_imported_accounts(encrypted: &EncryptedAccounts, passphrase: &str) -> Result<(AccountsData, AccountsImportResult), String> {
+    use aes_gcm::aead::{Aead, KeyInit};
+    use base64::{engine::general_purpose, Engine as _};
+
+    if encrypted.kdf != "pbkdf2-sha256" {
+        return Err(format!("Unsupported key derivation '{}'", encrypted.kdf));
+    }
+    if !IMPORT_KDF_ROUNDS.contains(&encrypted.rounds) {
+        return Err("The accounts in this file are damaged".to_string());
+    }
+    let decode = |field: &str| general_purpose::STANDARD.decode(field).map_err(|_| "The accounts in this file are damaged".to_string());
+    let salt = decode(&encrypted.salt)?;
+    let nonce = decode(&encrypted.nonce)?;
+    let ciphertext = decode(&encrypted.ciphertext)?;
+    if nonce.len() != 12 {
+        return Err("The accounts in this file are damaged".to_string());
+    }
+    let cipher = aes_gcm::Aes256Gcm::new(&export_key(passphrase, &salt, encrypted.rounds));
+    let plaintext = cipher
+        .decrypt(aes_gcm::Nonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|_| "Wrong passphrase, or the file was modified".to_string())?;
+    let imported: AccountsData =
+        serde_json::from_slice(&plaintext).map_err(|e| format!("The accounts in this file are invalid: {}", e))?;
+
+    let mut data = load_accounts();
+    let mut result = AccountsImportResult::default();
+    for account in imported.accounts {
+        if account.username.trim().is_empty() || account.uuid.trim().is_empty() {
+            continue;
+        }
+        let exists = data.accounts.iter().any(|a| {
+            a.is_microsoft == account.is_microsoft
+                && if account.is_microsoft { a.uuid == account.uuid } else { a.username == account.username }
+        });
+        if exists {
+            result.skipped.push(account.username);
+        } else {
+            result.added.push(account.username.clone());
+            data.accounts.push(account);
+        }
+    }
+    if data.active_account.is_none() {
+        data.active_account = imported
+            .active_account
+            .filter(|name| data.accounts.iter().any(|a| &a.username == name));
+    }
+    Ok((data, result))
+}
+
 /// Add or update an account
 pub fn add_account(account: SavedAccount) -> Result<(), String> {
     let mut data = load_accounts();
@@ -499,6 +612,34 @@ pub fn remove_account(username: &str) -> Result<(), String> {
     save_accounts(&data)
 }
 
+/// The UUID vanilla servers and other launchers give an offline player: a version 3 UUID
+/// from the MD5 of "OfflinePlayer:<name>", matching Java's UUID.nameUUIDFromBytes
+pub fn offline_uuid(username: &str) -> String {
+    use md5::{Digest, Md5};
+
+    let digest: [u8; 16] = Md5::digest(format!("OfflinePlayer:{}", username).as_bytes()).into();
+    uuid::Builder::from_md5_bytes(digest).into_uuid().simple().to_string()
+}
+
+/// Switch a saved offline account over to its offline_uuid. Returns the previous UUID,
+/// or None when the account already uses it.
+pub fn migrate_offline_uuid(username: &str) -> Result<Option<String>, String> {
+    let mut data = load_accounts();
+    let account = data
+        .accounts
+        .iter_mut()
+        .find(|a| a.username == username && !a.is_microsoft)
+        .ok_or_else(|| format!("No offline account named {}", username))?;
+
+    let new_uuid = offline_uuid(username);
+    if account.uuid.replace('-', "").eq_ignore_ascii_case(&new_uuid) {
+        return Ok(None);
+    }
+    let old_uuid = std::mem::replace(&mut account.uuid, new_uuid);
+    save_accounts(&data)?;
+    Ok(Some(old_uuid))
+}
+
 /// Set active account
 pub fn set_active_account(username: &str) -> Result<(), String> {
     let mut data = load_accounts();
@@ -615,16 +756,168 @@ pub async fn reset_mc_skin(mc_token: &str) -> Result<(), Box<dyn Error + Send +
 
 /// Validate a Microsoft account by checking if the token still works
 pub async fn validate_token(access_token: &str) -> bool {
+    check_token(access_token).await == TokenState::Valid
+}
+
+const SESSION_ATTEMPTS: u32 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenState {
+    Valid,
+    Invalid,
+    /// Minecraft services could not be reached or are erroring; the token may still be fine
+    Unreachable,
+}
+
+/// Check an access token against the profile endpoint, telling an expired token
+/// apart from a network or service failure.
+pub async fn check_token(access_token: &str) -> TokenState {
     let client = create_client();
-    
+
     let response = client
         .get("https://api.minecraftservices.com/minecraft/profile")
         .header("Authorization", format!("Bearer {}", access_token))
         .send()
         .await;
-    
+
     match response {
-        Ok(resp) => resp.status().is_success(),
-        Err(_) => false,
+        Ok(resp) if resp.status().is_success() => TokenState::Valid,
+        Ok(resp) if resp.status().is_server_error() || resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+            TokenState::Unreachable
+        }
+        Ok(_) => TokenState::Invalid,
+        Err(_) => TokenState::Unreachable,
+    }
+}
+
+/// Check a token, retrying with backoff while the services are unreachable
+pub async fn check_token_with_retry(access_token: &str) -> TokenState {
+    let mut attempt = 1;
+    loop {
+        let state = check_token(access_token).await;
+        if state != TokenState::Unreachable || attempt >= SESSION_ATTEMPTS {
+            return state;
+        }
+        tokio::time::sleep(crate::minecraft::downloader::retry_backoff(attempt)).await;
+        attempt += 1;
+    }
+}
+
+fn is_transient_error(error: &(dyn Error + Send + Sync)) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .map(|e| e.is_timeout() || e.is_connect() || e.is_request())
+        .unwrap_or(false)
+}
+
+/// Exchange a refresh token for a fresh Minecraft session. Network failures are
+/// retried with backoff; rejected refresh tokens fail immediately.
+pub async fn refresh_session(refresh_tok: &str) -> Result<MicrosoftAccount, Box<dyn Error + Send + Sync>> {
+    let mut attempt = 1;
+    loop {
+        let result = async {
+            let token_response = refresh_token(refresh_tok).await?;
+            let ms_token = token_response
+                .access_token
+                .ok_or("No access token in refresh response")?;
+            complete_authentication(
+                &ms_token,
+                token_response.refresh_token.or_else(|| Some(refresh_tok.to_string())),
+            )
+            .await
+        }
+        .await;
+
+        match result {
+            Ok(account) => return Ok(account),
+            Err(e) if attempt < SESSION_ATTEMPTS && is_transient_error(e.as_ref()) => {
+                log::warn!("Session refresh attempt {} failed, retrying: {}", attempt, e);
+                tokio::time::sleep(crate::minecraft::downloader::retry_backoff(attempt)).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// Accounts refreshed at once by refresh_all_accounts
+const BULK_REFRESH_CONCURRENCY: usize = 4;
+
+// ----------
+// AccountRefreshResult
+// Description: Outcome of refreshing one saved Microsoft account. `status` is "valid" (token
+//              still worked), "refreshed", "needs_relogin" (refresh token rejected or missing)
+//              or "failed" (network trouble; the account is left as it was)
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountRefreshResult {
+    pub username: String,
+    pub uuid: String,
+    pub status: String,
+    pub error: Option<String>,
+    #[serde(skip)]
+    refreshed: Option<MicrosoftAccount>,
+}
+
+async fn refresh_saved_account(account: SavedAccount) -> AccountRefreshResult {
+    let result = |status: &str, error: Option<String>, refreshed: Option<MicrosoftAccount>| AccountRefreshResult {
+        username: account.username.clone(),
+        uuid: account.uuid.clone(),
+        status: status.to_string(),
+        error,
+        refreshed,
+    };
+    if check_token_with_retry(&account.access_token).await == TokenState::Valid {
+        return result("valid", None, None);
+    }
+    let Some(refresh_tok) = account.refresh_token.as_deref() else {
+        return result("needs_relogin", Some("No refresh token is stored".to_string()), None);
+    };
+    match refresh_session(refresh_tok).await {
+        Ok(refreshed) => result("refreshed", None, Some(refreshed)),
+        // refresh_session already retried these, so the service is just unreachable right now
+        Err(e) if is_transient_error(e.as_ref()) => result("failed", Some(e.to_string()), None),
+        Err(e) => result("needs_relogin", Some(e.to_string()), None),
+    }
+}
+
+/// Check every saved Microsoft account and refresh the expired ones, a few at a time.
+/// Results are written back in one save; accounts whose refresh token was rejected are
+/// flagged with `needs_relogin` instead of being removed.
+pub async fn refresh_all_accounts() -> Result<Vec<AccountRefreshResult>, String> {
+    use futures::stream::{self, StreamExt};
+
+    let microsoft: Vec<SavedAccount> = load_accounts()
+        .accounts
+        .into_iter()
+        .filter(|a| a.is_microsoft)
+        .collect();
+    let results: Vec<AccountRefreshResult> = stream::iter(microsoft.into_iter().map(refresh_saved_account))
+        .buffer_unordered(BULK_REFRESH_CONCURRENCY)
+        .collect()
+        .await;
+
+    // Reload so accounts added or removed while refreshing are kept as they are now
+    let mut data = load_accounts();
+    for result in &results {
+        let Some(saved) = data.accounts.iter_mut().find(|a| a.is_microsoft && a.uuid == result.uuid) else {
+            continue;
+        };
+        match (&result.refreshed, result.status.as_str()) {
+            (Some(refreshed), _) => {
+                if data.active_account.as_deref() == Some(saved.username.as_str()) {
+                    data.active_account = Some(refreshed.username.clone());
+                }
+                saved.username = refreshed.username.clone();
+                saved.access_token = refreshed.access_token.clone();
+                saved.refresh_token = refreshed.refresh_token.clone();
+                saved.needs_relogin = false;
+            }
+            (None, "valid") => saved.needs_relogin = false,
+            (None, "needs_relogin") => saved.needs_relogin = true,
+            _ => {}
+        }
     }
+    save_accounts(&data)?;
+    Ok(results)
 }
@@ -11,8 +11,8 @@ use std::sync::{Arc, Mutex};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::{AppHandle, Emitter};
 
-use crate::minecraft::downloader::DownloadProgress;
-use crate::minecraft::{fabric, forge, instances};
+use crate::minecraft::downloader::{DownloadProgress, ModpackFileFailure, ModpackInstallPlan, ModpackInstallSummary, ModpackPlanFile};
+use crate::minecraft::{fabric, forge, instances, quilt};
 
 const CURSEFORGE_API_BASE: &str = "https://api.curseforge.com/v1";
 const CURSEFORGE_MINECRAFT_GAME_ID: u32 = 432;
@@ -85,6 +85,22 @@ pub struct CurseForgeModpackVersion {
     pub version_type: String,
 }
 
+// ----------
+// CurseForgeModUpdate
+// Description: Newest CurseForge file matching an instance for one installed mod
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CurseForgeModUpdate {
+    pub project_id: String,
+    pub installed_filename: String,
+    pub installed_file_id: Option<String>,
+    pub latest: CurseForgeModpackVersion,
+    pub update_available: bool,
+    // Pinned mods are reported with update_available false and must not be updated
+    #[serde(default)]
+    pub pinned: bool,
+}
+
 #[derive(Debug, Deserialize)]
 struct CurseForgeApiResponse<T> {
     data: T,
@@ -138,6 +154,16 @@ struct CurseForgeProject {
     categories: Vec<CurseForgeCategory>,
     #[serde(default)]
     screenshots: Vec<CurseForgeAsset>,
+    #[serde(default, rename = "latestFilesIndexes")]
+    latest_files_indexes: Vec<CurseForgeFileIndex>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CurseForgeFileIndex {
+    #[serde(rename = "gameVersion")]
+    game_version: String,
+    #[serde(rename = "fileId")]
+    file_id: u64,
 }
 
 #[derive(Debug, Deserialize)]
@@ -205,12 +231,33 @@ struct CurseForgeFile {
     game_versions: Vec<String>,
     #[serde(default, rename = "releaseType")]
     release_type: u32,
+    #[serde(default)]
+    hashes: Vec<CurseForgeFileHash>,
+    #[serde(default, rename = "isServerPack")]
+    is_server_pack: bool,
+    // Set on additional files (server packs, changelogs, ...) attached to a main file
+    #[serde(default, rename = "parentProjectFileId")]
+    parent_project_file_id: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct CurseForgeFileHash {
+    value: String,
+    algo: u32, // 1 = SHA1, 2 = MD5
+}
+
+impl CurseForgeFile {
+    fn sha1(&self) -> Option<&str> {
+        self.hashes.iter().find(|h| h.algo == 1).map(|h| h.value.as_str())
+    }
 }
 
 #[derive(Debug, Deserialize)]
 struct CurseForgeManifest {
     minecraft: CurseForgeManifestMinecraft,
     #[serde(default)]
+    name: String,
+    #[serde(default)]
     files: Vec<CurseForgeManifestFile>,
     #[serde(default)]
     overrides: Option<String>,
@@ -236,7 +283,7 @@ struct CurseForgeManifestFile {
     project_id: u64,
     #[serde(rename = "fileID")]
     file_id: u64,
-    #[serde(default = "default_true")]
+    #[serde(default = "crate::minecraft::default_true")]
     required: bool,
 }
 
@@ -260,8 +307,6 @@ struct DownloadedCurseForgeModMeta {
     version_name: String,
 }
 
-fn default_true() -> bool { true }
-
 fn parse_u64_id(value: &str, label: &str) -> Result<u64, Box<dyn Error + Send + Sync>> {
     value.trim().parse::<u64>().map_err(|_| format!("Invalid {} '{}'", label, value).into())
 }
@@ -691,6 +736,26 @@ async fn get_modpack_file_detail(project_id: u64, file_id: u64) -> Result<CurseF
     Ok(response.data)
 }
 
+/// The client pack archive for a modpack file id. Additional files such as server packs are
+/// resolved to the main file they're attached to.
+async fn get_modpack_archive(project_id: u64, file_id: u64) -> Result<CurseForgeFile, Box<dyn Error + Send + Sync>> {
+    let file = get_modpack_file_detail(project_id, file_id).await?;
+    match file.parent_project_file_id {
+        Some(parent_id) if parent_id != file.id => {
+            log::info!(
+                "CurseForge file {} ({}) is an additional file, using its main file {} instead",
+                file.id, file.file_name, parent_id
+            );
+            get_modpack_file_detail(project_id, parent_id).await
+        }
+        _ if file.is_server_pack => Err(format!("{} is a server pack and can't be installed as a client modpack", file.file_name).into()),
+        _ => {
+            log::info!("Using CurseForge modpack file {} ({})", file.id, file.file_name);
+            Ok(file)
+        }
+    }
+}
+
 async fn get_mods_bulk(mod_ids: &[u64]) -> Result<HashMap<u64, CurseForgeProject>, Box<dyn Error + Send + Sync>> {
     if mod_ids.is_empty() {
         return Ok(HashMap::new());
@@ -751,13 +816,135 @@ async fn get_files_bulk(file_ids: &[u64]) -> Result<HashMap<u64, CurseForgeFile>
     Ok(map)
 }
 
+/// Whether a file's loader tags fit an instance loader key. Untagged files are allowed, and
+/// Quilt instances also take Fabric files.
+fn file_matches_loader(file_loaders: &[String], loader: Option<&str>) -> bool {
+    let Some(loader) = loader else {
+        return true;
+    };
+    file_loaders.is_empty()
+        || file_loaders.iter().any(|l| l == loader || (loader == "quilt" && l == "fabric"))
+}
+
+/// Find the newest file for each installed CurseForge mod that fits the game version and loader.
+/// `installed` holds (project id, installed file id, filename) entries.
+pub async fn check_mod_updates(
+    installed: &[(String, Option<String>, String)],
+    game_version: &str,
+    loader: Option<&str>,
+) -> Result<Vec<CurseForgeModUpdate>, Box<dyn Error + Send + Sync>> {
+    // Fail once up front instead of once per mod
+    curseforge_api_key()?;
+
+    let mod_ids: Vec<u64> = installed.iter().filter_map(|(id, _, _)| id.parse().ok()).collect();
+    let projects = get_mods_bulk(&mod_ids).await?;
+
+    // The latest file index has one entry per game version/loader pair, so it holds every candidate
+    let mut candidates: HashMap<u64, Vec<u64>> = HashMap::new();
+    for project in projects.values() {
+        let ids = candidates.entry(project.id).or_default();
+        for index in &project.latest_files_indexes {
+            if index.game_version == game_version && !ids.contains(&index.file_id) {
+                ids.push(index.file_id);
+            }
+        }
+    }
+    let all_file_ids: Vec<u64> = candidates.values().flatten().copied().collect();
+    let files = get_files_bulk(&all_file_ids).await?;
+
+    let mut updates = Vec::new();
+    for (project_id, installed_file_id, filename) in installed {
+        let Ok(mod_id) = project_id.parse::<u64>() else {
+            continue;
+        };
+        let newest = candidates
+            .get(&mod_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|file_id| files.get(file_id))
+            .filter(|file| {
+                let (mc_versions, loaders) = classify_game_versions_and_loaders(&file.game_versions);
+                mc_versions.iter().any(|v| v == game_version) && file_matches_loader(&loaders, loader)
+            })
+            .max_by(|a, b| a.file_date.cmp(&b.file_date));
+        let Some(newest) = newest else {
+            continue;
+        };
+
+        let update_available = installed_file_id.as_deref() != Some(newest.id.to_string().as_str());
+        updates.push(CurseForgeModUpdate {
+            project_id: project_id.clone(),
+            installed_filename: filename.clone(),
+            installed_file_id: installed_file_id.clone(),
+            latest: to_modpack_version(mod_id, newest.clone()),
+            update_available,
+            pinned: false,
+        });
+    }
+
+    Ok(updates)
+}
+
+const NOT_A_CURSEFORGE_PACK: &str = "This doesn't look like a CurseForge modpack";
+const SUPPORTED_MANIFEST_LOADERS: &[&str] = &["fabric-", "quilt-", "forge-", "neoforge-"];
+
+/// Check the fields the installer relies on so a bad pack fails before anything is downloaded
+fn validate_manifest(manifest: &serde_json::Value) -> Result<(), String> {
+    let minecraft = manifest
+        .get("minecraft")
+        .filter(|v| v.is_object())
+        .ok_or_else(|| format!("{}: manifest.json has no \"minecraft\" section", NOT_A_CURSEFORGE_PACK))?;
+
+    let version = minecraft.get("version").and_then(|v| v.as_str()).unwrap_or("").trim();
+    if version.is_empty() {
+        return Err("manifest.json is missing minecraft.version".to_string());
+    }
+
+    let loaders = minecraft
+        .get("modLoaders")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "manifest.json is missing minecraft.modLoaders".to_string())?;
+    for loader in loaders {
+        let id = loader.get("id").and_then(|v| v.as_str()).unwrap_or("").trim().to_ascii_lowercase();
+        if id.is_empty() {
+            return Err("manifest.json lists a mod loader without an id".to_string());
+        }
+        if !SUPPORTED_MANIFEST_LOADERS.iter().any(|prefix| id.starts_with(prefix)) {
+            return Err(format!("Unsupported mod loader \"{}\" in manifest.json", id));
+        }
+    }
+
+    if let Some(files) = manifest.get("files") {
+        let entries = files.as_array().ok_or_else(|| "manifest.json \"files\" must be a list".to_string())?;
+        for (i, entry) in entries.iter().enumerate() {
+            if entry.get("projectID").and_then(|v| v.as_u64()).is_none()
+                || entry.get("fileID").and_then(|v| v.as_u64()).is_none()
+            {
+                return Err(format!("manifest.json file entry {} is missing projectID or fileID", i + 1));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn parse_manifest(archive_path: &Path) -> Result<CurseForgeManifest, Box<dyn Error + Send + Sync>> {
-    let file = File::open(archive_path)?;
-    let mut archive = zip::ZipArchive::new(file)?;
-    let mut manifest_file = archive.by_name("manifest.json")?;
+    let file = File::open(archive_path).map_err(|e| format!("Could not open modpack archive: {}", e))?;
+    let mut archive = zip::ZipArchive::new(file)
+        .map_err(|_| format!("{} (the file is not a zip archive)", NOT_A_CURSEFORGE_PACK))?;
+    let mut manifest_file = archive
+        .by_name("manifest.json")
+        .map_err(|_| format!("{} (no manifest.json found)", NOT_A_CURSEFORGE_PACK))?;
     let mut manifest_content = String::new();
-    manifest_file.read_to_string(&mut manifest_content)?;
-    Ok(serde_json::from_str::<CurseForgeManifest>(&manifest_content)?)
+    manifest_file
+        .read_to_string(&mut manifest_content)
+        .map_err(|e| format!("Could not read manifest.json: {}", e))?;
+
+    let raw: serde_json::Value = serde_json::from_str(&manifest_content)
+        .map_err(|e| format!("{} (manifest.json is not valid JSON: {})", NOT_A_CURSEFORGE_PACK, e))?;
+    validate_manifest(&raw)?;
+
+    Ok(serde_json::from_value::<CurseForgeManifest>(raw).map_err(|e| format!("manifest.json is malformed: {}", e))?)
 }
 
 async fn download_with_progress(
@@ -787,6 +974,7 @@ async fn download_with_progress(
 
     while let Some(item) = stream.next().await {
         let chunk = item?;
+        crate::minecraft::downloader::throttle_download(chunk.len() as u64).await;
         out_file.write_all(&chunk)?;
         downloaded += chunk.len() as u64;
 
@@ -821,6 +1009,9 @@ fn parse_loader_from_manifest(manifest: &CurseForgeManifest) -> (instances::ModL
     if let Some(version) = id.strip_prefix("fabric-") {
         return (instances::ModLoader::Fabric, Some(version.to_string()));
     }
+    if let Some(version) = id.strip_prefix("quilt-") {
+        return (instances::ModLoader::Quilt, Some(version.to_string()));
+    }
     if let Some(version) = id.strip_prefix("forge-") {
         return (instances::ModLoader::Forge, Some(version.to_string()));
     }
@@ -1110,7 +1301,8 @@ pub async fn get_modpack_total_size(
 ) -> Result<u64, Box<dyn Error + Send + Sync>> {
     let mod_id = parse_u64_id(project_id, "project_id")?;
     let pack_file_id = parse_u64_id(file_id, "file_id")?;
-    let mut file = get_modpack_file_detail(mod_id, pack_file_id).await?;
+    let mut file = get_modpack_archive(mod_id, pack_file_id).await?;
+    let pack_file_id = file.id;
     file.download_url = Some(resolve_file_download_url(mod_id, pack_file_id, file.download_url.take()).await?);
 
     let temp_dir = std::env::temp_dir().join("palethea_curseforge_size_check");
@@ -1146,7 +1338,7 @@ pub async fn install_modpack(
     instance_id: &str,
     project_id: &str,
     file_id: &str,
-) -> Result<(), Box<dyn Error + Send + Sync>> {
+) -> Result<ModpackInstallSummary, Box<dyn Error + Send + Sync>> {
     let mod_id = parse_u64_id(project_id, "project_id")?;
     let pack_file_id = parse_u64_id(file_id, "file_id")?;
 
@@ -1159,7 +1351,8 @@ pub async fn install_modpack(
         downloaded_bytes: None,
     });
 
-    let mut modpack_file = get_modpack_file_detail(mod_id, pack_file_id).await?;
+    let mut modpack_file = get_modpack_archive(mod_id, pack_file_id).await?;
+    let pack_file_id = modpack_file.id;
     modpack_file.download_url = Some(resolve_file_download_url(mod_id, pack_file_id, modpack_file.download_url.take()).await?);
 
     let pack_download_url = modpack_file.download_url
@@ -1170,6 +1363,7 @@ pub async fn install_modpack(
     let temp_dir = std::env::temp_dir().join("palethea_curseforge_modpack");
     let _ = fs::create_dir_all(&temp_dir);
     let archive_path = temp_dir.join("modpack.zip");
+    crate::minecraft::downloader::ensure_disk_space(&temp_dir, modpack_file.file_length)?;
 
     let _ = app_handle.emit("download-progress", DownloadProgress {
         stage: format!("Downloading modpack file: {}...", modpack_file.file_name),
@@ -1213,12 +1407,24 @@ pub async fn install_modpack(
         .map(|f| f.file_length)
         .sum();
 
+    // Mods plus extracted overrides (approximated by the archive size) land in the instance folder
+    let instance_game_dir = instances::get_instance(instance_id)?.get_game_directory();
+    crate::minecraft::downloader::ensure_disk_space(&instance_game_dir, total_mods_size + modpack_file.file_length)?;
+
     let (mod_loader, loader_version) = parse_loader_from_manifest(&manifest);
 
     let mut instance = instances::get_instance(instance_id)?;
     instance.version_id = manifest.minecraft.version.clone();
     instance.mod_loader = mod_loader.clone();
     instance.mod_loader_version = loader_version.clone();
+    // Remember where the pack came from for update checks and re-export
+    instance.modpack_provider = Some("CurseForge".to_string());
+    instance.modpack_project_id = Some(mod_id.to_string());
+    instance.modpack_version_id = Some(pack_file_id.to_string());
+    if !instance.has_custom_logo() {
+        let icon_url = get_modpack(project_id).await.ok().and_then(|pack| pack.icon_url);
+        crate::minecraft::downloader::apply_modpack_logo(&mut instance, icon_url).await;
+    }
     instances::update_instance(instance.clone())?;
 
     if mod_loader != instances::ModLoader::Vanilla {
@@ -1232,19 +1438,36 @@ pub async fn install_modpack(
                 downloaded_bytes: None,
             });
 
+            // Map loader installer steps into the 25-30% slice of the overall bar
+            let report_loader_progress = |stage: &str, fraction: f32| {
+                let _ = app_handle.emit("download-progress", DownloadProgress {
+                    stage: stage.to_string(),
+                    percentage: 25.0 + fraction.clamp(0.0, 1.0) * 5.0,
+                    current: 15,
+                    total: 100,
+                    total_bytes: None,
+                    downloaded_bytes: None,
+                });
+            };
+
             match mod_loader {
                 instances::ModLoader::Fabric => {
-                    if let Err(e) = fabric::install_fabric(&instance, loader_ver).await {
+                    if let Err(e) = fabric::install_fabric(&instance, loader_ver, Some(&report_loader_progress)).await {
                         crate::log_error!(app_handle, "Failed to install Fabric loader: {}", e);
                     }
                 }
+                instances::ModLoader::Quilt => {
+                    if let Err(e) = quilt::install_quilt(&instance, loader_ver, Some(&report_loader_progress)).await {
+                        crate::log_error!(app_handle, "Failed to install Quilt loader: {}", e);
+                    }
+                }
                 instances::ModLoader::Forge => {
-                    if let Err(e) = forge::install_forge(&instance, loader_ver).await {
+                    if let Err(e) = forge::install_forge(&instance, loader_ver, Some(&report_loader_progress)).await {
                         crate::log_error!(app_handle, "Failed to install Forge loader: {}", e);
                     }
                 }
                 instances::ModLoader::NeoForge => {
-                    if let Err(e) = forge::install_neoforge(&instance, loader_ver).await {
+                    if let Err(e) = forge::install_neoforge(&instance, loader_ver, Some(&report_loader_progress)).await {
                         crate::log_error!(app_handle, "Failed to install NeoForge loader: {}", e);
                     }
                 }
@@ -1260,6 +1483,11 @@ pub async fn install_modpack(
     let downloaded_meta = Arc::new(Mutex::new(Vec::<DownloadedCurseForgeModMeta>::new()));
     let game_dir = instance.get_game_directory();
     let client = super::http_client();
+    let meta_writer = crate::minecraft::files::MetaWriter::spawn();
+    let meta_writer_ref = &meta_writer;
+    let failures = Arc::new(Mutex::new(Vec::<ModpackFileFailure>::new()));
+    let concurrency = crate::minecraft::settings::get_download_concurrency();
+    let max_retries = crate::minecraft::settings::get_download_max_retries();
 
     let _ = app_handle.emit("download-progress", DownloadProgress {
         stage: format!("Downloading mods 0/{}...", total_files),
@@ -1271,25 +1499,43 @@ pub async fn install_modpack(
     });
 
     stream::iter(required_entries.into_iter())
-        .for_each_concurrent(12, |entry| {
+        .for_each_concurrent(concurrency, |entry| {
             let app_handle = app_handle.clone();
             let downloaded_bytes_counter = downloaded_bytes_counter.clone();
             let completed_count = completed_count.clone();
             let last_progress_emit_ms = last_progress_emit_ms.clone();
             let downloaded_meta = downloaded_meta.clone();
+            let failures = failures.clone();
             let game_dir = game_dir.clone();
             let client = client.clone();
             let file_map = file_map.clone();
 
             async move {
+                if crate::minecraft::installqueue::is_cancelled(instance_id) {
+                    return;
+                }
+                let manual_url = format!("https://www.curseforge.com/projects/{}", entry.project_id);
+                let record_failure = |failure: ModpackFileFailure| {
+                    let failure = failure.with_project(entry.project_id.to_string(), Some(manual_url.clone()));
+                    crate::log_warn!(&app_handle, "Failed to download file {}: {}", failure.path, failure.reason);
+                    if let Ok(mut list) = failures.lock() {
+                        list.push(failure);
+                    }
+                    completed_count.fetch_add(1, Ordering::SeqCst);
+                };
+
                 let details = match file_map.get(&entry.file_id) {
                     Some(details) => details.clone(),
                     None => {
-                        crate::log_warn!(&app_handle, "Missing CurseForge file metadata for fileID={}", entry.file_id);
-                        completed_count.fetch_add(1, Ordering::SeqCst);
+                        record_failure(ModpackFileFailure::new(
+                            &format!("fileID={}", entry.file_id),
+                            "metadata",
+                            format!("CurseForge returned no metadata for file {} (project {})", entry.file_id, entry.project_id),
+                        ));
                         return;
                     }
                 };
+                let rel_path = format!("mods/{}", details.file_name);
 
                 let download_url = match resolve_file_download_url(
                     entry.project_id,
@@ -1299,9 +1545,12 @@ pub async fn install_modpack(
                 .await
                 {
                     Ok(url) => url,
-                    Err(_) => {
-                        crate::log_warn!(&app_handle, "No download URL for CurseForge file {} (project {})", entry.file_id, entry.project_id);
-                        completed_count.fetch_add(1, Ordering::SeqCst);
+                    Err(e) => {
+                        record_failure(ModpackFileFailure::new(
+                            &rel_path,
+                            "missing_url",
+                            format!("No download URL for CurseForge file {} (project {}): {}", entry.file_id, entry.project_id, e),
+                        ));
                         return;
                     }
                 };
@@ -1311,91 +1560,123 @@ pub async fn install_modpack(
                     let _ = fs::create_dir_all(parent);
                 }
 
-                let response = match client.get(&download_url).header("User-Agent", user_agent()).send().await {
-                    Ok(resp) => match resp.error_for_status() {
-                        Ok(ok) => ok,
-                        Err(_) => {
-                            completed_count.fetch_add(1, Ordering::SeqCst);
-                            return;
-                        }
-                    },
-                    Err(_) => {
-                        completed_count.fetch_add(1, Ordering::SeqCst);
-                        return;
-                    }
-                };
-
-                let mut file = match File::create(&dest) {
-                    Ok(file) => file,
-                    Err(_) => {
-                        completed_count.fetch_add(1, Ordering::SeqCst);
-                        return;
-                    }
-                };
-
-                let mut stream = response.bytes_stream();
-                let mut downloaded_for_this = 0u64;
-                let mut ok = true;
-
-                while let Some(item) = stream.next().await {
-                    match item {
-                        Ok(chunk) => {
-                            if file.write_all(&chunk).is_err() {
-                                ok = false;
+                let mut attempt = 0u32;
+                let outcome = loop {
+                    let attempt_result: Result<(), ModpackFileFailure> = async {
+                        let response = match client.get(&download_url).header("User-Agent", user_agent()).send().await {
+                            Ok(resp) if resp.status().is_success() => resp,
+                            Ok(resp) => return Err(ModpackFileFailure::from_status(&rel_path, resp.status())),
+                            Err(e) => return Err(ModpackFileFailure::from_request_error(&rel_path, &e)),
+                        };
+
+                        let mut file = File::create(&dest)
+                            .map_err(|e| ModpackFileFailure::new(&rel_path, "io", format!("Failed to create file: {}", e)))?;
+
+                        let mut stream = response.bytes_stream();
+                        let mut downloaded_for_this = 0u64;
+                        let mut stream_failure: Option<ModpackFileFailure> = None;
+
+                        while let Some(item) = stream.next().await {
+                            if crate::minecraft::installqueue::is_cancelled(instance_id) {
+                                stream_failure = Some(ModpackFileFailure::new(&rel_path, "cancelled", "Installation cancelled"));
                                 break;
                             }
+                            match item {
+                                Ok(chunk) => {
+                                    crate::minecraft::downloader::throttle_download(chunk.len() as u64).await;
+                                    if let Err(e) = file.write_all(&chunk) {
+                                        stream_failure = Some(ModpackFileFailure::new(&rel_path, "io", format!("Failed to write file: {}", e)));
+                                        break;
+                                    }
+
+                                    let len = chunk.len() as u64;
+                                    downloaded_for_this += len;
+                                    let total_downloaded = downloaded_bytes_counter.fetch_add(len, Ordering::SeqCst) + len;
+
+                                    let now_ms = SystemTime::now()
+                                        .duration_since(UNIX_EPOCH)
+                                        .map(|d| d.as_millis() as u64)
+                                        .unwrap_or(0);
+                                    let last_ms = last_progress_emit_ms.load(Ordering::Relaxed);
+
+                                    if now_ms.saturating_sub(last_ms) >= 120
+                                        && last_progress_emit_ms.compare_exchange(last_ms, now_ms, Ordering::SeqCst, Ordering::Relaxed).is_ok()
+                                    {
+                                        let completed_so_far = completed_count.load(Ordering::SeqCst);
+                                        let ratio = if total_mods_size > 0 {
+                                            (total_downloaded as f32 / total_mods_size as f32).clamp(0.0, 1.0)
+                                        } else if total_files > 0 {
+                                            (completed_so_far as f32 / total_files as f32).clamp(0.0, 1.0)
+                                        } else {
+                                            1.0
+                                        };
+
+                                        let _ = app_handle.emit("download-progress", DownloadProgress {
+                                            stage: format!("Downloading mods {}/{}...", completed_so_far, total_files),
+                                            percentage: 30.0 + (ratio * 60.0),
+                                            current: completed_so_far,
+                                            total: total_files as u32,
+                                            total_bytes: Some(total_mods_size),
+                                            downloaded_bytes: Some(total_downloaded),
+                                        });
+                                    }
+                                }
+                                Err(e) => {
+                                    stream_failure = Some(ModpackFileFailure::from_request_error(&rel_path, &e));
+                                    break;
+                                }
+                            }
+                        }
 
-                            let len = chunk.len() as u64;
-                            downloaded_for_this += len;
-                            let total_downloaded = downloaded_bytes_counter.fetch_add(len, Ordering::SeqCst) + len;
-
-                            let now_ms = SystemTime::now()
-                                .duration_since(UNIX_EPOCH)
-                                .map(|d| d.as_millis() as u64)
-                                .unwrap_or(0);
-                            let last_ms = last_progress_emit_ms.load(Ordering::Relaxed);
-
-                            if now_ms.saturating_sub(last_ms) >= 120
-                                && last_progress_emit_ms.compare_exchange(last_ms, now_ms, Ordering::SeqCst, Ordering::Relaxed).is_ok()
-                            {
-                                let completed_so_far = completed_count.load(Ordering::SeqCst);
-                                let ratio = if total_mods_size > 0 {
-                                    (total_downloaded as f32 / total_mods_size as f32).clamp(0.0, 1.0)
-                                } else if total_files > 0 {
-                                    (completed_so_far as f32 / total_files as f32).clamp(0.0, 1.0)
-                                } else {
-                                    1.0
-                                };
-
-                                let _ = app_handle.emit("download-progress", DownloadProgress {
-                                    stage: format!("Downloading mods {}/{}...", completed_so_far, total_files),
-                                    percentage: 30.0 + (ratio * 60.0),
-                                    current: completed_so_far,
-                                    total: total_files as u32,
-                                    total_bytes: Some(total_mods_size),
-                                    downloaded_bytes: Some(total_downloaded),
-                                });
+                        if let Some(failure) = stream_failure {
+                            if downloaded_for_this > 0 {
+                                downloaded_bytes_counter.fetch_sub(downloaded_for_this, Ordering::SeqCst);
                             }
+                            let _ = fs::remove_file(&dest);
+                            return Err(failure);
                         }
-                        Err(_) => {
-                            ok = false;
-                            break;
+                        Ok(())
+                    }
+                    .await;
+
+                    match attempt_result {
+                        Ok(()) => break Ok(()),
+                        Err(failure) if failure.retryable && attempt < max_retries => {
+                            attempt += 1;
+                            tokio::time::sleep(crate::minecraft::downloader::retry_backoff(attempt)).await;
                         }
+                        Err(failure) => break Err(failure),
                     }
-                }
+                };
 
-                if !ok {
-                    if downloaded_for_this > 0 {
-                        downloaded_bytes_counter.fetch_sub(downloaded_for_this, Ordering::SeqCst);
+                if let Err(failure) = outcome {
+                    let failure = failure.with_project(entry.project_id.to_string(), Some(manual_url.clone()));
+                    crate::log_warn!(&app_handle, "Failed to download file {}: {}", failure.path, failure.reason);
+                    if let Ok(mut list) = failures.lock() {
+                        list.push(failure);
                     }
-                    let _ = fs::remove_file(&dest);
-                } else if let Ok(mut meta) = downloaded_meta.lock() {
-                    meta.push(DownloadedCurseForgeModMeta {
-                        dest,
-                        project_id: entry.project_id,
-                        file_id: entry.file_id,
-                        version_name: if details.display_name.is_empty() { details.file_name } else { details.display_name },
+                } else {
+                    let version_name = if details.display_name.is_empty() { details.file_name } else { details.display_name };
+                    // Persist the IDs right away so an interrupted install keeps usable metadata
+                    meta_writer_ref.queue(dest.clone(), crate::minecraft::files::ModMeta {
+                        project_id: entry.project_id.to_string(),
+                        version_id: Some(entry.file_id.to_string()),
+                        name: None,
+                        author: None,
+                        icon_url: None,
+                        version_name: Some(version_name.clone()),
+                        categories: None,
+                        source_url: None,
+                        pinned: false,
                     });
+                    if let Ok(mut meta) = downloaded_meta.lock() {
+                        meta.push(DownloadedCurseForgeModMeta {
+                            dest,
+                            project_id: entry.project_id,
+                            file_id: entry.file_id,
+                            version_name,
+                        });
+                    }
                 }
 
                 let completed = completed_count.fetch_add(1, Ordering::SeqCst) + 1;
@@ -1420,6 +1701,11 @@ pub async fn install_modpack(
         })
         .await;
 
+    if crate::minecraft::installqueue::is_cancelled(instance_id) {
+        let _ = fs::remove_file(&archive_path);
+        return Err("Modpack installation cancelled".into());
+    }
+
     let downloaded_meta_entries = downloaded_meta.lock().map(|m| m.clone()).unwrap_or_default();
     if !downloaded_meta_entries.is_empty() {
         let _ = app_handle.emit("download-progress", DownloadProgress {
@@ -1452,13 +1738,17 @@ pub async fn install_modpack(
                 icon_url,
                 version_name: Some(entry.version_name.clone()),
                 categories,
+                source_url: None,
+                pinned: false,
             };
 
-            let _ = crate::minecraft::files::write_meta_for_file(&entry.dest, &meta);
+            meta_writer.queue(entry.dest, meta);
         }
     }
+    // Joins the writer thread, so it waits off the async runtime
+    let _ = tokio::task::spawn_blocking(move || meta_writer.finish()).await;
 
-    let overrides_dir = manifest.overrides.as_deref().filter(|value| !value.trim().is_empty()).unwrap_or("overrides").to_string();
+    let overrides_dir = normalize_overrides_dir(manifest.overrides.as_deref());
 
     let _ = app_handle.emit("download-progress", DownloadProgress {
         stage: "Applying overrides...".to_string(),
@@ -1469,26 +1759,72 @@ pub async fn install_modpack(
         downloaded_bytes: None,
     });
 
-    {
-        let file = File::open(&archive_path)?;
-        let mut archive = zip::ZipArchive::new(file)?;
+    apply_pack_overrides(&archive_path, &instance.get_game_directory(), &overrides_dir)?;
+
+    let _ = app_handle.emit("download-progress", DownloadProgress {
+        stage: "Modpack installed!".to_string(),
+        percentage: 100.0,
+        current: 100,
+        total: 100,
+        total_bytes: None,
+        downloaded_bytes: None,
+    });
+
+    let _ = fs::remove_file(&archive_path);
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let failures = failures.lock().map(|list| list.clone()).unwrap_or_default();
+    let summary = ModpackInstallSummary::new(total_files, failures);
+    if !summary.failures.is_empty() {
+        crate::log_warn!(app_handle, "Modpack installed with {} of {} file(s) failing", summary.failures.len(), summary.total);
+    }
+    Ok(summary)
+}
+
+/// The manifest's overrides folder as a bare relative name, e.g. "./Overrides/" -> "Overrides"
+fn normalize_overrides_dir(value: Option<&str>) -> String {
+    let normalized = value
+        .unwrap_or("")
+        .trim()
+        .replace('\\', "/")
+        .trim_start_matches("./")
+        .trim_matches('/')
+        .to_string();
+    if normalized.is_empty() {
+        "overrides".to_string()
+    } else {
+        normalized
+    }
+}
+
+/// Extract the pack's overrides folder, then `client-overrides` on top so it wins.
+/// `server-overrides` is never applied, since the launcher only installs clients.
+fn apply_pack_overrides(
+    archive_path: &Path,
+    game_dir: &Path,
+    overrides_dir: &str,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let file = File::open(archive_path)?;
+    let mut archive = zip::ZipArchive::new(file)?;
+    fs::create_dir_all(game_dir)?;
 
+    for folder in [overrides_dir, "client-overrides"] {
         for i in 0..archive.len() {
             let mut zipped = archive.by_index(i)?;
-            let name = zipped.name().to_string();
-
-            let target_rel = if let Some(stripped) = name.strip_prefix(&format!("{}/", overrides_dir)) {
-                Some(stripped.to_string())
-            } else if let Some(stripped) = name.strip_prefix("client-overrides/") {
-                Some(stripped.to_string())
-            } else {
-                None
+            let Some(enclosed) = zipped.enclosed_name() else {
+                continue;
             };
+            let Ok(rel_path) = enclosed.strip_prefix(folder) else {
+                continue;
+            };
+            if rel_path.as_os_str().is_empty() {
+                continue;
+            }
 
-            let Some(rel_path) = target_rel else { continue; };
-            if rel_path.is_empty() { continue; }
-
-            let dest = instance.get_game_directory().join(rel_path);
+            let Some(dest) = crate::minecraft::downloader::safe_extract_path(game_dir, rel_path) else {
+                log::warn!("Skipping override {} that would be written outside the instance", rel_path.display());
+                continue;
+            };
             if zipped.is_dir() {
                 let _ = fs::create_dir_all(dest);
             } else {
@@ -1502,16 +1838,261 @@ pub async fn install_modpack(
         }
     }
 
+    Ok(())
+}
+
+/// Download a CurseForge pack archive to a temp file and read its manifest
+async fn fetch_modpack_manifest(mod_id: u64, pack_file_id: u64) -> Result<CurseForgeManifest, Box<dyn Error + Send + Sync>> {
+    let mut modpack_file = get_modpack_archive(mod_id, pack_file_id).await?;
+    let pack_file_id = modpack_file.id;
+    let pack_download_url = resolve_file_download_url(mod_id, pack_file_id, modpack_file.download_url.take()).await?;
+
+    let temp_dir = std::env::temp_dir().join("palethea_curseforge_verify");
+    fs::create_dir_all(&temp_dir)?;
+    let archive_path = temp_dir.join(format!("{}-{}.zip", mod_id, pack_file_id));
+    download_with_progress(&pack_download_url, &archive_path, None, None, 0.0, 0.0).await?;
+
+    let manifest = parse_manifest(&archive_path);
+    let _ = fs::remove_file(&archive_path);
+    manifest
+}
+
+/// Resolve what installing a pack file would do without touching the instance.
+/// Files already on disk in `game_dir` with a matching hash are marked as present.
+pub async fn plan_modpack_install(
+    project_id: &str,
+    file_id: &str,
+    game_dir: Option<&Path>,
+) -> Result<ModpackInstallPlan, Box<dyn Error + Send + Sync>> {
+    let mod_id = parse_u64_id(project_id, "project_id")?;
+    let pack_file_id = parse_u64_id(file_id, "file_id")?;
+
+    let mut modpack_file = get_modpack_archive(mod_id, pack_file_id).await?;
+    let pack_file_id = modpack_file.id;
+    let pack_download_url = resolve_file_download_url(mod_id, pack_file_id, modpack_file.download_url.take()).await?;
+
+    let temp_dir = std::env::temp_dir().join("palethea_curseforge_plan");
+    fs::create_dir_all(&temp_dir)?;
+    let archive_path = temp_dir.join(format!("{}-{}.zip", mod_id, pack_file_id));
+    download_with_progress(&pack_download_url, &archive_path, None, None, 0.0, 0.0).await?;
+
+    let parsed = parse_manifest(&archive_path).and_then(|manifest| {
+        let overrides_dir = normalize_overrides_dir(manifest.overrides.as_deref());
+        let overrides = crate::minecraft::downloader::list_archive_overrides(
+            &archive_path,
+            &[overrides_dir.as_str(), "client-overrides"],
+        )?;
+        Ok((manifest, overrides))
+    });
+    let _ = fs::remove_file(&archive_path);
+    let (manifest, overrides) = parsed?;
+
+    let required_entries: Vec<&CurseForgeManifestFile> = manifest.files.iter().filter(|entry| entry.required).collect();
+    let required_file_ids: Vec<u64> = required_entries.iter().map(|f| f.file_id).collect();
+    let file_map = get_files_bulk(&required_file_ids).await?;
+    let (mod_loader, mod_loader_version) = parse_loader_from_manifest(&manifest);
+
+    let files = required_entries
+        .iter()
+        .map(|entry| {
+            let details = file_map.get(&entry.file_id);
+            let path = details
+                .map(|d| format!("mods/{}", d.file_name))
+                .unwrap_or_else(|| format!("fileID={}", entry.file_id));
+            let already_present = match (game_dir, details) {
+                (Some(dir), Some(d)) => check_modpack_file(dir, d).is_none(),
+                _ => false,
+            };
+            ModpackPlanFile {
+                path,
+                size: details.map(|d| d.file_length).unwrap_or(0),
+                distributable: details.is_some_and(|d| normalize_download_url(d.download_url.clone()).is_some()),
+                project_id: Some(entry.project_id.to_string()),
+                file_id: Some(entry.file_id.to_string()),
+                already_present,
+            }
+        })
+        .collect();
+
+    let mut plan = ModpackInstallPlan {
+        provider: "CurseForge".to_string(),
+        pack_name: manifest.name.clone(),
+        version_id: file_id.to_string(),
+        minecraft_version: manifest.minecraft.version.clone(),
+        mod_loader: mod_loader.to_string(),
+        mod_loader_version,
+        files,
+        pack_archive_bytes: modpack_file.file_length,
+        total_download_bytes: 0,
+        overrides,
+    };
+    plan.compute_total();
+    Ok(plan)
+}
+
+/// Check a single manifest entry against what is on disk
+fn check_modpack_file(game_dir: &Path, details: &CurseForgeFile) -> Option<ModpackFileFailure> {
+    let rel_path = format!("mods/{}", details.file_name);
+    // A file the user disabled counts as present
+    let dest = crate::minecraft::downloader::installed_pack_file(&game_dir.join("mods").join(&details.file_name));
+    let Ok(meta) = fs::metadata(&dest) else {
+        return Some(ModpackFileFailure::new(&rel_path, "missing", "File is missing from the instance"));
+    };
+
+    if details.file_length > 0 && meta.len() != details.file_length {
+        return Some(ModpackFileFailure::new(
+            &rel_path,
+            "size_mismatch",
+            format!("Expected {} bytes, found {}", details.file_length, meta.len()),
+        ));
+    }
+
+    if let Some(expected) = details.sha1() {
+        if !crate::minecraft::downloader::verify_sha1(&dest, expected) {
+            return Some(ModpackFileFailure::new(&rel_path, "hash_mismatch", "SHA1 does not match CurseForge file metadata"));
+        }
+    }
+
+    None
+}
+
+/// Resolve the manifest into per-file details and report anything missing or corrupt
+async fn collect_modpack_issues(
+    game_dir: &Path,
+    manifest: &CurseForgeManifest,
+) -> Result<(Vec<ModpackFileFailure>, HashMap<u64, CurseForgeFile>, Vec<CurseForgeManifestFile>), Box<dyn Error + Send + Sync>> {
+    let required_entries: Vec<CurseForgeManifestFile> = manifest
+        .files
+        .iter()
+        .filter(|entry| entry.required)
+        .cloned()
+        .collect();
+    let required_file_ids: Vec<u64> = required_entries.iter().map(|f| f.file_id).collect();
+    let file_map = get_files_bulk(&required_file_ids).await?;
+
+    let check_dir = game_dir.to_path_buf();
+    let check_entries = required_entries.clone();
+    let check_map = file_map.clone();
+    let issues = tokio::task::spawn_blocking(move || {
+        use rayon::prelude::*;
+
+        check_entries
+            .par_iter()
+            .filter_map(|entry| match check_map.get(&entry.file_id) {
+                Some(details) => check_modpack_file(&check_dir, details),
+                None => Some(ModpackFileFailure::new(
+                    &format!("fileID={}", entry.file_id),
+                    "metadata",
+                    format!("CurseForge returned no metadata for file {} (project {})", entry.file_id, entry.project_id),
+                )),
+            })
+            .collect::<Vec<_>>()
+    })
+    .await?;
+
+    Ok((issues, file_map, required_entries))
+}
+
+/// Compare an installed CurseForge pack against its manifest
+pub async fn verify_modpack(
+    instance: &instances::Instance,
+    project_id: &str,
+    file_id: &str,
+) -> Result<Vec<ModpackFileFailure>, Box<dyn Error + Send + Sync>> {
+    let mod_id = parse_u64_id(project_id, "project_id")?;
+    let pack_file_id = parse_u64_id(file_id, "file_id")?;
+    let manifest = fetch_modpack_manifest(mod_id, pack_file_id).await?;
+
+    let (issues, _, _) = collect_modpack_issues(&instance.get_game_directory(), &manifest).await?;
+    Ok(issues)
+}
+
+/// Re-download every missing or corrupt file of an installed CurseForge pack
+pub async fn repair_modpack_files(
+    app_handle: &AppHandle,
+    instance: &instances::Instance,
+    project_id: &str,
+    file_id: &str,
+) -> Result<Vec<ModpackFileFailure>, Box<dyn Error + Send + Sync>> {
+    let mod_id = parse_u64_id(project_id, "project_id")?;
+    let pack_file_id = parse_u64_id(file_id, "file_id")?;
+    let manifest = fetch_modpack_manifest(mod_id, pack_file_id).await?;
+    let game_dir = instance.get_game_directory();
+
+    let (issues, file_map, required_entries) = collect_modpack_issues(&game_dir, &manifest).await?;
+    let client = super::http_client();
+
+    let total = issues.len() as u32;
+    let mut remaining = Vec::new();
+    for (i, issue) in issues.into_iter().enumerate() {
+        let _ = app_handle.emit("download-progress", DownloadProgress {
+            stage: format!("Repairing modpack files {}/{}...", i + 1, total),
+            percentage: (i as f32 / total.max(1) as f32) * 100.0,
+            current: i as u32,
+            total,
+            total_bytes: None,
+            downloaded_bytes: None,
+        });
+
+        let found = required_entries.iter().find_map(|entry| {
+            file_map
+                .get(&entry.file_id)
+                .filter(|details| format!("mods/{}", details.file_name) == issue.path)
+                .map(|details| (entry, details))
+        });
+        let Some((entry, details)) = found else {
+            remaining.push(issue);
+            continue;
+        };
+
+        let download_url = match resolve_file_download_url(entry.project_id, entry.file_id, details.download_url.clone()).await {
+            Ok(url) => url,
+            Err(e) => {
+                remaining.push(ModpackFileFailure::new(&issue.path, "missing_url", e.to_string()));
+                continue;
+            }
+        };
+
+        let bytes = match client.get(&download_url).header("User-Agent", user_agent()).send().await {
+            Ok(resp) if resp.status().is_success() => match crate::minecraft::downloader::read_body_throttled(resp).await {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    remaining.push(ModpackFileFailure::from_request_error(&issue.path, &e));
+                    continue;
+                }
+            },
+            Ok(resp) => {
+                remaining.push(ModpackFileFailure::from_status(&issue.path, resp.status()));
+                continue;
+            }
+            Err(e) => {
+                remaining.push(ModpackFileFailure::from_request_error(&issue.path, &e));
+                continue;
+            }
+        };
+
+        let dest = crate::minecraft::downloader::installed_pack_file(&game_dir.join("mods").join(&details.file_name));
+        if let Some(parent) = dest.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Err(e) = fs::write(&dest, &bytes) {
+            remaining.push(ModpackFileFailure::new(&issue.path, "io", format!("Failed to write file: {}", e)));
+            continue;
+        }
+
+        if let Some(failure) = check_modpack_file(&game_dir, details) {
+            remaining.push(failure);
+        }
+    }
+
     let _ = app_handle.emit("download-progress", DownloadProgress {
-        stage: "Modpack installed!".to_string(),
+        stage: "Modpack repair finished".to_string(),
         percentage: 100.0,
-        current: 100,
-        total: 100,
+        current: total,
+        total,
         total_bytes: None,
         downloaded_bytes: None,
     });
 
-    let _ = fs::remove_file(&archive_path);
-    let _ = fs::remove_dir_all(&temp_dir);
-    Ok(())
+    Ok(remaining)
 }
@@ -3,8 +3,20 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const DISCORD_APP_ID: &str = "1470118432929484963";
 
+// ----------
+// PresenceInstance
+// Description: Details of the instance shown in Rich Presence
+// ----------
+#[derive(Debug, Clone)]
+pub struct PresenceInstance {
+    pub name: String,
+    pub version: String,
+    pub mod_loader: String,
+    pub started_at: u64,
+}
+
 enum DiscordCommand {
-    UpdatePresence { running_count: usize },
+    UpdatePresence { running_count: usize, active: Option<PresenceInstance> },
     Shutdown,
 }
 
@@ -12,6 +24,11 @@ static DISCORD_SENDER: LazyLock<Mutex<Option<mpsc::Sender<DiscordCommand>>>> =
     LazyLock::new(|| Mutex::new(None));
 
 pub fn init() {
+    if !crate::minecraft::settings::is_discord_presence_enabled() {
+        log::info!("Discord Rich Presence disabled in settings");
+        return;
+    }
+
     let (tx, rx) = mpsc::channel();
 
     if let Ok(mut sender) = DISCORD_SENDER.lock() {
@@ -32,12 +49,13 @@ fn discord_thread(rx: mpsc::Receiver<DiscordCommand>) {
         .as_secs() as i64;
 
     let mut last_running_count: usize = 0;
+    let mut last_active: Option<PresenceInstance> = None;
 
     // Try to create and connect the client
     let mut client: Option<DiscordIpcClient> = try_connect();
 
     if let Some(ref mut c) = client {
-        let _ = apply_presence(c, 0, start_time);
+        let _ = apply_presence(c, 0, None, start_time);
         log::info!("Discord Rich Presence connected");
     } else {
         log::info!("Discord not available, will retry in background");
@@ -45,8 +63,9 @@ fn discord_thread(rx: mpsc::Receiver<DiscordCommand>) {
 
     loop {
         match rx.recv_timeout(Duration::from_secs(20)) {
-            Ok(DiscordCommand::UpdatePresence { running_count }) => {
+            Ok(DiscordCommand::UpdatePresence { running_count, active }) => {
                 last_running_count = running_count;
+                last_active = active;
                 if client.is_none() {
                     client = try_connect();
                     if client.is_some() {
@@ -55,7 +74,7 @@ fn discord_thread(rx: mpsc::Receiver<DiscordCommand>) {
                 }
 
                 if let Some(ref mut c) = client {
-                    if let Err(e) = apply_presence(c, running_count, start_time) {
+                    if let Err(e) = apply_presence(c, last_running_count, last_active.as_ref(), start_time) {
                         log::warn!("Discord apply_presence failed: {}", e);
                         let _ = c.close();
                         client = None;
@@ -72,7 +91,7 @@ fn discord_thread(rx: mpsc::Receiver<DiscordCommand>) {
                 if client.is_none() {
                     client = try_connect();
                     if let Some(ref mut c) = client {
-                        if apply_presence(c, last_running_count, start_time).is_ok() {
+                        if apply_presence(c, last_running_count, last_active.as_ref(), start_time).is_ok() {
                             log::info!("Discord Rich Presence reconnected");
                         } else {
                             let _ = c.close();
@@ -102,45 +121,80 @@ fn try_connect() -> Option<discord_rich_presence::DiscordIpcClient> {
 fn apply_presence(
     client: &mut discord_rich_presence::DiscordIpcClient,
     running_count: usize,
+    active: Option<&PresenceInstance>,
     start_time: i64,
 ) -> Result<(), Box<dyn std::error::Error>> {
     use discord_rich_presence::{activity, DiscordIpc};
 
-    let details = if running_count == 0 {
-        "No instances running".to_string()
-    } else {
-        format!(
-            "Currently playing {} instance{}",
-            running_count,
-            if running_count == 1 { "" } else { "s" }
-        )
+    let (details, state, started_at) = match active {
+        Some(instance) if running_count > 0 => {
+            let loader = if instance.mod_loader.eq_ignore_ascii_case("vanilla") {
+                String::new()
+            } else {
+                format!(" ({})", instance.mod_loader)
+            };
+            let state = if running_count > 1 {
+                format!("Minecraft {}{} - {} instances running", instance.version, loader, running_count)
+            } else {
+                format!("Minecraft {}{}", instance.version, loader)
+            };
+            (format!("Playing {}", instance.name), Some(state), instance.started_at as i64)
+        }
+        _ if running_count > 0 => (
+            format!(
+                "Currently playing {} instance{}",
+                running_count,
+                if running_count == 1 { "" } else { "s" }
+            ),
+            None,
+            start_time,
+        ),
+        _ => ("No instances running".to_string(), None, start_time),
     };
 
-    client.set_activity(
-        activity::Activity::new()
-            .details(&details)
-            .timestamps(activity::Timestamps::new().start(start_time))
-            .assets(
-                activity::Assets::new()
-                    .large_image("palethea")
-                    .large_text("Palethea Launcher"),
-            ),
-    )?;
+    let mut presence = activity::Activity::new()
+        .details(&details)
+        .timestamps(activity::Timestamps::new().start(started_at))
+        .assets(
+            activity::Assets::new()
+                .large_image("palethea")
+                .large_text("Palethea Launcher"),
+        );
+    if let Some(state) = state.as_deref() {
+        presence = presence.state(state);
+    }
+
+    client.set_activity(presence)?;
 
     Ok(())
 }
 
-pub fn update_presence(running_count: usize) {
+/// Push the running count and the most recently launched instance to Discord.
+/// Starts or stops the presence thread when the setting was toggled since the last update.
+pub fn update_presence(running_count: usize, active: Option<PresenceInstance>) {
+    let enabled = crate::minecraft::settings::is_discord_presence_enabled();
+    let is_running = DISCORD_SENDER.lock().map(|sender| sender.is_some()).unwrap_or(false);
+
+    if !enabled {
+        if is_running {
+            shutdown();
+        }
+        return;
+    }
+    if !is_running {
+        init();
+    }
+
     if let Ok(sender) = DISCORD_SENDER.lock() {
         if let Some(tx) = sender.as_ref() {
-            let _ = tx.send(DiscordCommand::UpdatePresence { running_count });
+            let _ = tx.send(DiscordCommand::UpdatePresence { running_count, active });
         }
     }
 }
 
 pub fn shutdown() {
-    if let Ok(sender) = DISCORD_SENDER.lock() {
-        if let Some(tx) = sender.as_ref() {
+    if let Ok(mut sender) = DISCORD_SENDER.lock() {
+        if let Some(tx) = sender.take() {
             let _ = tx.send(DiscordCommand::Shutdown);
         }
     }
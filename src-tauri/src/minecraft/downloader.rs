@@ -13,6 +13,8 @@ use crate::log_info;
 
 // Number of concurrent downloads
 const CONCURRENT_DOWNLOADS: usize = 32;
+// Asset objects are tiny, so more parallel requests mostly add contention
+const CONCURRENT_ASSET_DOWNLOADS: usize = 20;
 
 const DEFAULT_INSTANCE_LOGO: &[u8] = include_bytes!("../../resources/instance_logos/minecraft_logo.png");
 
@@ -26,6 +28,250 @@ pub struct DownloadProgress {
     pub downloaded_bytes: Option<u64>,
 }
 
+// ----------
+// ModpackFileFailure
+// Description: Why a single modpack file failed to install, so the UI can tell
+// dead references (404) apart from retryable network problems
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModpackFileFailure {
+    pub path: String,
+    pub kind: String, // "http_status", "timeout", "network", "hash_mismatch", "size_mismatch", "missing", "io", "missing_url", "metadata", "unsafe_path"
+    pub reason: String,
+    pub retryable: bool,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub file_name: Option<String>,
+    #[serde(default)]
+    pub manual_url: Option<String>, // Page where the user can fetch the file by hand
+}
+
+impl ModpackFileFailure {
+    pub fn new(path: &str, kind: &str, reason: impl Into<String>) -> Self {
+        let file_name = path.rsplit('/').next().filter(|name| !name.is_empty()).map(|name| name.to_string());
+        ModpackFileFailure {
+            path: path.to_string(),
+            kind: kind.to_string(),
+            reason: reason.into(),
+            retryable: matches!(kind, "timeout" | "network" | "hash_mismatch" | "size_mismatch" | "missing" | "io"),
+            project_id: None,
+            file_name,
+            manual_url: None,
+        }
+    }
+
+    pub fn with_project(mut self, project_id: impl Into<String>, manual_url: Option<String>) -> Self {
+        self.project_id = Some(project_id.into());
+        self.manual_url = manual_url;
+        self
+    }
+
+    pub fn from_status(path: &str, status: reqwest::StatusCode) -> Self {
+        let mut failure = Self::new(path, "http_status", format!("Server returned HTTP {}", status));
+        failure.retryable = status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+        failure
+    }
+
+    pub fn from_request_error(path: &str, error: &reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Self::new(path, "timeout", format!("Request timed out: {}", error))
+        } else if let Some(status) = error.status() {
+            Self::from_status(path, status)
+        } else {
+            Self::new(path, "network", format!("Network error: {}", error))
+        }
+    }
+}
+
+// ----------
+// ModpackInstallSummary
+// Description: Outcome of a modpack install - how many files made it and which did not
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ModpackInstallSummary {
+    pub total: u32,
+    pub succeeded: u32,
+    pub failures: Vec<ModpackFileFailure>,
+}
+
+impl ModpackInstallSummary {
+    pub fn new(total: usize, failures: Vec<ModpackFileFailure>) -> Self {
+        ModpackInstallSummary {
+            total: total as u32,
+            succeeded: total.saturating_sub(failures.len()) as u32,
+            failures,
+        }
+    }
+}
+
+// ----------
+// ModpackPlanFile
+// Description: One file a modpack install would fetch, as listed in a ModpackInstallPlan
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModpackPlanFile {
+    pub path: String,
+    pub size: u64,
+    pub distributable: bool, // false when the author blocked third-party downloads (CurseForge)
+    pub project_id: Option<String>,
+    pub file_id: Option<String>,
+    pub already_present: bool, // Same file already on disk, so it will not be downloaded again
+}
+
+// ----------
+// ModpackInstallPlan
+// Description: What installing a modpack version would do, resolved from the pack's
+//              index/manifest alone so nothing is written to the instance
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModpackInstallPlan {
+    pub provider: String,
+    pub pack_name: String,
+    pub version_id: String,
+    pub minecraft_version: String,
+    pub mod_loader: String,
+    pub mod_loader_version: Option<String>,
+    pub files: Vec<ModpackPlanFile>,
+    pub pack_archive_bytes: u64,
+    pub total_download_bytes: u64, // Pack archive plus every file not already present
+    pub overrides: Vec<String>,
+}
+
+impl ModpackInstallPlan {
+    pub fn compute_total(&mut self) {
+        self.total_download_bytes = self.pack_archive_bytes
+            + self.files.iter().filter(|f| !f.already_present).map(|f| f.size).sum::<u64>();
+    }
+}
+
+/// The file a pack entry is installed as: its `.disabled` copy when the user turned it off
+pub fn installed_pack_file(dest: &std::path::Path) -> PathBuf {
+    let mut disabled = dest.as_os_str().to_os_string();
+    disabled.push(".disabled");
+    let disabled = PathBuf::from(disabled);
+    if !dest.exists() && disabled.exists() {
+        disabled
+    } else {
+        dest.to_path_buf()
+    }
+}
+
+/// Where an archive entry at `relative` should be written under `base`, or None when it would land
+/// outside `base`: `..` or absolute components, or a folder inside `base` that symlinks elsewhere.
+/// `base` has to exist so it can be canonicalized.
+pub fn safe_extract_path(base: &std::path::Path, relative: &std::path::Path) -> Option<PathBuf> {
+    use std::path::Component;
+
+    let lexically_safe = relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if !lexically_safe {
+        return None;
+    }
+
+    let canonical_base = base.canonicalize().ok()?;
+    let destination = base.join(relative);
+
+    // The destination usually doesn't exist yet, so resolve its deepest existing ancestor.
+    // symlink_metadata keeps a dangling link in the chain, which then fails to canonicalize.
+    let mut existing = destination.as_path();
+    while existing.symlink_metadata().is_err() {
+        existing = existing.parent()?;
+    }
+    let resolved = existing.canonicalize().ok()?;
+    resolved.starts_with(&canonical_base).then_some(destination)
+}
+
+/// Files under any of `folders` in a pack archive, relative to that folder and sorted
+pub fn list_archive_overrides(archive_path: &std::path::Path, folders: &[&str]) -> Result<Vec<String>, Box<dyn Error + Send + Sync>> {
+    let mut archive = zip::ZipArchive::new(File::open(archive_path)?)?;
+    let mut paths = std::collections::BTreeSet::new();
+    for i in 0..archive.len() {
+        let entry = archive.by_index(i)?;
+        if entry.is_dir() {
+            continue;
+        }
+        let Some(enclosed) = entry.enclosed_name() else {
+            continue;
+        };
+        for folder in folders {
+            if let Ok(rel_path) = enclosed.strip_prefix(folder) {
+                if !rel_path.as_os_str().is_empty() {
+                    paths.insert(rel_path.to_string_lossy().replace('\\', "/"));
+                }
+            }
+        }
+    }
+    Ok(paths.into_iter().collect())
+}
+
+/// Progress callback used by loader installers: (stage label, fraction of the phase 0.0-1.0)
+pub type StepProgress<'a> = &'a (dyn Fn(&str, f32) + Send + Sync);
+
+/// Exponential backoff before retry `attempt` (1-based): 500ms, 1s, 2s, ... capped at 16s
+pub fn retry_backoff(attempt: u32) -> std::time::Duration {
+    std::time::Duration::from_millis(500u64 << attempt.saturating_sub(1).min(5))
+}
+
+// How often the throttle re-reads the speed limit, so settings changes apply mid-download
+const THROTTLE_LIMIT_REFRESH: std::time::Duration = std::time::Duration::from_secs(2);
+
+// Token bucket shared by every download worker
+struct DownloadThrottle {
+    rate: Option<u64>,
+    rate_checked: Option<std::time::Instant>,
+    tokens: f64,
+    last_refill: std::time::Instant,
+}
+
+static DOWNLOAD_THROTTLE: std::sync::LazyLock<std::sync::Mutex<DownloadThrottle>> = std::sync::LazyLock::new(|| {
+    std::sync::Mutex::new(DownloadThrottle {
+        rate: None,
+        rate_checked: None,
+        tokens: 0.0,
+        last_refill: std::time::Instant::now(),
+    })
+});
+
+/// Wait until `bytes` fit under the download speed limit. The budget is global, so
+/// parallel downloads share the configured rate instead of each getting all of it.
+pub async fn throttle_download(bytes: u64) {
+    let wait = {
+        let Ok(mut state) = DOWNLOAD_THROTTLE.lock() else { return };
+        let now = std::time::Instant::now();
+        if state.rate_checked.map_or(true, |checked| now.duration_since(checked) >= THROTTLE_LIMIT_REFRESH) {
+            state.rate = crate::minecraft::settings::get_download_speed_limit();
+            state.rate_checked = Some(now);
+        }
+        let Some(rate) = state.rate else { return };
+        let rate = rate as f64;
+
+        // Refill, allowing at most one second of burst, then take this chunk out (possibly going into debt)
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * rate).min(rate);
+        state.last_refill = now;
+        state.tokens -= bytes as f64;
+        if state.tokens >= 0.0 {
+            return;
+        }
+        std::time::Duration::from_secs_f64(-state.tokens / rate)
+    };
+    tokio::time::sleep(wait).await;
+}
+
+/// Read a whole response body chunk by chunk, keeping to the download speed limit
+pub async fn read_body_throttled(response: reqwest::Response) -> Result<Vec<u8>, reqwest::Error> {
+    let mut body = Vec::with_capacity(response.content_length().unwrap_or(0) as usize);
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        throttle_download(chunk.len() as u64).await;
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body)
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InstanceLaunchProgress {
     pub instance_id: String,
@@ -44,8 +290,31 @@ pub fn emit_download_progress(handle: &AppHandle, progress: DownloadProgress, in
     }
 }
 
-/// Get the Minecraft data directory
+// custom_data_dir from settings, read once and replaced when the data directory is migrated
+static DATA_DIR_OVERRIDE: std::sync::LazyLock<std::sync::RwLock<Option<PathBuf>>> = std::sync::LazyLock::new(|| {
+    std::sync::RwLock::new(crate::minecraft::settings::load_settings().custom_data_dir.map(PathBuf::from))
+});
+
+/// Get the Minecraft data directory, honoring `custom_data_dir` when set
 pub fn get_minecraft_dir() -> PathBuf {
+    if let Ok(custom) = DATA_DIR_OVERRIDE.read() {
+        if let Some(dir) = custom.as_ref() {
+            return dir.clone();
+        }
+    }
+    get_default_data_dir()
+}
+
+/// Point the data directory somewhere else for the rest of this run (None = default)
+pub fn set_data_dir_override(dir: Option<PathBuf>) {
+    if let Ok(mut custom) = DATA_DIR_OVERRIDE.write() {
+        *custom = dir;
+    }
+}
+
+/// The fixed per-OS data directory. settings.json always lives here so a custom
+/// data directory can be found on startup.
+pub fn get_default_data_dir() -> PathBuf {
     let base = dirs::data_dir().unwrap_or_else(|| PathBuf::from("."));
     
     if cfg!(target_os = "windows") {
@@ -93,6 +362,21 @@ pub fn get_skins_dir() -> PathBuf {
     get_minecraft_dir().join("skin_collection")
 }
 
+/// Get the directory holding pack.png icons extracted from resource packs
+pub fn get_pack_icons_cache_dir() -> PathBuf {
+    get_minecraft_dir().join("cache").join("pack_icons")
+}
+
+/// Get the directory holding downscaled JPEG copies of instance screenshots
+pub fn get_screenshot_thumbnails_dir() -> PathBuf {
+    get_minecraft_dir().join("cache").join("screenshot_thumbnails")
+}
+
+/// Get the directory holding rendered account head avatars keyed by UUID
+pub fn get_account_heads_cache_dir() -> PathBuf {
+    get_minecraft_dir().join("cache").join("account_heads")
+}
+
 /// Ensure instance logos directory exists and default logo is present
 pub fn ensure_instance_logos_dir() -> Result<(), String> {
     let logos_dir = get_instance_logos_dir();
@@ -107,6 +391,99 @@ pub fn ensure_instance_logos_dir() -> Result<(), String> {
     Ok(())
 }
 
+pub const LOADER_BADGE_PREFIX: &str = "loader_badge_";
+
+/// Default logo for a mod loader: the stock logo with a loader-coloured corner badge.
+/// Generated on first use; None for vanilla or if the image can't be written.
+pub fn loader_badge_logo(loader: &crate::minecraft::instances::ModLoader) -> Option<String> {
+    use crate::minecraft::instances::ModLoader;
+    let (name, color) = match loader {
+        ModLoader::Vanilla => return None,
+        ModLoader::Fabric => ("fabric", [219, 208, 180, 255]),
+        ModLoader::Quilt => ("quilt", [151, 34, 255, 255]),
+        ModLoader::Forge => ("forge", [42, 58, 84, 255]),
+        ModLoader::NeoForge => ("neoforge", [215, 116, 47, 255]),
+    };
+    let filename = format!("{}{}.png", LOADER_BADGE_PREFIX, name);
+    let path = get_instance_logos_dir().join(&filename);
+    if path.exists() {
+        return Some(filename);
+    }
+
+    let mut logo = image::load_from_memory(DEFAULT_INSTANCE_LOGO).ok()?.to_rgba8();
+    let (width, height) = logo.dimensions();
+    let radius = (width.min(height) as f32) * 0.22;
+    let border = (radius * 0.18).max(1.0);
+    let center_x = width as f32 - radius - border;
+    let center_y = height as f32 - radius - border;
+    for y in 0..height {
+        for x in 0..width {
+            let distance = ((x as f32 + 0.5 - center_x).powi(2) + (y as f32 + 0.5 - center_y).powi(2)).sqrt();
+            if distance <= radius {
+                logo.put_pixel(x, y, image::Rgba(color));
+            } else if distance <= radius + border {
+                logo.put_pixel(x, y, image::Rgba([255, 255, 255, 255]));
+            }
+        }
+    }
+
+    fs::create_dir_all(get_instance_logos_dir()).ok()?;
+    logo.save_with_format(&path, image::ImageFormat::Png).ok()?;
+    Some(filename)
+}
+
+/// Download an image into the instance logos directory and return its filename
+pub async fn download_instance_logo(instance_id: &str, logo_url: &str) -> Result<String, String> {
+    ensure_instance_logos_dir()?;
+
+    let client = super::http_client();
+    let response = client.get(logo_url)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to download logo: {}", e))?
+        .error_for_status()
+        .map_err(|e| format!("Logo download returned an error status: {}", e))?;
+
+    let bytes = response.bytes().await.map_err(|e| format!("Failed to get logo bytes: {}", e))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    
+    // Better extension detection
+    let url_no_query = logo_url.split('?').next().unwrap_or(logo_url);
+    let ext = url_no_query.split('.').last().unwrap_or("png").to_lowercase();
+    let safe_ext = if ext == "jpg" || ext == "jpeg" || ext == "png" || ext == "webp" {
+        ext
+    } else {
+        "png".to_string()
+    };
+
+    let filename = format!("{}_{}.{}", instance_id, timestamp, safe_ext);
+    let destination = get_instance_logos_dir().join(&filename);
+
+    fs::write(&destination, &bytes)
+        .map_err(|e| format!("Failed to save logo: {}", e))?;
+
+    Ok(filename)
+}
+
+/// Use a modpack's icon as the instance logo, falling back to the loader badge
+pub async fn apply_modpack_logo(instance: &mut crate::minecraft::instances::Instance, icon_url: Option<String>) {
+    let Some(url) = icon_url else {
+        instance.apply_loader_badge();
+        return;
+    };
+    match download_instance_logo(&instance.id, &url).await {
+        Ok(filename) => instance.logo_filename = Some(filename),
+        Err(e) => {
+            log::warn!("Failed to fetch modpack icon: {}", e);
+            instance.apply_loader_badge();
+        }
+    }
+}
+
 /// Sync logos from resources to data directory
 pub fn sync_logos(app_handle: &AppHandle) -> Result<(), String> {
     ensure_instance_logos_dir()?;
@@ -138,6 +515,21 @@ pub fn sync_logos(app_handle: &AppHandle) -> Result<(), String> {
 }
 
 
+/// SHA1 of a file on disk as lowercase hex
+pub fn file_sha1(path: &std::path::Path) -> Option<String> {
+    let mut file = File::open(path).ok()?;
+    let mut hasher = Sha1::new();
+    let mut buffer = [0u8; 8192];
+    loop {
+        match file.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => hasher.update(&buffer[..n]),
+            Err(_) => return None,
+        }
+    }
+    Some(format!("{:x}", hasher.finalize()))
+}
+
 /// Verify a file's SHA1 hash
 pub fn verify_sha1(path: &PathBuf, expected: &str) -> bool {
     if !path.exists() {
@@ -165,6 +557,43 @@ pub fn verify_sha1(path: &PathBuf, expected: &str) -> bool {
     hash == expected
 }
 
+// ----------
+// DownloadHasher
+// Description: Hashes a download as it streams so it can be checked against a manifest
+// ----------
+#[derive(Default)]
+pub struct DownloadHasher {
+    sha1: Sha1,
+    sha512: sha2::Sha512,
+}
+
+impl DownloadHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.sha1.update(chunk);
+        self.sha512.update(chunk);
+    }
+
+    /// Compare against SHA512 when listed, otherwise SHA1. Passes when neither is known.
+    pub fn verify(self, expected_sha512: Option<&str>, expected_sha1: Option<&str>) -> Result<(), String> {
+        if let Some(expected) = expected_sha512.filter(|h| !h.trim().is_empty()) {
+            let actual = format!("{:x}", self.sha512.finalize());
+            if !actual.eq_ignore_ascii_case(expected.trim()) {
+                return Err(format!("SHA512 mismatch (expected {}, got {})", expected, actual));
+            }
+        } else if let Some(expected) = expected_sha1.filter(|h| !h.trim().is_empty()) {
+            let actual = format!("{:x}", self.sha1.finalize());
+            if !actual.eq_ignore_ascii_case(expected.trim()) {
+                return Err(format!("SHA1 mismatch (expected {}, got {})", expected, actual));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Download a file with progress tracking
 pub async fn download_file(
     url: &str,
@@ -202,7 +631,7 @@ pub async fn download_file(
     if !response.status().is_success() {
         return Err(format!("Download failed ({}): {}", response.status(), url).into());
     }
-    let bytes = response.bytes().await?;
+    let bytes = read_body_throttled(response).await?;
     
     let mut file = File::create(path)?;
     file.write_all(&bytes)?;
@@ -218,6 +647,83 @@ pub async fn download_file(
     Ok(())
 }
 
+/// Human readable byte count for error messages
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Fail before a large operation when the volume holding `target` can't fit `required_bytes`.
+/// A 64 MB margin is kept so the launcher can still write settings and logs afterwards.
+pub fn ensure_disk_space(target: &std::path::Path, required_bytes: u64) -> Result<(), String> {
+    const SAFETY_MARGIN: u64 = 64 * 1024 * 1024;
+
+    // The target itself may not exist yet, so query the closest existing ancestor
+    let Some(existing) = target.ancestors().find(|p| p.exists()) else {
+        return Ok(());
+    };
+    let available = match fs2::available_space(existing) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            log::warn!("Could not read free space for {}: {}", existing.display(), e);
+            return Ok(());
+        }
+    };
+
+    let needed = required_bytes.saturating_add(SAFETY_MARGIN);
+    if available < needed {
+        return Err(format!(
+            "Not enough disk space (need {}, have {})",
+            format_bytes(needed),
+            format_bytes(available)
+        ));
+    }
+    Ok(())
+}
+
+/// Bytes a version download will still write: client jar, missing libraries, and assets when the index is new
+pub fn estimate_version_download_size(version_details: &VersionDetails) -> u64 {
+    let mut total = 0u64;
+
+    let client_path = get_versions_dir()
+        .join(&version_details.id)
+        .join(format!("{}.jar", &version_details.id));
+    if !client_path.exists() {
+        total += version_details.downloads.as_ref().map(|d| d.client.size.max(0) as u64).unwrap_or(0);
+    }
+
+    let libraries_dir = get_libraries_dir();
+    for library in &version_details.libraries {
+        if !should_use_library(library) {
+            continue;
+        }
+        if let Some(artifact) = library.downloads.as_ref().and_then(|d| d.artifact.as_ref()) {
+            if !libraries_dir.join(&artifact.path).exists() {
+                total += artifact.size.max(0) as u64;
+            }
+        }
+    }
+
+    if let Some(asset_index) = &version_details.asset_index {
+        let index_path = get_assets_dir().join("indexes").join(format!("{}.json", &asset_index.id));
+        if !index_path.exists() {
+            total += asset_index.total_size.unwrap_or(0).max(0) as u64;
+        }
+    }
+
+    total
+}
+
 /// Download the client JAR for a version
 pub async fn download_client(version_details: &VersionDetails) -> Result<PathBuf, Box<dyn Error + Send + Sync>> {
     let versions_dir = get_versions_dir();
@@ -237,22 +743,17 @@ pub async fn download_client(version_details: &VersionDetails) -> Result<PathBuf
     Ok(client_path)
 }
 
-/// Download all libraries for a version
-pub async fn download_libraries(
-    version_details: &VersionDetails,
-    app_handle: Option<&AppHandle>,
-    launch_instance_id: Option<&str>,
-) -> Result<Vec<PathBuf>, Box<dyn Error + Send + Sync>> {
+struct LibDownload {
+    url: String,
+    path: PathBuf,
+    sha1: String,
+    size: u64,
+}
+
+/// Every library file (artifacts and natives) a version needs on this OS, with the total known size
+fn collect_library_downloads(version_details: &VersionDetails) -> (Vec<LibDownload>, u64) {
     let libraries_dir = get_libraries_dir();
     
-    // Collect all download tasks
-    struct LibDownload {
-        url: String,
-        path: PathBuf,
-        sha1: String,
-        size: u64,
-    }
-    
     let mut downloads: Vec<LibDownload> = Vec::new();
     let mut total_bytes = 0u64;
     
@@ -323,6 +824,27 @@ pub async fn download_libraries(
         }
     }
     
+    (downloads, total_bytes)
+}
+
+/// Library files of a version that are missing or fail their SHA1 check
+pub fn find_broken_libraries(version_details: &VersionDetails) -> Vec<PathBuf> {
+    let (downloads, _) = collect_library_downloads(version_details);
+    downloads
+        .into_iter()
+        .filter(|dl| if dl.sha1.is_empty() { !dl.path.exists() } else { !verify_sha1(&dl.path, &dl.sha1) })
+        .map(|dl| dl.path)
+        .collect()
+}
+
+/// Download all libraries for a version
+pub async fn download_libraries(
+    version_details: &VersionDetails,
+    app_handle: Option<&AppHandle>,
+    launch_instance_id: Option<&str>,
+) -> Result<Vec<PathBuf>, Box<dyn Error + Send + Sync>> {
+    let (downloads, total_bytes) = collect_library_downloads(version_details);
+    
     let total = downloads.len() as u32;
     let completed = Arc::new(AtomicU32::new(0));
     let downloaded_bytes = Arc::new(std::sync::atomic::AtomicU64::new(0));
@@ -419,13 +941,24 @@ pub async fn download_assets(
     
     let mut downloads: Vec<AssetDownload> = Vec::new();
     let mut total_bytes = 0u64;
+    let mut seen_hashes = std::collections::HashSet::new();
     
     if let Some(objects) = index_json.get("objects").and_then(|o| o.as_object()) {
         for (_name, info) in objects {
             if let Some(hash) = info.get("hash").and_then(|h| h.as_str()) {
+                // Several names can point at the same object; fetch it once
+                if hash.len() < 2 || !seen_hashes.insert(hash.to_string()) {
+                    continue;
+                }
                 let size = info.get("size").and_then(|s| s.as_u64()).unwrap_or(0);
                 let prefix = &hash[..2];
                 let object_path = objects_dir.join(prefix).join(hash);
+
+                // Present at the right size: skip without rehashing thousands of files
+                if fs::metadata(&object_path).map(|m| m.len() == size).unwrap_or(false) {
+                    continue;
+                }
+
                 let url = format!("https://resources.download.minecraft.net/{}/{}", prefix, hash);
                 
                 total_bytes += size;
@@ -442,6 +975,8 @@ pub async fn download_assets(
     let total = downloads.len() as u32;
     let completed = Arc::new(AtomicU32::new(0));
     let downloaded_bytes = Arc::new(std::sync::atomic::AtomicU64::new(0));
+    let last_emit_ms = Arc::new(std::sync::atomic::AtomicU64::new(0));
+    let started = std::time::Instant::now();
     
     // Emit initial progress
     if let Some(handle) = app_handle {
@@ -460,6 +995,7 @@ pub async fn download_assets(
         .map(|dl| {
             let completed = Arc::clone(&completed);
             let downloaded_bytes = Arc::clone(&downloaded_bytes);
+            let last_emit_ms = Arc::clone(&last_emit_ms);
             let app_handle = app_handle.cloned();
             let total = total;
             let total_bytes_val = total_bytes;
@@ -469,10 +1005,19 @@ pub async fn download_assets(
                 let done = completed.fetch_add(1, Ordering::SeqCst) + 1;
                 let current_bytes = downloaded_bytes.fetch_add(dl.size, Ordering::SeqCst) + dl.size;
                 
-                // Emit progress every 100 assets to avoid spam
-                if done % 100 == 0 || done == total {
+                // Throttle to ~10 updates a second across all workers
+                let now_ms = started.elapsed().as_millis() as u64;
+                let last_ms = last_emit_ms.load(Ordering::Relaxed);
+                let due = now_ms.saturating_sub(last_ms) >= 100
+                    && last_emit_ms.compare_exchange(last_ms, now_ms, Ordering::SeqCst, Ordering::Relaxed).is_ok();
+                if due || done == total {
                     if let Some(handle) = &app_handle {
-                        let percentage = 35.0 + ((done as f32 * 65.0) / total as f32);
+                        let ratio = if total_bytes_val > 0 {
+                            current_bytes as f32 / total_bytes_val as f32
+                        } else {
+                            done as f32 / total.max(1) as f32
+                        };
+                        let percentage = 35.0 + ratio.clamp(0.0, 1.0) * 65.0;
                         emit_download_progress(handle, DownloadProgress {
                             stage: format!("Downloading assets ({}/{})", done, total),
                             current: done,
@@ -487,7 +1032,7 @@ pub async fn download_assets(
                 Ok(())
             }
         })
-        .buffer_unordered(CONCURRENT_DOWNLOADS)
+        .buffer_unordered(CONCURRENT_ASSET_DOWNLOADS)
         .collect()
         .await;
     
@@ -529,6 +1074,8 @@ pub async fn download_version(
     
     // Get version details
     let version_details = versions::fetch_version_details(&version_info.url).await?;
+
+    ensure_disk_space(&get_minecraft_dir(), estimate_version_download_size(&version_details))?;
     
     // Download client (5%)
     if let Some(handle) = app_handle {
@@ -588,3 +1135,64 @@ pub async fn download_version(
     
     Ok(version_details)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn temp_base() -> PathBuf {
+        let base = std::env::temp_dir().join(format!("palethea-extract-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(&base).unwrap();
+        base
+    }
+
+    #[test]
+    fn safe_extract_path_accepts_nested_paths() {
+        let base = temp_base();
+        for relative in ["mods/sodium.jar", "config/sub/dir/options.toml", "./resourcepacks/pack.zip"] {
+            assert_eq!(safe_extract_path(&base, Path::new(relative)), Some(base.join(relative)), "{}", relative);
+        }
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn safe_extract_path_rejects_parent_components() {
+        let base = temp_base();
+        for relative in ["..", "../x", "../../x", "mods/../../x", "mods/../config"] {
+            assert_eq!(safe_extract_path(&base, Path::new(relative)), None, "{}", relative);
+        }
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn safe_extract_path_rejects_absolute_paths() {
+        let base = temp_base();
+        let absolute = std::env::temp_dir().join("outside.txt");
+        assert_eq!(safe_extract_path(&base, &absolute), None);
+        #[cfg(unix)]
+        assert_eq!(safe_extract_path(&base, Path::new("/etc/passwd")), None);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn safe_extract_path_rejects_windows_prefixes() {
+        let base = temp_base();
+        for relative in [r"\Windows\win.ini", r"C:\Windows\win.ini", "C:relative.txt", r"\\server\share\x"] {
+            assert_eq!(safe_extract_path(&base, Path::new(relative)), None, "{}", relative);
+        }
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn safe_extract_path_rejects_symlinked_folders() {
+        let base = temp_base();
+        let outside = temp_base();
+        std::os::unix::fs::symlink(&outside, base.join("mods")).unwrap();
+        assert_eq!(safe_extract_path(&base, Path::new("mods/evil.jar")), None);
+        let _ = fs::remove_dir_all(&base);
+        let _ = fs::remove_dir_all(&outside);
+    }
+}
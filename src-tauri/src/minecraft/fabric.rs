@@ -4,7 +4,7 @@ use std::error::Error;
 use std::fs;
 use std::path::PathBuf;
 
-use crate::minecraft::downloader::get_libraries_dir;
+use crate::minecraft::downloader::{get_libraries_dir, StepProgress};
 use crate::minecraft::instances::Instance;
 
 const FABRIC_META_API: &str = "https://meta.fabricmc.net/v2";
@@ -132,7 +132,7 @@ async fn download_library(
 }
 
 /// Download a library from a maven repository with SHA1 verification
-async fn download_library_with_sha1(
+pub async fn download_library_with_sha1(
     url_base: &str,
     maven: &str,
     libraries_dir: &PathBuf,
@@ -175,7 +175,7 @@ async fn download_library_with_sha1(
         return Err(format!("Failed to download library from {}: {}", url, response.status()).into());
     }
 
-    let bytes = response.bytes().await?;
+    let bytes = crate::minecraft::downloader::read_body_throttled(response).await?;
     
     // Verify SHA1 if provided
     if let Some(expected) = expected_sha1 {
@@ -192,14 +192,77 @@ async fn download_library_with_sha1(
     Ok(dest)
 }
 
+/// Resolve a Fabric loader version as packs write it ("0.15.7", "fabric-loader-0.15.7" or
+/// "fabric-loader-0.15.7-1.20.1") against the loader versions Fabric publishes for the game version
+async fn resolve_fabric_loader_version(game_version: &str, requested: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let version = requested.trim();
+    let version = version
+        .strip_prefix("fabric-loader-")
+        .or_else(|| version.strip_prefix("fabric-"))
+        .unwrap_or(version);
+    let short = version.strip_suffix(&format!("-{}", game_version)).unwrap_or(version).to_string();
+
+    #[derive(Deserialize)]
+    struct LoaderEntry {
+        loader: LoaderVersionOnly,
+    }
+    #[derive(Deserialize)]
+    struct LoaderVersionOnly {
+        version: String,
+    }
+    let url = format!("{}/versions/loader/{}", FABRIC_META_API, game_version);
+    let available = match super::http_client().get(&url).send().await {
+        Ok(response) if response.status().is_success() => response.json::<Vec<LoaderEntry>>().await.ok(),
+        _ => None,
+    };
+    let Some(available) = available else {
+        log::warn!("Could not fetch Fabric loader versions, installing {} as given", short);
+        return Ok(short);
+    };
+    let available: Vec<String> = available.into_iter().map(|entry| entry.loader.version).collect();
+
+    if available.contains(&short) {
+        if short != requested.trim() {
+            log::info!("Resolved Fabric loader version {} to {}", requested, short);
+        }
+        return Ok(short);
+    }
+    if available.is_empty() {
+        return Err(format!("Fabric has no loader versions for Minecraft {}", game_version).into());
+    }
+
+    let nearby = super::versions::nearby_versions(&short, &available, 5);
+    let suggestions = if nearby.is_empty() { available.iter().take(5).cloned().collect() } else { nearby };
+    Err(format!(
+        "Fabric loader {} doesn't exist for Minecraft {}. Closest versions: {}",
+        requested,
+        game_version,
+        suggestions.join(", ")
+    )
+    .into())
+}
+
 /// Install Fabric for an instance
 pub async fn install_fabric(
     instance: &Instance,
     loader_version: &str,
+    progress: Option<StepProgress<'_>>,
 ) -> Result<FabricLoaderVersion, Box<dyn Error + Send + Sync>> {
-    let fabric_info = get_fabric_loader_info(&instance.version_id, loader_version).await?;
+    let loader_version = resolve_fabric_loader_version(&instance.version_id, loader_version).await?;
+    let fabric_info = get_fabric_loader_info(&instance.version_id, &loader_version).await?;
     let libraries_dir = get_libraries_dir();
 
+    let total_steps = (2
+        + fabric_info.launcher_meta.libraries.common.len()
+        + fabric_info.launcher_meta.libraries.client.len()) as f32;
+    let mut completed_steps = 0f32;
+    let report_step = |completed: f32| {
+        if let Some(report) = progress {
+            report("Installing Fabric: downloading libraries...", (completed / total_steps).min(1.0));
+        }
+    };
+    report_step(completed_steps);
+
     // Download loader library
     download_library(
         "https://maven.fabricmc.net/",
@@ -207,6 +270,8 @@ pub async fn install_fabric(
         &libraries_dir,
     )
     .await?;
+    completed_steps += 1.0;
+    report_step(completed_steps);
 
     // Download intermediary library
     download_library(
@@ -215,15 +280,21 @@ pub async fn install_fabric(
         &libraries_dir,
     )
     .await?;
+    completed_steps += 1.0;
+    report_step(completed_steps);
 
     // Download common libraries with SHA1 verification
     for lib in &fabric_info.launcher_meta.libraries.common {
         download_library_with_sha1(&lib.url, &lib.name, &libraries_dir, lib.sha1.as_deref()).await?;
+        completed_steps += 1.0;
+        report_step(completed_steps);
     }
 
     // Download client libraries with SHA1 verification
     for lib in &fabric_info.launcher_meta.libraries.client {
         download_library_with_sha1(&lib.url, &lib.name, &libraries_dir, lib.sha1.as_deref()).await?;
+        completed_steps += 1.0;
+        report_step(completed_steps);
     }
 
     // Save Fabric info to instance folder for later use
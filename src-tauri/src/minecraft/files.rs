@@ -8,7 +8,7 @@ use flate2::write::GzEncoder;
 use flate2::Compression;
 use std::io::Write;
 
-use crate::minecraft::instances::Instance;
+use crate::minecraft::instances::{Instance, ModLoader};
 
 fn provider_from_project_id(project_id: &str) -> String {
     if !project_id.is_empty() && project_id.chars().all(|c| c.is_ascii_digit()) {
@@ -40,6 +40,18 @@ pub struct InstalledMod {
     // ----------
     #[serde(default)]
     pub categories: Option<Vec<String>>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub source_url: Option<String>,
+    #[serde(default)]
+    pub pinned: bool,
+    // Loaders the jar itself declares, e.g. ["fabric"]
+    #[serde(default)]
+    pub loaders: Vec<String>,
+    // The jar declares loaders and none of them runs on the instance's current loader
+    #[serde(default)]
+    pub loader_mismatch: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -61,6 +73,18 @@ pub struct ModMeta {
     // ----------
     #[serde(default)]
     pub categories: Option<Vec<String>>,
+    // ----------
+    // Source URL
+    // Description: Where a mod installed from a direct link came from; these have no project_id
+    // ----------
+    #[serde(default)]
+    pub source_url: Option<String>,
+    // ----------
+    // Pinned
+    // Description: Update checks skip pinned mods; kept in the sidecar so it follows enable/disable
+    // ----------
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -86,6 +110,16 @@ pub struct ResourcePack {
     // ----------
     #[serde(default)]
     pub categories: Option<Vec<String>>,
+    // ----------
+    // Pack metadata
+    // Description: Read from pack.mcmeta; icon_path points at the extracted pack.png
+    // ----------
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub pack_format: Option<u32>,
+    #[serde(default)]
+    pub icon_path: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -111,6 +145,12 @@ pub struct ShaderPack {
     // ----------
     #[serde(default)]
     pub categories: Option<Vec<String>>,
+    // ----------
+    // Shader format
+    // Description: "iris" or "optifine" based on the pack layout, None when no shaders/ folder was found
+    // ----------
+    #[serde(default)]
+    pub shader_format: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -138,6 +178,15 @@ pub struct World {
     pub name: String,
     pub last_played: Option<i64>,
     pub game_mode: Option<i32>,
+    // Stored as a string since 64-bit seeds don't survive a JS number
+    #[serde(default)]
+    pub seed: Option<String>,
+    #[serde(default)]
+    pub difficulty: Option<i32>,
+    #[serde(default)]
+    pub hardcore: Option<bool>,
+    #[serde(default)]
+    pub day_count: Option<i64>,
     pub icon: Option<String>,
     pub size: u64,
 }
@@ -156,11 +205,102 @@ pub struct Screenshot {
     pub filename: String,
     pub path: String,
     pub date: Option<String>,
+    // Unix seconds the shot was taken, from the game's filename timestamp or else the file mtime
+    #[serde(default)]
+    pub captured_at: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CrashReport {
+    pub filename: String,
+    pub path: String,
+    pub date: Option<String>,
+    pub modified: u64,
+    pub description: Option<String>,
+    pub head: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigFile {
+    pub rel_path: String, // Relative to config/, always with forward slashes
+    pub size: u64,
+    pub kind: String,     // File extension, e.g. "json", "toml", "properties"
+    pub modified: u64,
 }
 
 /// Get mods directory for an instance
 pub fn get_mods_dir(instance: &Instance) -> PathBuf {
-    instance.get_game_directory().join("mods")
+    get_mods_dir_for_loader(instance, &instance.mod_loader)
+}
+
+// Inside mods/ when loader separation is on; names the loader the folder belongs to
+pub const ACTIVE_LOADER_MARKER: &str = ".palethea-loader";
+
+/// Folder holding `loader`'s mods. The game only reads mods/, so that is always the current
+/// loader's folder; with separate_loader_mods on, other loaders' mods wait in mods-<loader>/
+pub fn get_mods_dir_for_loader(instance: &Instance, loader: &ModLoader) -> PathBuf {
+    let game_dir = instance.get_game_directory();
+    if !instance.separate_loader_mods || *loader == instance.mod_loader {
+        game_dir.join("mods")
+    } else {
+        game_dir.join(format!("mods-{}", loader.slug()))
+    }
+}
+
+/// Record which loader the mods in mods/ belong to
+pub fn mark_mods_dir_loader(instance: &Instance, loader: &ModLoader) -> Result<(), String> {
+    let mods_dir = instance.get_game_directory().join("mods");
+    fs::create_dir_all(&mods_dir).map_err(|e| format!("Failed to create mods folder: {}", e))?;
+    fs::write(mods_dir.join(ACTIVE_LOADER_MARKER), loader.slug())
+        .map_err(|e| format!("Failed to mark mods folder: {}", e))
+}
+
+/// Make mods/ hold the current loader's mods when separation is on, parking the previous
+/// loader's mods in mods-<loader>/. Returns true when folders were swapped.
+///
+/// Migration: a flat mods/ without a marker (the folder from before separation was turned
+/// on) is taken to belong to the instance's current loader, so nothing moves until the
+/// loader changes. Turning separation off again leaves mods/ as the flat folder and the
+/// parked mods-<loader>/ folders untouched.
+pub fn sync_loader_mods_dir(instance: &Instance) -> Result<bool, String> {
+    if !instance.separate_loader_mods {
+        return Ok(false);
+    }
+    let game_dir = instance.get_game_directory();
+    let mods_dir = game_dir.join("mods");
+    let current = instance.mod_loader.slug();
+    let active = fs::read_to_string(mods_dir.join(ACTIVE_LOADER_MARKER)).ok().map(|s| s.trim().to_string());
+    let active = match active {
+        Some(active) if !active.is_empty() && mods_dir.exists() => active,
+        _ => {
+            mark_mods_dir_loader(instance, &instance.mod_loader)?;
+            return Ok(false);
+        }
+    };
+    if active == current {
+        return Ok(false);
+    }
+
+    let parked = game_dir.join(format!("mods-{}", active));
+    if parked.exists() {
+        return Err(format!(
+            "Cannot set aside the {} mods: {} already exists. Move its contents into mods/ or remove it.",
+            active,
+            parked.display()
+        ));
+    }
+    fs::rename(&mods_dir, &parked).map_err(|e| format!("Failed to set aside the {} mods: {}", active, e))?;
+
+    let incoming = game_dir.join(format!("mods-{}", current));
+    if incoming.exists() {
+        if let Err(e) = fs::rename(&incoming, &mods_dir) {
+            // Put the previous loader's mods back rather than leave the instance without mods/
+            let _ = fs::rename(&parked, &mods_dir);
+            return Err(format!("Failed to restore the {} mods: {}", current, e));
+        }
+    }
+    mark_mods_dir_loader(instance, &instance.mod_loader)?;
+    Ok(true)
 }
 
 /// Get resource packs directory for an instance
@@ -188,6 +328,16 @@ pub fn get_logs_dir(instance: &Instance) -> PathBuf {
     instance.get_game_directory().join("logs")
 }
 
+/// Get crash reports directory for an instance
+pub fn get_crash_reports_dir(instance: &Instance) -> PathBuf {
+    instance.get_game_directory().join("crash-reports")
+}
+
+/// Get mod config directory for an instance
+pub fn get_config_dir(instance: &Instance) -> PathBuf {
+    instance.get_game_directory().join("config")
+}
+
 pub fn metadata_dir(parent_dir: &Path) -> PathBuf {
     parent_dir.join("metadata")
 }
@@ -228,6 +378,65 @@ pub fn write_meta_for_file(file_path: &Path, meta: &ModMeta) -> Result<(), Strin
     write_meta_for_entry(parent_dir, filename, meta)
 }
 
+// ----------
+// MetaWriter
+// Description: Background thread that persists metadata sidecars queued by bulk installers,
+// so the download loop never blocks on small synchronous writes
+// ----------
+pub struct MetaWriter {
+    sender: Option<std::sync::mpsc::Sender<(PathBuf, ModMeta)>>,
+    handle: Option<std::thread::JoinHandle<usize>>,
+}
+
+impl MetaWriter {
+    pub fn spawn() -> Self {
+        let (sender, receiver) = std::sync::mpsc::channel::<(PathBuf, ModMeta)>();
+        let handle = std::thread::spawn(move || {
+            let mut written = 0usize;
+            for (file_path, meta) in receiver {
+                match write_meta_for_file(&file_path, &meta) {
+                    Ok(()) => written += 1,
+                    Err(e) => log::warn!("Failed to write metadata for {}: {}", file_path.display(), e),
+                }
+            }
+            written
+        });
+
+        MetaWriter {
+            sender: Some(sender),
+            handle: Some(handle),
+        }
+    }
+
+    /// Queue a metadata write; later entries for the same file overwrite earlier ones
+    pub fn queue(&self, file_path: PathBuf, meta: ModMeta) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send((file_path, meta));
+        }
+    }
+
+    /// Flush all pending writes and return how many succeeded. Blocks until the writer
+    /// thread is done, so async callers run it through `spawn_blocking`.
+    pub fn finish(mut self) -> usize {
+        self.flush()
+    }
+
+    fn flush(&mut self) -> usize {
+        self.sender.take();
+        self.handle
+            .take()
+            .and_then(|handle| handle.join().ok())
+            .unwrap_or(0)
+    }
+}
+
+impl Drop for MetaWriter {
+    fn drop(&mut self) {
+        // Installers may bail out early with `?`; still persist what was queued.
+        self.flush();
+    }
+}
+
 fn try_read_meta_file(path: &Path) -> Option<ModMeta> {
     let content = fs::read_to_string(path).ok()?;
     serde_json::from_str::<ModMeta>(&content).ok()
@@ -314,7 +523,7 @@ fn read_meta_for_entry(parent_dir: &Path, filename: &str) -> Option<ModMeta> {
     try_read_meta_file(&legacy_path)
 }
 
-fn delete_meta_for_entry(parent_dir: &Path, filename: &str) {
+pub fn delete_meta_for_entry(parent_dir: &Path, filename: &str) {
     let new_path = metadata_path(parent_dir, filename);
     if new_path.exists() {
         let _ = fs::remove_file(new_path);
@@ -339,6 +548,241 @@ fn should_skip_pack_entry(path: &Path, filename: &str) -> bool {
     false
 }
 
+// ----------
+// JarModInfo
+// Description: Details a mod jar declares about itself in fabric.mod.json, quilt.mod.json,
+//              mods.toml or its MANIFEST.MF
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct JarModInfo {
+    pub mod_id: Option<String>,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub authors: Vec<String>,
+    // Loaders the jar declares metadata for, e.g. ["fabric"]; empty when it has none
+    #[serde(default)]
+    pub loaders: Vec<String>,
+}
+
+// (mtime, size, parsed info)
+type JarInfoCacheEntry = (u64, u64, Option<JarModInfo>);
+
+// Parsed jars keyed by path, reused while size and mtime are unchanged
+static JAR_INFO_CACHE: std::sync::LazyLock<std::sync::Mutex<std::collections::HashMap<PathBuf, JarInfoCacheEntry>>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+
+/// Read the mod's own metadata from a jar, cached by path + mtime
+pub fn read_jar_mod_info(path: &Path) -> Option<JarModInfo> {
+    let metadata = fs::metadata(path).ok()?;
+    let mtime = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let len = metadata.len();
+
+    if let Ok(cache) = JAR_INFO_CACHE.lock() {
+        if let Some((cached_mtime, cached_len, info)) = cache.get(path) {
+            if *cached_mtime == mtime && *cached_len == len {
+                return info.clone();
+            }
+        }
+    }
+
+    let info = parse_jar_mod_info(path);
+    if let Ok(mut cache) = JAR_INFO_CACHE.lock() {
+        cache.insert(path.to_path_buf(), (mtime, len, info.clone()));
+    }
+    info
+}
+
+fn read_zip_text(archive: &mut zip::ZipArchive<File>, name: &str) -> Option<String> {
+    let mut entry = archive.by_name(name).ok()?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+// Metadata file in a jar -> the loader it is written for
+pub const JAR_LOADER_METADATA: &[(&str, &str)] = &[
+    ("fabric.mod.json", "fabric"),
+    ("quilt.mod.json", "quilt"),
+    ("META-INF/mods.toml", "forge"),
+    ("META-INF/neoforge.mods.toml", "neoforge"),
+];
+
+/// NeoForge read Forge's mods.toml until Minecraft 1.20.5. Ids that aren't plain releases
+/// (snapshots) are given the benefit of the doubt.
+fn neoforge_reads_mods_toml(game_version: &str) -> bool {
+    let core = game_version.split(['-', '+']).next().unwrap_or_default();
+    let release: Option<Vec<u64>> = core.split('.').map(|part| part.parse().ok()).collect();
+    release.map_or(true, |release| release < vec![1, 20, 5])
+}
+
+/// Loader tags whose mods load under the loader with slug `loader` ("quilt", "neoforge", ...)
+/// on Minecraft `game_version`. Vanilla and unknown loaders accept none.
+pub fn loaders_accepted_by(loader: &str, game_version: &str) -> &'static [&'static str] {
+    match loader {
+        "fabric" => &["fabric"],
+        // Quilt runs Fabric mods as well as its own
+        "quilt" => &["quilt", "fabric"],
+        "forge" => &["forge"],
+        // NeoForge started as a Forge fork (1.20.1) and read mods.toml up to 1.20.4
+        "neoforge" if neoforge_reads_mods_toml(game_version) => &["neoforge", "forge"],
+        "neoforge" => &["neoforge"],
+        _ => &[],
+    }
+}
+
+/// Whether a jar declaring `jar_loaders` can load under `loader` on `game_version`. Jars
+/// without loader metadata (libraries, very old mods) are given the benefit of the doubt.
+pub fn jar_supports_loader(jar_loaders: &[String], loader: &ModLoader, game_version: &str) -> bool {
+    if jar_loaders.is_empty() || *loader == ModLoader::Vanilla {
+        return true;
+    }
+    let accepted = loaders_accepted_by(&loader.slug(), game_version);
+    jar_loaders.iter().any(|l| accepted.contains(&l.as_str()))
+}
+
+fn parse_jar_mod_info(path: &Path) -> Option<JarModInfo> {
+    let file = File::open(path).ok()?;
+    let mut archive = zip::ZipArchive::new(file).ok()?;
+    let manifest = read_zip_text(&mut archive, "META-INF/MANIFEST.MF")
+        .map(|content| parse_jar_manifest(&content))
+        .unwrap_or_default();
+    let loaders: Vec<String> = JAR_LOADER_METADATA
+        .iter()
+        .filter(|(entry, _)| archive.index_for_name(entry).is_some())
+        .map(|(_, loader)| loader.to_string())
+        .collect();
+    let with_loaders = |info: JarModInfo| JarModInfo { loaders: loaders.clone(), ..info };
+
+    if let Some(info) = read_zip_text(&mut archive, "fabric.mod.json").and_then(|c| parse_fabric_mod_json(&c)) {
+        return Some(with_loaders(info));
+    }
+    if let Some(info) = read_zip_text(&mut archive, "quilt.mod.json").and_then(|c| parse_quilt_mod_json(&c)) {
+        return Some(with_loaders(info));
+    }
+    for toml_name in ["META-INF/neoforge.mods.toml", "META-INF/mods.toml"] {
+        if let Some(info) = read_zip_text(&mut archive, toml_name).and_then(|c| parse_mods_toml(&c, &manifest)) {
+            return Some(with_loaders(info));
+        }
+    }
+
+    let name = manifest.get("Implementation-Title").or_else(|| manifest.get("Specification-Title")).cloned();
+    let version = manifest.get("Implementation-Version").or_else(|| manifest.get("Specification-Version")).cloned();
+    if name.is_none() && version.is_none() {
+        return None;
+    }
+    Some(JarModInfo {
+        name,
+        version,
+        authors: manifest.get("Implementation-Vendor").cloned().into_iter().collect(),
+        ..Default::default()
+    })
+}
+
+/// MANIFEST.MF main attributes, joining wrapped continuation lines
+fn parse_jar_manifest(content: &str) -> std::collections::HashMap<String, String> {
+    let mut attributes = std::collections::HashMap::new();
+    let mut last_key: Option<String> = None;
+    for line in content.lines() {
+        if line.is_empty() {
+            break; // Main section ends at the first blank line
+        }
+        if let Some(continuation) = line.strip_prefix(' ') {
+            if let Some(value) = last_key.as_ref().and_then(|k| attributes.get_mut(k)) {
+                value.push_str(continuation);
+            }
+            continue;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            let key = key.trim().to_string();
+            attributes.insert(key.clone(), value.trim().to_string());
+            last_key = Some(key);
+        }
+    }
+    attributes
+}
+
+fn non_empty_str(value: Option<&serde_json::Value>) -> Option<String> {
+    value.and_then(|v| v.as_str()).map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+fn parse_fabric_mod_json(content: &str) -> Option<JarModInfo> {
+    let json: serde_json::Value = serde_json::from_str(content).ok()?;
+    let authors = json
+        .get("authors")
+        .and_then(|a| a.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|a| non_empty_str(Some(a)).or_else(|| non_empty_str(a.get("name"))))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(JarModInfo {
+        mod_id: non_empty_str(json.get("id")),
+        name: non_empty_str(json.get("name")).or_else(|| non_empty_str(json.get("id"))),
+        version: non_empty_str(json.get("version")),
+        description: non_empty_str(json.get("description")),
+        authors,
+        loaders: Vec::new(),
+    })
+}
+
+fn parse_quilt_mod_json(content: &str) -> Option<JarModInfo> {
+    let json: serde_json::Value = serde_json::from_str(content).ok()?;
+    let loader = json.get("quilt_loader")?;
+    let metadata = loader.get("metadata");
+    let authors = metadata
+        .and_then(|m| m.get("contributors"))
+        .and_then(|c| c.as_object())
+        .map(|contributors| contributors.keys().cloned().collect())
+        .unwrap_or_default();
+
+    Some(JarModInfo {
+        mod_id: non_empty_str(loader.get("id")),
+        name: non_empty_str(metadata.and_then(|m| m.get("name"))).or_else(|| non_empty_str(loader.get("id"))),
+        version: non_empty_str(loader.get("version")),
+        description: non_empty_str(metadata.and_then(|m| m.get("description"))),
+        authors,
+        loaders: Vec::new(),
+    })
+}
+
+fn parse_mods_toml(content: &str, manifest: &std::collections::HashMap<String, String>) -> Option<JarModInfo> {
+    let doc: toml::Value = toml::from_str(content).ok()?;
+    let first_mod = doc.get("mods").and_then(|m| m.as_array()).and_then(|m| m.first())?;
+    let toml_str = |value: Option<&toml::Value>| {
+        value.and_then(|v| v.as_str()).map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+    };
+
+    // "${file.jarVersion}" is filled in from the jar manifest at runtime
+    let version = toml_str(first_mod.get("version")).and_then(|v| {
+        if v.contains("${") {
+            manifest.get("Implementation-Version").cloned()
+        } else {
+            Some(v)
+        }
+    });
+
+    let authors = toml_str(first_mod.get("authors").or_else(|| doc.get("authors")))
+        .map(|a| a.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+
+    Some(JarModInfo {
+        mod_id: toml_str(first_mod.get("modId")),
+        name: toml_str(first_mod.get("displayName")).or_else(|| toml_str(first_mod.get("modId"))),
+        version,
+        description: toml_str(first_mod.get("description")),
+        authors,
+        loaders: Vec::new(),
+    })
+}
+
 /// List installed mods
 pub fn list_mods(instance: &Instance) -> Vec<InstalledMod> {
     let mods_dir = get_mods_dir(instance);
@@ -373,17 +817,38 @@ pub fn list_mods(instance: &Instance) -> Vec<InstalledMod> {
                 let mut version = None;
                 let mut provider = "Manual".to_string();
                 let mut categories = None;
+                let mut description = None;
+                let mut source_url = None;
+                let mut pinned = false;
+                let jar_info = read_jar_mod_info(&path);
                 
                 if let Some(m) = read_meta_for_entry(&mods_dir, base_filename) {
-                    project_id = Some(m.project_id.clone());
+                    // Direct-URL installs have a name but no project to check for updates
+                    if m.project_id.is_empty() && m.source_url.is_some() {
+                        provider = "Url".to_string();
+                    } else {
+                        project_id = Some(m.project_id.clone());
+                        provider = provider_from_project_id(&m.project_id);
+                    }
                     version_id = m.version_id;
                     if let Some(n) = m.name { name = Some(n); }
                     author = m.author;
                     icon_url = m.icon_url;
                     version = m.version_name;
                     categories = m.categories;
-                    provider = provider_from_project_id(&m.project_id);
+                    source_url = m.source_url;
+                    pinned = m.pinned;
+                } else if let Some(info) = jar_info.clone() {
+                    // Manually added jar: fall back to what the mod declares about itself
+                    if let Some(n) = info.name { name = Some(n); }
+                    version = info.version;
+                    description = info.description;
+                    if !info.authors.is_empty() {
+                        author = Some(info.authors.join(", "));
+                    }
                 }
+                let loaders = jar_info.map(|info| info.loaders).unwrap_or_default();
+                let loader_mismatch = !jar_supports_loader(&loaders, &instance.mod_loader, &instance.version_id);
                 
                 mods.push(InstalledMod {
                     filename: filename.clone(),
@@ -397,6 +862,11 @@ pub fn list_mods(instance: &Instance) -> Vec<InstalledMod> {
                     size,
                     provider,
                     categories,
+                    description,
+                    source_url,
+                    pinned,
+                    loaders,
+                    loader_mismatch,
                 });
             }
         }
@@ -406,6 +876,178 @@ pub fn list_mods(instance: &Instance) -> Vec<InstalledMod> {
     mods
 }
 
+// ----------
+// ModConflictGroup
+// Description: Installed mod files that look like copies of the same mod
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModConflictGroup {
+    pub key: String,
+    pub match_kind: String, // "project" or "name"
+    pub mods: Vec<InstalledMod>,
+}
+
+const LOADER_FILENAME_TOKENS: &[&str] = &["fabric", "forge", "neoforge", "quilt", "mc"];
+
+/// Reduce a mod filename to a comparable name, e.g. "Sodium-Fabric-0.5.3+mc1.20.1.jar" -> "sodium"
+pub fn normalize_mod_name(filename: &str) -> String {
+    let stem = filename
+        .trim_end_matches(".disabled")
+        .trim_end_matches(".jar")
+        .to_lowercase();
+
+    let mut parts = Vec::new();
+    for token in stem.split(['-', '_', '+', ' ']) {
+        if token.is_empty() {
+            continue;
+        }
+        let starts_with_digit = token.chars().next().is_some_and(|c| c.is_ascii_digit());
+        let is_version_tag = token.starts_with('v') && token.chars().nth(1).is_some_and(|c| c.is_ascii_digit());
+        let is_mc_tag = token.starts_with("mc") && token.chars().nth(2).is_some_and(|c| c.is_ascii_digit());
+        if starts_with_digit || is_version_tag || is_mc_tag {
+            break;
+        }
+        parts.push(token);
+    }
+
+    // Loader tags only come before or after the name ("sodium-fabric", "forge-jei-tweaks").
+    // A leading one is kept when it would leave a single word, since "fabric-api" is a name
+    // of its own and not the "api" mod.
+    while parts.len() > 1 && parts.last().is_some_and(|token| LOADER_FILENAME_TOKENS.contains(token)) {
+        parts.pop();
+    }
+    while parts.len() > 2 && LOADER_FILENAME_TOKENS.contains(&parts[0]) {
+        parts.remove(0);
+    }
+
+    if parts.is_empty() {
+        stem
+    } else {
+        parts.join("-")
+    }
+}
+
+/// Group installed mods (enabled and disabled) that share a project id or a normalized filename
+pub fn detect_mod_conflicts(instance: &Instance) -> Vec<ModConflictGroup> {
+    let mods = list_mods(instance);
+
+    // Manual copies of a tracked mod should land in the tracked mod's group
+    let mut project_by_name: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+    for m in &mods {
+        if let Some(pid) = &m.project_id {
+            project_by_name.entry(normalize_mod_name(&m.filename)).or_insert_with(|| pid.clone());
+        }
+    }
+
+    let mut groups: std::collections::BTreeMap<(String, String), Vec<InstalledMod>> = std::collections::BTreeMap::new();
+    for m in mods {
+        let key = match &m.project_id {
+            Some(pid) => ("project".to_string(), pid.clone()),
+            None => {
+                let name = normalize_mod_name(&m.filename);
+                match project_by_name.get(&name) {
+                    Some(pid) => ("project".to_string(), pid.clone()),
+                    None => ("name".to_string(), name),
+                }
+            }
+        };
+        groups.entry(key).or_default().push(m);
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, mods)| mods.len() > 1)
+        .map(|((match_kind, key), mods)| ModConflictGroup { key, match_kind, mods })
+        .collect()
+}
+
+// ----------
+// InstalledContentMatch
+// Description: One installed mod, pack or datapack that matched a global content search
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InstalledContentMatch {
+    pub instance_id: String,
+    pub instance_name: String,
+    pub content_type: String, // "mod", "resourcepack", "shaderpack", "datapack"
+    pub filename: String,
+    pub name: Option<String>,
+    pub enabled: bool,
+    pub project_id: Option<String>,
+    pub world: Option<String>, // Owning world for datapacks
+    pub score: u32,
+}
+
+fn search_key(value: &str) -> String {
+    value.chars().filter(|c| c.is_alphanumeric()).flat_map(|c| c.to_lowercase()).collect()
+}
+
+/// 2 for a substring match, 1 when the query's characters appear in order, 0 otherwise
+fn content_match_score(query: &str, candidate: &str) -> u32 {
+    let candidate = search_key(candidate);
+    if candidate.contains(query) {
+        return 2;
+    }
+    let mut chars = candidate.chars();
+    if query.chars().all(|q| chars.any(|c| c == q)) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Search every instance's mods, resource packs, shader packs and datapacks by filename and name
+pub fn search_installed_content(instances: &[Instance], query: &str) -> Vec<InstalledContentMatch> {
+    let query = search_key(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut results = Vec::new();
+    let mut push = |instance: &Instance, content_type: &str, filename: &str, name: &Option<String>, enabled: bool, project_id: &Option<String>, world: Option<&str>| {
+        let score = content_match_score(&query, filename)
+            .max(name.as_deref().map(|n| content_match_score(&query, n)).unwrap_or(0));
+        if score > 0 {
+            results.push(InstalledContentMatch {
+                instance_id: instance.id.clone(),
+                instance_name: instance.name.clone(),
+                content_type: content_type.to_string(),
+                filename: filename.to_string(),
+                name: name.clone(),
+                enabled,
+                project_id: project_id.clone(),
+                world: world.map(|w| w.to_string()),
+                score,
+            });
+        }
+    };
+
+    for instance in instances {
+        for item in list_mods(instance) {
+            push(instance, "mod", &item.filename, &item.name, item.enabled, &item.project_id, None);
+        }
+        for item in list_resourcepacks(instance) {
+            push(instance, "resourcepack", &item.filename, &item.name, item.enabled, &item.project_id, None);
+        }
+        for item in list_shaderpacks(instance) {
+            push(instance, "shaderpack", &item.filename, &item.name, item.enabled, &item.project_id, None);
+        }
+        for world in list_worlds(instance) {
+            for item in list_datapacks(instance, &world.folder_name) {
+                push(instance, "datapack", &item.filename, &item.name, item.enabled, &item.project_id, Some(&world.folder_name));
+            }
+        }
+    }
+
+    results.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.name.as_deref().unwrap_or(&a.filename).to_lowercase().cmp(&b.name.as_deref().unwrap_or(&b.filename).to_lowercase()))
+            .then_with(|| a.instance_name.cmp(&b.instance_name))
+    });
+    results
+}
+
 /// Toggle mod enabled/disabled
 pub fn toggle_mod(instance: &Instance, filename: &str) -> Result<bool, String> {
     let mods_dir = get_mods_dir(instance);
@@ -426,6 +1068,24 @@ pub fn toggle_mod(instance: &Instance, filename: &str) -> Result<bool, String> {
     Ok(!filename.ends_with(".disabled"))
 }
 
+/// Pin or unpin a mod. Only mods with a metadata sidecar can be pinned; manually added
+/// jars are never update-checked anyway.
+pub fn set_mod_pinned(instance: &Instance, filename: &str, pinned: bool) -> Result<(), String> {
+    if !is_mod_filename(filename) {
+        return Err("Invalid mod filename".to_string());
+    }
+    let mods_dir = get_mods_dir(instance);
+    if !mods_dir.join(filename).exists() {
+        return Err("Mod file not found".to_string());
+    }
+
+    let base_filename = filename.trim_end_matches(".disabled");
+    let mut meta = read_meta_for_entry(&mods_dir, base_filename)
+        .ok_or_else(|| "Only mods installed from Modrinth or CurseForge can be pinned".to_string())?;
+    meta.pinned = pinned;
+    write_meta_for_entry(&mods_dir, base_filename, &meta)
+}
+
 /// Delete a mod
 pub fn delete_mod(instance: &Instance, filename: &str) -> Result<(), String> {
     let mods_dir = get_mods_dir(instance);
@@ -442,6 +1102,330 @@ pub fn delete_mod(instance: &Instance, filename: &str) -> Result<(), String> {
     Ok(())
 }
 
+// ----------
+// BulkModResult
+// Description: Outcome for one file of a bulk toggle or delete. `filename` is the name
+//              after the operation when it succeeded.
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BulkModResult {
+    pub requested: String,
+    pub filename: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+impl BulkModResult {
+    fn ok(requested: &str, filename: String) -> Self {
+        BulkModResult { requested: requested.to_string(), filename, success: true, error: None }
+    }
+
+    fn failed(requested: &str, error: String) -> Self {
+        BulkModResult { requested: requested.to_string(), filename: requested.to_string(), success: false, error: Some(error) }
+    }
+}
+
+fn is_mod_filename(filename: &str) -> bool {
+    !filename.contains('/') && !filename.contains('\\') && !filename.contains("..")
+        && (filename.ends_with(".jar") || filename.ends_with(".jar.disabled"))
+}
+
+/// Metadata is keyed by the enabled filename; pull along any record written under the old name
+fn move_mod_meta(mods_dir: &Path, from: &str, to: &str) {
+    let base = to.trim_end_matches(".disabled");
+    for old_name in [from, from.trim_end_matches(".disabled")] {
+        if old_name == base {
+            continue;
+        }
+        for (old_path, new_path) in [
+            (metadata_path(mods_dir, old_name), metadata_path(mods_dir, base)),
+            (legacy_metadata_path(mods_dir, old_name), legacy_metadata_path(mods_dir, base)),
+        ] {
+            if old_path.exists() && !new_path.exists() {
+                let _ = fs::rename(&old_path, &new_path);
+            }
+        }
+    }
+}
+
+/// Enable or disable several mods at once. Every rename is rolled back if any of them
+/// fails, so the folder never ends up half-toggled.
+pub fn set_mods_enabled(instance: &Instance, filenames: &[String], enabled: bool) -> Vec<BulkModResult> {
+    let mods_dir = get_mods_dir(instance);
+    let mut planned = Vec::new();
+    let mut results = Vec::new();
+
+    for requested in filenames {
+        if !is_mod_filename(requested) {
+            results.push(BulkModResult::failed(requested, "Not a mod file".to_string()));
+            continue;
+        }
+        let base = requested.trim_end_matches(".disabled");
+        let target = if enabled { base.to_string() } else { format!("{}.disabled", base) };
+        if !mods_dir.join(requested).exists() {
+            results.push(BulkModResult::failed(requested, "Mod file not found".to_string()));
+        } else if target != *requested && mods_dir.join(&target).exists() {
+            results.push(BulkModResult::failed(requested, format!("{} already exists", target)));
+        } else {
+            planned.push((requested.clone(), target));
+        }
+    }
+
+    if !results.is_empty() {
+        // Refuse the whole batch so the caller can fix the list and retry
+        results.extend(planned.iter().map(|(requested, _)| BulkModResult::failed(requested, "Skipped because another file failed".to_string())));
+        return results;
+    }
+
+    let mut renamed: Vec<(String, String)> = Vec::new();
+    for (requested, target) in &planned {
+        if requested == target {
+            continue;
+        }
+        if let Err(e) = fs::rename(mods_dir.join(requested), mods_dir.join(target)) {
+            for (from, to) in renamed.iter().rev() {
+                let _ = fs::rename(mods_dir.join(to), mods_dir.join(from));
+            }
+            return planned
+                .iter()
+                .map(|(name, _)| {
+                    if name == requested {
+                        BulkModResult::failed(name, e.to_string())
+                    } else {
+                        BulkModResult::failed(name, "Rolled back because another file failed".to_string())
+                    }
+                })
+                .collect();
+        }
+        renamed.push((requested.clone(), target.clone()));
+    }
+
+    for (requested, target) in &renamed {
+        move_mod_meta(&mods_dir, requested, target);
+    }
+    planned.into_iter().map(|(requested, target)| BulkModResult::ok(&requested, target)).collect()
+}
+
+/// Enable or disable every mod in the folder, e.g. to bisect which one crashes the game
+pub fn set_all_mods_enabled(instance: &Instance, enabled: bool) -> Vec<BulkModResult> {
+    let filenames: Vec<String> = fs::read_dir(get_mods_dir(instance))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| entry.file_name().to_str().map(|n| n.to_string()))
+                .filter(|name| is_mod_filename(name) && name.ends_with(".disabled") == enabled)
+                .collect()
+        })
+        .unwrap_or_default();
+    set_mods_enabled(instance, &filenames, enabled)
+}
+
+/// Delete several mods at once. Files are first moved into a staging folder and only
+/// removed once every move succeeded; otherwise they are put back.
+pub fn delete_mods(instance: &Instance, filenames: &[String]) -> Vec<BulkModResult> {
+    let mods_dir = get_mods_dir(instance);
+    let invalid: Vec<BulkModResult> = filenames
+        .iter()
+        .filter(|name| !is_mod_filename(name) || !mods_dir.join(name).exists())
+        .map(|name| BulkModResult::failed(name, "Mod file not found".to_string()))
+        .collect();
+    if !invalid.is_empty() {
+        let mut results = invalid;
+        for name in filenames {
+            if !results.iter().any(|r| &r.requested == name) {
+                results.push(BulkModResult::failed(name, "Skipped because another file failed".to_string()));
+            }
+        }
+        return results;
+    }
+
+    let staging_dir = mods_dir.join(".palethea-delete");
+    if let Err(e) = fs::create_dir_all(&staging_dir) {
+        return filenames.iter().map(|name| BulkModResult::failed(name, e.to_string())).collect();
+    }
+
+    let mut staged: Vec<&String> = Vec::new();
+    for name in filenames {
+        if let Err(e) = fs::rename(mods_dir.join(name), staging_dir.join(name)) {
+            for restored in &staged {
+                let _ = fs::rename(staging_dir.join(restored), mods_dir.join(restored));
+            }
+            let _ = fs::remove_dir(&staging_dir);
+            return filenames
+                .iter()
+                .map(|other| {
+                    if other == name {
+                        BulkModResult::failed(other, e.to_string())
+                    } else {
+                        BulkModResult::failed(other, "Rolled back because another file failed".to_string())
+                    }
+                })
+                .collect();
+        }
+        staged.push(name);
+    }
+
+    let _ = fs::remove_dir_all(&staging_dir);
+    for name in filenames {
+        delete_meta_for_entry(&mods_dir, name.trim_end_matches(".disabled"));
+    }
+    filenames.iter().map(|name| BulkModResult::ok(name, name.clone())).collect()
+}
+
+// ----------
+// PackArchiveInfo
+// Description: What a resource or shader pack declares about itself, read from the zip or folder
+// ----------
+#[derive(Debug, Clone, Default)]
+struct PackArchiveInfo {
+    description: Option<String>,
+    pack_format: Option<u32>,
+    icon_path: Option<String>,
+    shader_format: Option<String>,
+}
+
+// (mtime, size, parsed info)
+type PackInfoCacheEntry = (u64, u64, PackArchiveInfo);
+
+// Parsed packs keyed by path, reused while size and mtime are unchanged
+static PACK_INFO_CACHE: std::sync::LazyLock<std::sync::Mutex<std::collections::HashMap<PathBuf, PackInfoCacheEntry>>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+
+/// Read pack.mcmeta, pack.png and the shader layout of a pack, cached by path + mtime
+fn read_pack_info(path: &Path) -> PackArchiveInfo {
+    // Folder packs are edited in place, so their pack.mcmeta is the better change marker
+    let stamp_path = if path.is_dir() { path.join("pack.mcmeta") } else { path.to_path_buf() };
+    let metadata = fs::metadata(&stamp_path).or_else(|_| fs::metadata(path)).ok();
+    let mtime = metadata
+        .as_ref()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let len = metadata.map(|m| m.len()).unwrap_or(0);
+
+    if let Ok(cache) = PACK_INFO_CACHE.lock() {
+        if let Some((cached_mtime, cached_len, info)) = cache.get(path) {
+            if *cached_mtime == mtime && *cached_len == len {
+                return info.clone();
+            }
+        }
+    }
+
+    let info = parse_pack_info(path, mtime);
+    if let Ok(mut cache) = PACK_INFO_CACHE.lock() {
+        cache.insert(path.to_path_buf(), (mtime, len, info.clone()));
+    }
+    info
+}
+
+fn parse_pack_info(path: &Path, mtime: u64) -> PackArchiveInfo {
+    let mut mcmeta = None;
+    let mut icon = None;
+    let mut entry_names: Vec<String> = Vec::new();
+
+    if path.is_dir() {
+        mcmeta = fs::read_to_string(path.join("pack.mcmeta")).ok();
+        icon = fs::read(path.join("pack.png")).ok();
+        let shaders_dir = path.join("shaders");
+        if shaders_dir.is_dir() {
+            entry_names.push("shaders/".to_string());
+            if shaders_dir.join("dimension.properties").exists() {
+                entry_names.push("shaders/dimension.properties".to_string());
+            }
+        }
+    } else if let Ok(mut archive) = File::open(path).map_err(|e| e.to_string()).and_then(|f| zip::ZipArchive::new(f).map_err(|e| e.to_string())) {
+        mcmeta = read_zip_text(&mut archive, "pack.mcmeta");
+        if let Ok(mut entry) = archive.by_name("pack.png") {
+            let mut bytes = Vec::new();
+            if entry.read_to_end(&mut bytes).is_ok() {
+                icon = Some(bytes);
+            }
+        }
+        entry_names = archive.file_names().map(|n| n.replace('\\', "/")).collect();
+    }
+
+    let (description, pack_format) = mcmeta
+        .as_deref()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(content.trim_start_matches('\u{feff}')).ok())
+        .and_then(|json| json.get("pack").cloned())
+        .map(|pack| {
+            let description = pack.get("description").map(text_component_to_plain).filter(|d| !d.is_empty());
+            let pack_format = pack.get("pack_format").and_then(|f| f.as_u64()).map(|f| f as u32);
+            (description, pack_format)
+        })
+        .unwrap_or((None, None));
+
+    PackArchiveInfo {
+        description,
+        pack_format,
+        icon_path: icon.and_then(|bytes| cache_pack_icon(path, mtime, &bytes)),
+        shader_format: detect_shader_format(&entry_names),
+    }
+}
+
+/// Flatten a JSON text component (string, object or array) and drop § formatting codes
+fn text_component_to_plain(value: &serde_json::Value) -> String {
+    fn collect(value: &serde_json::Value, out: &mut String) {
+        match value {
+            serde_json::Value::String(text) => out.push_str(text),
+            serde_json::Value::Array(parts) => parts.iter().for_each(|part| collect(part, out)),
+            serde_json::Value::Object(map) => {
+                if let Some(text) = map.get("text") {
+                    collect(text, out);
+                }
+                if let Some(extra) = map.get("extra") {
+                    collect(extra, out);
+                }
+            }
+            other => out.push_str(&other.to_string()),
+        }
+    }
+
+    let mut raw = String::new();
+    collect(value, &mut raw);
+
+    let mut plain = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c == '§' {
+            chars.next();
+        } else {
+            plain.push(c);
+        }
+    }
+    plain.trim().to_string()
+}
+
+/// Shader packs keep their programs in a root shaders/ folder; dimension.properties is Iris-only
+fn detect_shader_format(entry_names: &[String]) -> Option<String> {
+    if !entry_names.iter().any(|name| name.starts_with("shaders/")) {
+        return None;
+    }
+    if entry_names.iter().any(|name| name == "shaders/dimension.properties") {
+        Some("iris".to_string())
+    } else {
+        Some("optifine".to_string())
+    }
+}
+
+/// Write pack.png into the icon cache, named by pack path + mtime so stale icons are never reused
+fn cache_pack_icon(path: &Path, mtime: u64, bytes: &[u8]) -> Option<String> {
+    use sha1::{Digest, Sha1};
+
+    let cache_dir = crate::minecraft::downloader::get_pack_icons_cache_dir();
+    let mut hasher = Sha1::new();
+    hasher.update(path.to_string_lossy().as_bytes());
+    hasher.update(mtime.to_le_bytes());
+    let icon_path = cache_dir.join(format!("{:x}.png", hasher.finalize()));
+
+    if !icon_path.exists() {
+        fs::create_dir_all(&cache_dir).ok()?;
+        fs::write(&icon_path, bytes).ok()?;
+    }
+    Some(icon_path.to_string_lossy().to_string())
+}
+
 /// List resource packs
 pub fn list_resourcepacks(instance: &Instance) -> Vec<ResourcePack> {
     let dir = get_resourcepacks_dir(instance);
@@ -493,6 +1477,8 @@ pub fn list_resourcepacks(instance: &Instance) -> Vec<ResourcePack> {
                     provider = provider_from_project_id(&m.project_id);
                 }
 
+                let pack_info = read_pack_info(&path);
+
                 packs.push(ResourcePack {
                     filename: filename.clone(),
                     name,
@@ -505,6 +1491,9 @@ pub fn list_resourcepacks(instance: &Instance) -> Vec<ResourcePack> {
                     size,
                     provider,
                     categories,
+                    description: pack_info.description,
+                    pack_format: pack_info.pack_format,
+                    icon_path: pack_info.icon_path,
                 });
             }
         }
@@ -600,6 +1589,8 @@ pub fn list_shaderpacks(instance: &Instance) -> Vec<ShaderPack> {
                     provider = provider_from_project_id(&m.project_id);
                 }
 
+                let pack_info = read_pack_info(&path);
+
                 packs.push(ShaderPack {
                     filename: filename.clone(),
                     name,
@@ -612,6 +1603,7 @@ pub fn list_shaderpacks(instance: &Instance) -> Vec<ShaderPack> {
                     size,
                     provider,
                     categories,
+                    shader_format: pack_info.shader_format,
                 });
             }
         }
@@ -679,39 +1671,12 @@ pub fn list_worlds(instance: &Instance) -> Vec<World> {
             
             let level_dat = path.join("level.dat");
             if level_dat.exists() {
-                let mut world_name = folder_name.clone();
-                let mut last_played = None;
-                let mut game_mode = None;
+                let data_tag = read_level_data(&level_dat);
+                let world_name = data_tag
+                    .as_ref()
+                    .and_then(|tag| tag.level_name.clone())
+                    .unwrap_or_else(|| folder_name.clone());
                 let mut icon = None;
-                
-                // Try to parse level.dat for actual world name and meta
-                if let Ok(data) = fs::read(&level_dat) {
-                    // level.dat is Gzip-compressed NBT
-                    let mut decoder = GzDecoder::new(&data[..]);
-                    let mut decoded = Vec::new();
-                    if decoder.read_to_end(&mut decoded).is_ok() {
-                        if let Ok(nbt) = fastnbt::from_bytes::<LevelDatNbt>(&decoded) {
-                            if let Some(data_tag) = nbt.data {
-                                if let Some(level_name) = data_tag.level_name {
-                                    world_name = level_name;
-                                }
-                                last_played = data_tag.last_played;
-                                game_mode = data_tag.game_type;
-                            }
-                        }
-                    } else {
-                        // If not gzipped (rare but possible in some formats/backups), try raw
-                        if let Ok(nbt) = fastnbt::from_bytes::<LevelDatNbt>(&data) {
-                            if let Some(data_tag) = nbt.data {
-                                if let Some(level_name) = data_tag.level_name {
-                                    world_name = level_name;
-                                }
-                                last_played = data_tag.last_played;
-                                game_mode = data_tag.game_type;
-                            }
-                        }
-                    }
-                }
 
                 // Check for icon
                 let icon_path = path.join("icon.png");
@@ -727,8 +1692,12 @@ pub fn list_worlds(instance: &Instance) -> Vec<World> {
                 worlds.push(World {
                     folder_name: folder_name.clone(),
                     name: world_name,
-                    last_played,
-                    game_mode,
+                    last_played: data_tag.as_ref().and_then(|tag| tag.last_played),
+                    game_mode: data_tag.as_ref().and_then(|tag| tag.game_type),
+                    seed: data_tag.as_ref().and_then(LevelDataTag::seed).map(|seed| seed.to_string()),
+                    difficulty: data_tag.as_ref().and_then(|tag| tag.difficulty),
+                    hardcore: data_tag.as_ref().and_then(|tag| tag.hardcore).map(|flag| flag != 0),
+                    day_count: data_tag.as_ref().and_then(LevelDataTag::day_count),
                     icon,
                     size,
                 });
@@ -753,22 +1722,77 @@ struct LevelDataTag {
     last_played: Option<i64>,
     #[serde(rename = "GameType")]
     game_type: Option<i32>,
+    #[serde(rename = "Difficulty")]
+    difficulty: Option<i32>,
+    #[serde(rename = "hardcore")]
+    hardcore: Option<i8>,
+    #[serde(rename = "DayTime")]
+    day_time: Option<i64>,
+    #[serde(rename = "Time")]
+    time: Option<i64>,
+    // Pre-1.16 worlds keep the seed directly in Data
+    #[serde(rename = "RandomSeed")]
+    random_seed: Option<i64>,
+    // 1.16+ moved it into the world generation settings
+    #[serde(rename = "WorldGenSettings")]
+    world_gen_settings: Option<WorldGenSettingsTag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorldGenSettingsTag {
+    seed: Option<i64>,
+}
+
+impl LevelDataTag {
+    fn seed(&self) -> Option<i64> {
+        self.world_gen_settings
+            .as_ref()
+            .and_then(|settings| settings.seed)
+            .or(self.random_seed)
+    }
+
+    fn day_count(&self) -> Option<i64> {
+        self.day_time.or(self.time).map(|ticks| ticks / 24000)
+    }
+}
+
+/// Read the Data compound of a level.dat. Corrupt, truncated or locked files yield None.
+fn read_level_data(level_dat: &Path) -> Option<LevelDataTag> {
+    let data = fs::read(level_dat).ok()?;
+
+    // level.dat is Gzip-compressed NBT
+    let mut decoder = GzDecoder::new(&data[..]);
+    let mut decoded = Vec::new();
+    let nbt = if decoder.read_to_end(&mut decoded).is_ok() {
+        fastnbt::from_bytes::<LevelDatNbt>(&decoded).ok()?
+    } else {
+        // If not gzipped (rare but possible in some formats/backups), try raw
+        fastnbt::from_bytes::<LevelDatNbt>(&data).ok()?
+    };
+    nbt.data
 }
 
+/// Symlinks are not followed, so a link back up the tree can't recurse forever
 fn get_dir_size(path: &Path) -> std::io::Result<u64> {
-    let mut size = 0;
-    if path.is_dir() {
-        for entry in fs::read_dir(path)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_dir() {
-                size += get_dir_size(&path)?;
+    use rayon::prelude::*;
+
+    if !path.is_dir() {
+        return Ok(0);
+    }
+    let entries = fs::read_dir(path)?.collect::<std::io::Result<Vec<_>>>()?;
+    entries
+        .into_par_iter()
+        .map(|entry| {
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                get_dir_size(&entry.path())
+            } else if file_type.is_file() {
+                Ok(entry.metadata()?.len())
             } else {
-                size += fs::metadata(path)?.len();
+                Ok(0)
             }
-        }
-    }
-    Ok(size)
+        })
+        .sum()
 }
 
 /// Delete a world
@@ -1033,6 +2057,17 @@ fn copy_dir_recursive(source: &Path, destination: &Path) -> Result<(), String> {
 
 /// Update world's internal name in level.dat
 pub fn update_world_level_name(world_path: &Path, new_name: &str) -> Result<(), String> {
+    edit_level_data(world_path, |data_tag| {
+        data_tag.insert("LevelName".to_string(), fastnbt::Value::String(new_name.to_string()));
+    })
+}
+
+/// Apply an edit to the Data compound of a world's level.dat and write it back gzipped.
+/// Worlds without a level.dat are left alone.
+fn edit_level_data<F>(world_path: &Path, edit: F) -> Result<(), String>
+where
+    F: FnOnce(&mut std::collections::HashMap<String, fastnbt::Value>),
+{
     let level_dat = world_path.join("level.dat");
     if !level_dat.exists() {
         return Ok(());
@@ -1050,14 +2085,14 @@ pub fn update_world_level_name(world_path: &Path, new_name: &str) -> Result<(),
 
     let mut nbt: fastnbt::Value = fastnbt::from_bytes(&decoded).map_err(|e| e.to_string())?;
 
-    // NBT structure: Root Compound -> "Data" Compound -> "LevelName" String
+    // NBT structure: Root Compound -> "Data" Compound
     if let fastnbt::Value::Compound(root) = &mut nbt {
         if let Some(fastnbt::Value::Compound(data_tag)) = root.get_mut("Data") {
-            data_tag.insert("LevelName".to_string(), fastnbt::Value::String(new_name.to_string()));
+            edit(data_tag);
         } else if let Some(fastnbt::Value::Compound(data_tag)) = root.get_mut("") {
             // Some versions/parsers might have an empty string root
              if let Some(fastnbt::Value::Compound(inner_data)) = data_tag.get_mut("Data") {
-                inner_data.insert("LevelName".to_string(), fastnbt::Value::String(new_name.to_string()));
+                edit(inner_data);
              }
         }
     }
@@ -1074,6 +2109,40 @@ pub fn update_world_level_name(world_path: &Path, new_name: &str) -> Result<(),
     Ok(())
 }
 
+/// Keep level.dat's DataPacks lists in line with a datapack the launcher renamed. Both names
+/// are dropped from both lists, then the pack is listed as enabled again, or as disabled when
+/// the game would still load it (folders are picked up whatever their name).
+fn sync_level_datapack(world_path: &Path, old_filename: &str, new_filename: &str, enabled: bool, is_dir: bool) -> Result<(), String> {
+    let old_id = format!("file/{}", old_filename);
+    let new_id = format!("file/{}", new_filename);
+    edit_level_data(world_path, |data_tag| {
+        let Some(fastnbt::Value::Compound(packs)) = data_tag.get_mut("DataPacks") else {
+            // Worlds from before 1.13 have no datapack lists
+            return;
+        };
+        for list in ["Enabled", "Disabled"] {
+            if let Some(fastnbt::Value::List(ids)) = packs.get_mut(list) {
+                ids.retain(|id| !matches!(id, fastnbt::Value::String(id) if *id == old_id || *id == new_id));
+            }
+        }
+        let target = if enabled {
+            Some("Enabled")
+        } else if is_dir {
+            Some("Disabled")
+        } else {
+            None
+        };
+        if let Some(list) = target {
+            match packs.get_mut(list) {
+                Some(fastnbt::Value::List(ids)) => ids.push(fastnbt::Value::String(new_id)),
+                _ => {
+                    packs.insert(list.to_string(), fastnbt::Value::List(vec![fastnbt::Value::String(new_id)]));
+                }
+            }
+        }
+    })
+}
+
 /// List datapacks for a world
 pub fn list_datapacks(instance: &Instance, world_name: &str) -> Vec<Datapack> {
     let datapacks_dir = get_saves_dir(instance).join(world_name).join("datapacks");
@@ -1151,15 +2220,23 @@ pub fn toggle_datapack(instance: &Instance, world_name: &str, filename: &str) ->
         return Err("Datapack not found".to_string());
     }
 
-    let new_path = if filename.ends_with(".disabled") {
-        dir.join(filename.trim_end_matches(".disabled"))
+    let enabling = filename.ends_with(".disabled");
+    let new_filename = if enabling {
+        filename.trim_end_matches(".disabled").to_string()
     } else {
-        dir.join(format!("{}.disabled", filename))
+        format!("{}.disabled", filename)
     };
+    let is_dir = current_path.is_dir();
 
-    fs::rename(&current_path, &new_path).map_err(|e| e.to_string())?;
+    fs::rename(&current_path, dir.join(&new_filename)).map_err(|e| e.to_string())?;
 
-    Ok(!filename.ends_with(".disabled"))
+    // The file name decides for zipped packs, but a pack the world itself lists as disabled
+    // would stay off after being re-enabled here, and folders load under any name
+    if let Err(e) = sync_level_datapack(&get_saves_dir(instance).join(world_name), filename, &new_filename, enabling, is_dir) {
+        log::warn!("Could not update level.dat datapack lists for {}: {}", world_name, e);
+    }
+
+    Ok(enabling)
 }
 
 /// Delete a datapack from a world
@@ -1197,18 +2274,22 @@ pub fn list_screenshots(instance: &Instance) -> Vec<Screenshot> {
                 .to_string();
             
             if filename.ends_with(".png") {
-                let date = fs::metadata(&path)
-                    .ok()
-                    .and_then(|m| m.modified().ok())
-                    .map(|t| {
-                        let datetime: chrono::DateTime<chrono::Local> = t.into();
-                        datetime.to_rfc3339()
-                    });
+                let modified = fs::metadata(&path).ok().and_then(|m| m.modified().ok());
+                let date = modified.map(|t| {
+                    let datetime: chrono::DateTime<chrono::Local> = t.into();
+                    datetime.to_rfc3339()
+                });
+                let captured_at = screenshot_filename_timestamp(&filename).or_else(|| {
+                    modified
+                        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                        .map(|d| d.as_secs())
+                });
 
                 screenshots.push(Screenshot {
                     filename: filename.clone(),
                     path: path.to_string_lossy().to_string(),
                     date,
+                    captured_at,
                 });
             }
         }
@@ -1218,6 +2299,105 @@ pub fn list_screenshots(instance: &Instance) -> Vec<Screenshot> {
     screenshots
 }
 
+/// Capture time from the game's screenshot naming, "2024-01-31_18.04.12.png" or "..._2.png"
+fn screenshot_filename_timestamp(filename: &str) -> Option<u64> {
+    use chrono::TimeZone;
+
+    let stamp = filename.get(..19)?;
+    let naive = chrono::NaiveDateTime::parse_from_str(stamp, "%Y-%m-%d_%H.%M.%S").ok()?;
+    let local = chrono::Local.from_local_datetime(&naive).earliest()?;
+    u64::try_from(local.timestamp()).ok()
+}
+
+const SCREENSHOT_THUMBNAIL_WIDTH: u32 = 320;
+const SCREENSHOT_THUMBNAIL_INDEX: &str = "index.json";
+
+// Serializes renames into the thumbnail cache with updates to its index
+static SCREENSHOT_THUMBNAIL_LOCK: std::sync::LazyLock<std::sync::Mutex<()>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(()));
+
+/// Move a freshly encoded thumbnail into the cache and record its source in the index.
+/// Entries whose screenshot is gone, or that an edited screenshot has superseded, are deleted.
+fn store_screenshot_thumbnail(cache_dir: &Path, temp_path: &Path, thumbnail_path: &Path, source: &Path) -> Result<(), String> {
+    let _guard = SCREENSHOT_THUMBNAIL_LOCK.lock().map_err(|_| "Thumbnail cache lock poisoned".to_string())?;
+    fs::rename(temp_path, thumbnail_path).map_err(|e| {
+        let _ = fs::remove_file(temp_path);
+        format!("Failed to save thumbnail: {}", e)
+    })?;
+
+    let index_path = cache_dir.join(SCREENSHOT_THUMBNAIL_INDEX);
+    let mut index: std::collections::HashMap<String, String> = fs::read_to_string(&index_path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default();
+    let name = thumbnail_path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let source = source.to_string_lossy().to_string();
+    index.retain(|thumbnail, screenshot| {
+        let keep = thumbnail == &name || (screenshot != &source && Path::new(screenshot.as_str()).exists());
+        if !keep {
+            let _ = fs::remove_file(cache_dir.join(thumbnail));
+        }
+        keep
+    });
+    index.insert(name, source);
+
+    let json = serde_json::to_string(&index).map_err(|e| e.to_string())?;
+    let index_temp = index_path.with_extension("json.tmp");
+    fs::write(&index_temp, json).map_err(|e| e.to_string())?;
+    fs::rename(&index_temp, &index_path).map_err(|e| e.to_string())
+}
+
+/// Path of a cached JPEG thumbnail for a screenshot, generating it first when needed.
+/// The cache name includes the source's mtime and size, so an edited screenshot gets a fresh one.
+pub fn screenshot_thumbnail(instance: &Instance, filename: &str) -> Result<String, String> {
+    use sha1::{Digest, Sha1};
+
+    if filename.contains('/') || filename.contains('\\') || filename.contains("..") {
+        return Err("Invalid screenshot name".to_string());
+    }
+    let source = get_screenshots_dir(instance).join(filename);
+    let metadata = fs::metadata(&source).map_err(|_| format!("Screenshot not found: {}", filename))?;
+    let mtime = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let mut hasher = Sha1::new();
+    hasher.update(source.to_string_lossy().as_bytes());
+    hasher.update(mtime.to_le_bytes());
+    hasher.update(metadata.len().to_le_bytes());
+    let cache_dir = crate::minecraft::downloader::get_screenshot_thumbnails_dir();
+    let thumbnail_path = cache_dir.join(format!("{:x}.jpg", hasher.finalize()));
+    if thumbnail_path.exists() {
+        return Ok(thumbnail_path.to_string_lossy().to_string());
+    }
+
+    let image = image::open(&source).map_err(|e| format!("Failed to read screenshot: {}", e))?;
+    let height = (image.height() as u64 * SCREENSHOT_THUMBNAIL_WIDTH as u64 / image.width().max(1) as u64).max(1) as u32;
+    let thumbnail = image.thumbnail(SCREENSHOT_THUMBNAIL_WIDTH, height).to_rgb8();
+
+    fs::create_dir_all(&cache_dir).map_err(|e| e.to_string())?;
+    // Encode next to the final path so a half-written file is never served from the cache
+    static TEMP_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let temp_id = TEMP_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let temp_path = thumbnail_path.with_extension(format!("{}-{}.tmp", std::process::id(), temp_id));
+    let encoded = fs::File::create(&temp_path)
+        .map_err(|e| format!("Failed to create thumbnail: {}", e))
+        .and_then(|mut out| {
+            image::codecs::jpeg::JpegEncoder::new_with_quality(&mut out, 80)
+                .encode_image(&thumbnail)
+                .map_err(|e| format!("Failed to encode thumbnail: {}", e))
+        });
+    if let Err(e) = encoded {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e);
+    }
+    store_screenshot_thumbnail(&cache_dir, &temp_path, &thumbnail_path, &source)?;
+    Ok(thumbnail_path.to_string_lossy().to_string())
+}
+
 /// Delete a screenshot
 pub fn delete_screenshot(instance: &Instance, filename: &str) -> Result<(), String> {
     let dir = get_screenshots_dir(instance);
@@ -1230,6 +2410,95 @@ pub fn delete_screenshot(instance: &Instance, filename: &str) -> Result<(), Stri
     Ok(())
 }
 
+/// Pull the "Description:" line and the "-- Head --" stack out of a crash report
+pub fn parse_crash_report_summary(content: &str) -> (Option<String>, Option<String>) {
+    let description = content
+        .lines()
+        .find_map(|line| line.strip_prefix("Description:"))
+        .map(|d| d.trim().to_string())
+        .filter(|d| !d.is_empty());
+
+    let mut head_lines = Vec::new();
+    let mut in_head = false;
+    for line in content.lines() {
+        if line.trim() == "-- Head --" {
+            in_head = true;
+            continue;
+        }
+        if !in_head {
+            continue;
+        }
+        if line.trim().is_empty() || line.starts_with("-- ") || head_lines.len() >= 16 {
+            break;
+        }
+        head_lines.push(line.trim_end());
+    }
+    let head = if head_lines.is_empty() { None } else { Some(head_lines.join("\n")) };
+
+    (description, head)
+}
+
+/// List crash reports, newest first
+pub fn list_crash_reports(instance: &Instance) -> Vec<CrashReport> {
+    let dir = get_crash_reports_dir(instance);
+    let mut reports = Vec::new();
+
+    if let Ok(entries) = fs::read_dir(&dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let filename = path.file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or("")
+                .to_string();
+            if !filename.ends_with(".txt") {
+                continue;
+            }
+
+            let modified_time = fs::metadata(&path).ok().and_then(|m| m.modified().ok());
+            let modified = modified_time
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            let date = modified_time.map(|t| {
+                let datetime: chrono::DateTime<chrono::Local> = t.into();
+                datetime.to_rfc3339()
+            });
+            let (description, head) = fs::read_to_string(&path)
+                .map(|content| parse_crash_report_summary(&content))
+                .unwrap_or((None, None));
+
+            reports.push(CrashReport {
+                filename,
+                path: path.to_string_lossy().to_string(),
+                date,
+                modified,
+                description,
+                head,
+            });
+        }
+    }
+
+    reports.sort_by(|a, b| b.modified.cmp(&a.modified));
+    reports
+}
+
+/// Read a single crash report
+pub fn read_crash_report(instance: &Instance, filename: &str) -> Result<String, String> {
+    if filename.contains('/') || filename.contains('\\') || filename.contains("..") {
+        return Err("Invalid crash report name".to_string());
+    }
+    let path = get_crash_reports_dir(instance).join(filename);
+    fs::read_to_string(&path).map_err(|e| format!("Failed to read crash report: {}", e))
+}
+
+/// Newest crash report written at or after `since` (unix seconds)
+pub fn latest_crash_report_since(instance: &Instance, since: u64) -> Option<CrashReport> {
+    list_crash_reports(instance)
+        .into_iter()
+        .next()
+        .filter(|report| report.modified >= since)
+}
+
 /// Get latest log content
 pub fn get_latest_log(instance: &Instance) -> Result<String, String> {
     let logs_dir = get_logs_dir(instance);
@@ -1261,6 +2530,91 @@ pub fn clear_latest_log(instance: &Instance) -> Result<(), String> {
     Ok(())
 }
 
+// Config files larger than this are almost always generated data, not settings
+const MAX_CONFIG_EDIT_BYTES: u64 = 2 * 1024 * 1024;
+
+/// List every file under config/, sorted by path
+pub fn list_configs(instance: &Instance) -> Vec<ConfigFile> {
+    let config_dir = get_config_dir(instance);
+    let mut configs = Vec::new();
+    let mut pending = vec![config_dir.clone()];
+
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if metadata.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            let Ok(relative) = path.strip_prefix(&config_dir) else {
+                continue;
+            };
+            configs.push(ConfigFile {
+                rel_path: relative.to_string_lossy().replace('\\', "/"),
+                size: metadata.len(),
+                kind: path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .unwrap_or("")
+                    .to_ascii_lowercase(),
+                modified: metadata
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0),
+            });
+        }
+    }
+
+    configs.sort_by(|a, b| a.rel_path.to_lowercase().cmp(&b.rel_path.to_lowercase()));
+    configs
+}
+
+/// Resolve a path relative to config/, rejecting anything that could escape it
+fn resolve_config_path(instance: &Instance, rel_path: &str) -> Result<PathBuf, String> {
+    let relative = Path::new(rel_path);
+    let is_plain = !rel_path.trim().is_empty()
+        && relative
+            .components()
+            .all(|component| matches!(component, std::path::Component::Normal(_)));
+    if !is_plain {
+        return Err("Invalid config path".to_string());
+    }
+    Ok(get_config_dir(instance).join(relative))
+}
+
+pub fn read_config(instance: &Instance, rel_path: &str) -> Result<String, String> {
+    let path = resolve_config_path(instance, rel_path)?;
+    let metadata = fs::metadata(&path).map_err(|e| format!("Failed to read {}: {}", rel_path, e))?;
+    if metadata.len() > MAX_CONFIG_EDIT_BYTES {
+        return Err(format!("{} is too large to edit here", rel_path));
+    }
+    let bytes = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", rel_path, e))?;
+    String::from_utf8(bytes).map_err(|_| format!("{} is not a text file", rel_path))
+}
+
+pub fn write_config(instance: &Instance, rel_path: &str, content: &str) -> Result<(), String> {
+    let path = resolve_config_path(instance, rel_path)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    // Write through a temp file so a crash mid-write doesn't leave the mod with a truncated config
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("config");
+    let temp_path = path.with_file_name(format!("{}.palethea-tmp", file_name));
+    fs::write(&temp_path, content).map_err(|e| format!("Failed to write {}: {}", rel_path, e))?;
+    fs::rename(&temp_path, &path).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Failed to write {}: {}", rel_path, e)
+    })
+}
+
 /// Read servers.dat NBT file
 pub fn list_servers(instance: &Instance) -> Vec<Server> {
     let servers_dat = instance.get_game_directory().join("servers.dat");
@@ -1385,3 +2739,239 @@ pub fn open_path(path: &PathBuf) -> Result<(), String> {
     
     Ok(())
 }
+
+// ----------
+// InstanceSummary
+// Description: Cheap counts for the instance grid, built from directory listings only
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct InstanceSummary {
+    pub mods_enabled: u32,
+    pub mods_disabled: u32,
+    pub resourcepacks: u32,
+    pub shaderpacks: u32,
+    pub worlds: u32,
+    pub mod_loader: String,
+    pub mod_loader_version: Option<String>,
+    pub version_id: String,
+    pub total_size: u64,
+}
+
+struct CachedSummary {
+    fingerprint: Vec<Option<std::time::SystemTime>>,
+    computed_at: std::time::Instant,
+    summary: InstanceSummary,
+}
+
+// Directory size is the slow part and only changes noticeably when content folders do,
+// so cached entries also expire after a while to pick up world growth.
+const SUMMARY_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(300);
+
+static SUMMARY_CACHE: std::sync::LazyLock<std::sync::Mutex<std::collections::HashMap<String, CachedSummary>>> =
+    std::sync::LazyLock::new(|| std::sync::Mutex::new(std::collections::HashMap::new()));
+
+/// Modification times of the folders the summary counts; any change invalidates the cache
+fn summary_fingerprint(instance: &Instance) -> Vec<Option<std::time::SystemTime>> {
+    [
+        instance.get_game_directory(),
+        get_mods_dir(instance),
+        get_resourcepacks_dir(instance),
+        get_shaderpacks_dir(instance),
+        get_saves_dir(instance),
+    ]
+    .iter()
+    .map(|dir| fs::metadata(dir).and_then(|m| m.modified()).ok())
+    .collect()
+}
+
+fn count_dir_entries(dir: &Path, filter: impl Fn(&Path, &str) -> bool) -> u32 {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| {
+                    let path = entry.path();
+                    let filename = entry.file_name().to_string_lossy().to_string();
+                    filter(&path, &filename)
+                })
+                .count() as u32
+        })
+        .unwrap_or(0)
+}
+
+/// Get mod/pack/world counts, loader and size for an instance, cached until its folders change
+pub fn get_instance_summary(instance: &Instance) -> InstanceSummary {
+    let fingerprint = summary_fingerprint(instance);
+
+    let cached = SUMMARY_CACHE.lock().ok().and_then(|cache| {
+        cache
+            .get(&instance.id)
+            .filter(|entry| entry.fingerprint == fingerprint && entry.computed_at.elapsed() < SUMMARY_CACHE_TTL)
+            .map(|entry| entry.summary.clone())
+    });
+
+    let mut summary = cached.unwrap_or_else(|| {
+        let summary = InstanceSummary {
+            mods_enabled: count_dir_entries(&get_mods_dir(instance), |_, name| name.ends_with(".jar")),
+            mods_disabled: count_dir_entries(&get_mods_dir(instance), |_, name| name.ends_with(".jar.disabled")),
+            resourcepacks: count_dir_entries(&get_resourcepacks_dir(instance), |path, name| !should_skip_pack_entry(path, name)),
+            shaderpacks: count_dir_entries(&get_shaderpacks_dir(instance), |path, name| !should_skip_pack_entry(path, name)),
+            worlds: count_dir_entries(&get_saves_dir(instance), |path, _| path.join("level.dat").exists()),
+            total_size: get_dir_size(&instance.get_directory()).unwrap_or(0),
+            ..Default::default()
+        };
+
+        if let Ok(mut cache) = SUMMARY_CACHE.lock() {
+            cache.insert(instance.id.clone(), CachedSummary {
+                fingerprint,
+                computed_at: std::time::Instant::now(),
+                summary: summary.clone(),
+            });
+        }
+        summary
+    });
+
+    // Loader info lives in the instance config, so it's always read fresh
+    summary.mod_loader = instance.mod_loader.to_string();
+    summary.mod_loader_version = instance.mod_loader_version.clone();
+    summary.version_id = instance.version_id.clone();
+    summary
+}
+
+/// Drop the cached summary, e.g. after the instance was deleted
+pub fn invalidate_instance_summary(instance_id: &str) {
+    if let Ok(mut cache) = SUMMARY_CACHE.lock() {
+        cache.remove(instance_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn normalize_mod_name_strips_loader_tags_around_the_name() {
+        assert_eq!(normalize_mod_name("Sodium-Fabric-0.5.3+mc1.20.1.jar"), "sodium");
+        assert_eq!(normalize_mod_name("fabric-api-0.92.0+1.20.1.jar"), "fabric-api");
+        assert_eq!(normalize_mod_name("forge-jei-tweaks-1.0.jar.disabled"), "jei-tweaks");
+        assert_eq!(normalize_mod_name("create-forge-mc1.20.1.jar"), "create");
+        assert_eq!(normalize_mod_name("fabric-1.0.jar"), "fabric");
+    }
+
+    #[test]
+    fn neoforge_accepts_forge_mods_until_it_stopped_reading_mods_toml() {
+        let forge = vec!["forge".to_string()];
+        assert!(jar_supports_loader(&forge, &ModLoader::NeoForge, "1.20.1"));
+        assert!(jar_supports_loader(&forge, &ModLoader::NeoForge, "1.20.4"));
+        assert!(!jar_supports_loader(&forge, &ModLoader::NeoForge, "1.20.5"));
+        assert!(!jar_supports_loader(&forge, &ModLoader::NeoForge, "1.21.1"));
+        assert!(jar_supports_loader(&forge, &ModLoader::NeoForge, "24w14a"));
+        assert!(!jar_supports_loader(&forge, &ModLoader::Fabric, "1.20.1"));
+    }
+
+    fn string_list(ids: &[&str]) -> fastnbt::Value {
+        fastnbt::Value::List(ids.iter().map(|id| fastnbt::Value::String(id.to_string())).collect())
+    }
+
+    fn write_level_dat(world_path: &Path, enabled: &[&str], disabled: &[&str]) {
+        let mut packs = HashMap::new();
+        packs.insert("Enabled".to_string(), string_list(enabled));
+        packs.insert("Disabled".to_string(), string_list(disabled));
+        let mut data = HashMap::new();
+        data.insert("LevelName".to_string(), fastnbt::Value::String("Fixture".to_string()));
+        data.insert("DataPacks".to_string(), fastnbt::Value::Compound(packs));
+        let mut root = HashMap::new();
+        root.insert("Data".to_string(), fastnbt::Value::Compound(data));
+
+        let bytes = fastnbt::to_bytes(&fastnbt::Value::Compound(root)).unwrap();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&bytes).unwrap();
+        fs::write(world_path.join("level.dat"), encoder.finish().unwrap()).unwrap();
+    }
+
+    // (Enabled, Disabled) as stored in the world's level.dat
+    fn read_datapack_lists(world_path: &Path) -> (Vec<String>, Vec<String>) {
+        let data = fs::read(world_path.join("level.dat")).unwrap();
+        let mut decoded = Vec::new();
+        GzDecoder::new(&data[..]).read_to_end(&mut decoded).unwrap();
+        let nbt: fastnbt::Value = fastnbt::from_bytes(&decoded).unwrap();
+
+        let fastnbt::Value::Compound(root) = nbt else { panic!("root is not a compound") };
+        let Some(fastnbt::Value::Compound(data)) = root.get("Data") else { panic!("no Data compound") };
+        let Some(fastnbt::Value::Compound(packs)) = data.get("DataPacks") else { panic!("no DataPacks compound") };
+        let list = |name: &str| match packs.get(name) {
+            Some(fastnbt::Value::List(ids)) => ids
+                .iter()
+                .filter_map(|id| match id {
+                    fastnbt::Value::String(id) => Some(id.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        (list("Enabled"), list("Disabled"))
+    }
+
+    #[test]
+    fn toggle_datapack_keeps_level_dat_lists_in_sync() {
+        let game_dir = std::env::temp_dir().join(format!("palethea-datapack-test-{}", uuid::Uuid::new_v4()));
+        let world_path = game_dir.join("saves").join("World");
+        let datapacks = world_path.join("datapacks");
+        fs::create_dir_all(datapacks.join("folder_pack")).unwrap();
+        fs::write(datapacks.join("zip_pack.zip"), b"zip").unwrap();
+        write_level_dat(&world_path, &["vanilla", "file/zip_pack.zip", "file/folder_pack"], &[]);
+
+        let mut instance = Instance::new("Fixture".to_string(), "1.20.1".to_string());
+        instance.game_directory = Some(game_dir.to_string_lossy().to_string());
+
+        // Disabled zips are simply not loaded, so they leave both lists
+        assert!(!toggle_datapack(&instance, "World", "zip_pack.zip").unwrap());
+        assert!(datapacks.join("zip_pack.zip.disabled").exists());
+        let (enabled, disabled) = read_datapack_lists(&world_path);
+        assert_eq!(enabled, vec!["vanilla", "file/folder_pack"]);
+        assert!(disabled.is_empty());
+
+        // Folders load under any name, so the renamed one must be listed as disabled
+        assert!(!toggle_datapack(&instance, "World", "folder_pack").unwrap());
+        assert!(datapacks.join("folder_pack.disabled").is_dir());
+        let (enabled, disabled) = read_datapack_lists(&world_path);
+        assert_eq!(enabled, vec!["vanilla"]);
+        assert_eq!(disabled, vec!["file/folder_pack.disabled"]);
+
+        // Re-enabling both lists them as enabled under their original names again
+        assert!(toggle_datapack(&instance, "World", "zip_pack.zip.disabled").unwrap());
+        assert!(toggle_datapack(&instance, "World", "folder_pack.disabled").unwrap());
+        let (enabled, disabled) = read_datapack_lists(&world_path);
+        assert_eq!(enabled, vec!["vanilla", "file/zip_pack.zip", "file/folder_pack"]);
+        assert!(disabled.is_empty());
+
+        let _ = fs::remove_dir_all(&game_dir);
+    }
+
+    #[test]
+    fn enabling_separation_with_a_loader_change_parks_the_old_loaders_mods() {
+        let game_dir = std::env::temp_dir().join(format!("palethea-loader-mods-test-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(game_dir.join("mods")).unwrap();
+        fs::write(game_dir.join("mods").join("sodium.jar"), b"jar").unwrap();
+
+        let mut instance = Instance::new("Fixture".to_string(), "1.20.1".to_string());
+        instance.game_directory = Some(game_dir.to_string_lossy().to_string());
+        instance.mod_loader = ModLoader::Fabric;
+
+        // Separation and the switch to Forge arrive in the same save
+        let mut updated = instance.clone();
+        updated.separate_loader_mods = true;
+        updated.mod_loader = ModLoader::Forge;
+        mark_mods_dir_loader(&updated, &instance.mod_loader).unwrap();
+        assert!(sync_loader_mods_dir(&updated).unwrap());
+
+        assert!(game_dir.join("mods-fabric").join("sodium.jar").exists());
+        assert!(!game_dir.join("mods").join("sodium.jar").exists());
+        assert_eq!(
+            fs::read_to_string(game_dir.join("mods").join(ACTIVE_LOADER_MARKER)).unwrap(),
+            ModLoader::Forge.slug()
+        );
+        let _ = fs::remove_dir_all(&game_dir);
+    }
+}
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fs;
+use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus, Stdio};
 use std::time::{Duration, Instant};
 
+use crate::minecraft::downloader::StepProgress;
 use crate::minecraft::instances::Instance;
 use crate::minecraft::launcher;
 
@@ -32,15 +34,31 @@ pub struct ForgeLibrary {
     pub url: Option<String>,
 }
 
-fn run_command_with_timeout(mut command: Command, timeout: Duration) -> Result<ExitStatus, String> {
+fn run_command_with_timeout(
+    mut command: Command,
+    timeout: Duration,
+    line_tx: Option<tokio::sync::mpsc::UnboundedSender<String>>,
+) -> Result<ExitStatus, String> {
     command
         .stdin(Stdio::null())
-        .stdout(Stdio::null())
+        .stdout(if line_tx.is_some() { Stdio::piped() } else { Stdio::null() })
         .stderr(Stdio::null());
 
     let mut child = command
         .spawn()
         .map_err(|e| format!("Failed to start installer process: {}", e))?;
+
+    // Forward installer output lines so the caller can derive progress
+    if let (Some(tx), Some(stdout)) = (line_tx, child.stdout.take()) {
+        std::thread::spawn(move || {
+            use std::io::BufRead;
+            for line in std::io::BufReader::new(stdout).lines().map_while(Result::ok) {
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+    }
     let start = Instant::now();
 
     loop {
@@ -64,12 +82,194 @@ fn run_command_with_timeout(mut command: Command, timeout: Duration) -> Result<E
     }
 }
 
+/// Count library downloads and client processors declared by a modern installer
+fn count_installer_steps(installer_path: &std::path::Path) -> u32 {
+    use std::io::Read;
+    let Ok(file) = fs::File::open(installer_path) else {
+        return 0;
+    };
+    let Ok(mut archive) = zip::ZipArchive::new(file) else {
+        return 0;
+    };
+    let mut content = String::new();
+    {
+        let Ok(mut entry) = archive.by_name("install_profile.json") else {
+            return 0;
+        };
+        if entry.read_to_string(&mut content).is_err() {
+            return 0;
+        }
+    }
+    let Ok(profile) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return 0;
+    };
+
+    let libraries = profile
+        .get("libraries")
+        .and_then(|v| v.as_array())
+        .map(|libs| libs.len())
+        .unwrap_or(0);
+    let processors = profile
+        .get("processors")
+        .and_then(|v| v.as_array())
+        .map(|procs| {
+            procs
+                .iter()
+                .filter(|p| {
+                    p.get("sides")
+                        .and_then(|sides| sides.as_array())
+                        .map(|sides| sides.iter().any(|side| side.as_str() == Some("client")))
+                        .unwrap_or(true)
+                })
+                .count()
+        })
+        .unwrap_or(0);
+
+    (libraries + processors) as u32
+}
+
+/// Run an installer process while mapping its output to step progress
+async fn run_installer_with_progress(
+    command: Command,
+    timeout: Duration,
+    loader_label: &str,
+    total_steps: u32,
+    progress: Option<StepProgress<'_>>,
+) -> Result<ExitStatus, String> {
+    let (line_tx, mut line_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    let task = tokio::task::spawn_blocking(move || run_command_with_timeout(command, timeout, Some(line_tx)));
+
+    let mut steps = 0u32;
+    let mut running_processors = false;
+    while let Some(line) = line_rx.recv().await {
+        let trimmed = line.trim();
+        let is_library = trimmed.starts_with("Considering library");
+        let is_processor = trimmed.starts_with("MainClass:");
+        if !is_library && !is_processor {
+            continue;
+        }
+        running_processors |= is_processor;
+        steps += 1;
+
+        if let Some(report) = progress {
+            let stage = if running_processors {
+                format!("Installing {}: running processors...", loader_label)
+            } else {
+                format!("Installing {}: checking libraries...", loader_label)
+            };
+            let fraction = if total_steps > 0 {
+                (steps as f32 / total_steps as f32).min(0.95)
+            } else {
+                0.5
+            };
+            report(&stage, 0.1 + fraction * 0.9);
+        }
+    }
+
+    task.await
+        .map_err(|e| format!("Failed to join {} installer task: {}", loader_label, e))?
+}
+
+const FORGE_MAVEN_METADATA: &str = "https://maven.minecraftforge.net/net/minecraftforge/forge/maven-metadata.json";
+const NEOFORGE_VERSIONS_API: &str = "https://maven.neoforged.net/api/maven/versions/releases/net/neoforged/neoforge";
+
+/// Forge's own part of a version string: "forge-1.20.1-47.2.0", "1.20.1-47.2.0" and
+/// "1.7.10-10.13.4.1614-1.7.10" become "47.2.0" and "10.13.4.1614"
+fn short_forge_version(version: &str, mc_version: &str) -> String {
+    let version = version.trim();
+    let version = version.strip_prefix("forge-").unwrap_or(version);
+    let version = version.strip_prefix(&format!("{}-", mc_version)).unwrap_or(version);
+    let version = version.strip_suffix(&format!("-{}", mc_version)).unwrap_or(version);
+    version.to_string()
+}
+
+/// Resolve a Forge version as packs write it (short or with the Minecraft version attached)
+/// to the short form the installer URLs use, checked against Forge's maven metadata.
+/// When the metadata can't be fetched the normalized input is used as-is.
+async fn resolve_forge_version(mc_version: &str, requested: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let short = short_forge_version(requested, mc_version);
+    let metadata = match super::http_client().get(FORGE_MAVEN_METADATA).send().await {
+        Ok(response) if response.status().is_success() => {
+            response.json::<std::collections::HashMap<String, Vec<String>>>().await.ok()
+        }
+        _ => None,
+    };
+    let Some(metadata) = metadata else {
+        log::warn!("Could not fetch Forge version metadata, installing {} as given", short);
+        return Ok(short);
+    };
+
+    let available: Vec<String> = metadata
+        .get(mc_version)
+        .map(|versions| versions.iter().rev().map(|v| short_forge_version(v, mc_version)).collect())
+        .unwrap_or_default();
+    if available.is_empty() {
+        return Err(format!("Forge has no versions for Minecraft {}", mc_version).into());
+    }
+    if available.contains(&short) {
+        if short != requested.trim() {
+            log::info!("Resolved Forge version {} to {}", requested, short);
+        }
+        return Ok(short);
+    }
+
+    let nearby = super::versions::nearby_versions(&short, &available, 5);
+    let suggestions = if nearby.is_empty() { available.iter().take(5).cloned().collect() } else { nearby };
+    Err(format!(
+        "Forge {} doesn't exist for Minecraft {}. Closest versions: {}",
+        requested,
+        mc_version,
+        suggestions.join(", ")
+    )
+    .into())
+}
+
+/// Resolve a NeoForge version ("21.1.77", "neoforge-21.1.77" or "1.21.1-21.1.77") against the
+/// published release list
+async fn resolve_neoforge_version(mc_version: &str, requested: &str) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let version = requested.trim();
+    let version = version.strip_prefix("neoforge-").unwrap_or(version);
+    let short = version.strip_prefix(&format!("{}-", mc_version)).unwrap_or(version).to_string();
+
+    #[derive(Deserialize)]
+    struct NeoForgeVersions {
+        versions: Vec<String>,
+    }
+    let available = match super::http_client().get(NEOFORGE_VERSIONS_API).send().await {
+        Ok(response) if response.status().is_success() => response.json::<NeoForgeVersions>().await.ok(),
+        _ => None,
+    };
+    let Some(NeoForgeVersions { versions }) = available else {
+        log::warn!("Could not fetch NeoForge versions, installing {} as given", short);
+        return Ok(short);
+    };
+
+    if versions.contains(&short) {
+        if short != requested.trim() {
+            log::info!("Resolved NeoForge version {} to {}", requested, short);
+        }
+        return Ok(short);
+    }
+
+    let newest_first: Vec<String> = versions.into_iter().rev().collect();
+    let nearby = super::versions::nearby_versions(&short, &newest_first, 5);
+    Err(format!(
+        "NeoForge {} doesn't exist. Closest versions: {}",
+        requested,
+        if nearby.is_empty() { "none found".to_string() } else { nearby.join(", ") }
+    )
+    .into())
+}
+
 /// Download Forge installer and run it
 pub async fn install_forge(
     instance: &Instance,
     forge_version: &str,
+    progress: Option<StepProgress<'_>>,
 ) -> Result<ForgeVersionInfo, Box<dyn Error + Send + Sync>> {
     let mc_version = &instance.version_id;
+    let forge_version = resolve_forge_version(mc_version, forge_version).await?;
+    let forge_version = forge_version.as_str();
     // Forge URL patterns are nightmare. Older versions (like 1.8.9) often use {mc}-{forge}-{mc}
     // while newer ones use {mc}-{forge}.
     
@@ -88,6 +288,9 @@ pub async fn install_forge(
     let installer_path = temp_dir.join(format!("forge-{}-{}-installer.jar", mc_version, forge_version));
     
     // Download installer
+    if let Some(report) = progress {
+        report("Downloading Forge installer...", 0.0);
+    }
     let client = super::http_client();
 
     let mut response = None;
@@ -111,7 +314,7 @@ pub async fn install_forge(
 
     let response = response.ok_or_else(|| format!("Failed to download Forge installer: {}", last_error))?;
     
-    let bytes = response.bytes().await?;
+    let bytes = crate::minecraft::downloader::read_body_throttled(response).await?;
     log::info!("Downloaded Forge installer ({} bytes)", bytes.len());
     fs::write(&installer_path, &bytes)?;
     
@@ -144,9 +347,8 @@ pub async fn install_forge(
     command.creation_flags(CREATE_NO_WINDOW);
     
     let installer_timeout = Duration::from_secs(600);
-    let output_status = tokio::task::spawn_blocking(move || run_command_with_timeout(command, installer_timeout))
-        .await
-        .map_err(|e| format!("Failed to join Forge installer task: {}", e))??;
+    let total_steps = count_installer_steps(&installer_path);
+    let output_status = run_installer_with_progress(command, installer_timeout, "Forge", total_steps, progress).await?;
     
     if !output_status.success() {
         // Fallback for older installers (like 1.8.9) that don't support --installClient
@@ -254,11 +456,379 @@ fn handle_legacy_forge_installer(
     Ok(version_id.to_string())
 }
 
+// ----------
+// Installer processors
+// Description: Runs a modern (spec 1) installer's client processors directly instead of
+//              through the installer's own CLI, so each step can report progress
+// ----------
+#[derive(Debug, Deserialize)]
+struct InstallProfile {
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    json: Option<String>,
+    #[serde(default)]
+    data: std::collections::HashMap<String, InstallDataEntry>,
+    #[serde(default)]
+    processors: Vec<InstallProcessor>,
+    #[serde(default)]
+    libraries: Vec<InstallLibrary>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstallDataEntry {
+    client: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstallProcessor {
+    jar: String,
+    #[serde(default)]
+    classpath: Vec<String>,
+    #[serde(default)]
+    args: Vec<String>,
+    #[serde(default)]
+    outputs: std::collections::HashMap<String, String>,
+    #[serde(default)]
+    sides: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstallLibrary {
+    name: String,
+    #[serde(default)]
+    downloads: Option<InstallLibraryDownloads>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstallLibraryDownloads {
+    artifact: Option<InstallArtifact>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstallArtifact {
+    #[serde(default)]
+    path: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    sha1: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InstallVersionJson {
+    id: String,
+    #[serde(default)]
+    libraries: Vec<InstallLibrary>,
+}
+
+/// Convert `group:artifact:version[:classifier][@ext]` to a path relative to the libraries dir
+fn maven_artifact_path(coordinate: &str) -> Option<String> {
+    let (coords, ext) = match coordinate.split_once('@') {
+        Some((coords, ext)) => (coords, ext),
+        None => (coordinate, "jar"),
+    };
+    let parts: Vec<&str> = coords.split(':').collect();
+    if parts.len() < 3 {
+        return None;
+    }
+    let (group, artifact, version) = (parts[0].replace('.', "/"), parts[1], parts[2]);
+    let file_name = match parts.get(3) {
+        Some(classifier) => format!("{}-{}-{}.{}", artifact, version, classifier, ext),
+        None => format!("{}-{}.{}", artifact, version, ext),
+    };
+    Some(format!("{}/{}/{}/{}", group, artifact, version, file_name))
+}
+
+fn read_installer_entry(archive: &mut zip::ZipArchive<fs::File>, name: &str) -> Result<Vec<u8>, String> {
+    use std::io::Read;
+    let mut entry = archive
+        .by_name(name.trim_start_matches('/'))
+        .map_err(|_| format!("Installer is missing {}", name))?;
+    let mut bytes = Vec::new();
+    entry.read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
+
+/// Main-Class from a jar manifest
+fn jar_main_class(jar_path: &Path) -> Result<String, String> {
+    let file = fs::File::open(jar_path).map_err(|e| format!("Failed to open {}: {}", jar_path.display(), e))?;
+    let mut archive = zip::ZipArchive::new(file).map_err(|e| e.to_string())?;
+    let manifest = read_installer_entry(&mut archive, "META-INF/MANIFEST.MF")?;
+    String::from_utf8_lossy(&manifest)
+        .lines()
+        .find_map(|line| line.strip_prefix("Main-Class:").map(|class| class.trim().to_string()))
+        .ok_or_else(|| format!("{} has no Main-Class", jar_path.display()))
+}
+
+/// Fetch the artifacts of installer libraries. Those with a URL are downloaded; those
+/// without one are bundled under `maven/` in the installer, except the ones a processor
+/// writes, which are listed in `processor_outputs`.
+async fn download_installer_libraries(
+    libraries: &[InstallLibrary],
+    libraries_dir: &Path,
+    archive: &mut zip::ZipArchive<fs::File>,
+    processor_outputs: &[PathBuf],
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    for library in libraries {
+        let Some(artifact) = library.downloads.as_ref().and_then(|d| d.artifact.as_ref()) else {
+            continue;
+        };
+        let relative = artifact
+            .path
+            .clone()
+            .or_else(|| maven_artifact_path(&library.name))
+            .ok_or_else(|| format!("Invalid library name {}", library.name))?;
+        let target = libraries_dir.join(&relative);
+
+        if let Some(url) = artifact.url.as_deref().filter(|url| !url.is_empty()) {
+            crate::minecraft::downloader::download_file(url, &target, artifact.sha1.as_deref())
+                .await
+                .map_err(|e| format!("Failed to download {}: {}", library.name, e))?;
+            continue;
+        }
+        if processor_outputs.iter().any(|output| output.as_path() == target.as_path()) {
+            continue;
+        }
+        let up_to_date = match artifact.sha1.as_deref() {
+            Some(sha1) => crate::minecraft::downloader::file_sha1(&target).as_deref() == Some(sha1),
+            None => target.exists(),
+        };
+        if up_to_date {
+            continue;
+        }
+        let bytes = read_installer_entry(archive, &format!("maven/{}", relative))?;
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, bytes)?;
+    }
+    Ok(())
+}
+
+/// Removes the installer work folder however the install ends, so a CLI fallback starts clean
+struct WorkDirGuard(PathBuf);
+
+impl Drop for WorkDirGuard {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Install a modern installer jar by downloading its libraries, writing its version json
+/// and running its client processors with the given Java.
+async fn run_installer_processors(
+    installer_path: &Path,
+    java_path: &Path,
+    minecraft_version: &str,
+    loader_label: &str,
+    progress: Option<StepProgress<'_>>,
+) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let report = |stage: String, fraction: f32| {
+        if let Some(report) = progress {
+            report(&stage, fraction);
+        }
+    };
+
+    let minecraft_dir = crate::minecraft::downloader::get_minecraft_dir();
+    let libraries_dir = crate::minecraft::downloader::get_libraries_dir();
+    let versions_dir = crate::minecraft::downloader::get_versions_dir();
+    let minecraft_jar = versions_dir.join(minecraft_version).join(format!("{}.jar", minecraft_version));
+    if !minecraft_jar.exists() {
+        return Err(format!("Minecraft {} client jar is not downloaded yet", minecraft_version).into());
+    }
+
+    let mut archive = zip::ZipArchive::new(fs::File::open(installer_path)?)?;
+    let profile: InstallProfile = serde_json::from_slice(&read_installer_entry(&mut archive, "install_profile.json")?)?;
+    let version_json_name = profile.json.clone().unwrap_or_else(|| "/version.json".to_string());
+    let version_json_bytes = read_installer_entry(&mut archive, &version_json_name)?;
+    let version_json: InstallVersionJson = serde_json::from_slice(&version_json_bytes)?;
+    let version_id = profile.version.clone().unwrap_or_else(|| version_json.id.clone());
+
+    // Data entries that point inside the installer are extracted next to it
+    let work_dir = std::env::temp_dir().join(format!("palethea-installer-{}", version_id));
+    let _ = fs::remove_dir_all(&work_dir);
+    fs::create_dir_all(&work_dir)?;
+    let _work_dir_guard = WorkDirGuard(work_dir.clone());
+    let mut variables: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+    for (key, entry) in &profile.data {
+        let value = &entry.client;
+        let resolved = if value.starts_with('[') && value.ends_with(']') {
+            let relative = maven_artifact_path(&value[1..value.len() - 1])
+                .ok_or_else(|| format!("Invalid artifact {} in installer data", value))?;
+            libraries_dir.join(relative).to_string_lossy().to_string()
+        } else if value.starts_with('/') {
+            let target = work_dir.join(value.trim_start_matches('/'));
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&target, read_installer_entry(&mut archive, value)?)?;
+            target.to_string_lossy().to_string()
+        } else {
+            value.trim_matches('\'').to_string()
+        };
+        variables.insert(key.clone(), resolved);
+    }
+    variables.insert("SIDE".to_string(), "client".to_string());
+    variables.insert("MINECRAFT_JAR".to_string(), minecraft_jar.to_string_lossy().to_string());
+    variables.insert("MINECRAFT_VERSION".to_string(), minecraft_version.to_string());
+    variables.insert("ROOT".to_string(), minecraft_dir.to_string_lossy().to_string());
+    variables.insert("INSTALLER".to_string(), installer_path.to_string_lossy().to_string());
+    variables.insert("LIBRARY_DIR".to_string(), libraries_dir.to_string_lossy().to_string());
+
+    let resolve = |arg: &str| -> Result<String, String> {
+        if arg.starts_with('[') && arg.ends_with(']') {
+            let relative = maven_artifact_path(&arg[1..arg.len() - 1])
+                .ok_or_else(|| format!("Invalid artifact {} in processor arguments", arg))?;
+            return Ok(libraries_dir.join(relative).to_string_lossy().to_string());
+        }
+        if arg.starts_with('\'') && arg.ends_with('\'') && arg.len() >= 2 {
+            return Ok(arg[1..arg.len() - 1].to_string());
+        }
+        let mut resolved = arg.to_string();
+        for (key, value) in &variables {
+            resolved = resolved.replace(&format!("{{{}}}", key), value);
+        }
+        Ok(resolved)
+    };
+
+    let processors: Vec<&InstallProcessor> = profile
+        .processors
+        .iter()
+        .filter(|p| p.sides.as_ref().map_or(true, |sides| sides.iter().any(|side| side == "client")))
+        .collect();
+    let processor_outputs: Vec<PathBuf> = processors
+        .iter()
+        .flat_map(|processor| processor.outputs.keys())
+        .map(|path| resolve(path).map(PathBuf::from))
+        .collect::<Result<_, String>>()?;
+
+    report(format!("Installing {}: downloading libraries...", loader_label), 0.1);
+    download_installer_libraries(&profile.libraries, &libraries_dir, &mut archive, &processor_outputs).await?;
+    download_installer_libraries(&version_json.libraries, &libraries_dir, &mut archive, &processor_outputs).await?;
+    drop(archive);
+
+    let separator = if cfg!(target_os = "windows") { ";" } else { ":" };
+
+    for (index, processor) in processors.iter().enumerate() {
+        let fraction = 0.2 + 0.75 * (index as f32 / processors.len().max(1) as f32);
+        report(
+            format!("Installing {}: running processor {}/{}...", loader_label, index + 1, processors.len()),
+            fraction,
+        );
+
+        // Skip processors whose outputs are already in place from an earlier install
+        let outputs: Vec<(String, String)> = processor
+            .outputs
+            .iter()
+            .map(|(path, sha)| Ok((resolve(path)?, resolve(sha)?)))
+            .collect::<Result<_, String>>()?;
+        if !outputs.is_empty()
+            && outputs.iter().all(|(path, sha)| {
+                crate::minecraft::downloader::file_sha1(Path::new(path)).as_deref() == Some(sha.as_str())
+            })
+        {
+            continue;
+        }
+
+        let jar_path = libraries_dir.join(
+            maven_artifact_path(&processor.jar).ok_or_else(|| format!("Invalid processor jar {}", processor.jar))?,
+        );
+        let main_class = jar_main_class(&jar_path)?;
+        let mut classpath = vec![jar_path.to_string_lossy().to_string()];
+        for entry in &processor.classpath {
+            let relative = maven_artifact_path(entry).ok_or_else(|| format!("Invalid classpath entry {}", entry))?;
+            classpath.push(libraries_dir.join(relative).to_string_lossy().to_string());
+        }
+        let args = processor
+            .args
+            .iter()
+            .map(|arg| resolve(arg))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        log::info!("Running {} processor {} ({})", loader_label, processor.jar, main_class);
+        let mut command = Command::new(java_path);
+        command
+            .arg("-cp")
+            .arg(classpath.join(separator))
+            .arg(&main_class)
+            .args(&args)
+            .current_dir(&work_dir);
+        #[cfg(target_os = "windows")]
+        command.creation_flags(CREATE_NO_WINDOW);
+
+        let status = tokio::task::spawn_blocking(move || run_command_with_timeout(command, Duration::from_secs(600), None))
+            .await
+            .map_err(|e| format!("Failed to join processor task: {}", e))??;
+        if !status.success() {
+            return Err(format!("{} processor {} failed with {}", loader_label, processor.jar, status).into());
+        }
+
+        for (path, sha) in &outputs {
+            let actual = crate::minecraft::downloader::file_sha1(Path::new(path));
+            if actual.as_deref() != Some(sha.as_str()) {
+                return Err(format!("{} processor {} produced an unexpected {}", loader_label, processor.jar, path).into());
+            }
+        }
+    }
+
+    let version_dir = versions_dir.join(&version_id);
+    fs::create_dir_all(&version_dir)?;
+    fs::write(version_dir.join(format!("{}.json", version_id)), &version_json_bytes)?;
+
+    report(format!("Installing {}: finishing up...", loader_label), 0.98);
+    Ok(version_id)
+}
+
+/// Run the NeoForge installer jar in headless client mode
+async fn run_neoforge_installer_cli(
+    installer_path: &Path,
+    java_path: &Path,
+    progress: Option<StepProgress<'_>>,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let minecraft_dir = crate::minecraft::downloader::get_minecraft_dir();
+
+    // Ensure launcher_profiles.json exists (NeoForge installer requirement)
+    let profiles_path = minecraft_dir.join("launcher_profiles.json");
+    if !profiles_path.exists() {
+        let _ = fs::write(&profiles_path, "{\"profiles\":{}}");
+    }
+
+    // Run installer in client mode
+    let neoforge_cmd_str = format!("\"{}\" -jar \"{}\" --installClient \"{}\"", java_path.display(), installer_path.display(), minecraft_dir.display());
+    println!("\n[DEBUG] Running NeoForge installer:\n{}\n", neoforge_cmd_str);
+    log::info!("Running NeoForge installer: {}", neoforge_cmd_str);
+
+    let mut neoforge_cmd = Command::new(java_path);
+    neoforge_cmd.args(&[
+        "-jar", 
+        installer_path.to_str().ok_or("Invalid path")?, 
+        "--installClient", 
+        minecraft_dir.to_str().ok_or("Invalid path")?
+    ]);
+
+    #[cfg(target_os = "windows")]
+    neoforge_cmd.creation_flags(CREATE_NO_WINDOW);
+
+    let installer_timeout = Duration::from_secs(600);
+    let total_steps = count_installer_steps(installer_path);
+    let output_status = run_installer_with_progress(neoforge_cmd, installer_timeout, "NeoForge", total_steps, progress).await?;
+
+    if !output_status.success() {
+        return Err("NeoForge installer failed. See logs for details.".to_string().into());
+    }
+
+    Ok(())
+}
+
 /// Download NeoForge installer and run it
 pub async fn install_neoforge(
     instance: &Instance,
     neoforge_version: &str,
+    progress: Option<StepProgress<'_>>,
 ) -> Result<ForgeVersionInfo, Box<dyn Error + Send + Sync>> {
+    let neoforge_version = resolve_neoforge_version(&instance.version_id, neoforge_version).await?;
+    let neoforge_version = neoforge_version.as_str();
     let installer_url = format!(
         "https://maven.neoforged.net/releases/net/neoforged/neoforge/{}/neoforge-{}-installer.jar",
         neoforge_version, neoforge_version
@@ -270,6 +840,9 @@ pub async fn install_neoforge(
     
     // Download installer
     log::info!("Downloading NeoForge installer from {}", installer_url);
+    if let Some(report) = progress {
+        report("Downloading NeoForge installer...", 0.0);
+    }
     let client = super::http_client();
     let response = client
         .get(&installer_url)
@@ -281,45 +854,22 @@ pub async fn install_neoforge(
         return Err(format!("Failed to download NeoForge installer: {}. URL: {}", response.status(), installer_url).into());
     }
     
-    let bytes = response.bytes().await?;
+    let bytes = crate::minecraft::downloader::read_body_throttled(response).await?;
     fs::write(&installer_path, &bytes)?;
     
     // Find Java
     let java_path = launcher::find_java()
         .ok_or("Java not found. Please install Java to install NeoForge.")?;
     
-    let minecraft_dir = crate::minecraft::downloader::get_minecraft_dir();
-    
-    // Ensure launcher_profiles.json exists (NeoForge installer requirement)
-    let profiles_path = minecraft_dir.join("launcher_profiles.json");
-    if !profiles_path.exists() {
-        let _ = fs::write(&profiles_path, "{\"profiles\":{}}");
-    }
-    
-    // Run installer in client mode
-    let neoforge_cmd_str = format!("\"{}\" -jar \"{}\" --installClient \"{}\"", java_path.display(), installer_path.display(), minecraft_dir.display());
-    println!("\n[DEBUG] Running NeoForge installer:\n{}\n", neoforge_cmd_str);
-    log::info!("Running NeoForge installer: {}", neoforge_cmd_str);
-    
-    let mut neoforge_cmd = Command::new(&java_path);
-    neoforge_cmd.args(&[
-        "-jar", 
-        installer_path.to_str().ok_or("Invalid path")?, 
-        "--installClient", 
-        minecraft_dir.to_str().ok_or("Invalid path")?
-    ]);
-    
-    #[cfg(target_os = "windows")]
-    neoforge_cmd.creation_flags(CREATE_NO_WINDOW);
-    
-    let installer_timeout = Duration::from_secs(600);
-    let output_status = tokio::task::spawn_blocking(move || run_command_with_timeout(neoforge_cmd, installer_timeout))
-        .await
-        .map_err(|e| format!("Failed to join NeoForge installer task: {}", e))??;
-    
-    if !output_status.success() {
-        return Err("NeoForge installer failed. See logs for details.".to_string().into());
-    }
+    // Run the processors ourselves for per-step progress; fall back to the installer's own CLI
+    let version_id = match run_installer_processors(&installer_path, &java_path, &instance.version_id, "NeoForge", progress).await {
+        Ok(version_id) => Some(version_id),
+        Err(e) => {
+            log::warn!("Direct NeoForge install failed ({}), falling back to the installer CLI", e);
+            run_neoforge_installer_cli(&installer_path, &java_path, progress).await?;
+            None
+        }
+    };
     
     // Clean up installer
     let _ = fs::remove_file(&installer_path);
@@ -329,7 +879,7 @@ pub async fn install_neoforge(
         forge_version: neoforge_version.to_string(),
         minecraft_version: instance.version_id.to_string(),
         main_class: String::new(),
-        version_id: None,
+        version_id,
         libraries: Vec::new(),
     };
     
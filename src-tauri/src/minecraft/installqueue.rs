@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use tauri::{AppHandle, Emitter};
+
+// ----------
+// InstallTask
+// Description: One entry of the content install queue, pushed to the frontend as
+//              `install-queue-progress` whenever any task changes
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InstallTask {
+    pub task_id: String,
+    pub label: String,
+    pub instance_id: Option<String>,
+    pub status: String, // "queued", "downloading", "done", "failed", "cancelled"
+    pub downloaded_bytes: u64,
+    pub total_bytes: Option<u64>,
+    pub error: Option<String>,
+}
+
+struct QueueEntry {
+    task: InstallTask,
+    cancel: Arc<AtomicBool>,
+}
+
+static INSTALL_QUEUE: LazyLock<Mutex<Vec<QueueEntry>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+static NEXT_TASK_ID: AtomicU64 = AtomicU64::new(1);
+
+fn is_finished(status: &str) -> bool {
+    matches!(status, "done" | "failed" | "cancelled")
+}
+
+fn emit_queue(app_handle: &AppHandle) {
+    let _ = app_handle.emit("install-queue-progress", snapshot());
+}
+
+/// Add a task to the queue. Modpack installs pass the instance id as `task_id` so their
+/// download loops can look the flag up without threading it through.
+pub fn register(app_handle: &AppHandle, label: String, instance_id: Option<String>, task_id: Option<String>) -> String {
+    let task_id = task_id.unwrap_or_else(|| format!("task-{}", NEXT_TASK_ID.fetch_add(1, Ordering::SeqCst)));
+    if let Ok(mut queue) = INSTALL_QUEUE.lock() {
+        // Start a fresh list once everything from the previous batch has settled
+        if queue.iter().all(|entry| is_finished(&entry.task.status)) {
+            queue.clear();
+        }
+        queue.retain(|entry| entry.task.task_id != task_id);
+        queue.push(QueueEntry {
+            task: InstallTask {
+                task_id: task_id.clone(),
+                label,
+                instance_id,
+                status: "queued".to_string(),
+                downloaded_bytes: 0,
+                total_bytes: None,
+                error: None,
+            },
+            cancel: Arc::new(AtomicBool::new(false)),
+        });
+    }
+    emit_queue(app_handle);
+    task_id
+}
+
+pub fn set_status(app_handle: &AppHandle, task_id: &str, status: &str, error: Option<String>) {
+    if let Ok(mut queue) = INSTALL_QUEUE.lock() {
+        if let Some(entry) = queue.iter_mut().find(|entry| entry.task.task_id == task_id) {
+            entry.task.status = status.to_string();
+            entry.task.error = error;
+        }
+    }
+    emit_queue(app_handle);
+}
+
+pub fn report_bytes(app_handle: &AppHandle, task_id: &str, downloaded: u64, total: Option<u64>) {
+    if let Ok(mut queue) = INSTALL_QUEUE.lock() {
+        if let Some(entry) = queue.iter_mut().find(|entry| entry.task.task_id == task_id) {
+            entry.task.downloaded_bytes = downloaded;
+            entry.task.total_bytes = total;
+        }
+    }
+    emit_queue(app_handle);
+}
+
+/// Flag a task as cancelled. Running downloads notice on their next chunk.
+pub fn cancel(app_handle: &AppHandle, task_id: &str) -> bool {
+    let found = match INSTALL_QUEUE.lock() {
+        Ok(mut queue) => match queue
+            .iter_mut()
+            .find(|entry| entry.task.task_id == task_id && !is_finished(&entry.task.status))
+        {
+            Some(entry) => {
+                entry.cancel.store(true, Ordering::SeqCst);
+                if entry.task.status == "queued" {
+                    entry.task.status = "cancelled".to_string();
+                }
+                true
+            }
+            None => false,
+        },
+        Err(_) => false,
+    };
+    if found {
+        emit_queue(app_handle);
+    }
+    found
+}
+
+pub fn is_cancelled(task_id: &str) -> bool {
+    INSTALL_QUEUE
+        .lock()
+        .map(|queue| {
+            queue
+                .iter()
+                .any(|entry| entry.task.task_id == task_id && entry.cancel.load(Ordering::SeqCst))
+        })
+        .unwrap_or(false)
+}
+
+pub fn snapshot() -> Vec<InstallTask> {
+    INSTALL_QUEUE
+        .lock()
+        .map(|queue| queue.iter().map(|entry| entry.task.clone()).collect())
+        .unwrap_or_default()
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 use crate::minecraft::downloader::{get_instances_dir, get_minecraft_dir};
@@ -20,15 +20,24 @@ const CREATE_NO_WINDOW: u32 = 0x08000000;
 pub enum ModLoader {
     Vanilla,
     Fabric,
+    Quilt,
     Forge,
     NeoForge,
 }
 
+impl ModLoader {
+    /// Lowercase name used in folder names and loader tags, e.g. "neoforge"
+    pub fn slug(&self) -> String {
+        self.to_string().to_lowercase()
+    }
+}
+
 impl std::fmt::Display for ModLoader {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ModLoader::Vanilla => write!(f, "Vanilla"),
             ModLoader::Fabric => write!(f, "Fabric"),
+            ModLoader::Quilt => write!(f, "Quilt"),
             ModLoader::Forge => write!(f, "Forge"),
             ModLoader::NeoForge => write!(f, "NeoForge"),
         }
@@ -41,10 +50,6 @@ impl Default for ModLoader {
     }
 }
 
-fn default_true() -> bool {
-    true
-}
-
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Instance {
     pub id: String,
@@ -77,7 +82,7 @@ pub struct Instance {
     pub category: Option<String>,
     #[serde(default)]
     pub preferred_account: Option<String>,
-    #[serde(default = "default_true")]
+    #[serde(default = "crate::minecraft::default_true")]
     pub check_mod_updates_on_launch: bool,
     #[serde(default)]
     pub modpack_provider: Option<String>,
@@ -85,12 +90,47 @@ pub struct Instance {
     pub modpack_project_id: Option<String>,
     #[serde(default)]
     pub modpack_version_id: Option<String>,
+    // Pack archive picked when the Modrinth version has several files; verify, repair and
+    // update read the same one instead of choosing again
+    #[serde(default)]
+    pub modpack_file_name: Option<String>,
     #[serde(default)]
     pub modpack_title: Option<String>,
     #[serde(default)]
     pub modpack_author: Option<String>,
     #[serde(default)]
     pub modpack_url: Option<String>,
+    #[serde(default)]
+    pub config_locked: bool,
+    #[serde(default)]
+    pub jvm_preset: Option<String>,
+    #[serde(default)]
+    pub auto_backup_on_launch: bool,
+    #[serde(default)]
+    pub auto_backup_keep: Option<u32>,
+    // None leaves the in-game choice alone; Some forces fullscreen on or off at launch
+    #[serde(default)]
+    pub start_fullscreen: Option<bool>,
+    // Program the game is started through, e.g. "gamemoderun" or "mangohud prime-run"
+    #[serde(default)]
+    pub wrapper_command: Option<String>,
+    // Launch with the demo feature flags; refused for Microsoft accounts at launch
+    #[serde(default)]
+    pub demo_mode: bool,
+    // Maven coordinates ("group:artifact" or with version/classifier) left off the classpath at launch
+    #[serde(default)]
+    pub excluded_libraries: Vec<String>,
+    // Real location of the game directory after it was moved to another drive; the default
+    // location is then a symlink to it
+    #[serde(default)]
+    pub relocated_game_dir: Option<String>,
+    // Position in the user's manual ordering; instances without one are listed after those with one
+    #[serde(default)]
+    pub order: Option<u32>,
+    // Keep each loader's mods apart: mods/ holds the current loader's, the others wait in
+    // mods-<loader>/ until the instance switches back
+    #[serde(default)]
+    pub separate_loader_mods: bool,
 }
 
 impl Instance {
@@ -121,9 +161,21 @@ impl Instance {
             modpack_provider: None,
             modpack_project_id: None,
             modpack_version_id: None,
+            modpack_file_name: None,
             modpack_title: None,
             modpack_author: None,
             modpack_url: None,
+            config_locked: false,
+            jvm_preset: None,
+            auto_backup_on_launch: false,
+            auto_backup_keep: None,
+            start_fullscreen: None,
+            wrapper_command: None,
+            demo_mode: false,
+            excluded_libraries: Vec::new(),
+            relocated_game_dir: None,
+            order: None,
+            separate_loader_mods: false,
         }
     }
     
@@ -137,6 +189,112 @@ impl Instance {
             None => self.get_directory().join("minecraft"),
         }
     }
+
+    /// Whether the logo was picked by the user rather than the stock default or a loader badge
+    pub fn has_custom_logo(&self) -> bool {
+        match self.logo_filename.as_deref() {
+            None | Some("minecraft_logo.png") => false,
+            Some(name) => !name.starts_with(crate::minecraft::downloader::LOADER_BADGE_PREFIX),
+        }
+    }
+
+    /// Swap a default logo for the badge of the instance's mod loader
+    pub fn apply_loader_badge(&mut self) {
+        if self.has_custom_logo() {
+            return;
+        }
+        match crate::minecraft::downloader::loader_badge_logo(&self.mod_loader) {
+            Some(badge) => self.logo_filename = Some(badge),
+            None if self.mod_loader == ModLoader::Vanilla => self.logo_filename = Some("minecraft_logo.png".to_string()),
+            None => {}
+        }
+    }
+
+    /// Refuse mutating operations while the instance is locked
+    pub fn ensure_unlocked(&self) -> Result<(), String> {
+        if self.config_locked {
+            return Err(format!(
+                "Instance \"{}\" is locked. Unlock it before making changes.",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+}
+
+// Instance names end up in export file names and desktop shortcuts, so keep them portable
+const MAX_INSTANCE_NAME_CHARS: usize = 64;
+const RESERVED_NAME_CHARS: [char; 9] = ['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+const RESERVED_DEVICE_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9",
+    "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Drop control and reserved characters, collapse whitespace and trailing dots
+fn clean_instance_name(name: &str) -> String {
+    let filtered: String = name
+        .chars()
+        .filter(|c| !c.is_control() && !RESERVED_NAME_CHARS.contains(c))
+        .collect();
+    let collapsed = filtered.split_whitespace().collect::<Vec<_>>().join(" ");
+    collapsed.trim_end_matches(['.', ' ']).to_string()
+}
+
+fn is_reserved_device_name(name: &str) -> bool {
+    RESERVED_DEVICE_NAMES.iter().any(|device| device.eq_ignore_ascii_case(name))
+}
+
+/// Normalize a name the user typed, or explain why it can't be used
+pub fn normalize_instance_name(name: &str) -> Result<String, String> {
+    let cleaned = clean_instance_name(name);
+    if cleaned.is_empty() {
+        return Err(if name.trim().is_empty() {
+            "Instance name cannot be empty".to_string()
+        } else {
+            "Instance name needs at least one character besides / \\ : * ? \" < > | and dots".to_string()
+        });
+    }
+    if cleaned.chars().count() > MAX_INSTANCE_NAME_CHARS {
+        return Err(format!("Instance name must be {} characters or fewer", MAX_INSTANCE_NAME_CHARS));
+    }
+    if is_reserved_device_name(&cleaned) {
+        return Err(format!("\"{}\" is reserved by Windows and can't be an instance name", cleaned));
+    }
+    Ok(cleaned)
+}
+
+/// Like normalize_instance_name but never fails: for names taken from imported packs or
+/// generated by the launcher, over-long names are cut and unusable ones replaced by `fallback`
+pub fn fit_instance_name(name: &str, fallback: &str) -> String {
+    let cleaned: String = clean_instance_name(name).chars().take(MAX_INSTANCE_NAME_CHARS).collect();
+    let cleaned = cleaned.trim_end_matches(['.', ' ']).to_string();
+    if cleaned.is_empty() || is_reserved_device_name(&cleaned) {
+        fallback.to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// Append " (2)", " (3)", ... until no other instance has the name
+fn unique_instance_name(name: String, instances: &[Instance]) -> String {
+    let taken = |candidate: &str| instances.iter().any(|i| i.name.eq_ignore_ascii_case(candidate));
+    if !taken(&name) {
+        return name;
+    }
+    (2..)
+        .map(|n| {
+            let suffix = format!(" ({})", n);
+            let base: String = name.chars().take(MAX_INSTANCE_NAME_CHARS - suffix.len()).collect();
+            format!("{}{}", base.trim_end(), suffix)
+        })
+        .find(|candidate| !taken(candidate))
+        .unwrap_or(name)
+}
+
+/// `unique_instance_name` for renaming an existing instance, which must not count itself
+pub fn unique_rename(instance_id: &str, name: String) -> Result<String, String> {
+    let others: Vec<Instance> = load_instances()?.into_iter().filter(|i| i.id != instance_id).collect();
+    Ok(unique_instance_name(name, &others))
 }
 
 fn chrono_now() -> String {
@@ -174,6 +332,34 @@ pub fn load_instances() -> Result<Vec<Instance>, String> {
     Ok(config.instances)
 }
 
+/// Load all instances in the user's manual order, with unordered (new) instances appended
+/// in the order they were created
+pub fn load_instances_ordered() -> Result<Vec<Instance>, String> {
+    let mut instances = load_instances()?;
+    instances.sort_by_key(|instance| instance.order.unwrap_or(u32::MAX));
+    Ok(instances)
+}
+
+/// Persist a manual ordering. Listed ids come first in the given order; instances missing from
+/// the list keep their relative order after them. Unknown ids are ignored.
+pub fn reorder_instances(ordered_ids: &[String]) -> Result<Vec<Instance>, String> {
+    let mut instances = load_instances_ordered()?;
+    let listed = ordered_ids.len() as u32;
+    let mut unlisted = 0;
+    for instance in instances.iter_mut() {
+        match ordered_ids.iter().position(|id| *id == instance.id) {
+            Some(pos) => instance.order = Some(pos as u32),
+            None => {
+                instance.order = Some(listed + unlisted);
+                unlisted += 1;
+            }
+        }
+    }
+    instances.sort_by_key(|instance| instance.order);
+    save_instances(&instances)?;
+    Ok(instances)
+}
+
 /// Save instances to config
 pub fn save_instances(instances: &[Instance]) -> Result<(), String> {
     let config_path = get_instances_config_path();
@@ -200,8 +386,16 @@ pub fn save_instances(instances: &[Instance]) -> Result<(), String> {
 /// Create a new instance
 pub fn create_instance(name: String, version_id: String) -> Result<Instance, String> {
     let mut instances = load_instances()?;
+    let name = unique_instance_name(normalize_instance_name(&name)?, &instances);
     
-    let instance = Instance::new(name, version_id);
+    let mut instance = Instance::new(name, version_id);
+    // Never reuse a folder left behind by something else
+    while instance.get_directory().exists() {
+        instance.id = Uuid::new_v4().to_string();
+    }
+    let (memory_min, memory_max) = crate::minecraft::settings::get_default_instance_memory();
+    instance.memory_min = Some(memory_min);
+    instance.memory_max = Some(memory_max);
     
     // Create instance directory
     let instance_dir = instance.get_directory();
@@ -224,11 +418,24 @@ pub async fn delete_instance(instance_id: &str) -> Result<(), String> {
     let mut instances = load_instances()?;
     
     // Find and remove the instance
-    let initial_len = instances.len();
-    instances.retain(|i| i.id != instance_id);
+    let pos = instances.iter().position(|i| i.id == instance_id)
+        .ok_or("Instance not found")?;
+    let removed = instances.remove(pos);
     
-    if instances.len() == initial_len {
-        return Err("Instance not found".to_string());
+    // Relocated data lives behind a symlink that remove_dir_all would only unlink
+    if let Some(real_dir) = removed.relocated_game_dir.as_deref().map(PathBuf::from) {
+        let link = removed.get_directory().join("minecraft");
+        // Windows junctions read back with a `\??\` prefix, so compare where both paths resolve to
+        let is_link = fs::symlink_metadata(&link).map(|m| m.file_type().is_symlink()).unwrap_or(false);
+        let points_at_data = matches!(
+            (fs::canonicalize(&link), fs::canonicalize(&real_dir)),
+            (Ok(target), Ok(real)) if target == real
+        );
+        if is_link && points_at_data && real_dir.is_dir() {
+            tokio::fs::remove_dir_all(&real_dir)
+                .await
+                .map_err(|e| format!("Failed to delete relocated game directory: {}", e))?;
+        }
     }
     
     // Delete instance directory
@@ -257,6 +464,44 @@ pub fn update_instance(instance: Instance) -> Result<Instance, String> {
     Ok(instance)
 }
 
+/// Lock or unlock an instance against accidental edits
+pub fn set_instance_lock(instance_id: &str, locked: bool) -> Result<Instance, String> {
+    let mut instances = load_instances()?;
+
+    let instance = instances.iter_mut()
+        .find(|i| i.id == instance_id)
+        .ok_or("Instance not found")?;
+    instance.config_locked = locked;
+    let updated = instance.clone();
+
+    save_instances(&instances)?;
+
+    Ok(updated)
+}
+
+/// Add or remove a library from the instance's classpath exclusions
+pub fn set_excluded_library(instance_id: &str, identity: &str, excluded: bool) -> Result<Instance, String> {
+    let identity = identity.trim();
+    if identity.split(':').filter(|part| !part.is_empty()).count() < 2 {
+        return Err(format!("\"{}\" is not a maven coordinate like group:artifact", identity));
+    }
+    let mut instances = load_instances()?;
+
+    let instance = instances.iter_mut()
+        .find(|i| i.id == instance_id)
+        .ok_or("Instance not found")?;
+    instance.ensure_unlocked()?;
+    instance.excluded_libraries.retain(|entry| entry != identity);
+    if excluded {
+        instance.excluded_libraries.push(identity.to_string());
+    }
+    let updated = instance.clone();
+
+    save_instances(&instances)?;
+
+    Ok(updated)
+}
+
 /// Get a single instance by ID
 pub fn get_instance(instance_id: &str) -> Result<Instance, String> {
     let instances = load_instances()?;
@@ -271,6 +516,7 @@ pub fn get_instance(instance_id: &str) -> Result<Instance, String> {
 pub fn clone_instance(instance_id: &str, new_name: String) -> Result<Instance, String> {
     let source = get_instance(instance_id)?;
     let mut instances = load_instances()?;
+    let new_name = unique_instance_name(fit_instance_name(&new_name, &source.name), &instances);
     
     let new_id = Uuid::new_v4().to_string();
     let cloned = Instance {
@@ -299,9 +545,22 @@ pub fn clone_instance(instance_id: &str, new_name: String) -> Result<Instance, S
         modpack_provider: source.modpack_provider.clone(),
         modpack_project_id: source.modpack_project_id.clone(),
         modpack_version_id: source.modpack_version_id.clone(),
+        modpack_file_name: source.modpack_file_name.clone(),
         modpack_title: source.modpack_title.clone(),
         modpack_author: source.modpack_author.clone(),
         modpack_url: source.modpack_url.clone(),
+        config_locked: false,
+        jvm_preset: source.jvm_preset.clone(),
+        auto_backup_on_launch: source.auto_backup_on_launch,
+        auto_backup_keep: source.auto_backup_keep,
+        start_fullscreen: source.start_fullscreen,
+        wrapper_command: source.wrapper_command.clone(),
+        demo_mode: source.demo_mode,
+        excluded_libraries: source.excluded_libraries.clone(),
+        // The copy gets its own data under the instances folder
+        relocated_game_dir: None,
+        order: None,
+        separate_loader_mods: source.separate_loader_mods,
     };
     
     // Create new instance directory
@@ -392,9 +651,49 @@ pub struct GameSession {
     pub pid: Option<u32>,
     #[serde(default)]
     pub launch_username: Option<String>,
+    // Launcher process that started the game and credits its playtime on exit
+    #[serde(default)]
+    pub owner_pid: Option<u32>,
+    // Running-process key of the session; the file name is only a hash of it
+    #[serde(default)]
+    pub session_key: Option<String>,
 }
 
-fn get_session_file_path() -> PathBuf {
+// ----------
+// RecoveredSession
+// Description: A session record found at startup. `pid` is set when the
+//              game is still running; otherwise `credited_seconds` was added to playtime.
+//              `session_key` is the running-process key the record was written under.
+//              `owned_elsewhere` marks a game another launcher process (a `--launch` run)
+//              is still watching; that process credits its playtime, not this one.
+// ----------
+#[derive(Debug, Clone)]
+pub struct RecoveredSession {
+    pub session_key: String,
+    pub instance_id: String,
+    pub start_time: u64,
+    pub pid: Option<u32>,
+    pub credited_seconds: u64,
+    pub launch_username: Option<String>,
+    pub owned_elsewhere: bool,
+}
+
+// Guards every read and write of the session records
+static SESSION_FILE_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// One JSON record per running session, so concurrent launches and exits never race on a shared file
+fn get_sessions_dir() -> PathBuf {
+    get_minecraft_dir().join("sessions")
+}
+
+/// Session keys carry the launch username, which offline accounts leave unrestricted,
+/// so the record is named after a hash of the key rather than the key itself
+fn get_session_record_path(session_key: &str) -> PathBuf {
+    use sha1::{Digest, Sha1};
+    get_sessions_dir().join(format!("{:x}.json", Sha1::digest(session_key.as_bytes())))
+}
+
+fn get_legacy_sessions_file_path() -> PathBuf {
     get_minecraft_dir().join("active_sessions.json")
 }
 
@@ -402,79 +701,125 @@ fn get_legacy_session_file_path() -> PathBuf {
     get_minecraft_dir().join("active_session.json")
 }
 
-fn load_active_sessions() -> HashMap<String, GameSession> {
-    let path = get_session_file_path();
-    if path.exists() {
-        if let Ok(content) = fs::read_to_string(&path) {
-            if let Ok(mut sessions) = serde_json::from_str::<HashMap<String, GameSession>>(&content) {
-                sessions.retain(|_, s| !s.instance_id.is_empty());
-                return sessions;
-            }
+fn write_session_record(session_key: &str, mut session: GameSession) -> Result<(), String> {
+    fs::create_dir_all(get_sessions_dir())
+        .map_err(|e| format!("Failed to create sessions folder: {}", e))?;
+    session.session_key = Some(session_key.to_string());
+    let content = serde_json::to_string(&session)
+        .map_err(|e| format!("Failed to serialize session: {}", e))?;
+
+    // Write then rename so a crash mid-write never leaves a truncated record
+    let path = get_session_record_path(session_key);
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, content)
+        .map_err(|e| format!("Failed to write session file: {}", e))?;
+    fs::rename(&temp_path, &path)
+        .map_err(|e| format!("Failed to write session file: {}", e))
+}
+
+/// Move sessions from the old shared files into per-instance records
+fn migrate_legacy_sessions() {
+    let mut legacy = Vec::new();
+
+    if let Ok(content) = fs::read_to_string(get_legacy_sessions_file_path()) {
+        if let Ok(sessions) = serde_json::from_str::<HashMap<String, GameSession>>(&content) {
+            legacy.extend(sessions.into_values());
         }
     }
-
-    let legacy_path = get_legacy_session_file_path();
-    if legacy_path.exists() {
-        if let Ok(content) = fs::read_to_string(&legacy_path) {
-            if let Ok(session) = serde_json::from_str::<GameSession>(&content) {
-                let mut sessions = HashMap::new();
-                if !session.instance_id.is_empty() {
-                    sessions.insert(session.instance_id.clone(), session);
-                }
-                return sessions;
-            }
+    if let Ok(content) = fs::read_to_string(get_legacy_session_file_path()) {
+        if let Ok(session) = serde_json::from_str::<GameSession>(&content) {
+            legacy.push(session);
         }
     }
 
-    HashMap::new()
+    for session in legacy.into_iter().filter(|s| !s.instance_id.is_empty()) {
+        let session_key = session.instance_id.clone();
+        if !get_session_record_path(&session_key).exists() {
+            let _ = write_session_record(&session_key, session);
+        }
+    }
+    let _ = fs::remove_file(get_legacy_sessions_file_path());
+    let _ = fs::remove_file(get_legacy_session_file_path());
 }
 
-fn save_active_sessions(sessions: &HashMap<String, GameSession>) -> Result<(), String> {
-    if sessions.is_empty() {
-        let _ = fs::remove_file(get_session_file_path());
-        let _ = fs::remove_file(get_legacy_session_file_path());
-        return Ok(());
-    }
+fn load_active_sessions() -> HashMap<String, GameSession> {
+    migrate_legacy_sessions();
 
-    let content = serde_json::to_string(sessions)
-        .map_err(|e| format!("Failed to serialize sessions: {}", e))?;
-    fs::write(get_session_file_path(), content)
-        .map_err(|e| format!("Failed to write sessions file: {}", e))?;
-    let _ = fs::remove_file(get_legacy_session_file_path());
-    Ok(())
+    let mut sessions = HashMap::new();
+    if let Ok(entries) = fs::read_dir(get_sessions_dir()) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            if let Ok(content) = fs::read_to_string(&path) {
+                if let Ok(session) = serde_json::from_str::<GameSession>(&content) {
+                    if !session.instance_id.is_empty() {
+                        match session.session_key.clone() {
+                            Some(session_key) => {
+                                sessions.insert(session_key, session);
+                                continue;
+                            }
+                            // Records from before hashed names were named after their key; move them over
+                            None => {
+                                let session_key = path.file_stem().and_then(|s| s.to_str()).map(|s| s.to_string());
+                                if let Some(session_key) = session_key {
+                                    if write_session_record(&session_key, session.clone()).is_ok() {
+                                        let _ = fs::remove_file(&path);
+                                    }
+                                    sessions.insert(session_key, session);
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            // Unreadable record, nothing to recover from it
+            let _ = fs::remove_file(&path);
+        }
+    }
+    sessions
 }
 
 /// Write an active session to disk (called when game launches)
 pub fn write_active_session(
+    session_key: &str,
     instance_id: &str,
     start_time: u64,
     pid: Option<u32>,
     launch_username: Option<String>,
 ) -> Result<(), String> {
-    let mut sessions = load_active_sessions();
-    sessions.insert(
-        instance_id.to_string(),
-        GameSession {
-            instance_id: instance_id.to_string(),
-            start_time,
-            pid,
-            launch_username,
-        },
-    );
-    save_active_sessions(&sessions)
+    let _guard = SESSION_FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    write_session_record(session_key, GameSession {
+        instance_id: instance_id.to_string(),
+        start_time,
+        pid,
+        launch_username,
+        owner_pid: Some(std::process::id()),
+        session_key: None,
+    })
+}
+
+fn remove_session_record(session_key: &str) {
+    let _ = fs::remove_file(get_session_record_path(session_key));
 }
 
 /// Clear one active session entry (called when a specific game exits normally)
-pub fn clear_active_session(instance_id: &str) {
-    let mut sessions = load_active_sessions();
-    sessions.remove(instance_id);
-    let _ = save_active_sessions(&sessions);
+pub fn clear_active_session(session_key: &str) {
+    let _guard = SESSION_FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    remove_session_record(session_key);
 }
 
-/// Clear all active session entries (called when fully exiting launcher)
-pub fn clear_all_active_sessions() {
-    let _ = fs::remove_file(get_session_file_path());
-    let _ = fs::remove_file(get_legacy_session_file_path());
+/// Pid of a still-running session of the instance recorded by any launcher process,
+/// including headless `--launch` runs the GUI does not track in memory
+pub fn find_running_session(instance_id: &str) -> Option<u32> {
+    let _guard = SESSION_FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    load_active_sessions()
+        .into_values()
+        .filter(|session| session.instance_id == instance_id)
+        .filter_map(|session| session.pid)
+        .find(|pid| is_process_running(*pid))
 }
 
 pub fn is_process_running(pid: u32) -> bool {
@@ -493,6 +838,14 @@ pub fn is_process_running(pid: u32) -> bool {
     }
     #[cfg(not(target_os = "windows"))]
     {
+        // Games started through a wrapper lead their own process group; the group outlives
+        // a wrapper that forks Java and exits, so check it before the single pid
+        let group = format!("-{}", pid);
+        if let Ok(status) = std::process::Command::new("kill").args(["-0", "--", &group]).stderr(std::process::Stdio::null()).status() {
+            if status.success() {
+                return true;
+            }
+        }
         if let Ok(status) = std::process::Command::new("kill").args(&["-0", &pid.to_string()]).status() {
             return status.success();
         }
@@ -501,9 +854,11 @@ pub fn is_process_running(pid: u32) -> bool {
 }
 
 /// Check for orphaned sessions on startup and credit playtime or recover processes.
-/// Returns a list of recovered sessions:
-/// (instance_id, start_time_or_credited_duration, pid, original_playtime, launch_username)
-pub fn recover_orphaned_sessions() -> Vec<(String, u64, Option<u32>, u64, Option<String>)> {
+/// Every session in the file is handled, so multiple instances left running by a crash
+/// are all re-registered or finalized. Parallel sessions of one instance are credited
+/// once: dead ones fold into a live session's span, or share the earliest start.
+pub fn recover_orphaned_sessions() -> Vec<RecoveredSession> {
+    let _guard = SESSION_FILE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
     let sessions = load_active_sessions();
     if sessions.is_empty() {
         return Vec::new();
@@ -515,51 +870,67 @@ pub fn recover_orphaned_sessions() -> Vec<(String, u64, Option<u32>, u64, Option
         .as_secs();
 
     let mut recovered = Vec::new();
-    let mut still_running = HashMap::new();
-
-    for (_key, session) in sessions {
-        let instance_id = session.instance_id.clone();
-        let original_playtime = if let Ok(inst) = get_instance(&instance_id) {
-            inst.playtime_seconds
-        } else {
-            0
-        };
+    let mut dead = Vec::new();
 
-        if let Some(pid) = session.pid {
-            if is_process_running(pid) {
-                let launch_username = session.launch_username.clone();
-                let session_start_time = session.start_time;
-                still_running.insert(instance_id.clone(), session);
-                recovered.push((
-                    instance_id,
-                    session_start_time,
-                    Some(pid),
-                    original_playtime,
-                    launch_username,
-                ));
-                continue;
-            }
+    for (session_key, session) in sessions {
+        // A `--launch` process still waiting on its game finalizes the record itself
+        let owned_elsewhere = session
+            .owner_pid
+            .is_some_and(|owner| owner != std::process::id() && is_process_running(owner));
+        match session.pid.filter(|pid| is_process_running(*pid)) {
+            Some(pid) => recovered.push(RecoveredSession {
+                session_key,
+                instance_id: session.instance_id,
+                start_time: session.start_time,
+                pid: Some(pid),
+                credited_seconds: 0,
+                launch_username: session.launch_username,
+                owned_elsewhere,
+            }),
+            None if owned_elsewhere => {}
+            None => dead.push((session_key, session)),
         }
+    }
 
+    let live_instances: std::collections::HashSet<String> = recovered
+        .iter()
+        .filter(|r| !r.owned_elsewhere)
+        .map(|r| r.instance_id.clone())
+        .collect();
+    let mut credited_instances = std::collections::HashSet::new();
+    dead.sort_by_key(|(_, session)| session.start_time);
+
+    for (session_key, session) in dead {
+        remove_session_record(&session_key);
         let duration = now.saturating_sub(session.start_time);
-        if duration <= 86400 {
+        if duration > 86400 {
+            continue;
+        }
+
+        // The earliest dead session covers the others; a live one credits on its own exit
+        let instance_id = session.instance_id;
+        let credited_seconds = if live_instances.contains(&instance_id) || !credited_instances.insert(instance_id.clone()) {
+            0
+        } else {
             if let Ok(mut instance) = get_instance(&instance_id) {
                 instance.playtime_seconds += duration;
                 let _ = update_instance(instance.clone());
                 log_session(&instance_id, &instance.name, now, duration);
             }
+            duration
+        };
 
-            recovered.push((
-                instance_id,
-                duration,
-                None,
-                original_playtime,
-                session.launch_username.clone(),
-            ));
-        }
+        recovered.push(RecoveredSession {
+            session_key,
+            instance_id,
+            start_time: session.start_time,
+            pid: None,
+            credited_seconds,
+            launch_username: session.launch_username,
+            owned_elsewhere: false,
+        });
     }
 
-    let _ = save_active_sessions(&still_running);
     recovered
 }
 
@@ -614,6 +985,75 @@ pub fn load_session_history() -> Vec<SessionRecord> {
         .unwrap_or_default()
 }
 
+/// A single play session as shown in the instance history
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct InstanceSession {
+    pub session_start: u64,
+    pub session_end: u64,
+    pub duration_seconds: u64,
+}
+
+/// Sessions of one instance, newest first. Records store the end time, so the start is derived.
+pub fn get_instance_sessions(instance_id: &str) -> Vec<InstanceSession> {
+    let mut sessions: Vec<InstanceSession> = load_session_history()
+        .into_iter()
+        .filter(|rec| rec.instance_id == instance_id)
+        .map(|rec| InstanceSession {
+            session_start: rec.timestamp.saturating_sub(rec.duration_seconds),
+            session_end: rec.timestamp,
+            duration_seconds: rec.duration_seconds,
+        })
+        .collect();
+    sessions.sort_by(|a, b| b.session_end.cmp(&a.session_end));
+    sessions
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Write every logged session to a CSV file and return the number of rows written.
+/// Sessions of deleted instances keep their recorded name with empty version/loader columns.
+pub fn export_sessions_csv(destination: &Path) -> Result<usize, String> {
+    let instances: HashMap<String, Instance> = load_instances()?
+        .into_iter()
+        .map(|inst| (inst.id.clone(), inst))
+        .collect();
+
+    let mut records = load_session_history();
+    records.sort_by_key(|rec| rec.timestamp.saturating_sub(rec.duration_seconds));
+
+    let mut csv = String::from("instance,version,loader,session_start,duration_seconds\n");
+    for rec in &records {
+        let start = rec.timestamp.saturating_sub(rec.duration_seconds);
+        let start_text = chrono::DateTime::from_timestamp(start as i64, 0)
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_else(|| start.to_string());
+        let (name, version, loader) = match instances.get(&rec.instance_id) {
+            Some(inst) => (inst.name.clone(), inst.version_id.clone(), inst.mod_loader.to_string()),
+            None => (rec.instance_name.clone(), String::new(), String::new()),
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            csv_field(&name),
+            csv_field(&version),
+            csv_field(&loader),
+            start_text,
+            rec.duration_seconds
+        ));
+    }
+
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create export folder: {}", e))?;
+    }
+    fs::write(destination, csv).map_err(|e| format!("Failed to write {}: {}", destination.display(), e))?;
+    Ok(records.len())
+}
+
 pub fn get_daily_activity(days: u32) -> Vec<DailyActivity> {
     use chrono::{Local, Duration, NaiveDate};
 
@@ -2,72 +2,313 @@ use std::error::Error;
 use std::fs;
 use std::path::PathBuf;
 
-use crate::minecraft::downloader::get_minecraft_dir;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 
-pub async fn download_java(version: u32) -> Result<PathBuf, Box<dyn Error + Send + Sync>> {
-    let install_dir = get_minecraft_dir().join("java").join(format!("temurin-{}", version));
-    
-    // Check if already installed
-    if let Some(binary) = find_java_binary(&install_dir) {
-        return Ok(binary);
-    }
+use crate::minecraft::downloader::{emit_download_progress, get_minecraft_dir, DownloadProgress};
+
+/// Runtime vendors that can be downloaded, first one is the default
+pub const JAVA_VENDORS: &[&str] = &["temurin", "zulu", "graalvm"];
 
-    let os = if cfg!(target_os = "linux") {
-        "linux"
+// ----------
+// JavaAsset
+// Description: Resolved download for one vendor/version/platform combination
+// ----------
+struct JavaAsset {
+    url: String,
+    sha256: Option<String>,
+    is_zip: bool,
+}
+
+fn detect_os() -> Result<&'static str, Box<dyn Error + Send + Sync>> {
+    if cfg!(target_os = "linux") {
+        Ok("linux")
     } else if cfg!(target_os = "windows") {
-        "windows"
+        Ok("windows")
     } else if cfg!(target_os = "macos") {
-        "mac"
+        Ok("mac")
     } else {
-        return Err("Unsupported OS for Java download".into());
-    };
+        Err("Unsupported OS for Java download".into())
+    }
+}
 
-    let arch = if cfg!(target_arch = "x86_64") {
-        "x64"
+fn detect_arch() -> Result<&'static str, Box<dyn Error + Send + Sync>> {
+    if cfg!(target_arch = "x86_64") {
+        Ok("x64")
     } else if cfg!(target_arch = "aarch64") {
-        "aarch64"
+        Ok("aarch64")
     } else {
-        return Err("Unsupported CPU architecture for Java download".into());
+        Err("Unsupported CPU architecture for Java download".into())
+    }
+}
+
+fn normalize_vendor(vendor: Option<&str>) -> Result<&'static str, Box<dyn Error + Send + Sync>> {
+    let requested = vendor.map(|v| v.trim().to_ascii_lowercase()).unwrap_or_default();
+    if requested.is_empty() {
+        return Ok(JAVA_VENDORS[0]);
+    }
+    JAVA_VENDORS
+        .iter()
+        .find(|v| **v == requested)
+        .copied()
+        .ok_or_else(|| format!("Unknown Java vendor \"{}\"", requested).into())
+}
+
+/// Install folder for a runtime: java/<vendor>-<version>-<arch>
+pub fn java_install_dir(vendor: Option<&str>, version: u32) -> Result<PathBuf, String> {
+    let vendor = normalize_vendor(vendor).map_err(|e| e.to_string())?;
+    let arch = detect_arch().map_err(|e| e.to_string())?;
+    Ok(get_minecraft_dir().join("java").join(format!("{}-{}-{}", vendor, version, arch)))
+}
+
+/// Temurin folder from before the arch-suffixed layout: java/temurin-<version>
+fn legacy_temurin_dir(version: u32) -> PathBuf {
+    get_minecraft_dir().join("java").join(format!("temurin-{}", version))
+}
+
+/// Whether a runtime is installed, including Temurin installs from before the arch-suffixed layout
+pub fn is_java_installed(vendor: Option<&str>, version: u32) -> Result<bool, String> {
+    let install_dir = java_install_dir(vendor, version)?;
+    if find_java_binary(&install_dir).is_some() {
+        return Ok(true);
+    }
+    Ok(normalize_vendor(vendor).ok() == Some("temurin") && find_java_binary(&legacy_temurin_dir(version)).is_some())
+}
+
+/// A runtime this launcher downloaded earlier for exactly `version`, from any vendor
+pub fn find_downloaded_java(version: u32) -> Option<PathBuf> {
+    JAVA_VENDORS
+        .iter()
+        .filter_map(|vendor| java_install_dir(Some(vendor), version).ok())
+        .chain(std::iter::once(legacy_temurin_dir(version)))
+        .find_map(|dir| find_java_binary(&dir))
+}
+
+/// A runtime folder under java/ that this launcher downloaded
+pub struct DownloadedRuntime {
+    pub dir: PathBuf,
+    pub binary: PathBuf,
+    pub vendor: String,
+    pub version: u32,
+}
+
+/// Every runtime under java/, both <vendor>-<version>-<arch> and legacy temurin-<version> folders
+pub fn list_downloaded_runtimes() -> Vec<DownloadedRuntime> {
+    let Ok(entries) = fs::read_dir(get_minecraft_dir().join("java")) else {
+        return Vec::new();
     };
+    let mut runtimes: Vec<DownloadedRuntime> = entries
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let dir = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            let mut parts = name.splitn(3, '-');
+            let vendor = parts.next().filter(|v| JAVA_VENDORS.contains(v))?.to_string();
+            let version = parts.next()?.parse::<u32>().ok()?;
+            let binary = find_java_binary(&dir)?;
+            Some(DownloadedRuntime { dir, binary, vendor, version })
+        })
+        .collect();
+    runtimes.sort_by(|a, b| b.version.cmp(&a.version).then_with(|| a.vendor.cmp(&b.vendor)));
+    runtimes
+}
 
+async fn fetch_json(url: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+    let response = super::http_client()
+        .get(url)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(format!("{} returned {}", url, response.status()).into());
+    }
+    Ok(response.json().await?)
+}
+
+async fn resolve_temurin(version: u32, os: &str, arch: &str) -> Result<JavaAsset, Box<dyn Error + Send + Sync>> {
     let url = format!(
-        "https://api.adoptium.net/v3/binary/latest/{}/ga/{}/{}/jre/hotspot/normal/eclipse",
-        version, os, arch
+        "https://api.adoptium.net/v3/assets/latest/{}/hotspot?architecture={}&image_type=jre&os={}&vendor=eclipse",
+        version, arch, os
     );
+    let assets = fetch_json(&url).await?;
+    let package = assets
+        .as_array()
+        .and_then(|list| list.first())
+        .and_then(|asset| asset.pointer("/binary/package"))
+        .ok_or_else(|| format!("No Temurin {} JRE available for {}-{}", version, os, arch))?;
+
+    let link = package.get("link").and_then(|v| v.as_str()).ok_or("Temurin asset has no download link")?;
+    Ok(JavaAsset {
+        url: link.to_string(),
+        sha256: package.get("checksum").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        is_zip: link.ends_with(".zip"),
+    })
+}
+
+async fn resolve_zulu(version: u32, os: &str, arch: &str) -> Result<JavaAsset, Box<dyn Error + Send + Sync>> {
+    let zulu_os = if os == "mac" { "macos" } else { os };
+    let archive_type = if os == "windows" { "zip" } else { "tar.gz" };
+    let url = format!(
+        "https://api.azul.com/metadata/v1/zulu/packages/?java_version={}&os={}&arch={}&archive_type={}&java_package_type=jre&latest=true&release_status=ga&availability_types=CA&page_size=1",
+        version, zulu_os, arch, archive_type
+    );
+    let packages = fetch_json(&url).await?;
+    let package = packages
+        .as_array()
+        .and_then(|list| list.first())
+        .ok_or_else(|| format!("No Zulu {} JRE available for {}-{}", version, os, arch))?;
+
+    let link = package.get("download_url").and_then(|v| v.as_str()).ok_or("Zulu package has no download URL")?;
+
+    // The listing omits checksums, the package details carry them
+    let sha256 = match package.get("package_uuid").and_then(|v| v.as_str()) {
+        Some(uuid) => fetch_json(&format!("https://api.azul.com/metadata/v1/zulu/packages/{}", uuid))
+            .await
+            .ok()
+            .and_then(|details| details.get("sha256_hash").and_then(|v| v.as_str()).map(|s| s.to_string())),
+        None => None,
+    };
+
+    Ok(JavaAsset {
+        url: link.to_string(),
+        sha256,
+        is_zip: archive_type == "zip",
+    })
+}
+
+async fn resolve_graalvm(version: u32, os: &str, arch: &str) -> Result<JavaAsset, Box<dyn Error + Send + Sync>> {
+    if version < 17 {
+        return Err("GraalVM is only available for Java 17 and newer".into());
+    }
+    let graal_os = if os == "mac" { "macos" } else { os };
+    let extension = if os == "windows" { "zip" } else { "tar.gz" };
+    let url = format!(
+        "https://download.oracle.com/graalvm/{}/latest/graalvm-jdk-{}_{}-{}_bin.{}",
+        version, version, graal_os, arch, extension
+    );
+
+    // Oracle publishes the checksum next to the archive
+    let sha256 = match super::http_client().get(format!("{}.sha256", url)).send().await {
+        Ok(resp) if resp.status().is_success() => resp
+            .text()
+            .await
+            .ok()
+            .and_then(|text| text.split_whitespace().next().map(|s| s.to_string())),
+        _ => None,
+    };
+
+    Ok(JavaAsset {
+        url,
+        sha256,
+        is_zip: extension == "zip",
+    })
+}
+
+/// Download a Java runtime for this OS/CPU, verifying the vendor's SHA-256 before extracting.
+/// With an app handle the archive's byte progress is emitted like any other download.
+pub async fn download_java(
+    version: u32,
+    vendor: Option<&str>,
+    app_handle: Option<&tauri::AppHandle>,
+    instance_id: Option<&str>,
+) -> Result<PathBuf, Box<dyn Error + Send + Sync>> {
+    let vendor = normalize_vendor(vendor)?;
+    let os = detect_os()?;
+    let arch = detect_arch()?;
+    let install_dir = java_install_dir(Some(vendor), version)?;
+
+    // Check if already installed
+    if let Some(binary) = find_java_binary(&install_dir) {
+        return Ok(binary);
+    }
+    if vendor == "temurin" {
+        if let Some(binary) = find_java_binary(&legacy_temurin_dir(version)) {
+            return Ok(binary);
+        }
+    }
+
+    let asset = match vendor {
+        "zulu" => resolve_zulu(version, os, arch).await?,
+        "graalvm" => resolve_graalvm(version, os, arch).await?,
+        _ => resolve_temurin(version, os, arch).await?,
+    };
 
     let client = super::http_client();
     let response = client
-        .get(&url)
-        .header("User-Agent", format!("PaletheaLauncher/{}", super::get_launcher_version()))
+        .get(&asset.url)
         .send()
         .await?;
 
     if !response.status().is_success() {
-        return Err(format!("Failed to download Java {}: {}", version, response.status()).into());
+        return Err(format!("Failed to download {} Java {}: {}", vendor, version, response.status()).into());
+    }
+
+    let total_size = response.content_length();
+    let stage = format!("Downloading Java {}...", version);
+    let mut bytes = Vec::with_capacity(total_size.unwrap_or(0) as usize);
+    let mut last_emit = std::time::Instant::now();
+    let mut stream = response.bytes_stream();
+    use futures::StreamExt;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        crate::minecraft::downloader::throttle_download(chunk.len() as u64).await;
+        bytes.extend_from_slice(&chunk);
+
+        // Emit progress every 100ms or so to avoid flooding
+        if let Some(handle) = app_handle {
+            let downloaded = bytes.len() as u64;
+            if last_emit.elapsed().as_millis() > 100 || Some(downloaded) == total_size {
+                let percentage = total_size.map_or(0.0, |total| downloaded as f32 / total.max(1) as f32 * 100.0);
+                emit_download_progress(handle, DownloadProgress {
+                    stage: stage.clone(),
+                    current: 0,
+                    total: 0,
+                    percentage,
+                    total_bytes: total_size,
+                    downloaded_bytes: Some(downloaded),
+                }, instance_id);
+                last_emit = std::time::Instant::now();
+            }
+        }
+    }
+
+    match &asset.sha256 {
+        Some(expected) => {
+            let actual = format!("{:x}", Sha256::digest(&bytes));
+            if !actual.eq_ignore_ascii_case(expected.trim()) {
+                return Err(format!(
+                    "Checksum mismatch for {} Java {} (expected {}, got {})",
+                    vendor, version, expected, actual
+                )
+                .into());
+            }
+        }
+        None => log::warn!("No checksum published for {} Java {}, skipping verification", vendor, version),
     }
 
-    let bytes = response.bytes().await?;
     let temp_dir = std::env::temp_dir();
-    let archive_name = if os == "windows" {
-        format!("temurin-{}-{}.zip", version, os)
+    let archive_name = if asset.is_zip {
+        format!("{}-{}-{}-{}.zip", vendor, version, os, arch)
     } else {
-        format!("temurin-{}-{}.tar.gz", version, os)
+        format!("{}-{}-{}-{}.tar.gz", vendor, version, os, arch)
     };
     let archive_path = temp_dir.join(archive_name);
     fs::write(&archive_path, &bytes)?;
 
-    let install_dir = get_minecraft_dir().join("java").join(format!("temurin-{}", version));
     if install_dir.exists() {
         let _ = fs::remove_dir_all(&install_dir);
     }
     fs::create_dir_all(&install_dir)?;
 
-    if os == "windows" {
+    if asset.is_zip {
         let file = fs::File::open(&archive_path)?;
         let mut archive = zip::ZipArchive::new(file)?;
         for i in 0..archive.len() {
             let mut entry = archive.by_index(i)?;
-            let out_path = install_dir.join(entry.name());
+            let Some(relative) = entry.enclosed_name() else {
+                continue;
+            };
+            let out_path = install_dir.join(relative);
             if entry.is_dir() {
                 fs::create_dir_all(&out_path)?;
             } else {
@@ -109,6 +350,12 @@ fn find_java_binary(install_dir: &PathBuf) -> Option<PathBuf> {
                 if candidate.exists() {
                     return Some(candidate);
                 }
+
+                // macOS archives nest the runtime inside a bundle
+                let bundle_candidate = path.join("Contents").join("Home").join("bin").join(java_name);
+                if bundle_candidate.exists() {
+                    return Some(bundle_candidate);
+                }
             }
         }
     }
@@ -3,6 +3,7 @@ use crate::minecraft::instances::{Instance, ModLoader};
 use crate::minecraft::versions::{self, should_use_library, VersionDetails};
 use crate::minecraft::settings;
 use crate::minecraft::fabric;
+use crate::minecraft::quilt;
 use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::path::PathBuf;
@@ -101,6 +102,27 @@ pub fn find_java() -> Option<PathBuf> {
     None
 }
 
+/// Every Java the launcher would consider (custom path, system, downloaded runtimes)
+/// with its major version, for diagnostics
+pub fn detected_java_runtimes() -> Vec<(PathBuf, Option<u32>)> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+    if let Some(custom) = crate::minecraft::settings::get_java_path() {
+        candidates.push(PathBuf::from(custom));
+    }
+    candidates.extend(find_java());
+    candidates.extend([8, 16, 17, 21, 25].into_iter().filter_map(crate::minecraft::java::find_downloaded_java));
+
+    let mut seen = std::collections::HashSet::new();
+    candidates
+        .into_iter()
+        .filter(|path| path.exists() && seen.insert(path.clone()))
+        .map(|path| {
+            let major = get_java_major(&path);
+            (path, major)
+        })
+        .collect()
+}
+
 fn get_java_major(java_path: &PathBuf) -> Option<u32> {
     let output = Command::new(java_path)
         .arg("-version")
@@ -301,7 +323,7 @@ fn should_scan_mod_jar_for_loader(loader: &ModLoader, archive: &mut ZipArchive<F
             }
             true
         }
-        ModLoader::Fabric => {
+        ModLoader::Fabric | ModLoader::Quilt => {
             // Ignore clearly Forge/NeoForge-only jars for Java-requirement detection.
             if (has_forge_manifest || has_neoforge_manifest || has_modlauncher_service)
                 && !has_fabric_manifest
@@ -392,7 +414,8 @@ fn detect_mods_min_java_major(instance: &Instance) -> Option<u32> {
     }
 }
 
-fn select_java_for_launch(instance: &Instance, version_details: &VersionDetails) -> Result<PathBuf, String> {
+/// The Java major a launch needs: the version metadata's requirement, or what installed mods need when the metadata has none
+fn required_java_major(instance: &Instance, version_details: &VersionDetails) -> Option<u32> {
     let mut required_major = version_details
         .java_version
         .as_ref()
@@ -418,6 +441,18 @@ fn select_java_for_launch(instance: &Instance, version_details: &VersionDetails)
         }
     }
 
+    required_major
+}
+
+/// Versions that only run on Java 8: anything on the old LaunchWrapper, and Beta/Alpha
+/// releases whose metadata predates the javaVersion field
+pub fn needs_java8(version_details: &VersionDetails) -> bool {
+    version_details.main_class == "net.minecraft.launchwrapper.Launch"
+        || (version_details.java_version.is_none()
+            && crate::minecraft::versions::is_old_version_type(&version_details.version_type))
+}
+
+fn select_java_for_launch(instance: &Instance, version_details: &VersionDetails, required_major: Option<u32>) -> Result<PathBuf, String> {
     // If instance has a specific Java path set, use it only if it satisfies the required major.
     if let Some(java_path) = &instance.java_path {
         let path = PathBuf::from(java_path);
@@ -464,6 +499,12 @@ fn select_java_for_launch(instance: &Instance, version_details: &VersionDetails)
             }
         }
 
+        // Runtimes this launcher downloaded are not in the system locations below
+        if let Some(java_path) = crate::minecraft::java::find_downloaded_java(required_major) {
+            log::info!("Using downloaded Java {} for Minecraft {}", required_major, version_details.id);
+            return Ok(java_path);
+        }
+
         // Try to find a Java that meets the requirement
         if let Some(java_path) = find_java_by_version(required_major) {
             log::info!("Found Java {} for Minecraft {}", required_major, version_details.id);
@@ -495,8 +536,7 @@ fn select_java_for_launch(instance: &Instance, version_details: &VersionDetails)
         .or_else(find_java)
         .ok_or("Java not found. Please install Java or specify the path in settings.")?;
 
-    let is_legacy_launcher = version_details.main_class == "net.minecraft.launchwrapper.Launch";
-    if is_legacy_launcher {
+    if needs_java8(version_details) {
         if let Some(major) = get_java_major(&requested) {
             if major > 8 {
                 if let Some(java8) = find_java8() {
@@ -661,6 +701,8 @@ pub fn build_game_args(
             args.push(resolution_height);
         }
         
+        append_legacy_demo_arg(&mut args, instance);
+        append_fullscreen_arg(&mut args, instance);
         append_direct_connect_args(&mut args, join_server_address);
         return args;
     }
@@ -679,7 +721,7 @@ pub fn build_game_args(
                 }
                 // Handle complex arguments with rules
                 else if let Some(obj) = arg.as_object() {
-                    if check_argument_rules(obj, os_name, has_custom_resolution) {
+                    if check_argument_rules(obj, os_name, has_custom_resolution, instance.demo_mode) {
                         if let Some(value) = obj.get("value") {
                             if let Some(s) = value.as_str() {
                                 let processed = process_arg_string(s, username, &version_details.id, &game_dir, &assets_dir, &asset_index, uuid, access_token, &version_details.version_type, &resolution_width, &resolution_height, None, None, None);
@@ -701,10 +743,75 @@ pub fn build_game_args(
     
     // Deduplicate game arguments
     let mut deduped = deduplicate_game_args(args);
+    append_fullscreen_arg(&mut deduped, instance);
     append_direct_connect_args(&mut deduped, join_server_address);
     deduped
 }
 
+/// Legacy argument strings have no feature rules, so demo mode is passed as a plain flag.
+/// Applet-style versions before 1.6 don't parse options and have no demo switch.
+fn append_legacy_demo_arg(args: &mut Vec<String>, instance: &Instance) {
+    if !instance.demo_mode {
+        return;
+    }
+    let accepts_options = args.iter().any(|arg| arg.starts_with("--"));
+    if accepts_options && !args.iter().any(|arg| arg == "--demo") {
+        args.push("--demo".to_string());
+    }
+}
+
+/// Only the 1.6+ main class parses `--option` arguments; older applet-style versions
+/// rely on the options.txt fallback written by `sync_fullscreen_option`
+fn append_fullscreen_arg(args: &mut Vec<String>, instance: &Instance) {
+    if instance.start_fullscreen != Some(true) {
+        return;
+    }
+    let accepts_options = args.iter().any(|arg| arg.starts_with("--"));
+    if accepts_options && !args.iter().any(|arg| arg == "--fullscreen") {
+        args.push("--fullscreen".to_string());
+    }
+}
+
+/// Write the instance's fullscreen choice into options.txt. The configured resolution is
+/// kept as the windowed size so toggling out of fullscreen (F11) lands on it.
+///
+/// A missing options.txt is left for the game to create: one holding only these keys has no
+/// `version:` line, so the game would take it for a pre-1.13 file and run the data fixer on
+/// it. `--fullscreen` covers the first launch instead.
+fn sync_fullscreen_option(instance: &Instance, game_dir: &std::path::Path) -> Result<(), String> {
+    let Some(fullscreen) = instance.start_fullscreen else {
+        return Ok(());
+    };
+
+    let options_path = game_dir.join("options.txt");
+    let content = match fs::read_to_string(&options_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(format!("Failed to read options.txt: {}", e)),
+    };
+    let mut updates = vec![("fullscreen", fullscreen.to_string())];
+    if let (Some(width), Some(height)) = (instance.resolution_width, instance.resolution_height) {
+        updates.push(("overrideWidth", width.to_string()));
+        updates.push(("overrideHeight", height.to_string()));
+    }
+
+    let mut lines: Vec<String> = content.lines().map(|line| line.to_string()).collect();
+    for (key, value) in updates {
+        let prefix = format!("{}:", key);
+        match lines.iter_mut().find(|line| line.starts_with(&prefix)) {
+            Some(line) => *line = format!("{}{}", prefix, value),
+            None => lines.push(format!("{}{}", prefix, value)),
+        }
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    if updated != content {
+        fs::write(&options_path, updated).map_err(|e| format!("Failed to update options.txt: {}", e))?;
+    }
+    Ok(())
+}
+
 fn parse_server_address(address: &str) -> Option<(String, Option<u16>)> {
     let trimmed = address.trim();
     if trimmed.is_empty() {
@@ -802,7 +909,7 @@ fn process_arg_string(
         .replace("${resolution_width}", resolution_width)
         .replace("${resolution_height}", resolution_height)
         .replace("${launcher_name}", "PaletheaLauncher")
-        .replace("${launcher_version}", "0.1.0")
+        .replace("${launcher_version}", &super::get_launcher_version())
         .replace("${classpath_separator}", classpath_separator);
 
     if let Some(cp) = classpath {
@@ -819,7 +926,7 @@ fn process_arg_string(
 }
 
 /// Check if an argument's rules allow it to be used
-fn check_argument_rules(obj: &serde_json::Map<String, serde_json::Value>, os_name: &str, has_custom_resolution: bool) -> bool {
+fn check_argument_rules(obj: &serde_json::Map<String, serde_json::Value>, os_name: &str, has_custom_resolution: bool, is_demo_user: bool) -> bool {
     if let Some(rules) = obj.get("rules") {
         if let Some(rules_arr) = rules.as_array() {
             let mut result = false;
@@ -840,9 +947,9 @@ fn check_argument_rules(obj: &serde_json::Map<String, serde_json::Value>, os_nam
                     // Check features
                     if let Some(features) = rule_obj.get("features") {
                         if let Some(features_obj) = features.as_object() {
-                            // Skip demo mode arguments
+                            // Demo arguments only for instances set to demo mode
                             if features_obj.get("is_demo_user").is_some() {
-                                applies = false;
+                                applies = is_demo_user;
                             }
                             // Include custom resolution arguments only if resolution is set
                             if features_obj.get("has_custom_resolution").is_some() {
@@ -869,6 +976,143 @@ fn check_argument_rules(obj: &serde_json::Map<String, serde_json::Value>, os_nam
     true
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct JvmPreset {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+}
+
+/// List the GC/performance presets selectable per instance
+pub fn jvm_presets() -> Vec<JvmPreset> {
+    let preset = |id: &str, name: &str, description: &str| JvmPreset {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: description.to_string(),
+    };
+
+    vec![
+        preset("default", "Default", "Balanced G1GC settings used by the launcher."),
+        preset("aikar", "Aikar's flags", "Tuned G1GC flags for modded play; region sizes scale with max memory."),
+        preset("zgc", "ZGC", "Low-pause collector. Best with Java 17+ and plenty of memory."),
+        preset("shenandoah", "Shenandoah", "Low-pause collector. Requires a JDK build that ships Shenandoah."),
+        preset("none", "None", "No GC flags; only the memory limits and your custom arguments."),
+    ]
+}
+
+/// Expand a preset id into its JVM flags
+fn jvm_preset_flags(preset: Option<&str>, max_mem_mb: u32) -> Vec<String> {
+    let flags: Vec<&str> = match preset.unwrap_or("default") {
+        "none" => Vec::new(),
+        "aikar" => {
+            // Aikar recommends larger young gen and regions above 12GB
+            let large_heap = max_mem_mb >= 12288;
+            let mut flags = vec![
+                "-XX:+UseG1GC",
+                "-XX:+ParallelRefProcEnabled",
+                "-XX:MaxGCPauseMillis=200",
+                "-XX:+UnlockExperimentalVMOptions",
+                "-XX:+DisableExplicitGC",
+                "-XX:+AlwaysPreTouch",
+            ];
+            if large_heap {
+                flags.extend([
+                    "-XX:G1NewSizePercent=40",
+                    "-XX:G1MaxNewSizePercent=50",
+                    "-XX:G1HeapRegionSize=16M",
+                    "-XX:G1ReservePercent=15",
+                    "-XX:InitiatingHeapOccupancyPercent=20",
+                ]);
+            } else {
+                flags.extend([
+                    "-XX:G1NewSizePercent=30",
+                    "-XX:G1MaxNewSizePercent=40",
+                    "-XX:G1HeapRegionSize=8M",
+                    "-XX:G1ReservePercent=20",
+                    "-XX:InitiatingHeapOccupancyPercent=15",
+                ]);
+            }
+            flags.extend([
+                "-XX:G1HeapWastePercent=5",
+                "-XX:G1MixedGCCountTarget=4",
+                "-XX:G1MixedGCLiveThresholdPercent=90",
+                "-XX:G1RSetUpdatingPauseTimePercent=5",
+                "-XX:SurvivorRatio=32",
+                "-XX:+PerfDisableSharedMem",
+                "-XX:MaxTenuringThreshold=1",
+            ]);
+            flags
+        }
+        "zgc" => vec![
+            "-XX:+UnlockExperimentalVMOptions",
+            "-XX:+UseZGC",
+            "-XX:+DisableExplicitGC",
+        ],
+        "shenandoah" => vec![
+            "-XX:+UnlockExperimentalVMOptions",
+            "-XX:+UseShenandoahGC",
+            "-XX:+DisableExplicitGC",
+        ],
+        // Default performance optimizations (G1GC is standard for modpacks)
+        _ => vec![
+            "-XX:+UseG1GC",
+            "-XX:+UnlockExperimentalVMOptions",
+            "-XX:G1NewSizePercent=20",
+            "-XX:G1ReservePercent=20",
+            "-XX:MaxGCPauseMillis=50",
+            "-XX:G1HeapRegionSize=32M",
+        ],
+    };
+
+    flags.into_iter().map(|flag| flag.to_string()).collect()
+}
+
+/// Options whose value is passed as the next token
+const JVM_OPTIONS_WITH_VALUE: &[&str] = &["--add-opens", "--add-exports", "--add-reads", "--add-modules", "--patch-module"];
+
+/// Reject custom JVM arguments that would break or hijack the launch command
+pub fn validate_jvm_args(args: &[String]) -> Result<(), String> {
+    let mut expects_value = false;
+    for arg in args {
+        if expects_value {
+            expects_value = false;
+            continue;
+        }
+        if arg == "-cp" || arg == "-classpath" || arg == "--class-path" || arg == "-jar" {
+            return Err(format!("JVM argument \"{}\" is managed by the launcher", arg));
+        }
+        if !arg.starts_with('-') {
+            return Err(format!("Invalid JVM argument \"{}\": arguments must start with '-'", arg));
+        }
+        if arg.starts_with("-Xmx") || arg.starts_with("-Xms") {
+            let size = &arg[4..];
+            let digits = size.trim_end_matches(|c: char| matches!(c, 'k' | 'K' | 'm' | 'M' | 'g' | 'G'));
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return Err(format!("Invalid memory size in JVM argument \"{}\"", arg));
+            }
+        }
+        expects_value = JVM_OPTIONS_WITH_VALUE.contains(&arg.as_str());
+    }
+    if expects_value {
+        return Err("JVM argument list ends with an option that is missing its value".to_string());
+    }
+    Ok(())
+}
+
+/// Parse a JVM args file: one per line or whitespace separated, `#` starts a comment
+pub fn parse_jvm_args_file(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .flat_map(|line| {
+            let line = match line.find('#') {
+                Some(idx) if idx == 0 || line[..idx].ends_with(char::is_whitespace) => &line[..idx],
+                _ => line,
+            };
+            line.split_whitespace().map(|arg| arg.to_string()).collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 /// Build JVM arguments
 pub fn build_jvm_args(
     version_details: &VersionDetails,
@@ -889,13 +1133,8 @@ pub fn build_jvm_args(
     args.push(format!("-Xms{}M", min_mem));
     args.push(format!("-Xmx{}M", max_mem));
 
-    // Default performance optimizations (G1GC is standard for modpacks)
-    args.push("-XX:+UseG1GC".to_string());
-    args.push("-XX:+UnlockExperimentalVMOptions".to_string());
-    args.push("-XX:G1NewSizePercent=20".to_string());
-    args.push("-XX:G1ReservePercent=20".to_string());
-    args.push("-XX:MaxGCPauseMillis=50".to_string());
-    args.push("-XX:G1HeapRegionSize=32M".to_string());
+    // GC / performance flags from the selected preset
+    args.extend(jvm_preset_flags(instance.jvm_preset.as_deref(), max_mem));
     
     // Process JVM arguments from version JSON
     if let Some(arguments) = &version_details.arguments {
@@ -905,7 +1144,7 @@ pub fn build_jvm_args(
                     let processed = process_arg_string(s, "", &version_details.id, &game_dir, &assets_dir, &asset_index, "", "", &version_details.version_type, "854", "480", Some(classpath), Some(&natives_dir.to_string_lossy()), Some(&library_dir.to_string_lossy()));
                     args.push(processed);
                 } else if let Some(obj) = arg.as_object() {
-                    if check_argument_rules(obj, os_name, false) {
+                    if check_argument_rules(obj, os_name, false, false) {
                         if let Some(value) = obj.get("value") {
                             if let Some(s) = value.as_str() {
                                 let processed = process_arg_string(s, "", &version_details.id, &game_dir, &assets_dir, &asset_index, "", "", &version_details.version_type, "854", "480", Some(classpath), Some(&natives_dir.to_string_lossy()), Some(&library_dir.to_string_lossy()));
@@ -1046,17 +1285,98 @@ fn redact_sensitive_game_args(args: &[String]) -> Vec<String> {
     redacted
 }
 
-/// Launch Minecraft
-pub async fn launch_game(
-    instance: &Instance,
-    version_details: &VersionDetails,
-    username: &str,
-    access_token: &str,
-    uuid: &str,
-    app_handle: &tauri::AppHandle,
+// ----------
+// PreparedLaunch
+// Description: Everything needed to start the game process, resolved without spawning it
+// ----------
+pub struct PreparedLaunch {
+    pub java_path: PathBuf,
+    pub launch_java_path: PathBuf,
+    pub wrapper: Vec<String>, // Wrapper program and its arguments, empty when Java runs directly
+    pub jvm_args: Vec<String>,
+    pub main_class: String,
+    pub game_args: Vec<String>,
+    pub game_dir: PathBuf,
+}
+
+// ----------
+// LaunchCommand
+// Description: The resolved launch command for display, with auth secrets redacted
+// ----------
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LaunchCommand {
+    pub java_path: String,
+    pub java_major: Option<u32>,
+    pub args: Vec<String>,
+    pub working_dir: String,
+    pub env: std::collections::HashMap<String, String>, // Inherited variables that change how Java starts
+    pub command_line: String,
+}
+
+/// Java-related variables the game inherits from the launcher's environment
+const JAVA_ENV_VARS: &[&str] = &["JAVA_HOME", "JAVA_TOOL_OPTIONS", "_JAVA_OPTIONS", "JDK_JAVA_OPTIONS"];
+
+impl PreparedLaunch {
+    /// Describe the command without secrets, for copy-pasting into a terminal
+    pub fn describe(&self) -> LaunchCommand {
+        let mut args = self.jvm_args.clone();
+        args.push(self.main_class.clone());
+        args.extend(redact_sensitive_game_args(&self.game_args));
+
+        let quote = |value: &str| {
+            if value.contains(' ') || value.contains('"') || value.is_empty() {
+                format!("\"{}\"", value.replace('"', "\\\""))
+            } else {
+                value.to_string()
+            }
+        };
+        let java_path = self.launch_java_path.to_string_lossy().to_string();
+        let command_line = self.wrapper.iter().map(|w| quote(w))
+            .chain(std::iter::once(quote(&java_path)))
+            .chain(args.iter().map(|a| quote(a)))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        LaunchCommand {
+            java_path,
+            java_major: get_java_major(&self.java_path),
+            args,
+            working_dir: self.game_dir.to_string_lossy().to_string(),
+            env: JAVA_ENV_VARS
+                .iter()
+                .filter_map(|key| std::env::var(key).ok().map(|value| (key.to_string(), value)))
+                .collect(),
+            command_line,
+        }
+    }
+}
+
+/// Emit a launch stage when running with a UI; dry runs pass no handle and skip the pacing delay
+async fn report_launch_stage(
+    app_handle: Option<&tauri::AppHandle>,
     launch_instance_id: Option<&str>,
-    join_server_address: Option<&str>,
-) -> Result<std::process::Child, String> {
+    stage: &str,
+    total: u32,
+    percentage: f32,
+    pause_ms: u64,
+) {
+    let Some(app_handle) = app_handle else {
+        return;
+    };
+    crate::minecraft::downloader::emit_download_progress(app_handle, DownloadProgress {
+        stage: stage.to_string(),
+        current: 0,
+        total,
+        percentage,
+        total_bytes: None,
+        downloaded_bytes: None,
+    }, launch_instance_id);
+    tokio::time::sleep(std::time::Duration::from_millis(pause_ms)).await;
+}
+
+/// The version details an instance actually launches with: the vanilla JSON, with the
+/// Forge / NeoForge version JSON merged over it when the instance uses one of those loaders
+pub fn resolve_version_details(instance: &Instance, version_details: &VersionDetails) -> Result<VersionDetails, String> {
     // Determine the actual version details to use (may be overridden by mod loader)
     let mut actual_version_details = version_details.clone();
     
@@ -1067,16 +1387,6 @@ pub async fn launch_game(
             .as_ref()
             .ok_or_else(|| format!("{} instance is missing loader version", instance.mod_loader))?;
         
-        crate::minecraft::downloader::emit_download_progress(app_handle, DownloadProgress {
-            stage: format!("Merging {} config...", instance.mod_loader.to_string()),
-            current: 0,
-            total: 0,
-            percentage: 20.0,
-            total_bytes: None,
-            downloaded_bytes: None,
-        }, launch_instance_id);
-        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
-
         let mc_version = &instance.version_id;
         
         // Potential IDs for Forge/NeoForge
@@ -1183,36 +1493,59 @@ pub async fn launch_game(
         }
     }
 
-    // Ensure client JAR is present and valid (avoid corrupt vanilla jar)
-    log::info!("Checking for missing client JAR...");
-    crate::minecraft::downloader::emit_download_progress(app_handle, DownloadProgress {
-        stage: "Checking game files...".to_string(),
-        current: 0,
-        total: 0,
-        percentage: 40.0,
-        total_bytes: None,
-        downloaded_bytes: None,
-    }, launch_instance_id);
-    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
-    let _ = crate::minecraft::downloader::download_client(&actual_version_details).await
-        .map_err(|e| format!("Failed to download client JAR: {}", e))?;
+    Ok(actual_version_details)
+}
 
-    // Ensure all libraries (including mod loader dependencies) are downloaded
-    log::info!("Checking for missing libraries...");
-    crate::minecraft::downloader::emit_download_progress(app_handle, DownloadProgress {
-        stage: "Verifying libraries...".to_string(),
-        current: 0,
-        total: 1,
-        percentage: 60.0,
-        total_bytes: None,
-        downloaded_bytes: None,
-    }, launch_instance_id);
-    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
-    let _ = crate::minecraft::downloader::download_libraries(&actual_version_details, Some(app_handle), launch_instance_id).await
-        .map_err(|e| format!("Failed to download missing libraries: {}", e))?;
+/// Run the full argument-assembly pipeline: loader merge, file checks, Java selection,
+/// classpath, JVM/game arguments and natives. A dry run only resolves: nothing is
+/// downloaded, extracted or written, and a missing Java is an error instead of a download.
+pub async fn prepare_launch(
+    instance: &Instance,
+    version_details: &VersionDetails,
+    username: &str,
+    access_token: &str,
+    uuid: &str,
+    app_handle: Option<&tauri::AppHandle>,
+    launch_instance_id: Option<&str>,
+    join_server_address: Option<&str>,
+    dry_run: bool,
+) -> Result<PreparedLaunch, String> {
+    if instance.mod_loader == ModLoader::Forge || instance.mod_loader == ModLoader::NeoForge {
+        report_launch_stage(app_handle, launch_instance_id, &format!("Merging {} config...", instance.mod_loader), 0, 20.0, 200).await;
+    }
+    let actual_version_details = resolve_version_details(instance, version_details)?;
+
+    if !dry_run {
+        // Ensure client JAR is present and valid (avoid corrupt vanilla jar)
+        log::info!("Checking for missing client JAR...");
+        report_launch_stage(app_handle, launch_instance_id, "Checking game files...", 0, 40.0, 200).await;
+        let _ = crate::minecraft::downloader::download_client(&actual_version_details).await
+            .map_err(|e| format!("Failed to download client JAR: {}", e))?;
+
+        // Ensure all libraries (including mod loader dependencies) are downloaded
+        log::info!("Checking for missing libraries...");
+        report_launch_stage(app_handle, launch_instance_id, "Verifying libraries...", 1, 60.0, 200).await;
+        let _ = crate::minecraft::downloader::download_libraries(&actual_version_details, app_handle, launch_instance_id).await
+            .map_err(|e| format!("Failed to download missing libraries: {}", e))?;
+    }
 
     // Find Java: instance setting > global setting > auto-detect (with legacy Forge handling)
-    let java_path = select_java_for_launch(instance, &actual_version_details)?;
+    let required_major = required_java_major(instance, &actual_version_details);
+    let java_path = match select_java_for_launch(instance, &actual_version_details, required_major) {
+        Ok(path) => path,
+        Err(e) => match required_major {
+            Some(required) if !dry_run && settings::is_auto_download_java_enabled() => {
+                log::info!("{} Downloading Java {} automatically.", e, required);
+                report_launch_stage(app_handle, launch_instance_id, &format!("Downloading Java {}...", required), 0, 50.0, 0).await;
+                let downloaded = crate::minecraft::java::download_java(required, None, app_handle, launch_instance_id)
+                    .await
+                    .map_err(|download_err| format!("{} Automatic download of Java {} failed: {}", e, required, download_err))?;
+                report_launch_stage(app_handle, launch_instance_id, &format!("Installed Java {}", required), 0, 55.0, 0).await;
+                downloaded
+            }
+            _ => return Err(e),
+        },
+    };
     let selected_java_msg = format!(
         "[ShortcutDebug] Selected Java candidate path for {}: {}",
         instance.name,
@@ -1233,26 +1566,23 @@ pub async fn launch_game(
     crate::minecraft::logger::append_shortcut_debug(&launch_java_msg);
     
     // Build classpath - handle deduplication to avoid "duplicate ASM classes" error
-    crate::minecraft::downloader::emit_download_progress(app_handle, DownloadProgress {
-        stage: "Building classpath...".to_string(),
-        current: 0,
-        total: 0,
-        percentage: 80.0,
-        total_bytes: None,
-        downloaded_bytes: None,
-    }, launch_instance_id);
-    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    report_launch_stage(app_handle, launch_instance_id, "Building classpath...", 0, 80.0, 200).await;
     let mut classpath_elements: Vec<(String, String)> = Vec::new();
     
     // Determine main class
     let mut main_class = actual_version_details.main_class.clone();
     
-    // 1. Handle Fabric mod loader additions (takes priority)
+    // 1. Handle Fabric/Quilt mod loader additions (takes priority)
     if instance.mod_loader == ModLoader::Fabric {
         if let Some(fabric_info) = fabric::load_fabric_info(instance) {
             classpath_elements.extend(fabric::get_fabric_classpath(&fabric_info));
             main_class = fabric_info.launcher_meta.main_class.get_client_class().to_string();
         }
+    } else if instance.mod_loader == ModLoader::Quilt {
+        if let Some(quilt_info) = quilt::load_quilt_info(instance) {
+            classpath_elements.extend(quilt::get_quilt_classpath(&quilt_info));
+            main_class = quilt_info.launcher_meta.main_class.get_client_class().to_string();
+        }
     }
     
     // 2. Add vanilla / Forge merged libraries
@@ -1302,11 +1632,21 @@ pub async fn launch_game(
     // 3. Deduplicate elements by maven identity (group:artifact:classifier)
     // This solves the "duplicate ASM classes found on classpath" error by preferring 
     // the first version found (which will be Fabric's version if using Fabric)
+    // Libraries the instance excludes (for debugging crashes) are dropped here as well
     let mut final_paths = Vec::new();
     let mut seen_identities = std::collections::HashSet::new();
+    let excluded_identities: std::collections::HashSet<String> = instance
+        .excluded_libraries
+        .iter()
+        .map(|entry| get_lib_identity(entry.trim()))
+        .collect();
     
     for (name, path) in classpath_elements {
         let identity = get_lib_identity(&name);
+        if excluded_identities.contains(&identity) {
+            log::info!("Leaving excluded library {} off the classpath", name);
+            continue;
+        }
         if !seen_identities.contains(&identity) {
             seen_identities.insert(identity);
             final_paths.push(path);
@@ -1343,37 +1683,86 @@ pub async fn launch_game(
         join_server_address,
     );
     
-    // Create game directory if it doesn't exist
     let game_dir = instance.get_game_directory();
-    fs::create_dir_all(&game_dir)
-        .map_err(|e| format!("Failed to create game directory: {}", e))?;
-    
-    // Create natives directory
-    let natives_dir = instance.get_directory().join("natives");
-    fs::create_dir_all(&natives_dir)
-        .map_err(|e| format!("Failed to create natives directory: {}", e))?;
-    
-    // Extract natives from library JARs
-    extract_natives(&actual_version_details, &natives_dir)?;
-    
+    if !dry_run {
+        // Create game directory if it doesn't exist
+        fs::create_dir_all(&game_dir)
+            .map_err(|e| format!("Failed to create game directory: {}", e))?;
+        if let Err(e) = sync_fullscreen_option(instance, &game_dir) {
+            log::warn!("{}", e);
+        }
+
+        // Create natives directory
+        let natives_dir = instance.get_directory().join("natives");
+        fs::create_dir_all(&natives_dir)
+            .map_err(|e| format!("Failed to create natives directory: {}", e))?;
+
+        // Extract natives from library JARs
+        extract_natives(&actual_version_details, &natives_dir)?;
+    }
+
+    let wrapper: Vec<String> = instance
+        .wrapper_command
+        .as_deref()
+        .map(|w| w.split_whitespace().map(str::to_string).collect())
+        .unwrap_or_default();
+
+    Ok(PreparedLaunch {
+        java_path,
+        launch_java_path,
+        wrapper,
+        jvm_args,
+        main_class,
+        game_args,
+        game_dir,
+    })
+}
+
+/// Launch Minecraft. Without an app handle (headless launches) the game's output goes
+/// straight to this process's stdout/stderr instead of the log-line events.
+pub async fn launch_game(
+    instance: &Instance,
+    version_details: &VersionDetails,
+    username: &str,
+    access_token: &str,
+    uuid: &str,
+    app_handle: Option<&tauri::AppHandle>,
+    launch_instance_id: Option<&str>,
+    join_server_address: Option<&str>,
+) -> Result<std::process::Child, String> {
+    #[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
+    let PreparedLaunch {
+        java_path,
+        launch_java_path,
+        wrapper,
+        jvm_args,
+        main_class,
+        game_args,
+        game_dir,
+    } = prepare_launch(
+        instance,
+        version_details,
+        username,
+        access_token,
+        uuid,
+        app_handle,
+        launch_instance_id,
+        join_server_address,
+        false,
+    )
+    .await?;
+
     let redacted_game_args = redact_sensitive_game_args(&game_args);
 
     // Log the arguments for debugging (without auth secrets)
     log::info!("Game args: {:?}", redacted_game_args);
 
-    crate::minecraft::downloader::emit_download_progress(app_handle, DownloadProgress {
-        stage: "Starting process...".to_string(),
-        current: 0,
-        total: 0,
-        percentage: 100.0,
-        total_bytes: None,
-        downloaded_bytes: None,
-    }, launch_instance_id);
-    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    report_launch_stage(app_handle, launch_instance_id, "Starting process...", 0, 100.0, 500).await;
 
     // Build the full command string for verbose logging
     let full_command = format!(
-        "\"{}\" {} {} {}",
+        "{}\"{}\" {} {} {}",
+        wrapper.iter().map(|w| format!("{} ", w)).collect::<String>(),
         launch_java_path.display(),
         jvm_args.join(" "),
         main_class,
@@ -1382,10 +1771,25 @@ pub async fn launch_game(
     println!("\n=== STARTING MINECRAFT ===\n{}\n==========================\n", full_command);
     log::info!("Full launch command: {}", full_command);
     
-    // Build command
-    let mut command = Command::new(&launch_java_path);
+    // Build command; with a wrapper, Java and its arguments become the wrapper's arguments
+    let mut command = match wrapper.split_first() {
+        Some((program, wrapper_args)) => {
+            let mut command = Command::new(program);
+            command.args(wrapper_args).arg(&launch_java_path);
+            command
+        }
+        None => Command::new(&launch_java_path),
+    };
     command.current_dir(&game_dir);
 
+    // Give wrapped games their own process group: wrappers that fork leave Java behind under
+    // a different pid, and stopping the group reaches it (see is_process_running/terminate_pid)
+    #[cfg(unix)]
+    if !wrapper.is_empty() {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
     #[cfg(target_os = "windows")]
     {
         // Hide the Java console window
@@ -1450,17 +1854,63 @@ pub async fn launch_game(
     // Launch the game
     let spawn_msg = format!(
         "[ShortcutDebug] Spawning game process executable={} instance={}",
-        launch_java_path.to_string_lossy(),
+        wrapper.first().cloned().unwrap_or_else(|| launch_java_path.to_string_lossy().to_string()),
         instance.name
     );
     log::info!("{}", spawn_msg);
     crate::minecraft::logger::append_shortcut_debug(&spawn_msg);
-    let child = command.spawn()
-        .map_err(|e| format!("Failed to launch Minecraft: {}", e))?;
+    // Capture output directly so crashes before latest.log exists still show up. When the
+    // launcher quits right after the launch, nothing would drain the pipes, so detach them.
+    if app_handle.is_some() && settings::get_on_game_launch() == "exit" {
+        command.stdout(std::process::Stdio::null()).stderr(std::process::Stdio::null());
+    } else if app_handle.is_some() {
+        command.stdout(std::process::Stdio::piped()).stderr(std::process::Stdio::piped());
+    } else {
+        command.stdout(std::process::Stdio::inherit()).stderr(std::process::Stdio::inherit());
+    }
+    let mut child = command.spawn()
+        .map_err(|e| match wrapper.first() {
+            Some(program) => format!("Failed to launch Minecraft through wrapper '{}': {}", program, e),
+            None => format!("Failed to launch Minecraft: {}", e),
+        })?;
+
+    if let Some(app_handle) = app_handle {
+        if let Some(stdout) = child.stdout.take() {
+            crate::minecraft::logtail::forward_process_output(app_handle.clone(), instance.id.clone(), stdout, "stdout");
+        }
+        if let Some(stderr) = child.stderr.take() {
+            crate::minecraft::logtail::forward_process_output(app_handle.clone(), instance.id.clone(), stderr, "stderr");
+        }
+    }
     
     Ok(child)
 }
 
+/// Wipe an instance's natives folder and extract it again from the library JARs.
+/// Returns the number of files written.
+pub fn reextract_natives(instance: &Instance, version_details: &VersionDetails) -> Result<u32, String> {
+    let natives_dir = instance.get_directory().join("natives");
+    if natives_dir.exists() {
+        fs::remove_dir_all(&natives_dir)
+            .map_err(|e| format!("Failed to clear natives directory: {}", e))?;
+    }
+    fs::create_dir_all(&natives_dir)
+        .map_err(|e| format!("Failed to create natives directory: {}", e))?;
+    extract_natives(version_details, &natives_dir)?;
+
+    fn count_files(dir: &std::path::Path) -> u32 {
+        fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|e| if e.path().is_dir() { count_files(&e.path()) } else { 1 })
+                    .sum()
+            })
+            .unwrap_or(0)
+    }
+    Ok(count_files(&natives_dir))
+}
+
 /// Extract native libraries from JARs
 fn extract_natives(version_details: &VersionDetails, natives_dir: &PathBuf) -> Result<(), String> {
     let libraries_dir = get_libraries_dir();
@@ -1537,8 +1987,10 @@ fn extract_jar(jar_path: &PathBuf, dest_dir: &PathBuf, exclusions: &[&str]) -> R
             let _ = fs::create_dir_all(parent);
         }
         
-        // Only extract if file doesn't exist (avoid re-extracting on each launch)
-        if !dest_path.exists() {
+        // Skip files already extracted at the right size (avoid re-extracting on each launch),
+        // but rewrite truncated ones so a partial natives folder heals itself
+        let up_to_date = fs::metadata(&dest_path).map(|m| m.len() == entry.size()).unwrap_or(false);
+        if !up_to_date {
             let mut contents = Vec::new();
             entry.read_to_end(&mut contents)
                 .map_err(|e| format!("Failed to read {}: {}", name, e))?;
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Emitter};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+// ----------
+// LogLinePayload
+// Description: A single game log line pushed to the frontend as a `log-line` event
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LogLinePayload {
+    pub instance_id: String,
+    pub line: String,
+    pub source: String, // "file", "stdout", "stderr"
+}
+
+static LOG_STREAMS: LazyLock<Mutex<HashMap<String, Arc<AtomicBool>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// Recent stdout/stderr lines per running instance; oldest lines drop off past the setting's cap.
+// Lines are only kept while the instance has an entry, so output a pipe still flushes after
+// the buffer was cleared can't bring it back.
+static LIVE_OUTPUT: LazyLock<Mutex<HashMap<String, VecDeque<LogLinePayload>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn emit_line(app_handle: &AppHandle, instance_id: &str, line: String, source: &str) {
+    let _ = app_handle.emit("log-line", LogLinePayload {
+        instance_id: instance_id.to_string(),
+        line,
+        source: source.to_string(),
+    });
+}
+
+/// Start tailing `log_path` for an instance, replacing any stream already running for it.
+/// Only lines written after the stream starts are emitted.
+pub fn start_log_stream(app_handle: AppHandle, instance_id: String, log_path: PathBuf) {
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    if let Ok(mut streams) = LOG_STREAMS.lock() {
+        if let Some(previous) = streams.insert(instance_id.clone(), stop_flag.clone()) {
+            previous.store(true, Ordering::SeqCst);
+        }
+    }
+
+    std::thread::spawn(move || {
+        let initial_len = std::fs::metadata(&log_path).map(|m| m.len()).unwrap_or(0);
+        tail_file(&app_handle, &instance_id, &log_path, initial_len, &stop_flag);
+    });
+}
+
+/// Stop the tail for an instance. Returns false when no stream was running.
+pub fn stop_log_stream(instance_id: &str) -> bool {
+    match LOG_STREAMS.lock() {
+        Ok(mut streams) => match streams.remove(instance_id) {
+            Some(flag) => {
+                flag.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        },
+        Err(_) => false,
+    }
+}
+
+fn tail_file(app_handle: &AppHandle, instance_id: &str, log_path: &PathBuf, start_pos: u64, stop_flag: &AtomicBool) {
+    let mut position = start_pos;
+    let mut created: Option<SystemTime> = std::fs::metadata(log_path).and_then(|m| m.created()).ok();
+    let mut pending = String::new();
+
+    while !stop_flag.load(Ordering::SeqCst) {
+        let metadata = match std::fs::metadata(log_path) {
+            Ok(metadata) => metadata,
+            Err(_) => {
+                // The game rotates latest.log on startup; wait for the new one
+                std::thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+        };
+
+        // A shorter or recreated file means the log was rotated or cleared
+        let current_created = metadata.created().ok();
+        if metadata.len() < position || (current_created.is_some() && current_created != created) {
+            position = 0;
+            pending.clear();
+            created = current_created;
+        }
+
+        if metadata.len() > position {
+            if let Ok(mut file) = File::open(log_path) {
+                if file.seek(SeekFrom::Start(position)).is_ok() {
+                    let mut buffer = Vec::new();
+                    if let Ok(read) = file.read_to_end(&mut buffer) {
+                        position += read as u64;
+                        pending.push_str(&String::from_utf8_lossy(&buffer));
+
+                        while let Some(newline) = pending.find('\n') {
+                            let line: String = pending.drain(..=newline).collect();
+                            emit_line(app_handle, instance_id, line.trim_end_matches(['\r', '\n']).to_string(), "file");
+                        }
+                    }
+                }
+            }
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Forward a child process pipe line by line as `log-line` events until it closes,
+/// keeping the most recent lines for `live_output`
+pub fn forward_process_output<R: Read + Send + 'static>(
+    app_handle: AppHandle,
+    instance_id: String,
+    reader: R,
+    source: &'static str,
+) {
+    let capacity = crate::minecraft::settings::get_live_output_lines();
+    std::thread::spawn(move || {
+        // Game and mod output is not always UTF-8 (Windows code pages), so lines are read raw
+        // and converted lossily; stopping early would leave the pipe undrained and stall the game
+        let mut reader = BufReader::new(reader);
+        let mut buffer = Vec::new();
+        loop {
+            buffer.clear();
+            match reader.read_until(b'\n', &mut buffer) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(_) => break,
+            }
+            let line = String::from_utf8_lossy(&buffer).trim_end_matches(['\r', '\n']).to_string();
+            if capacity > 0 {
+                record_live_line(&instance_id, &line, source, capacity);
+            }
+            emit_line(&app_handle, &instance_id, line, source);
+        }
+    });
+}
+
+fn record_live_line(instance_id: &str, line: &str, source: &str, capacity: usize) {
+    let Ok(mut output) = LIVE_OUTPUT.lock() else { return };
+    let Some(buffer) = output.get_mut(instance_id) else { return };
+    while buffer.len() >= capacity {
+        buffer.pop_front();
+    }
+    buffer.push_back(LogLinePayload {
+        instance_id: instance_id.to_string(),
+        line: line.to_string(),
+        source: source.to_string(),
+    });
+}
+
+/// The buffered game output of a running instance, oldest line first
+pub fn live_output(instance_id: &str) -> Vec<LogLinePayload> {
+    LIVE_OUTPUT
+        .lock()
+        .map(|output| output.get(instance_id).map(|buffer| buffer.iter().cloned().collect()).unwrap_or_default())
+        .unwrap_or_default()
+}
+
+/// Start an empty buffer for an instance's new session. With `keep_existing` (another
+/// session of the instance is still running) its buffer is left as is.
+pub fn reset_live_output(instance_id: &str, keep_existing: bool) {
+    if let Ok(mut output) = LIVE_OUTPUT.lock() {
+        if keep_existing {
+            output.entry(instance_id.to_string()).or_default();
+        } else {
+            output.insert(instance_id.to_string(), VecDeque::new());
+        }
+    }
+}
+
+/// Drop the buffered output once the instance's last session has exited
+pub fn clear_live_output(instance_id: &str) {
+    if let Ok(mut output) = LIVE_OUTPUT.lock() {
+        output.remove(instance_id);
+    }
+}
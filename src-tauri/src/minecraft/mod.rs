@@ -7,26 +7,65 @@ pub mod auth;
 pub mod modrinth;
 pub mod files;
 pub mod fabric;
+pub mod quilt;
 pub mod forge;
 pub mod java;
 pub mod logger;
+pub mod logtail;
 pub mod ping;
 pub mod discord;
 pub mod secrets;
 pub mod curseforge;
+pub mod skin;
+pub mod installqueue;
+pub mod watcher;
+pub mod preflight;
 
+use serde::Serialize;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::Mutex;
-use std::sync::LazyLock;
-use std::time::Duration;
+use std::sync::{LazyLock, RwLock};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+// Any HTTP answer from here counts as being online
+const CONNECTIVITY_PROBE_URL: &str = "https://launchermeta.mojang.com/mc/game/version_manifest_v2.json";
+const CONNECTIVITY_PROBE_TIMEOUT: Duration = Duration::from_secs(4);
+
+// Hosts the bulk of downloads come from, checked by the connectivity diagnostic
+const DIAGNOSTIC_HOSTS: [&str; 4] = [
+    "https://piston-meta.mojang.com",
+    "https://resources.download.minecraft.net",
+    "https://api.modrinth.com",
+    "https://cdn.modrinth.com",
+];
+const DIAGNOSTIC_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// Serde default for flags that are on unless a file says otherwise
+pub(crate) fn default_true() -> bool {
+    true
+}
+
+static OFFLINE_MODE: AtomicBool = AtomicBool::new(false);
 static LAUNCHER_VERSION: Mutex<String> = Mutex::new(String::new());
-static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(|| {
+// Behind a lock so a changed network setting can swap in a freshly configured client
+static HTTP_CLIENT: LazyLock<RwLock<reqwest::Client>> =
+    LazyLock::new(|| RwLock::new(build_http_client(settings::is_force_ipv4_enabled())));
+
+fn client_builder() -> reqwest::ClientBuilder {
     reqwest::Client::builder()
         .user_agent(format!("PaletheaLauncher/{}", get_launcher_version()))
         .connect_timeout(Duration::from_secs(15))
-        .timeout(Duration::from_secs(900))
-        .build()
-        .unwrap_or_else(|_| reqwest::Client::new())
-});
+}
+
+fn build_http_client(force_ipv4: bool) -> reqwest::Client {
+    let mut builder = client_builder().timeout(Duration::from_secs(900));
+    if force_ipv4 {
+        // Binding to the IPv4 wildcard means only IPv4 addresses can be connected to
+        builder = builder.local_address(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    }
+    builder.build().unwrap_or_else(|_| reqwest::Client::new())
+}
 
 pub fn set_launcher_version(version: String) {
     if let Ok(mut v) = LAUNCHER_VERSION.lock() {
@@ -45,5 +84,87 @@ pub fn get_launcher_version() -> String {
 }
 
 pub fn http_client() -> reqwest::Client {
-    HTTP_CLIENT.clone()
+    match HTTP_CLIENT.read() {
+        Ok(client) => client.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Replace the shared client so network settings apply to every request started afterwards
+pub fn rebuild_http_client() {
+    let client = build_http_client(settings::is_force_ipv4_enabled());
+    match HTTP_CLIENT.write() {
+        Ok(mut current) => *current = client,
+        Err(poisoned) => *poisoned.into_inner() = client,
+    }
+}
+
+/// Whether the last connectivity probe failed. Network paths with a cached fallback
+/// check this to skip straight to the cache instead of waiting for timeouts.
+pub fn is_offline() -> bool {
+    OFFLINE_MODE.load(Ordering::Relaxed)
+}
+
+/// Quick reachability check; records the result for `is_offline`
+pub async fn probe_connectivity() -> bool {
+    let online = http_client()
+        .head(CONNECTIVITY_PROBE_URL)
+        .timeout(CONNECTIVITY_PROBE_TIMEOUT)
+        .send()
+        .await
+        .is_ok();
+    OFFLINE_MODE.store(!online, Ordering::Relaxed);
+    online
+}
+
+// ----------
+// HostConnectivity
+// Description: Whether a download host answered over IPv4 and over IPv6, with round-trip times
+// ----------
+#[derive(Debug, Serialize, Clone)]
+pub struct HostConnectivity {
+    pub url: String,
+    pub ipv4_ms: Option<u64>,
+    pub ipv6_ms: Option<u64>,
+    pub ipv4_error: Option<String>,
+    pub ipv6_error: Option<String>,
+}
+
+async fn time_request(client: &Result<reqwest::Client, String>, url: &str) -> Result<u64, String> {
+    let client = client.as_ref().map_err(|e| e.clone())?;
+    let started = Instant::now();
+    client
+        .head(url)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(started.elapsed().as_millis() as u64)
+}
+
+/// Try each download host once per address family. Any HTTP response counts as reachable.
+pub async fn diagnose_connectivity() -> Vec<HostConnectivity> {
+    let family_client = |addr: IpAddr| {
+        client_builder()
+            .timeout(DIAGNOSTIC_TIMEOUT)
+            .local_address(addr)
+            .build()
+            .map_err(|e| e.to_string())
+    };
+    let ipv4 = family_client(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    let ipv6 = family_client(IpAddr::V6(Ipv6Addr::UNSPECIFIED));
+
+    let checks = DIAGNOSTIC_HOSTS.iter().map(|url| {
+        let (ipv4, ipv6) = (&ipv4, &ipv6);
+        async move {
+            let (v4, v6) = futures::join!(time_request(ipv4, url), time_request(ipv6, url));
+            HostConnectivity {
+                url: url.to_string(),
+                ipv4_ms: v4.as_ref().ok().copied(),
+                ipv6_ms: v6.as_ref().ok().copied(),
+                ipv4_error: v4.err(),
+                ipv6_error: v6.err(),
+            }
+        }
+    });
+    futures::future::join_all(checks).await
 }
@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fs::{self, File};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 use std::sync::{Arc, Mutex};
 use std::sync::atomic::{AtomicU64, AtomicU32, Ordering};
@@ -10,9 +11,10 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::sync::Semaphore;
 use tauri::{AppHandle, Emitter};
 use futures::stream::{self, StreamExt};
+use sha1::{Digest, Sha1};
 
-use crate::minecraft::downloader::DownloadProgress;
-use crate::minecraft::{instances, fabric, forge};
+use crate::minecraft::downloader::{DownloadProgress, ModpackFileFailure, ModpackInstallPlan, ModpackInstallSummary, ModpackPlanFile};
+use crate::minecraft::{instances, fabric, forge, quilt};
 
 const MODRINTH_API_BASE: &str = "https://api.modrinth.com/v2";
 fn get_user_agent() -> String {
@@ -184,7 +186,7 @@ pub struct ModpackIndex {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct ModpackFile {
     pub path: String,
     pub hashes: std::collections::HashMap<String, String>,
@@ -195,12 +197,46 @@ pub struct ModpackFile {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct ModpackEnv {
     pub client: String,
     pub server: String,
 }
 
+const INSTALLED_MANIFEST_FILE: &str = "modpack_manifest.json";
+
+// ----------
+// InstalledModpackManifest
+// Description: What the installed pack version shipped, kept in the instance folder so an
+//              update can tell pack files apart from the user's own changes
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct InstalledModpackManifest {
+    pub version_id: String,
+    #[serde(default)]
+    pub files: HashMap<String, String>, // index path -> sha1
+    #[serde(default)]
+    pub overrides: HashMap<String, String>, // override path -> sha1 as shipped
+}
+
+pub fn load_installed_manifest(instance: &instances::Instance) -> Option<InstalledModpackManifest> {
+    let content = fs::read_to_string(instance.get_directory().join(INSTALLED_MANIFEST_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn save_installed_manifest(instance: &instances::Instance, manifest: &InstalledModpackManifest) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(manifest).map_err(|e| e.to_string())?;
+    fs::write(instance.get_directory().join(INSTALLED_MANIFEST_FILE), content).map_err(|e| e.to_string())
+}
+
+fn index_file_hashes(index: &ModpackIndex) -> HashMap<String, String> {
+    index
+        .files
+        .iter()
+        .map(|f| (f.path.clone(), f.hashes.get("sha1").cloned().unwrap_or_default()))
+        .collect()
+}
+
 /// Search for projects on Modrinth
 pub async fn search_projects(
     query: &str,
@@ -296,7 +332,12 @@ pub async fn get_project_versions(
         params.push(format!("game_versions=[\"{}\"]", version));
     }
     if let Some(loader) = loader {
-        params.push(format!("loaders=[\"{}\"]", loader));
+        if loader.eq_ignore_ascii_case("quilt") {
+            // Quilt runs Fabric mods, and most of them are only tagged as Fabric
+            params.push("loaders=[\"quilt\",\"fabric\"]".to_string());
+        } else {
+            params.push(format!("loaders=[\"{}\"]", loader));
+        }
     }
     
     if !params.is_empty() {
@@ -386,6 +427,145 @@ pub async fn get_version_by_file_sha1(sha1_hash: &str) -> Result<Option<Modrinth
     Ok(Some(version))
 }
 
+/// Resolve many file SHA1 hashes at once through the bulk `/version_files` endpoint.
+/// Hashes without a matching file are simply absent from the returned map.
+pub async fn get_versions_by_file_sha1_bulk(hashes: Vec<String>) -> Result<HashMap<String, ModrinthVersion>, Box<dyn Error + Send + Sync>> {
+    let mut resolved = HashMap::new();
+    if hashes.is_empty() {
+        return Ok(resolved);
+    }
+
+    let client = super::http_client();
+    let url = format!("{}/version_files", MODRINTH_API_BASE);
+
+    // The body has no URL limit, but keep batches small enough to retry cheaply
+    for chunk in hashes.chunks(200) {
+        let _permit = MODRINTH_SEMAPHORE.acquire().await?;
+        let response = client
+            .post(&url)
+            .header("User-Agent", get_user_agent())
+            .json(&serde_json::json!({ "hashes": chunk, "algorithm": "sha1" }))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let versions: HashMap<String, ModrinthVersion> = response.json().await?;
+        resolved.extend(versions);
+    }
+
+    Ok(resolved)
+}
+
+// ----------
+// DetailedDependency
+// Description: A version dependency resolved to something displayable (title, icon, version)
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DetailedDependency {
+    pub dependency_type: String, // "required", "optional", "incompatible", "embedded"
+    pub project_id: Option<String>,
+    pub slug: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub icon_url: Option<String>,
+    pub downloads: u64,
+    pub version_id: Option<String>,
+    pub version_number: Option<String>,
+}
+
+fn dependency_cache_path(version_id: &str) -> PathBuf {
+    super::downloader::get_minecraft_dir()
+        .join("cache")
+        .join("modrinth_dependencies")
+        .join(format!("{}.json", version_id))
+}
+
+/// Dependencies of a version with project titles and icons, in two bulk requests at most.
+/// Results are cached on disk and served from there when Modrinth can't be reached.
+pub async fn get_version_dependencies_detailed(version_id: &str) -> Result<Vec<DetailedDependency>, Box<dyn Error + Send + Sync>> {
+    let cache_path = dependency_cache_path(version_id);
+    match resolve_version_dependencies(version_id).await {
+        Ok(dependencies) => {
+            if let Some(parent) = cache_path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            if let Ok(json) = serde_json::to_string(&dependencies) {
+                let _ = fs::write(&cache_path, json);
+            }
+            Ok(dependencies)
+        }
+        Err(error) => {
+            let cached = fs::read_to_string(&cache_path)
+                .ok()
+                .and_then(|content| serde_json::from_str::<Vec<DetailedDependency>>(&content).ok());
+            match cached {
+                Some(dependencies) => {
+                    log::warn!("Using cached dependencies for {}: {}", version_id, error);
+                    Ok(dependencies)
+                }
+                None => Err(error),
+            }
+        }
+    }
+}
+
+async fn resolve_version_dependencies(version_id: &str) -> Result<Vec<DetailedDependency>, Box<dyn Error + Send + Sync>> {
+    let version = get_version(version_id).await?;
+
+    // Pinned versions give the version number, and the project when only the version is listed
+    let pinned_ids: Vec<String> = version
+        .dependencies
+        .iter()
+        .filter_map(|dep| dep.version_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let pinned: HashMap<String, ModrinthVersion> = get_versions_bulk(pinned_ids)
+        .await?
+        .into_iter()
+        .map(|v| (v.id.clone(), v))
+        .collect();
+
+    let project_of = |dep: &ModrinthDependency| {
+        dep.project_id
+            .clone()
+            .or_else(|| dep.version_id.as_ref().and_then(|id| pinned.get(id)).map(|v| v.project_id.clone()))
+    };
+
+    let project_ids: Vec<String> = version
+        .dependencies
+        .iter()
+        .filter_map(|dep| project_of(dep))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let projects: HashMap<String, ModrinthProject> = fetch_projects_bulk(project_ids, false)
+        .await?
+        .into_iter()
+        .map(|p| (p.project_id.clone(), p))
+        .collect();
+
+    Ok(version
+        .dependencies
+        .iter()
+        .map(|dep| {
+            let project_id = project_of(dep);
+            let project = project_id.as_ref().and_then(|id| projects.get(id));
+            DetailedDependency {
+                dependency_type: dep.dependency_type.clone(),
+                slug: project.map(|p| p.slug.clone()),
+                title: project.map(|p| p.title.clone()),
+                description: project.map(|p| p.description.clone()),
+                icon_url: project.and_then(|p| p.icon_url.clone()),
+                downloads: project.map_or(0, |p| p.downloads),
+                version_number: dep.version_id.as_ref().and_then(|id| pinned.get(id)).map(|v| v.version_number.clone()),
+                version_id: dep.version_id.clone(),
+                project_id,
+            }
+        })
+        .collect())
+}
+
 /// Download a file from Modrinth with optional progress reporting
 pub async fn download_mod_file(
     file: &ModrinthFile,
@@ -415,12 +595,16 @@ pub async fn download_mod_file(
     let mut downloaded: u64 = 0;
     let mut last_emit = std::time::Instant::now();
 
+    let mut hasher = crate::minecraft::downloader::DownloadHasher::new();
+
     let mut stream = response.bytes_stream();
     use futures::StreamExt;
 
     while let Some(item) = stream.next().await {
         let chunk = item?;
+        crate::minecraft::downloader::throttle_download(chunk.len() as u64).await;
         out_file.write_all(&chunk)?;
+        hasher.update(&chunk);
         downloaded += chunk.len() as u64;
 
         // Emit progress every 100ms or so to avoid flooding
@@ -440,6 +624,12 @@ pub async fn download_mod_file(
         }
     }
     
+    drop(out_file);
+    if let Err(e) = hasher.verify(file.hashes.sha512.as_deref(), file.hashes.sha1.as_deref()) {
+        let _ = fs::remove_file(destination);
+        return Err(format!("Downloaded {} is corrupt: {}", file.filename, e).into());
+    }
+    
     Ok(())
 }
 
@@ -495,6 +685,18 @@ pub async fn get_project(project_id: &str) -> Result<ModrinthProject, Box<dyn Er
 
 /// Get multiple projects at once
 pub async fn get_projects(project_ids: Vec<String>) -> Result<Vec<ModrinthProject>, Box<dyn Error + Send + Sync>> {
+    fetch_projects_bulk(project_ids, true).await
+}
+
+/// Bulk project fetch without the per-project author lookups, for when only the projects'
+/// existence or basic details matter
+pub async fn get_projects_without_authors(project_ids: Vec<String>) -> Result<Vec<ModrinthProject>, Box<dyn Error + Send + Sync>> {
+    fetch_projects_bulk(project_ids, false).await
+}
+
+/// Bulk project fetch; `with_authors` adds one members request per project, since the
+/// bulk endpoint leaves the author out
+async fn fetch_projects_bulk(project_ids: Vec<String>, with_authors: bool) -> Result<Vec<ModrinthProject>, Box<dyn Error + Send + Sync>> {
     if project_ids.is_empty() {
         return Ok(Vec::new());
     }
@@ -520,7 +722,7 @@ pub async fn get_projects(project_ids: Vec<String>) -> Result<Vec<ModrinthProjec
         
         // Fetch authors if missing (bulk response doesn't include them)
         for project in &mut projects {
-            if project.author.is_empty() {
+            if with_authors && project.author.is_empty() {
                 let members_url = format!("{}/project/{}/members", MODRINTH_API_BASE, project.project_id);
                 if let Ok(members_res) = client
                     .get(&members_url)
@@ -548,13 +750,83 @@ pub async fn get_projects(project_ids: Vec<String>) -> Result<Vec<ModrinthProjec
     Ok(all_projects)
 }
 
+// ----------
+// ModpackFileOption
+// Description: One file attached to a modpack version, for choosing the pack explicitly
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModpackFileOption {
+    pub filename: String,
+    pub size: u64,
+    pub primary: bool,
+    pub recommended: bool, // what select_modpack_file would pick on its own
+}
+
+fn is_server_pack_name(filename: &str) -> bool {
+    let lower = filename.to_ascii_lowercase();
+    lower.contains("server") && !lower.contains("client")
+}
+
+/// The pack archive among a modpack version's files. The primary flag alone can point at a
+/// server pack or an extra attachment, so .mrpack files win over .zip, client packs over
+/// files named like server packs, and the primary flag only breaks ties. `requested` picks
+/// a file by name when the user chose one.
+pub fn select_modpack_file<'a>(files: &'a [ModrinthFile], requested: Option<&str>) -> Option<&'a ModrinthFile> {
+    if let Some(requested) = requested {
+        if let Some(file) = files.iter().find(|f| f.filename == requested) {
+            log::info!("Using requested modpack file {}", file.filename);
+            return Some(file);
+        }
+        log::warn!("Requested modpack file {} is not part of this version, choosing automatically", requested);
+    }
+
+    let with_extension = |ext: &str| -> Vec<&'a ModrinthFile> {
+        files.iter().filter(|f| f.filename.to_ascii_lowercase().ends_with(ext)).collect()
+    };
+    let mut candidates = with_extension(".mrpack");
+    if candidates.is_empty() {
+        candidates = with_extension(".zip");
+    }
+    if candidates.iter().any(|f| !is_server_pack_name(&f.filename)) {
+        candidates.retain(|f| !is_server_pack_name(&f.filename));
+    }
+
+    let chosen = candidates
+        .iter()
+        .copied()
+        .find(|f| f.primary)
+        .or_else(|| candidates.first().copied())
+        .or_else(|| files.iter().find(|f| f.primary))
+        .or_else(|| files.first())?;
+    if files.len() > 1 {
+        log::info!("Chose modpack file {} out of {} attached files", chosen.filename, files.len());
+    }
+    Some(chosen)
+}
+
+/// Files attached to a modpack version, flagging the one picked automatically
+pub async fn get_modpack_files(version_id: &str) -> Result<Vec<ModpackFileOption>, Box<dyn Error + Send + Sync>> {
+    let version = get_version(version_id).await?;
+    let recommended = select_modpack_file(&version.files, None).map(|f| f.filename.clone());
+    Ok(version
+        .files
+        .iter()
+        .map(|f| ModpackFileOption {
+            filename: f.filename.clone(),
+            size: f.size,
+            primary: f.primary,
+            recommended: recommended.as_deref() == Some(f.filename.as_str()),
+        })
+        .collect())
+}
+
 /// Calculate total download size for a modpack
-pub async fn get_modpack_total_size(version_id: &str) -> Result<u64, Box<dyn Error + Send + Sync>> {
+pub async fn get_modpack_total_size(version_id: &str, file_name: Option<&str>) -> Result<u64, Box<dyn Error + Send + Sync>> {
     println!("Calculating total size for modpack version: {}", version_id);
     
     // 1. Get version details
     let version = get_version(version_id).await?;
-    let primary_file = version.files.iter().find(|f| f.primary).unwrap_or(&version.files[0]);
+    let primary_file = select_modpack_file(&version.files, file_name).ok_or("Modpack version has no files")?;
     
     println!("Found primary file: {} ({} bytes)", primary_file.filename, primary_file.size);
 
@@ -584,11 +856,59 @@ pub async fn get_modpack_total_size(version_id: &str) -> Result<u64, Box<dyn Err
     Ok(total_size)
 }
 
+/// Extract Modrinth project and version IDs from a pack file's download URLs
+fn parse_project_from_download_urls(urls: &[String]) -> (Option<String>, Option<String>) {
+    let mut project_id = None;
+    let mut version_id = None;
+
+    for url in urls {
+        if url.contains("cdn.modrinth.com/data/") {
+            let parts: Vec<&str> = url.split("/data/").collect();
+            if parts.len() > 1 {
+                let sub_parts: Vec<&str> = parts[1].split('/').collect();
+                if sub_parts.len() >= 3 {
+                    project_id = Some(sub_parts[0].to_string());
+                    version_id = Some(sub_parts[2].to_string());
+                    break;
+                }
+            }
+        } else if url.contains("api.modrinth.com/v2/project/") {
+            let parts: Vec<&str> = url.split("/project/").collect();
+            if parts.len() > 1 {
+                let sub_parts: Vec<&str> = parts[1].split('/').collect();
+                if sub_parts.len() >= 3 && sub_parts[1] == "version" {
+                    project_id = Some(sub_parts[0].to_string());
+                    version_id = Some(sub_parts[2].to_string());
+                    break;
+                }
+            }
+        } else if url.contains("/project/") && url.contains("/version/") {
+            if let Some(p_idx) = url.find("/project/") {
+                let after_p = &url[p_idx + 9..];
+                if let Some(slash_idx) = after_p.find('/') {
+                    project_id = Some(after_p[..slash_idx].to_string());
+
+                    if let Some(v_idx) = url.find("/version/") {
+                        let after_v = &url[v_idx + 9..];
+                        if let Some(slash_idx_v) = after_v.find('/') {
+                            version_id = Some(after_v[..slash_idx_v].to_string());
+                        }
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    (project_id, version_id)
+}
+
 pub async fn install_modpack(
     app_handle: &AppHandle,
     instance_id: &str,
     mr_version_id: &str,
-) -> Result<(), Box<dyn Error + Send + Sync>> {
+    file_name: Option<&str>,
+) -> Result<ModpackInstallSummary, Box<dyn Error + Send + Sync>> {
     let _ = app_handle.emit("download-progress", DownloadProgress { 
         stage: "Fetching modpack info...".to_string(), 
         percentage: 0.0,
@@ -600,13 +920,14 @@ pub async fn install_modpack(
     
     // 1. Get version details
     let version = get_version(mr_version_id).await?;
-    let primary_file = version.files.iter().find(|f| f.primary).unwrap_or(&version.files[0]);
+    let primary_file = select_modpack_file(&version.files, file_name).ok_or("Modpack version has no files")?;
     let modpack_size = primary_file.size;
     
     // 2. Download .mrpack
     let temp_dir = std::env::temp_dir().join("palethea_modpack");
     let _ = fs::create_dir_all(&temp_dir);
     let mrpack_path = temp_dir.join("modpack.mrpack");
+    crate::minecraft::downloader::ensure_disk_space(&temp_dir, modpack_size)?;
     
     let _ = app_handle.emit("download-progress", DownloadProgress { 
         stage: format!("Downloading modpack file: {}...", primary_file.filename), 
@@ -645,85 +966,40 @@ pub async fn install_modpack(
     // Calculate total mod sizes
     let total_mods_size: u64 = index.files.iter().map(|f| f.file_size).sum();
 
-    // 4. Update instance configuration
-    let mc_version = index.dependencies.get("minecraft").ok_or("No Minecraft version in modpack")?;
-    
-    let mut mod_loader = instances::ModLoader::Vanilla;
-    let mut loader_version = None;
-    
-    if let Some(fabric) = index.dependencies.get("fabric-loader") {
-        mod_loader = instances::ModLoader::Fabric;
-        loader_version = Some(fabric.clone());
-    } else if let Some(forge) = index.dependencies.get("forge") {
-        mod_loader = instances::ModLoader::Forge;
-        loader_version = Some(forge.clone());
-    } else if let Some(neoforge) = index.dependencies.get("neoforge") {
-        mod_loader = instances::ModLoader::NeoForge;
-        loader_version = Some(neoforge.clone());
-    }
+    // Mods plus extracted overrides (approximated by the archive size) land in the instance folder
+    let instance_game_dir = instances::get_instance(instance_id)?.get_game_directory();
+    crate::minecraft::downloader::ensure_disk_space(&instance_game_dir, total_mods_size + modpack_size)?;
 
-    let mut instance = instances::get_instance(instance_id)?;
-    instance.version_id = mc_version.clone();
-    instance.mod_loader = mod_loader.clone();
-    instance.mod_loader_version = loader_version.clone();
-    instances::update_instance(instance.clone())?;
-    
-    // 4.5 Install mod loader metadata and libraries
-    if mod_loader != instances::ModLoader::Vanilla {
-        if let Some(loader_ver) = &loader_version {
-            match mod_loader {
-                instances::ModLoader::Fabric => {
-                    let _ = app_handle.emit("download-progress", DownloadProgress { 
-                        stage: "Installing Fabric Loader...".to_string(), 
-                        percentage: 25.0,
-                        current: 15,
-                        total: 100,
-                        total_bytes: None,
-                        downloaded_bytes: None,
-                    });
-                    if let Err(e) = fabric::install_fabric(&instance, loader_ver).await {
-                        crate::log_error!(app_handle, "Failed to install Fabric loader: {}", e);
-                    }
-                },
-                instances::ModLoader::Forge => {
-                    let _ = app_handle.emit("download-progress", DownloadProgress { 
-                        stage: "Installing Forge Loader...".to_string(), 
-                        percentage: 25.0,
-                        current: 15,
-                        total: 100,
-                        total_bytes: None,
-                        downloaded_bytes: None,
-                    });
-                    if let Err(e) = forge::install_forge(&instance, loader_ver).await {
-                        crate::log_error!(app_handle, "Failed to install Forge loader: {}", e);
-                    }
-                },
-                instances::ModLoader::NeoForge => {
-                    let _ = app_handle.emit("download-progress", DownloadProgress { 
-                        stage: "Installing NeoForge Loader...".to_string(), 
-                        percentage: 25.0,
-                        current: 15,
-                        total: 100,
-                        total_bytes: None,
-                        downloaded_bytes: None,
-                    });
-                    if let Err(e) = forge::install_neoforge(&instance, loader_ver).await {
-                        crate::log_error!(app_handle, "Failed to install NeoForge loader: {}", e);
-                    }
-                },
-                _ => {}
-            }
-        }
+    // 4. Update instance configuration and install the loader
+    let mut instance = apply_modpack_dependencies(app_handle, instance_id, &index).await?;
+
+    // Remember where the pack came from for update checks and re-export
+    instance.modpack_provider = Some("Modrinth".to_string());
+    instance.modpack_project_id = Some(version.project_id.clone());
+    instance.modpack_version_id = Some(mr_version_id.to_string());
+    instance.modpack_file_name = Some(primary_file.filename.clone());
+    if !instance.has_custom_logo() {
+        let icon_url = get_project(&version.project_id).await.ok().and_then(|project| project.icon_url);
+        crate::minecraft::downloader::apply_modpack_logo(&mut instance, icon_url).await;
     }
+    instances::update_instance(instance.clone())?;
     
     // 5. Download mods in parallel
     let total_files = index.files.len();
+    let manifest_files = index_file_hashes(&index);
     let downloaded_bytes_counter = Arc::new(AtomicU64::new(0));
     let completed_count = Arc::new(AtomicU32::new(0));
     let last_progress_emit_ms = Arc::new(AtomicU64::new(0));
     let mods_metadata = Arc::new(Mutex::new(Vec::new()));
     let game_dir = instance.get_game_directory();
+    // Index paths are resolved against the real game directory, so it has to exist first
+    fs::create_dir_all(&game_dir)?;
     let client = super::http_client();
+    let meta_writer = crate::minecraft::files::MetaWriter::spawn();
+    let meta_writer_ref = &meta_writer;
+    let failures = Arc::new(Mutex::new(Vec::<ModpackFileFailure>::new()));
+    let concurrency = crate::minecraft::settings::get_download_concurrency();
+    let max_retries = crate::minecraft::settings::get_download_max_retries();
 
     let _ = app_handle.emit("download-progress", DownloadProgress {
         stage: format!("Downloading mods 0/{}...", total_files),
@@ -735,96 +1011,159 @@ pub async fn install_modpack(
     });
 
     stream::iter(index.files.into_iter())
-        .for_each_concurrent(15, |mp_file| {
+        .for_each_concurrent(concurrency, |mp_file| {
             let app_handle = app_handle.clone();
             let downloaded_bytes_counter = downloaded_bytes_counter.clone();
             let completed_count = completed_count.clone();
             let last_progress_emit_ms = last_progress_emit_ms.clone();
             let mods_metadata = mods_metadata.clone();
+            let failures = failures.clone();
             let game_dir = game_dir.clone();
             let total_mods_size = total_mods_size;
             let client = client.clone();
 
             async move {
-                let dest = game_dir.join(&mp_file.path);
+                if crate::minecraft::installqueue::is_cancelled(instance_id) {
+                    return;
+                }
+                let dest = match pack_file_target(&game_dir, &mp_file.path) {
+                    Ok(dest) => dest,
+                    Err(failure) => {
+                        crate::log_warn!(&app_handle, "Skipped file {}: {}", mp_file.path, failure.reason);
+                        completed_count.fetch_add(1, Ordering::SeqCst);
+                        if let Ok(mut list) = failures.lock() {
+                            list.push(failure);
+                        }
+                        return;
+                    }
+                };
                 
                 // Try each download URL
                 let mut downloaded = false;
-                for url in &mp_file.downloads {
-                    // Acquire rate limit permit
-                    let _permit = MODRINTH_SEMAPHORE.acquire().await.ok();
+                let mut last_failure: Option<ModpackFileFailure> = None;
+                let mut attempt = 0u32;
+                loop {
+                    for url in &mp_file.downloads {
+                        // Acquire rate limit permit
+                        let _permit = MODRINTH_SEMAPHORE.acquire().await.ok();
                     
-                    if let Ok(resp) = client.get(url).header("User-Agent", get_user_agent()).send().await {
+                        let resp = match client.get(url).header("User-Agent", get_user_agent()).send().await {
+                            Ok(resp) => resp,
+                            Err(e) => {
+                                last_failure = Some(ModpackFileFailure::from_request_error(&mp_file.path, &e));
+                                continue;
+                            }
+                        };
                         if !resp.status().is_success() {
+                            last_failure = Some(ModpackFileFailure::from_status(&mp_file.path, resp.status()));
                             continue;
                         }
                         if let Some(parent) = dest.parent() {
                             let _ = fs::create_dir_all(parent);
                         }
-                        if let Ok(mut f) = File::create(&dest) {
-                            let mut stream = resp.bytes_stream();
-                            let mut downloaded_for_attempt = 0u64;
-                            let mut attempt_ok = true;
-
-                            while let Some(item) = stream.next().await {
-                                match item {
-                                    Ok(chunk) => {
-                                        if f.write_all(&chunk).is_err() {
-                                            attempt_ok = false;
-                                            break;
-                                        }
-
-                                        let chunk_len = chunk.len() as u64;
-                                        downloaded_for_attempt += chunk_len;
-                                        let current_downloaded = downloaded_bytes_counter.fetch_add(chunk_len, Ordering::SeqCst) + chunk_len;
-
-                                        let now_ms = SystemTime::now()
-                                            .duration_since(UNIX_EPOCH)
-                                            .map(|d| d.as_millis() as u64)
-                                            .unwrap_or(0);
-                                        let last_ms = last_progress_emit_ms.load(Ordering::Relaxed);
-                                        if now_ms.saturating_sub(last_ms) >= 120
-                                            && last_progress_emit_ms
-                                                .compare_exchange(last_ms, now_ms, Ordering::SeqCst, Ordering::Relaxed)
-                                                .is_ok()
-                                        {
-                                            let completed_so_far = completed_count.load(Ordering::SeqCst);
-                                            let byte_ratio = if total_mods_size > 0 {
-                                                (current_downloaded as f32 / total_mods_size as f32).clamp(0.0, 1.0)
-                                            } else if total_files > 0 {
-                                                (completed_so_far as f32 / total_files as f32).clamp(0.0, 1.0)
-                                            } else {
-                                                1.0
-                                            };
-                                            let progress = 30.0 + (byte_ratio * 60.0);
-                                            let _ = app_handle.emit("download-progress", DownloadProgress {
-                                                stage: format!("Downloading mods {}/{}...", completed_so_far, total_files),
-                                                percentage: progress,
-                                                current: completed_so_far,
-                                                total: total_files as u32,
-                                                total_bytes: Some(total_mods_size),
-                                                downloaded_bytes: Some(current_downloaded),
-                                            });
-                                        }
-                                    }
-                                    Err(_) => {
+                        let mut f = match File::create(&dest) {
+                            Ok(f) => f,
+                            Err(e) => {
+                                last_failure = Some(ModpackFileFailure::new(&mp_file.path, "io", format!("Failed to create file: {}", e)));
+                                continue;
+                            }
+                        };
+
+                        let mut stream = resp.bytes_stream();
+                        let mut downloaded_for_attempt = 0u64;
+                        let mut attempt_ok = true;
+                        let mut hasher = crate::minecraft::downloader::DownloadHasher::new();
+
+                        while let Some(item) = stream.next().await {
+                            if crate::minecraft::installqueue::is_cancelled(instance_id) {
+                                last_failure = Some(ModpackFileFailure::new(&mp_file.path, "cancelled", "Installation cancelled"));
+                                attempt_ok = false;
+                                break;
+                            }
+                            match item {
+                                Ok(chunk) => {
+                                    crate::minecraft::downloader::throttle_download(chunk.len() as u64).await;
+                                    if let Err(e) = f.write_all(&chunk) {
+                                        last_failure = Some(ModpackFileFailure::new(&mp_file.path, "io", format!("Failed to write file: {}", e)));
                                         attempt_ok = false;
                                         break;
                                     }
+                                    hasher.update(&chunk);
+
+                                    let chunk_len = chunk.len() as u64;
+                                    downloaded_for_attempt += chunk_len;
+                                    let current_downloaded = downloaded_bytes_counter.fetch_add(chunk_len, Ordering::SeqCst) + chunk_len;
+
+                                    let now_ms = SystemTime::now()
+                                        .duration_since(UNIX_EPOCH)
+                                        .map(|d| d.as_millis() as u64)
+                                        .unwrap_or(0);
+                                    let last_ms = last_progress_emit_ms.load(Ordering::Relaxed);
+                                    if now_ms.saturating_sub(last_ms) >= 120
+                                        && last_progress_emit_ms
+                                            .compare_exchange(last_ms, now_ms, Ordering::SeqCst, Ordering::Relaxed)
+                                            .is_ok()
+                                    {
+                                        let completed_so_far = completed_count.load(Ordering::SeqCst);
+                                        let byte_ratio = if total_mods_size > 0 {
+                                            (current_downloaded as f32 / total_mods_size as f32).clamp(0.0, 1.0)
+                                        } else if total_files > 0 {
+                                            (completed_so_far as f32 / total_files as f32).clamp(0.0, 1.0)
+                                        } else {
+                                            1.0
+                                        };
+                                        let progress = 30.0 + (byte_ratio * 60.0);
+                                        let _ = app_handle.emit("download-progress", DownloadProgress {
+                                            stage: format!("Downloading mods {}/{}...", completed_so_far, total_files),
+                                            percentage: progress,
+                                            current: completed_so_far,
+                                            total: total_files as u32,
+                                            total_bytes: Some(total_mods_size),
+                                            downloaded_bytes: Some(current_downloaded),
+                                        });
+                                    }
+                                }
+                                Err(e) => {
+                                    last_failure = Some(ModpackFileFailure::from_request_error(&mp_file.path, &e));
+                                    attempt_ok = false;
+                                    break;
                                 }
                             }
+                        }
 
-                            if attempt_ok {
-                                downloaded = true;
-                                break;
+                        if attempt_ok {
+                            // A corrupt copy falls through to the next mirror
+                            if let Err(e) = hasher.verify(
+                                mp_file.hashes.get("sha512").map(|h| h.as_str()),
+                                mp_file.hashes.get("sha1").map(|h| h.as_str()),
+                            ) {
+                                last_failure = Some(ModpackFileFailure::new(&mp_file.path, "hash_mismatch", e));
+                                attempt_ok = false;
                             }
+                        }
 
-                            if downloaded_for_attempt > 0 {
-                                downloaded_bytes_counter.fetch_sub(downloaded_for_attempt, Ordering::SeqCst);
-                            }
-                            let _ = fs::remove_file(&dest);
+                        if attempt_ok {
+                            downloaded = true;
+                            break;
+                        }
+
+                        drop(f);
+
+                        if downloaded_for_attempt > 0 {
+                            downloaded_bytes_counter.fetch_sub(downloaded_for_attempt, Ordering::SeqCst);
                         }
+                        let _ = fs::remove_file(&dest);
+                    }
+
+                    if downloaded {
+                        break;
                     }
+                    let retryable = last_failure.as_ref().map(|f| f.retryable).unwrap_or(false);
+                    if !retryable || attempt >= max_retries {
+                        break;
+                    }
+                    attempt += 1;
+                    tokio::time::sleep(crate::minecraft::downloader::retry_backoff(attempt)).await;
                 }
                 
                 let current_completed = completed_count.fetch_add(1, Ordering::SeqCst) + 1;
@@ -848,52 +1187,33 @@ pub async fn install_modpack(
                 });
 
                 if !downloaded {
-                    crate::log_warn!(&app_handle, "Failed to download file: {}", mp_file.path);
-                } else {
-                    // Try to extract project and version IDs from the successful download URL for metadata
-                    let mut project_id = None;
-                    let mut version_id = None;
-                    
-                    for url in &mp_file.downloads {
-                        if url.contains("cdn.modrinth.com/data/") {
-                            let parts: Vec<&str> = url.split("/data/").collect();
-                            if parts.len() > 1 {
-                                let sub_parts: Vec<&str> = parts[1].split('/').collect();
-                                if sub_parts.len() >= 3 {
-                                    project_id = Some(sub_parts[0].to_string());
-                                    version_id = Some(sub_parts[2].to_string());
-                                    break;
-                                }
-                            }
-                        } else if url.contains("api.modrinth.com/v2/project/") {
-                             let parts: Vec<&str> = url.split("/project/").collect();
-                             if parts.len() > 1 {
-                                 let sub_parts: Vec<&str> = parts[1].split('/').collect();
-                                 if sub_parts.len() >= 3 && sub_parts[1] == "version" {
-                                     project_id = Some(sub_parts[0].to_string());
-                                     version_id = Some(sub_parts[2].to_string());
-                                     break;
-                                 }
-                             }
-                        } else if url.contains("/project/") && url.contains("/version/") {
-                            if let Some(p_idx) = url.find("/project/") {
-                                let after_p = &url[p_idx + 9..];
-                                if let Some(slash_idx) = after_p.find('/') {
-                                    project_id = Some(after_p[..slash_idx].to_string());
-                                    
-                                    if let Some(v_idx) = url.find("/version/") {
-                                        let after_v = &url[v_idx + 9..];
-                                        if let Some(slash_idx_v) = after_v.find('/') {
-                                            version_id = Some(after_v[..slash_idx_v].to_string());
-                                        }
-                                    }
-                                    break;
-                                }
-                            }
-                        }
+                    let mut failure = last_failure.unwrap_or_else(|| {
+                        ModpackFileFailure::new(&mp_file.path, "missing_url", "No download URLs listed in the modpack index")
+                    });
+                    if let (Some(pid), _) = parse_project_from_download_urls(&mp_file.downloads) {
+                        let manual_url = format!("https://modrinth.com/project/{}", pid);
+                        failure = failure.with_project(pid, Some(manual_url));
+                    }
+                    crate::log_warn!(&app_handle, "Failed to download file {}: {}", mp_file.path, failure.reason);
+                    if let Ok(mut list) = failures.lock() {
+                        list.push(failure);
                     }
+                } else {
+                    let (project_id, version_id) = parse_project_from_download_urls(&mp_file.downloads);
                     
                     if let Some(pid) = project_id {
+                        // Persist the IDs right away so an interrupted install keeps usable metadata
+                        meta_writer_ref.queue(dest.clone(), crate::minecraft::files::ModMeta {
+                            project_id: pid.clone(),
+                            version_id: version_id.clone(),
+                            name: None,
+                            author: None,
+                            icon_url: None,
+                            version_name: None,
+                            categories: None,
+                            source_url: None,
+                            pinned: false,
+                        });
                         if let Ok(mut meta) = mods_metadata.lock() {
                             meta.push((dest, pid, version_id));
                         }
@@ -903,6 +1223,11 @@ pub async fn install_modpack(
         })
         .await;
 
+    if crate::minecraft::installqueue::is_cancelled(instance_id) {
+        let _ = fs::remove_file(&mrpack_path);
+        return Err("Modpack installation cancelled".into());
+    }
+
     // 6. Fetch and write metadata for all mods
     let mods_metadata_vec = mods_metadata
         .lock()
@@ -918,32 +1243,10 @@ pub async fn install_modpack(
             downloaded_bytes: Some(total_mods_size),
         });
 
-        let mut unique_p_ids: Vec<String> = mods_metadata_vec.iter().map(|(_, p, _)| p.clone()).collect();
-        unique_p_ids.sort();
-        unique_p_ids.dedup();
-        
-        let v_ids: Vec<String> = mods_metadata_vec.iter().filter_map(|(_, _, v)| v.clone()).collect();
-
-        let projects = get_projects(unique_p_ids).await.unwrap_or_default();
-        let versions = get_versions_bulk(v_ids).await.unwrap_or_default();
-
-        for (dest, p_id, v_id) in mods_metadata_vec {
-            let project = projects.iter().find(|p| p.project_id == p_id);
-            let version = v_id.as_ref().and_then(|vid| versions.iter().find(|v| &v.id == vid));
-
-            let meta = crate::minecraft::files::ModMeta {
-                project_id: p_id,
-                version_id: v_id,
-                name: project.map(|p| p.title.clone()),
-                author: project.map(|p| p.author.clone()),
-                icon_url: project.and_then(|p| p.icon_url.clone()),
-                version_name: version.map(|v| v.version_number.clone()),
-                categories: project.map(|p| p.categories.clone()),
-            };
-
-            let _ = crate::minecraft::files::write_meta_for_file(&dest, &meta);
-        }
+        enrich_pack_mod_metadata(&meta_writer, mods_metadata_vec).await;
     }
+    // Joins the writer thread, so it waits off the async runtime
+    let _ = tokio::task::spawn_blocking(move || meta_writer.finish()).await;
     
     // 6. Copy overrides
     let _ = app_handle.emit("download-progress", DownloadProgress { 
@@ -955,50 +1258,754 @@ pub async fn install_modpack(
         downloaded_bytes: None,
     });
     
-    {
-        // We need to re-open the archive to iterate over files
-        let file = File::open(&mrpack_path)?;
-        let mut archive = zip::ZipArchive::new(file)?;
-        
-        for i in 0..archive.len() {
-            let mut file = archive.by_index(i)?;
-            let name = file.name().to_string();
-            
-            let target_rel = if let Some(stripped) = name.strip_prefix("overrides/") {
-                Some(stripped)
-            } else if let Some(stripped) = name.strip_prefix("client-overrides/") {
-                Some(stripped)
-            } else {
-                None
+    let override_hashes = apply_overrides(&mrpack_path, &instance.get_game_directory(), None)?;
+
+    save_installed_manifest(&instance, &InstalledModpackManifest {
+        version_id: mr_version_id.to_string(),
+        files: manifest_files,
+        overrides: override_hashes,
+    })?;
+    
+    let _ = app_handle.emit("download-progress", DownloadProgress { 
+        stage: "Modpack installed!".to_string(), 
+        percentage: 100.0,
+        current: 100,
+        total: 100,
+        total_bytes: None,
+        downloaded_bytes: None,
+    });
+    let _ = fs::remove_dir_all(&temp_dir);
+    
+    let failures = failures.lock().map(|list| list.clone()).unwrap_or_default();
+    let summary = ModpackInstallSummary::new(total_files, failures);
+    if !summary.failures.is_empty() {
+        crate::log_warn!(app_handle, "Modpack installed with {} of {} file(s) failing", summary.failures.len(), summary.total);
+    }
+    Ok(summary)
+}
+
+/// The loader and loader version a pack index depends on
+fn loader_from_index(index: &ModpackIndex) -> (instances::ModLoader, Option<String>) {
+    if let Some(fabric) = index.dependencies.get("fabric-loader") {
+        (instances::ModLoader::Fabric, Some(fabric.clone()))
+    } else if let Some(quilt) = index.dependencies.get("quilt-loader") {
+        (instances::ModLoader::Quilt, Some(quilt.clone()))
+    } else if let Some(forge) = index.dependencies.get("forge") {
+        (instances::ModLoader::Forge, Some(forge.clone()))
+    } else if let Some(neoforge) = index.dependencies.get("neoforge") {
+        (instances::ModLoader::NeoForge, Some(neoforge.clone()))
+    } else {
+        (instances::ModLoader::Vanilla, None)
+    }
+}
+
+/// Point the instance at the pack's Minecraft version and loader, installing the loader if needed
+async fn apply_modpack_dependencies(
+    app_handle: &AppHandle,
+    instance_id: &str,
+    index: &ModpackIndex,
+) -> Result<instances::Instance, Box<dyn Error + Send + Sync>> {
+    let mc_version = index.dependencies.get("minecraft").ok_or("No Minecraft version in modpack")?;
+    let (mod_loader, loader_version) = loader_from_index(index);
+
+    let mut instance = instances::get_instance(instance_id)?;
+    instance.version_id = mc_version.clone();
+    instance.mod_loader = mod_loader.clone();
+    instance.mod_loader_version = loader_version.clone();
+    instances::update_instance(instance.clone())?;
+    
+    // 4.5 Install mod loader metadata and libraries
+    if mod_loader != instances::ModLoader::Vanilla {
+        if let Some(loader_ver) = &loader_version {
+            // Map loader installer steps into the 25-30% slice of the overall bar
+            let report_loader_progress = |stage: &str, fraction: f32| {
+                let _ = app_handle.emit("download-progress", DownloadProgress {
+                    stage: stage.to_string(),
+                    percentage: 25.0 + fraction.clamp(0.0, 1.0) * 5.0,
+                    current: 15,
+                    total: 100,
+                    total_bytes: None,
+                    downloaded_bytes: None,
+                });
             };
-            
-            if let Some(rel_path) = target_rel {
-                if !rel_path.is_empty() {
-                    let dest = instance.get_game_directory().join(rel_path);
-                    if file.is_dir() {
-                        let _ = fs::create_dir_all(dest);
-                    } else {
-                        if let Some(parent) = dest.parent() {
-                            let _ = fs::create_dir_all(parent);
+
+            match mod_loader {
+                instances::ModLoader::Fabric => {
+                    let _ = app_handle.emit("download-progress", DownloadProgress { 
+                        stage: "Installing Fabric Loader...".to_string(), 
+                        percentage: 25.0,
+                        current: 15,
+                        total: 100,
+                        total_bytes: None,
+                        downloaded_bytes: None,
+                    });
+                    if let Err(e) = fabric::install_fabric(&instance, loader_ver, Some(&report_loader_progress)).await {
+                        crate::log_error!(app_handle, "Failed to install Fabric loader: {}", e);
+                    }
+                },
+                instances::ModLoader::Quilt => {
+                    let _ = app_handle.emit("download-progress", DownloadProgress { 
+                        stage: "Installing Quilt Loader...".to_string(), 
+                        percentage: 25.0,
+                        current: 15,
+                        total: 100,
+                        total_bytes: None,
+                        downloaded_bytes: None,
+                    });
+                    if let Err(e) = quilt::install_quilt(&instance, loader_ver, Some(&report_loader_progress)).await {
+                        crate::log_error!(app_handle, "Failed to install Quilt loader: {}", e);
+                    }
+                },
+                instances::ModLoader::Forge => {
+                    let _ = app_handle.emit("download-progress", DownloadProgress { 
+                        stage: "Installing Forge Loader...".to_string(), 
+                        percentage: 25.0,
+                        current: 15,
+                        total: 100,
+                        total_bytes: None,
+                        downloaded_bytes: None,
+                    });
+                    if let Err(e) = forge::install_forge(&instance, loader_ver, Some(&report_loader_progress)).await {
+                        crate::log_error!(app_handle, "Failed to install Forge loader: {}", e);
+                    }
+                },
+                instances::ModLoader::NeoForge => {
+                    let _ = app_handle.emit("download-progress", DownloadProgress { 
+                        stage: "Installing NeoForge Loader...".to_string(), 
+                        percentage: 25.0,
+                        current: 15,
+                        total: 100,
+                        total_bytes: None,
+                        downloaded_bytes: None,
+                    });
+                    if let Err(e) = forge::install_neoforge(&instance, loader_ver, Some(&report_loader_progress)).await {
+                        crate::log_error!(app_handle, "Failed to install NeoForge loader: {}", e);
+                    }
+                },
+                _ => {}
+            }
+        }
+    }
+
+    Ok(instance)
+}
+
+/// Fill in names, authors and icons for pack mods, given (path, project id, version id) entries
+async fn enrich_pack_mod_metadata(
+    meta_writer: &crate::minecraft::files::MetaWriter,
+    entries: Vec<(PathBuf, String, Option<String>)>,
+) {
+    let mut unique_p_ids: Vec<String> = entries.iter().map(|(_, p, _)| p.clone()).collect();
+    unique_p_ids.sort();
+    unique_p_ids.dedup();
+    
+    let v_ids: Vec<String> = entries.iter().filter_map(|(_, _, v)| v.clone()).collect();
+
+    let projects = get_projects(unique_p_ids).await.unwrap_or_default();
+    let versions = get_versions_bulk(v_ids).await.unwrap_or_default();
+
+    for (dest, p_id, v_id) in entries {
+        let project = projects.iter().find(|p| p.project_id == p_id);
+        let version = v_id.as_ref().and_then(|vid| versions.iter().find(|v| &v.id == vid));
+
+        let meta = crate::minecraft::files::ModMeta {
+            project_id: p_id,
+            version_id: v_id,
+            name: project.map(|p| p.title.clone()),
+            author: project.map(|p| p.author.clone()),
+            icon_url: project.and_then(|p| p.icon_url.clone()),
+            version_name: version.map(|v| v.version_number.clone()),
+            categories: project.map(|p| p.categories.clone()),
+            source_url: None,
+            pinned: false,
+        };
+
+        meta_writer.queue(dest, meta);
+    }
+}
+
+/// Extract `overrides/` and `client-overrides/` into the game directory and return the SHA1 the
+/// pack ships for each file. With `previous` hashes, files the user changed since they were
+/// installed (or that the old version never shipped) are left alone.
+fn apply_overrides(
+    mrpack_path: &Path,
+    game_dir: &Path,
+    previous: Option<&HashMap<String, String>>,
+) -> Result<HashMap<String, String>, Box<dyn Error + Send + Sync>> {
+    let file = File::open(mrpack_path)?;
+    let mut archive = zip::ZipArchive::new(file)?;
+    let mut shipped = HashMap::new();
+    fs::create_dir_all(game_dir)?;
+
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let Some(enclosed) = entry.enclosed_name() else {
+            continue;
+        };
+        let Some(rel_path) = enclosed
+            .strip_prefix("overrides")
+            .or_else(|_| enclosed.strip_prefix("client-overrides"))
+            .ok()
+            .map(|p| p.to_path_buf())
+        else {
+            continue;
+        };
+        if rel_path.as_os_str().is_empty() {
+            continue;
+        }
+
+        let Some(dest) = crate::minecraft::downloader::safe_extract_path(game_dir, &rel_path) else {
+            log::warn!("Skipping override {} that would be written outside the instance", rel_path.display());
+            continue;
+        };
+        if entry.is_dir() {
+            let _ = fs::create_dir_all(dest);
+            continue;
+        }
+
+        let mut content = Vec::new();
+        if entry.read_to_end(&mut content).is_err() {
+            continue;
+        }
+        let rel_key = rel_path.to_string_lossy().replace('\\', "/");
+        shipped.insert(rel_key.clone(), format!("{:x}", Sha1::digest(&content)));
+
+        if let Some(previous) = previous {
+            if dest.exists() {
+                let untouched = previous
+                    .get(&rel_key)
+                    .is_some_and(|hash| crate::minecraft::downloader::file_sha1(&dest).as_deref() == Some(hash.as_str()));
+                if !untouched {
+                    continue;
+                }
+            }
+        }
+
+        if let Some(parent) = dest.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(&dest, &content);
+    }
+
+    Ok(shipped)
+}
+
+/// Resolve an index path inside the game directory, rejecting paths that would land outside it
+fn pack_file_destination(game_dir: &Path, path: &str) -> Option<PathBuf> {
+    crate::minecraft::downloader::safe_extract_path(game_dir, Path::new(path))
+}
+
+/// `pack_file_destination` for entries about to be downloaded, reporting a rejected path as a failure
+fn pack_file_target(game_dir: &Path, path: &str) -> Result<PathBuf, ModpackFileFailure> {
+    pack_file_destination(game_dir, path)
+        .ok_or_else(|| ModpackFileFailure::new(path, "unsafe_path", "The modpack index points outside the instance folder"))
+}
+
+fn disabled_variant(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".disabled");
+    PathBuf::from(name)
+}
+
+/// Move a staged download over `target`, copying when the two sit on different drives
+fn move_staged_file(staged: &Path, target: &Path) -> std::io::Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::rename(staged, target).is_ok() {
+        return Ok(());
+    }
+    fs::copy(staged, target)?;
+    fs::remove_file(staged)
+}
+
+/// Update an installed Modrinth pack in place. Only files the old version shipped are removed or
+/// replaced; user-added mods and override files edited since install are left untouched.
+pub async fn update_modpack(
+    app_handle: &AppHandle,
+    instance_id: &str,
+    new_version_id: &str,
+    file_name: Option<&str>,
+) -> Result<ModpackInstallSummary, Box<dyn Error + Send + Sync>> {
+    let instance = instances::get_instance(instance_id)?;
+    let previous = load_installed_manifest(&instance)
+        .ok_or("No installed modpack manifest was recorded for this instance, reinstall the modpack instead")?;
+
+    let _ = app_handle.emit("download-progress", DownloadProgress {
+        stage: "Fetching modpack info...".to_string(),
+        percentage: 0.0,
+        current: 0,
+        total: 100,
+        total_bytes: None,
+        downloaded_bytes: None,
+    });
+
+    let version = get_version(new_version_id).await?;
+    let primary_file = select_modpack_file(&version.files, file_name).ok_or("Modpack version has no files")?;
+
+    // New files are fetched into a staging folder of this instance and only swapped in once
+    // every download succeeded, so a failed update leaves the installed pack intact
+    let staging_dir = instance.get_directory().join(format!(".update-staging-{}", new_version_id));
+    let _ = fs::remove_dir_all(&staging_dir);
+    fs::create_dir_all(&staging_dir)?;
+    crate::minecraft::downloader::ensure_disk_space(&staging_dir, primary_file.size)?;
+    let mrpack_path = staging_dir.join("modpack.mrpack");
+    download_mod_file(
+        primary_file,
+        &mrpack_path,
+        Some(app_handle),
+        Some(&format!("Downloading modpack file: {}...", primary_file.filename)),
+    )
+    .await?;
+    let index = read_modpack_index(&mrpack_path)?;
+    let game_dir = instance.get_game_directory();
+
+    // Fetch new and changed entries, skipping files already on disk at the right hash
+    let to_download: Vec<&ModpackFile> = index
+        .files
+        .iter()
+        .filter(|f| {
+            let Some(dest) = pack_file_destination(&game_dir, &f.path) else {
+                return false;
+            };
+            let sha1 = f.hashes.get("sha1").map(|h| h.as_str()).unwrap_or("");
+            let present = dest.exists() || disabled_variant(&dest).exists();
+            if present && previous.files.get(&f.path).is_some_and(|old| old == sha1) {
+                return false;
+            }
+            !(dest.exists() && crate::minecraft::downloader::file_sha1(&dest).as_deref() == Some(sha1))
+        })
+        .collect();
+
+    let total_files = to_download.len();
+    let completed_count = AtomicU32::new(0);
+    let failures = Mutex::new(Vec::<ModpackFileFailure>::new());
+    let staged_files = Mutex::new(Vec::<(String, PathBuf, PathBuf)>::new());
+    let mods_metadata = Mutex::new(Vec::new());
+    let concurrency = crate::minecraft::settings::get_download_concurrency();
+
+    stream::iter(to_download.into_iter())
+        .for_each_concurrent(concurrency, |mp_file| {
+            let game_dir = &game_dir;
+            let staging_dir = &staging_dir;
+            let completed_count = &completed_count;
+            let failures = &failures;
+            let staged_files = &staged_files;
+            let mods_metadata = &mods_metadata;
+
+            async move {
+                let targets = pack_file_target(game_dir, &mp_file.path)
+                    .and_then(|dest| pack_file_target(staging_dir, &mp_file.path).map(|staged| (dest, staged)));
+                let downloaded = match targets {
+                    Ok((dest, staged)) => download_modpack_entry(mp_file, &staged).await.map(|()| (dest, staged)),
+                    Err(failure) => Err(failure),
+                };
+
+                match downloaded {
+                    Ok((dest, staged)) => {
+                        if let Ok(mut list) = staged_files.lock() {
+                            list.push((mp_file.path.clone(), dest.clone(), staged));
                         }
-                        if let Ok(mut out) = File::create(dest) {
-                            let _ = std::io::copy(&mut file, &mut out);
+                        if let (Some(pid), version_id) = parse_project_from_download_urls(&mp_file.downloads) {
+                            if let Ok(mut meta) = mods_metadata.lock() {
+                                meta.push((dest, pid, version_id));
+                            }
+                        }
+                    }
+                    Err(mut failure) => {
+                        if let (Some(pid), _) = parse_project_from_download_urls(&mp_file.downloads) {
+                            let manual_url = format!("https://modrinth.com/project/{}", pid);
+                            failure = failure.with_project(pid, Some(manual_url));
+                        }
+                        crate::log_warn!(app_handle, "Failed to update file {}: {}", mp_file.path, failure.reason);
+                        if let Ok(mut list) = failures.lock() {
+                            list.push(failure);
                         }
                     }
                 }
+
+                let current = completed_count.fetch_add(1, Ordering::SeqCst) + 1;
+                let _ = app_handle.emit("download-progress", DownloadProgress {
+                    stage: format!("Updating mods {}/{}...", current, total_files),
+                    percentage: 30.0 + (current as f32 / total_files.max(1) as f32) * 60.0,
+                    current,
+                    total: total_files as u32,
+                    total_bytes: None,
+                    downloaded_bytes: None,
+                });
             }
+        })
+        .await;
+
+    // Nothing has been touched yet, so a failed download leaves the old version in place
+    let mut failures = failures.into_inner().unwrap_or_default();
+    if !failures.is_empty() {
+        let _ = fs::remove_dir_all(&staging_dir);
+        let details: Vec<String> = failures.iter().map(|f| format!("{} ({})", f.path, f.reason)).collect();
+        return Err(format!(
+            "{} file(s) could not be downloaded, the installed pack was left unchanged: {}",
+            failures.len(),
+            details.join(", ")
+        )
+        .into());
+    }
+
+    // Swap the staged files in; a mod the user disabled stays disabled after it is updated
+    let mut unreplaced = HashSet::new();
+    for (path, dest, staged) in staged_files.into_inner().unwrap_or_default() {
+        let target = crate::minecraft::downloader::installed_pack_file(&dest);
+        if let Err(e) = move_staged_file(&staged, &target) {
+            crate::log_warn!(app_handle, "Failed to replace {}: {}", path, e);
+            failures.push(ModpackFileFailure::new(&path, "io", format!("Failed to replace file: {}", e)));
+            unreplaced.insert(dest);
         }
     }
-    
-    let _ = app_handle.emit("download-progress", DownloadProgress { 
-        stage: "Modpack installed!".to_string(), 
+
+    // Drop files the old version shipped that the new one no longer lists
+    let new_paths: HashSet<&str> = index.files.iter().map(|f| f.path.as_str()).collect();
+    let mut removed = 0u32;
+    for (path, old_sha1) in &previous.files {
+        if new_paths.contains(path.as_str()) {
+            continue;
+        }
+        let Some(dest) = pack_file_destination(&game_dir, path) else {
+            continue;
+        };
+        // Without a recorded hash there is no telling a shipped file from one the user replaced
+        if old_sha1.is_empty() {
+            continue;
+        }
+        let mut removed_here = false;
+        for candidate in [dest.clone(), disabled_variant(&dest)] {
+            let matches_pack =
+                crate::minecraft::downloader::file_sha1(&candidate).as_deref() == Some(old_sha1.as_str());
+            if candidate.exists() && matches_pack && fs::remove_file(&candidate).is_ok() {
+                removed += 1;
+                removed_here = true;
+            }
+        }
+        if !removed_here {
+            continue;
+        }
+        if let (Some(parent), Some(name)) = (dest.parent(), dest.file_name().and_then(|n| n.to_str())) {
+            crate::minecraft::files::delete_meta_for_entry(parent, name);
+        }
+    }
+
+    // Metadata is only written for files that were actually replaced
+    let meta_writer = crate::minecraft::files::MetaWriter::spawn();
+    let mods_metadata: Vec<_> = mods_metadata
+        .into_inner()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(dest, _, _)| !unreplaced.contains(dest))
+        .collect();
+    for (dest, pid, version_id) in &mods_metadata {
+        meta_writer.queue(dest.clone(), crate::minecraft::files::ModMeta {
+            project_id: pid.clone(),
+            version_id: version_id.clone(),
+            name: None,
+            author: None,
+            icon_url: None,
+            version_name: None,
+            categories: None,
+            source_url: None,
+            pinned: false,
+        });
+    }
+    if !mods_metadata.is_empty() {
+        enrich_pack_mod_metadata(&meta_writer, mods_metadata).await;
+    }
+    // Joins the writer thread, so it waits off the async runtime
+    let _ = tokio::task::spawn_blocking(move || meta_writer.finish()).await;
+
+    let _ = app_handle.emit("download-progress", DownloadProgress {
+        stage: "Applying overrides...".to_string(),
+        percentage: 95.0,
+        current: 95,
+        total: 100,
+        total_bytes: None,
+        downloaded_bytes: None,
+    });
+    let override_hashes = apply_overrides(&mrpack_path, &game_dir, Some(&previous.overrides))?;
+
+    // The files are in place, so the loader and game version can follow the new pack
+    let mut instance = apply_modpack_dependencies(app_handle, instance_id, &index).await?;
+    instance.modpack_file_name = Some(primary_file.filename.clone());
+    instances::update_instance(instance.clone())?;
+
+    // A file that could not be replaced keeps the old version's hash, so the next update retries it
+    let mut files = index_file_hashes(&index);
+    for failure in &failures {
+        match previous.files.get(&failure.path) {
+            Some(old_sha1) => files.insert(failure.path.clone(), old_sha1.clone()),
+            None => files.remove(&failure.path),
+        };
+    }
+    save_installed_manifest(&instance, &InstalledModpackManifest {
+        version_id: new_version_id.to_string(),
+        files,
+        overrides: override_hashes,
+    })?;
+
+    let _ = fs::remove_dir_all(&staging_dir);
+    let _ = app_handle.emit("download-progress", DownloadProgress {
+        stage: "Modpack updated!".to_string(),
         percentage: 100.0,
         current: 100,
         total: 100,
         total_bytes: None,
         downloaded_bytes: None,
     });
-    let _ = fs::remove_dir_all(&temp_dir);
-    
-    Ok(())
+
+    crate::log_info!(
+        app_handle,
+        "Updated modpack to {}: {} file(s) fetched, {} removed, {} failed",
+        new_version_id,
+        total_files.saturating_sub(failures.len()),
+        removed,
+        failures.len()
+    );
+    Ok(ModpackInstallSummary::new(total_files, failures))
+}
+
+/// Download a modpack version's .mrpack to a temp file and read its index
+async fn fetch_modpack_index(mr_version_id: &str, file_name: Option<&str>) -> Result<ModpackIndex, Box<dyn Error + Send + Sync>> {
+    let version = get_version(mr_version_id).await?;
+    let primary_file = select_modpack_file(&version.files, file_name).ok_or("Modpack version has no files")?;
+
+    let temp_dir = std::env::temp_dir().join("palethea_modpack_verify");
+    fs::create_dir_all(&temp_dir)?;
+    let mrpack_path = temp_dir.join(format!("{}.mrpack", mr_version_id));
+    download_mod_file(primary_file, &mrpack_path, None, None).await?;
+
+    let index = read_modpack_index(&mrpack_path);
+    let _ = fs::remove_file(&mrpack_path);
+    index
+}
+
+/// Resolve what installing a modpack version would do without touching the instance.
+/// Files already on disk in `game_dir` with a matching hash are marked as present.
+pub async fn plan_modpack_install(
+    mr_version_id: &str,
+    file_name: Option<&str>,
+    game_dir: Option<&Path>,
+) -> Result<ModpackInstallPlan, Box<dyn Error + Send + Sync>> {
+    let version = get_version(mr_version_id).await?;
+    let primary_file = select_modpack_file(&version.files, file_name).ok_or("Modpack version has no files")?;
+
+    let temp_dir = std::env::temp_dir().join("palethea_modpack_plan");
+    fs::create_dir_all(&temp_dir)?;
+    let mrpack_path = temp_dir.join(format!("{}.mrpack", mr_version_id));
+    download_mod_file(primary_file, &mrpack_path, None, None).await?;
+
+    let parsed = read_modpack_index(&mrpack_path).and_then(|index| {
+        let overrides = crate::minecraft::downloader::list_archive_overrides(&mrpack_path, &["overrides", "client-overrides"])?;
+        Ok((index, overrides))
+    });
+    let _ = fs::remove_file(&mrpack_path);
+    let (index, overrides) = parsed?;
+
+    let minecraft_version = index
+        .dependencies
+        .get("minecraft")
+        .cloned()
+        .ok_or("No Minecraft version in modpack")?;
+    let (mod_loader, mod_loader_version) = loader_from_index(&index);
+
+    let files = index
+        .files
+        .iter()
+        .map(|file| {
+            let (project_id, file_id) = parse_project_from_download_urls(&file.downloads);
+            let already_present = game_dir
+                .and_then(|dir| pack_file_destination(dir, &file.path))
+                .zip(file.hashes.get("sha1"))
+                .is_some_and(|(dest, sha1)| crate::minecraft::downloader::file_sha1(&dest).as_deref() == Some(sha1.as_str()));
+            ModpackPlanFile {
+                path: file.path.clone(),
+                size: file.file_size,
+                distributable: !file.downloads.is_empty(),
+                project_id,
+                file_id,
+                already_present,
+            }
+        })
+        .collect();
+
+    let mut plan = ModpackInstallPlan {
+        provider: "Modrinth".to_string(),
+        pack_name: index.name.clone(),
+        version_id: mr_version_id.to_string(),
+        minecraft_version,
+        mod_loader: mod_loader.to_string(),
+        mod_loader_version,
+        files,
+        pack_archive_bytes: primary_file.size,
+        total_download_bytes: 0,
+        overrides,
+    };
+    plan.compute_total();
+    Ok(plan)
+}
+
+fn read_modpack_index(mrpack_path: &std::path::Path) -> Result<ModpackIndex, Box<dyn Error + Send + Sync>> {
+    let file = File::open(mrpack_path)?;
+    let mut archive = zip::ZipArchive::new(file)?;
+    let mut index_file = archive.by_name("modrinth.index.json")?;
+    let mut index_content = String::new();
+    index_file.read_to_string(&mut index_content)?;
+    Ok(serde_json::from_str(&index_content)?)
+}
+
+/// Check a single index entry against what is on disk
+fn check_modpack_file(game_dir: &std::path::Path, file: &ModpackFile) -> Option<ModpackFileFailure> {
+    let dest = match pack_file_target(game_dir, &file.path) {
+        // A file the user disabled counts as present
+        Ok(dest) => crate::minecraft::downloader::installed_pack_file(&dest),
+        Err(failure) => return Some(failure),
+    };
+    let Ok(meta) = fs::metadata(&dest) else {
+        return Some(ModpackFileFailure::new(&file.path, "missing", "File is missing from the instance"));
+    };
+
+    if file.file_size > 0 && meta.len() != file.file_size {
+        return Some(ModpackFileFailure::new(
+            &file.path,
+            "size_mismatch",
+            format!("Expected {} bytes, found {}", file.file_size, meta.len()),
+        ));
+    }
+
+    if let Some(expected) = file.hashes.get("sha1") {
+        if !crate::minecraft::downloader::verify_sha1(&dest, expected) {
+            return Some(ModpackFileFailure::new(&file.path, "hash_mismatch", "SHA1 does not match the modpack index"));
+        }
+    }
+
+    None
+}
+
+fn check_modpack_files(game_dir: &std::path::Path, files: &[ModpackFile]) -> Vec<ModpackFileFailure> {
+    use rayon::prelude::*;
+
+    // Hashing is disk and CPU bound, so spread it over the rayon pool
+    files.par_iter().filter_map(|file| check_modpack_file(game_dir, file)).collect()
+}
+
+/// Download one index entry, trying each mirror in order
+async fn download_modpack_entry(file: &ModpackFile, dest: &std::path::Path) -> Result<(), ModpackFileFailure> {
+    let client = super::http_client();
+    let mut last_failure = ModpackFileFailure::new(&file.path, "missing_url", "No download URLs listed in the modpack index");
+
+    for url in &file.downloads {
+        let _permit = MODRINTH_SEMAPHORE.acquire().await.ok();
+        let resp = match client.get(url).header("User-Agent", get_user_agent()).send().await {
+            Ok(resp) if resp.status().is_success() => resp,
+            Ok(resp) => {
+                last_failure = ModpackFileFailure::from_status(&file.path, resp.status());
+                continue;
+            }
+            Err(e) => {
+                last_failure = ModpackFileFailure::from_request_error(&file.path, &e);
+                continue;
+            }
+        };
+
+        let bytes = match crate::minecraft::downloader::read_body_throttled(resp).await {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                last_failure = ModpackFileFailure::from_request_error(&file.path, &e);
+                continue;
+            }
+        };
+
+        let mut hasher = crate::minecraft::downloader::DownloadHasher::new();
+        hasher.update(&bytes);
+        if let Err(e) = hasher.verify(
+            file.hashes.get("sha512").map(|h| h.as_str()),
+            file.hashes.get("sha1").map(|h| h.as_str()),
+        ) {
+            last_failure = ModpackFileFailure::new(&file.path, "hash_mismatch", e);
+            continue;
+        }
+
+        if let Some(parent) = dest.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Err(e) = fs::write(dest, &bytes) {
+            return Err(ModpackFileFailure::new(&file.path, "io", format!("Failed to write file: {}", e)));
+        }
+        return Ok(());
+    }
+
+    Err(last_failure)
+}
+
+/// Compare an installed Modrinth pack against its index
+pub async fn verify_modpack(
+    instance: &instances::Instance,
+    mr_version_id: &str,
+) -> Result<Vec<ModpackFileFailure>, Box<dyn Error + Send + Sync>> {
+    let index = fetch_modpack_index(mr_version_id, instance.modpack_file_name.as_deref()).await?;
+    let game_dir = instance.get_game_directory();
+
+    let issues = tokio::task::spawn_blocking(move || check_modpack_files(&game_dir, &index.files)).await?;
+    Ok(issues)
+}
+
+/// Re-download every missing or corrupt file of an installed Modrinth pack
+pub async fn repair_modpack_files(
+    app_handle: &AppHandle,
+    instance: &instances::Instance,
+    mr_version_id: &str,
+) -> Result<Vec<ModpackFileFailure>, Box<dyn Error + Send + Sync>> {
+    let index = fetch_modpack_index(mr_version_id, instance.modpack_file_name.as_deref()).await?;
+    let game_dir = instance.get_game_directory();
+
+    let check_dir = game_dir.clone();
+    let index_files = index.files.clone();
+    let issues = tokio::task::spawn_blocking(move || check_modpack_files(&check_dir, &index_files)).await?;
+
+    let total = issues.len() as u32;
+    let mut remaining = Vec::new();
+    for (i, issue) in issues.into_iter().enumerate() {
+        let _ = app_handle.emit("download-progress", DownloadProgress {
+            stage: format!("Repairing modpack files {}/{}...", i + 1, total),
+            percentage: (i as f32 / total.max(1) as f32) * 100.0,
+            current: i as u32,
+            total,
+            total_bytes: None,
+            downloaded_bytes: None,
+        });
+
+        let Some(file) = index.files.iter().find(|f| f.path == issue.path) else {
+            remaining.push(issue);
+            continue;
+        };
+
+        let downloaded = match pack_file_target(&game_dir, &file.path) {
+            Ok(dest) => download_modpack_entry(file, &crate::minecraft::downloader::installed_pack_file(&dest)).await,
+            Err(failure) => Err(failure),
+        };
+        match downloaded {
+            Ok(()) => {
+                if let Some(failure) = check_modpack_file(&game_dir, file) {
+                    remaining.push(failure);
+                }
+            }
+            Err(failure) => remaining.push(failure),
+        }
+    }
+
+    let _ = app_handle.emit("download-progress", DownloadProgress {
+        stage: "Modpack repair finished".to_string(),
+        percentage: 100.0,
+        current: total,
+        total,
+        total_bytes: None,
+        downloaded_bytes: None,
+    });
+
+    Ok(remaining)
 }
@@ -16,6 +16,9 @@ pub struct PingResponse {
     pub motd: String,
     pub motd_html: Option<String>,
     pub favicon: Option<String>,
+    // Vanilla only reports this as true when the server also runs in online mode
+    #[serde(default)]
+    pub enforces_secure_chat: Option<bool>,
 }
 
 fn parse_data_url_favicon(icon: Option<&str>) -> Option<String> {
@@ -90,6 +93,7 @@ async fn fetch_mcstatus_enrichment(address: &str) -> Option<PingResponse> {
         motd,
         motd_html,
         favicon,
+        enforces_secure_chat: None,
     })
 }
 
@@ -99,6 +103,8 @@ struct StatusResponse {
     players: StatusPlayers,
     description: serde_json::Value,
     favicon: Option<String>,
+    #[serde(default, rename = "enforcesSecureChat")]
+    enforces_secure_chat: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -287,8 +293,9 @@ fn parse_motd(description: &serde_json::Value) -> String {
     parse_component(description, &MotdStyle::default())
 }
 
-pub async fn ping_server(address: &str) -> Result<PingResponse, String> {
-    let address = address.trim();
+/// Split `host[:port]` and follow the `_minecraft._tcp` SRV record when no port is given.
+/// Returns (host as typed, resolved host, resolved port).
+async fn resolve_server_address(address: &str) -> Result<(String, String, u16), String> {
     let parts: Vec<&str> = address.split(':').collect();
     let original_host = parts[0];
     let mut host = original_host.to_string();
@@ -311,6 +318,14 @@ pub async fn ping_server(address: &str) -> Result<PingResponse, String> {
         }
     }
 
+    Ok((original_host.to_string(), host, port))
+}
+
+pub async fn ping_server(address: &str) -> Result<PingResponse, String> {
+    let address = address.trim();
+    let (original_host, host, port) = resolve_server_address(address).await?;
+    let original_host = original_host.as_str();
+
     let start = Instant::now();
     
     // Wrap connection and status exchange in a combined timeout
@@ -366,6 +381,7 @@ pub async fn ping_server(address: &str) -> Result<PingResponse, String> {
             motd: parse_motd(&response.description),
             motd_html: None,
             favicon: response.favicon,
+            enforces_secure_chat: response.enforces_secure_chat,
         })
     };
 
@@ -397,3 +413,163 @@ pub async fn ping_server(address: &str) -> Result<PingResponse, String> {
         Err(_) => Err(format!("Ping timed out (tried {}:{})", host, port)),
     }
 }
+
+// ----------
+// QueryResponse
+// Description: Full stat from the UDP query protocol (needs enable-query=true on the server)
+// ----------
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QueryResponse {
+    pub motd: String,
+    pub game_type: String,
+    pub version: String,
+    pub server_software: Option<String>,
+    pub plugins: Vec<String>,
+    pub map: String,
+    pub online_players: i32,
+    pub max_players: i32,
+    pub host_ip: String,
+    pub host_port: u16,
+    pub players: Vec<String>,
+}
+
+const QUERY_MAGIC: [u8; 2] = [0xFE, 0xFD];
+const QUERY_TYPE_HANDSHAKE: u8 = 0x09;
+const QUERY_TYPE_STAT: u8 = 0x00;
+const QUERY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Read a null-terminated string starting at `*pos`, advancing past the terminator
+fn read_cstring(data: &[u8], pos: &mut usize) -> Option<String> {
+    let start = *pos;
+    let end = start + data.get(start..)?.iter().position(|&b| b == 0)?;
+    *pos = end + 1;
+    Some(String::from_utf8_lossy(&data[start..end]).to_string())
+}
+
+/// Send one query packet and wait for the reply, `None` on timeout
+async fn query_exchange(socket: &tokio::net::UdpSocket, packet: &[u8]) -> Result<Option<Vec<u8>>, String> {
+    socket.send(packet).await.map_err(|e| format!("Query send failed: {}", e))?;
+    let mut buffer = vec![0u8; 65535];
+    match tokio::time::timeout(QUERY_TIMEOUT, socket.recv(&mut buffer)).await {
+        Ok(Ok(len)) => {
+            buffer.truncate(len);
+            Ok(Some(buffer))
+        }
+        // ICMP port unreachable surfaces as a refused connection when nothing listens on the query port
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => Ok(None),
+        Ok(Err(e)) => Err(format!("Query receive failed: {}", e)),
+        Err(_) => Ok(None),
+    }
+}
+
+fn parse_full_stat(data: &[u8]) -> Option<QueryResponse> {
+    // type (1) + session id (4) + "splitnum\0\x80\0" padding (11)
+    let mut pos = 16;
+    if data.len() < pos {
+        return None;
+    }
+
+    let mut values = std::collections::HashMap::new();
+    loop {
+        let key = read_cstring(data, &mut pos)?;
+        if key.is_empty() {
+            break;
+        }
+        let value = read_cstring(data, &mut pos)?;
+        values.insert(key, value);
+    }
+
+    // "\x01player_\0\0" padding before the player list
+    pos += 10;
+    let mut players = Vec::new();
+    while let Some(name) = read_cstring(data, &mut pos) {
+        if name.is_empty() {
+            break;
+        }
+        players.push(name);
+    }
+
+    // Plugins look like "Paper on 1.20.4: WorldEdit 7.2; EssentialsX 2.20"
+    let raw_plugins = values.get("plugins").cloned().unwrap_or_default();
+    let (server_software, plugins) = match raw_plugins.split_once(':') {
+        Some((software, list)) => (
+            Some(software.trim().to_string()),
+            list.split(';').map(|p| p.trim().to_string()).filter(|p| !p.is_empty()).collect(),
+        ),
+        None if !raw_plugins.trim().is_empty() => (Some(raw_plugins.trim().to_string()), Vec::new()),
+        None => (None, Vec::new()),
+    };
+
+    let get = |key: &str| values.get(key).cloned().unwrap_or_default();
+    Some(QueryResponse {
+        motd: get("hostname"),
+        game_type: get("gametype"),
+        version: get("version"),
+        server_software,
+        plugins,
+        map: get("map"),
+        online_players: get("numplayers").parse().unwrap_or(0),
+        max_players: get("maxplayers").parse().unwrap_or(0),
+        host_ip: get("hostip"),
+        host_port: get("hostport").parse().unwrap_or(0),
+        players,
+    })
+}
+
+/// Run the handshake + full stat query. Returns `Ok(None)` when the server doesn't answer,
+/// which is what happens when `enable-query` is off.
+pub async fn query_server(address: &str) -> Result<Option<QueryResponse>, String> {
+    let (_, host, port) = resolve_server_address(address.trim()).await?;
+
+    let target = tokio::net::lookup_host((host.as_str(), port)).await
+        .map_err(|e| format!("Failed to resolve {}:{}: {}", host, port, e))?
+        .next()
+        .ok_or_else(|| format!("Failed to resolve {}:{}", host, port))?;
+
+    // The local socket has to match the target's family, or an IPv6-only server can't be reached
+    let local: std::net::SocketAddr = if target.is_ipv6() {
+        (std::net::Ipv6Addr::UNSPECIFIED, 0).into()
+    } else {
+        (std::net::Ipv4Addr::UNSPECIFIED, 0).into()
+    };
+    let socket = tokio::net::UdpSocket::bind(local).await
+        .map_err(|e| format!("Failed to open UDP socket: {}", e))?;
+    socket.connect(target).await
+        .map_err(|e| format!("Failed to connect to {}: {}", target, e))?;
+
+    // Only the low 4 bits of each byte are used by the server
+    let session_id: u32 = (std::process::id() ^ 0x5A5A_5A5A) & 0x0F0F_0F0F;
+    let session_bytes = session_id.to_be_bytes();
+
+    // 1. Handshake to get a challenge token
+    let mut handshake = QUERY_MAGIC.to_vec();
+    handshake.push(QUERY_TYPE_HANDSHAKE);
+    handshake.extend_from_slice(&session_bytes);
+    let Some(reply) = query_exchange(&socket, &handshake).await? else {
+        return Ok(None);
+    };
+    if reply.len() < 6 || reply[0] != QUERY_TYPE_HANDSHAKE {
+        return Err("Unexpected query handshake response".to_string());
+    }
+    let mut pos = 5;
+    let token: i32 = read_cstring(&reply, &mut pos)
+        .and_then(|t| t.trim().parse().ok())
+        .ok_or("Invalid query challenge token")?;
+
+    // 2. Full stat request (the 4 trailing padding bytes select full over basic stat)
+    let mut stat = QUERY_MAGIC.to_vec();
+    stat.push(QUERY_TYPE_STAT);
+    stat.extend_from_slice(&session_bytes);
+    stat.extend_from_slice(&token.to_be_bytes());
+    stat.extend_from_slice(&[0, 0, 0, 0]);
+    let Some(reply) = query_exchange(&socket, &stat).await? else {
+        return Ok(None);
+    };
+    if reply.first() != Some(&QUERY_TYPE_STAT) {
+        return Err("Unexpected query stat response".to_string());
+    }
+
+    parse_full_stat(&reply)
+        .map(Some)
+        .ok_or_else(|| "Malformed query stat response".to_string())
+}
@@ -0,0 +1,381 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::minecraft::files::{self, InstalledMod};
+use crate::minecraft::instances::{Instance, ModLoader};
+use crate::minecraft::modrinth::ModrinthVersion;
+
+const MOD_LOADER_KEYS: &[&str] = &["fabric", "quilt", "forge", "neoforge"];
+
+// ----------
+// ModCompatibilityIssue
+// Description: An enabled mod that looks like it won't load in the instance. Advisory only;
+//              the launch goes ahead regardless.
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModCompatibilityIssue {
+    pub filename: String,
+    pub name: Option<String>,
+    pub kind: String, // "loader" or "game_version"
+    pub reason: String,
+}
+
+/// Check enabled mods against the instance's loader and game version. Mods whose Modrinth
+/// version is in `modrinth_versions` are judged by the loaders Modrinth lists; the game
+/// version, and everything for other mods, by the loader manifest and `minecraft`
+/// dependency inside the jar.
+pub fn check_mods(
+    instance: &Instance,
+    mods: &[InstalledMod],
+    modrinth_versions: &HashMap<String, ModrinthVersion>,
+) -> Vec<ModCompatibilityIssue> {
+    if instance.mod_loader == ModLoader::Vanilla {
+        return Vec::new();
+    }
+    let mods_dir = files::get_mods_dir(instance);
+
+    mods.iter()
+        .filter(|installed| installed.enabled)
+        .filter_map(|installed| {
+            let listed = installed.version_id.as_ref().and_then(|id| modrinth_versions.get(id));
+            let jar_path = mods_dir.join(&installed.filename);
+            let (kind, reason) = match listed {
+                Some(version) => modrinth_issue(instance, version).or_else(|| jar_issue(instance, &jar_path)),
+                None => jar_issue(instance, &jar_path),
+            }?;
+            Some(ModCompatibilityIssue {
+                filename: installed.filename.clone(),
+                name: installed.name.clone(),
+                kind: kind.to_string(),
+                reason,
+            })
+        })
+        .collect()
+}
+
+fn modrinth_issue(instance: &Instance, version: &ModrinthVersion) -> Option<(&'static str, String)> {
+    let loaders: Vec<String> = version
+        .loaders
+        .iter()
+        .map(|l| l.to_ascii_lowercase())
+        .filter(|l| MOD_LOADER_KEYS.contains(&l.as_str()))
+        .collect();
+    let accepted = files::loaders_accepted_by(&instance.mod_loader.slug(), &instance.version_id);
+    if !loaders.is_empty() && !loaders.iter().any(|l| accepted.contains(&l.as_str())) {
+        return Some(("loader", format!("Made for {}, not {}", loaders.join(", "), instance.mod_loader)));
+    }
+    // Modrinth's game version list is what the author tested, not what the mod accepts
+    // (a 1.20 release usually runs on 1.20.1), so the game version is left to the jar
+    None
+}
+
+fn read_entry(archive: &mut ZipArchive<File>, name: &str) -> Option<String> {
+    let mut entry = archive.by_name(name).ok()?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+fn jar_issue(instance: &Instance, path: &Path) -> Option<(&'static str, String)> {
+    let file = File::open(path).ok()?;
+    let mut archive = ZipArchive::new(file).ok()?;
+
+    // (loader the manifest is for, Minecraft requirement it declares)
+    let mut declared: Vec<(&str, Option<VersionRequirement>)> = Vec::new();
+    for (entry, loader) in files::JAR_LOADER_METADATA {
+        let Some(content) = read_entry(&mut archive, entry) else { continue };
+        let requirement = match *loader {
+            "fabric" => fabric_requirement(&content),
+            "quilt" => quilt_requirement(&content),
+            _ => mods_toml_requirement(&content),
+        };
+        declared.push((*loader, requirement));
+    }
+    if declared.is_empty() {
+        return None; // A library or something else we can't judge
+    }
+
+    let accepted = files::loaders_accepted_by(&instance.mod_loader.slug(), &instance.version_id);
+    let matching: Vec<&Option<VersionRequirement>> = declared
+        .iter()
+        .filter(|(loader, _)| accepted.contains(loader))
+        .map(|(_, requirement)| requirement)
+        .collect();
+    if matching.is_empty() {
+        let mut made_for: Vec<&str> = declared.iter().map(|(loader, _)| *loader).collect();
+        made_for.sort_unstable();
+        made_for.dedup();
+        return Some(("loader", format!("Made for {}, not {}", made_for.join(", "), instance.mod_loader)));
+    }
+
+    let game_version = parse_release(&instance.version_id)?;
+    let rejected_by = matching
+        .iter()
+        .filter_map(|requirement| requirement.as_ref())
+        .find(|requirement| requirement.matches(&game_version) == Some(false))?;
+    Some(("game_version", format!("Requires Minecraft {}", rejected_by.display)))
+}
+
+// ----------
+// VersionRequirement
+// Description: A declared Minecraft dependency. `alternatives` are OR'd; each is a Fabric
+//              style predicate ("1.20.x", ">=1.20 <1.21") or a Maven range ("[1.20,1.21)").
+// ----------
+struct VersionRequirement {
+    alternatives: Vec<String>,
+    maven: bool,
+    display: String,
+}
+
+impl VersionRequirement {
+    fn fabric(alternatives: Vec<String>) -> Option<Self> {
+        if alternatives.is_empty() {
+            return None;
+        }
+        Some(VersionRequirement { display: alternatives.join(" or "), alternatives, maven: false })
+    }
+
+    /// None when no alternative could be understood
+    fn matches(&self, version: &[u64]) -> Option<bool> {
+        let results: Vec<bool> = self
+            .alternatives
+            .iter()
+            .filter_map(|alt| if self.maven { maven_range_matches(alt, version) } else { fabric_predicate_matches(alt, version) })
+            .collect();
+        if results.is_empty() {
+            None
+        } else {
+            Some(results.into_iter().any(|ok| ok))
+        }
+    }
+}
+
+fn string_or_list(value: Option<&serde_json::Value>) -> Vec<String> {
+    match value {
+        Some(serde_json::Value::String(s)) => vec![s.clone()],
+        Some(serde_json::Value::Array(list)) => list.iter().filter_map(|v| v.as_str().map(str::to_string)).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn fabric_requirement(content: &str) -> Option<VersionRequirement> {
+    let json: serde_json::Value = serde_json::from_str(content).ok()?;
+    VersionRequirement::fabric(string_or_list(json.pointer("/depends/minecraft")))
+}
+
+fn quilt_requirement(content: &str) -> Option<VersionRequirement> {
+    let json: serde_json::Value = serde_json::from_str(content).ok()?;
+    let depends = json.pointer("/quilt_loader/depends")?.as_array()?;
+    let minecraft = depends.iter().find(|dep| dep.get("id").and_then(|id| id.as_str()) == Some("minecraft"))?;
+    let versions = minecraft.get("versions");
+    let alternatives = match versions.and_then(|v| v.get("any")) {
+        Some(any) => string_or_list(Some(any)),
+        None => string_or_list(versions),
+    };
+    VersionRequirement::fabric(alternatives)
+}
+
+fn mods_toml_requirement(content: &str) -> Option<VersionRequirement> {
+    let doc: toml::Value = toml::from_str(content).ok()?;
+    let range = doc
+        .get("dependencies")?
+        .as_table()?
+        .values()
+        .filter_map(|deps| deps.as_array())
+        .flatten()
+        .find(|dep| dep.get("modId").and_then(|id| id.as_str()) == Some("minecraft"))?
+        .get("versionRange")?
+        .as_str()?
+        .trim()
+        .to_string();
+    if range.is_empty() || range.contains("${") {
+        return None;
+    }
+    Some(VersionRequirement { display: range.clone(), alternatives: vec![range], maven: true })
+}
+
+/// "1.20.1" -> [1, 20, 1]. None for snapshots and other non-release ids.
+fn parse_release(version: &str) -> Option<Vec<u64>> {
+    let core = version.split(['-', '+']).next()?;
+    core.split('.').map(|part| part.parse().ok()).collect()
+}
+
+fn compare_versions(a: &[u64], b: &[u64]) -> Ordering {
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| a.get(i).copied().unwrap_or(0).cmp(&b.get(i).copied().unwrap_or(0)))
+        .find(|ord| *ord != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Fabric Loader predicate: space separated terms that must all hold
+fn fabric_predicate_matches(predicate: &str, version: &[u64]) -> Option<bool> {
+    let mut all = true;
+    for term in predicate.split_whitespace() {
+        all &= fabric_term_matches(term, version)?;
+    }
+    Some(all)
+}
+
+fn fabric_term_matches(term: &str, version: &[u64]) -> Option<bool> {
+    if term == "*" {
+        return Some(true);
+    }
+    let (op, rest) = [">=", "<=", ">", "<", "=", "~", "^"]
+        .iter()
+        .find_map(|op| term.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("", term));
+
+    // "1.20.x" matches every version starting with 1.20
+    if let Some(prefix) = rest.strip_suffix(".x").or_else(|| rest.strip_suffix(".*")) {
+        let prefix = parse_release(prefix)?;
+        return Some(version.len() >= prefix.len() && version[..prefix.len()] == prefix[..]);
+    }
+
+    let target = parse_release(rest)?;
+    let ord = compare_versions(version, &target);
+    Some(match op {
+        ">=" => ord != Ordering::Less,
+        "<=" => ord != Ordering::Greater,
+        ">" => ord == Ordering::Greater,
+        "<" => ord == Ordering::Less,
+        // ~1.20.1 allows later patches of 1.20, ^1.20.1 anything later in 1.x
+        "~" => ord != Ordering::Less && version.get(..2) == target.get(..2),
+        "^" => ord != Ordering::Less && version.first() == target.first(),
+        _ => ord == Ordering::Equal,
+    })
+}
+
+/// Maven version range as used by mods.toml, e.g. "[1.20,1.21)" or "[1.19.2],[1.20.1]".
+/// A bare version is only a recommendation in Maven, so it matches everything.
+fn maven_range_matches(spec: &str, version: &[u64]) -> Option<bool> {
+    let spec = spec.trim();
+    if !spec.starts_with(['[', '(']) {
+        return Some(true);
+    }
+
+    let mut any = false;
+    let mut rest = spec;
+    while let Some(start) = rest.find(['[', '(']) {
+        let end = start + rest[start..].find([']', ')'])?;
+        let lower_inclusive = rest[start..].starts_with('[');
+        let upper_inclusive = rest[end..].starts_with(']');
+        let body = &rest[start + 1..end];
+
+        let in_range = match body.split_once(',') {
+            None => compare_versions(version, &parse_release(body.trim())?) == Ordering::Equal,
+            Some((lower, upper)) => {
+                let lower_ok = match lower.trim() {
+                    "" => true,
+                    lower => match compare_versions(version, &parse_release(lower)?) {
+                        Ordering::Greater => true,
+                        Ordering::Equal => lower_inclusive,
+                        Ordering::Less => false,
+                    },
+                };
+                let upper_ok = match upper.trim() {
+                    "" => true,
+                    upper => match compare_versions(version, &parse_release(upper)?) {
+                        Ordering::Less => true,
+                        Ordering::Equal => upper_inclusive,
+                        Ordering::Greater => false,
+                    },
+                };
+                lower_ok && upper_ok
+            }
+        };
+        any |= in_range;
+        rest = &rest[end + 1..];
+    }
+    Some(any)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(version: &str) -> Vec<u64> {
+        parse_release(version).unwrap()
+    }
+
+    #[test]
+    fn parse_release_handles_suffixes_and_snapshots() {
+        assert_eq!(parse_release("1.20.1"), Some(vec![1, 20, 1]));
+        assert_eq!(parse_release("1.20.1-pre2"), Some(vec![1, 20, 1]));
+        assert_eq!(parse_release("0.15.3+build.1"), Some(vec![0, 15, 3]));
+        assert_eq!(parse_release("23w13a"), None);
+    }
+
+    #[test]
+    fn maven_ranges() {
+        let cases: &[(&str, &str, Option<bool>)] = &[
+            ("[1.0,2.0)", "1.0", Some(true)),
+            ("[1.0,2.0)", "1.5", Some(true)),
+            ("[1.0,2.0)", "1.99", Some(true)),
+            ("[1.0,2.0)", "2.0", Some(false)),
+            ("[1.0,2.0)", "0.9", Some(false)),
+            ("(1.0,2.0)", "1.0", Some(false)),
+            ("(,1.5]", "1.5", Some(true)),
+            ("(,1.5]", "0.1", Some(true)),
+            ("(,1.5]", "1.5.1", Some(false)),
+            ("(,1.5]", "1.6", Some(false)),
+            ("[1.20,)", "1.21.4", Some(true)),
+            ("[1.20,)", "1.19.4", Some(false)),
+            ("[1.19.2],[1.20.1]", "1.19.2", Some(true)),
+            ("[1.19.2],[1.20.1]", "1.20.1", Some(true)),
+            ("[1.19.2],[1.20.1]", "1.20", Some(false)),
+            // A bare version is only a recommendation
+            ("1.20.1", "1.16.5", Some(true)),
+            ("[1.0,abc)", "1.5", None),
+            ("[1.0,2.0", "1.5", None),
+        ];
+        for (spec, version, expected) in cases {
+            assert_eq!(maven_range_matches(spec, &release(version)), *expected, "{} against {}", spec, version);
+        }
+    }
+
+    #[test]
+    fn fabric_predicates() {
+        let cases: &[(&str, &str, Option<bool>)] = &[
+            ("*", "1.20.1", Some(true)),
+            ("*", "1.7.10", Some(true)),
+            (">=0.14 <0.16", "0.14", Some(true)),
+            (">=0.14 <0.16", "0.15.3", Some(true)),
+            (">=0.14 <0.16", "0.16", Some(false)),
+            (">=0.14 <0.16", "0.13.9", Some(false)),
+            ("~1.20", "1.20", Some(true)),
+            ("~1.20", "1.20.4", Some(true)),
+            ("~1.20", "1.21", Some(false)),
+            ("~1.20", "1.19.4", Some(false)),
+            ("~1.20.1", "1.20.6", Some(true)),
+            ("~1.20.1", "1.20", Some(false)),
+            ("^1.20.1", "1.21", Some(true)),
+            ("^1.20.1", "2.0", Some(false)),
+            ("1.20.x", "1.20", Some(true)),
+            ("1.20.x", "1.20.4", Some(true)),
+            ("1.20.x", "1.21", Some(false)),
+            ("1.20.1", "1.20.1", Some(true)),
+            ("=1.20.1", "1.20.2", Some(false)),
+            (">1.20 <=1.20.4", "1.20.4", Some(true)),
+            (">1.20 <=1.20.4", "1.20", Some(false)),
+            (">=23w13a", "1.20", None),
+        ];
+        for (predicate, version, expected) in cases {
+            assert_eq!(fabric_predicate_matches(predicate, &release(version)), *expected, "{} against {}", predicate, version);
+        }
+    }
+
+    #[test]
+    fn fabric_terms() {
+        assert_eq!(fabric_term_matches("*", &release("1.0")), Some(true));
+        assert_eq!(fabric_term_matches("<1.17", &release("1.16.5")), Some(true));
+        assert_eq!(fabric_term_matches("1.16.*", &release("1.16.5")), Some(true));
+        assert_eq!(fabric_term_matches("1.16.*", &release("1.1")), Some(false));
+        assert_eq!(fabric_term_matches("~abc", &release("1.0")), None);
+    }
+}
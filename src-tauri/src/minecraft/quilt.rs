@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+
+use crate::minecraft::downloader::{get_libraries_dir, StepProgress};
+use crate::minecraft::fabric::{download_library_with_sha1, maven_to_path, FabricLibraries, FabricMainClass};
+use crate::minecraft::instances::Instance;
+
+const QUILT_META_API: &str = "https://meta.quiltmc.org/v3";
+const QUILT_MAVEN: &str = "https://maven.quiltmc.org/repository/release/";
+const FABRIC_MAVEN: &str = "https://maven.fabricmc.net/";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuiltLoaderVersion {
+    pub loader: QuiltLoader,
+    /// Quilt runs on Fabric's intermediary mappings; the `hashed` entry is ignored
+    pub intermediary: QuiltMappings,
+    #[serde(rename = "launcherMeta")]
+    pub launcher_meta: QuiltLauncherMeta,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuiltLoader {
+    #[serde(default)]
+    pub separator: String,
+    #[serde(default)]
+    pub build: u32,
+    pub maven: String,
+    pub version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuiltMappings {
+    pub maven: String,
+    pub version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuiltLauncherMeta {
+    pub version: u32,
+    pub libraries: FabricLibraries,
+    #[serde(rename = "mainClass")]
+    pub main_class: FabricMainClass,
+}
+
+/// Fetch Quilt loader info for a game version and loader version
+pub async fn get_quilt_loader_info(
+    game_version: &str,
+    loader_version: &str,
+) -> Result<QuiltLoaderVersion, Box<dyn Error + Send + Sync>> {
+    let client = super::http_client();
+    let url = format!(
+        "{}/versions/loader/{}/{}",
+        QUILT_META_API, game_version, loader_version
+    );
+
+    let response = client.get(&url).send().await?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch Quilt info: {}", response.status()).into());
+    }
+
+    let info: QuiltLoaderVersion = response.json().await?;
+    Ok(info)
+}
+
+/// List the Quilt loader versions available for a game version, newest first
+pub async fn get_quilt_loader_versions(
+    game_version: &str,
+) -> Result<Vec<QuiltLoader>, Box<dyn Error + Send + Sync>> {
+    #[derive(Deserialize)]
+    struct QuiltLoaderEntry {
+        loader: QuiltLoader,
+    }
+
+    let client = super::http_client();
+    let url = format!("{}/versions/loader/{}", QUILT_META_API, game_version);
+    let response = client.get(&url).send().await?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(format!("Quilt does not yet support Minecraft version {}.", game_version).into());
+    }
+    if !response.status().is_success() {
+        return Err(format!("Quilt API returned error status: {}", response.status()).into());
+    }
+
+    let entries: Vec<QuiltLoaderEntry> = response.json().await?;
+    Ok(entries.into_iter().map(|e| e.loader).collect())
+}
+
+/// Install Quilt for an instance
+pub async fn install_quilt(
+    instance: &Instance,
+    loader_version: &str,
+    progress: Option<StepProgress<'_>>,
+) -> Result<QuiltLoaderVersion, Box<dyn Error + Send + Sync>> {
+    let quilt_info = get_quilt_loader_info(&instance.version_id, loader_version).await?;
+    let libraries_dir = get_libraries_dir();
+
+    let total_steps = (2
+        + quilt_info.launcher_meta.libraries.common.len()
+        + quilt_info.launcher_meta.libraries.client.len()) as f32;
+    let mut completed_steps = 0f32;
+    let report_step = |completed: f32| {
+        if let Some(report) = progress {
+            report("Installing Quilt: downloading libraries...", (completed / total_steps).min(1.0));
+        }
+    };
+    report_step(completed_steps);
+
+    // Download loader library
+    download_library_with_sha1(QUILT_MAVEN, &quilt_info.loader.maven, &libraries_dir, None).await?;
+    completed_steps += 1.0;
+    report_step(completed_steps);
+
+    // Download intermediary library (shared with Fabric)
+    download_library_with_sha1(FABRIC_MAVEN, &quilt_info.intermediary.maven, &libraries_dir, None).await?;
+    completed_steps += 1.0;
+    report_step(completed_steps);
+
+    // Download common and client libraries
+    for lib in quilt_info
+        .launcher_meta
+        .libraries
+        .common
+        .iter()
+        .chain(quilt_info.launcher_meta.libraries.client.iter())
+    {
+        download_library_with_sha1(&lib.url, &lib.name, &libraries_dir, lib.sha1.as_deref()).await?;
+        completed_steps += 1.0;
+        report_step(completed_steps);
+    }
+
+    // Save Quilt info to instance folder for later use
+    let quilt_json_path = instance.get_directory().join("quilt.json");
+    let quilt_json = serde_json::to_string_pretty(&quilt_info)?;
+    fs::write(&quilt_json_path, quilt_json)?;
+
+    Ok(quilt_info)
+}
+
+/// Load saved Quilt info from instance
+pub fn load_quilt_info(instance: &Instance) -> Option<QuiltLoaderVersion> {
+    let quilt_json_path = instance.get_directory().join("quilt.json");
+    if !quilt_json_path.exists() {
+        return None;
+    }
+
+    let content = fs::read_to_string(&quilt_json_path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Get Quilt classpath additions as (maven_name, absolute_path) pairs
+pub fn get_quilt_classpath(quilt_info: &QuiltLoaderVersion) -> Vec<(String, String)> {
+    let libraries_dir = get_libraries_dir();
+    let mut classpath = Vec::new();
+
+    let mut push = |name: &str| {
+        let path = libraries_dir.join(maven_to_path(name));
+        if path.exists() {
+            classpath.push((name.to_string(), path.to_string_lossy().to_string()));
+        }
+    };
+
+    push(&quilt_info.loader.maven);
+    push(&quilt_info.intermediary.maven);
+    for lib in &quilt_info.launcher_meta.libraries.common {
+        push(&lib.name);
+    }
+    for lib in &quilt_info.launcher_meta.libraries.client {
+        push(&lib.name);
+    }
+
+    classpath
+}
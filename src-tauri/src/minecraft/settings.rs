@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
-use crate::minecraft::downloader::get_minecraft_dir;
+use crate::minecraft::downloader::get_default_data_dir;
 
 // ----------
 // LauncherSettings
@@ -27,6 +27,81 @@ pub struct LauncherSettings {
     pub enable_instance_animations: Option<bool>,
     pub show_fps_counter: Option<bool>,
     pub show_instance_editor_tab_icons: Option<bool>,
+    /// Parallel file downloads during modpack installs
+    pub download_concurrency: Option<u32>,
+    /// Extra attempts for a file after a transient failure
+    pub download_max_retries: Option<u32>,
+    /// Show the running instance in Discord Rich Presence
+    pub discord_presence_enabled: Option<bool>,
+    /// Personal GitHub token used for update checks to raise the API rate limit
+    pub github_token: Option<String>,
+    /// Instance id or route key the user last viewed, restored on startup
+    pub last_view: Option<String>,
+    /// Ask before a launch that needs to download more than this many MB (0 = never ask)
+    pub launch_confirm_download_mb: Option<u32>,
+    /// Check GitHub for launcher updates automatically
+    pub auto_update_check: Option<bool>,
+    /// Show routine in-app toasts such as finished downloads (errors and warnings always show)
+    pub enable_notifications: Option<bool>,
+    /// What the launcher window does once a game starts: "keep_open", "minimize", "close_to_tray" or "exit"
+    pub on_game_launch: Option<String>,
+    /// Warn when the game window has not shown up this many seconds after launch (0 = off)
+    pub launch_watchdog_secs: Option<u32>,
+    /// Download the required Java runtime during launch when no installed one fits
+    pub auto_download_java: Option<bool>,
+    /// Where instances, versions, libraries and assets live instead of the default data directory
+    pub custom_data_dir: Option<String>,
+    /// "error", "warn", "info", "debug" or "trace"; unset uses debug for dev builds and info otherwise
+    pub log_level: Option<String>,
+    /// Combined download speed cap in KB/s across all downloads (0 = unlimited)
+    pub download_speed_limit_kbps: Option<u32>,
+    /// Game stdout/stderr lines kept in memory per running instance for the console (0 = don't keep any)
+    pub live_output_lines: Option<u32>,
+    /// Closing the main window while games run hides it to the tray instead of asking to exit
+    pub minimize_to_tray_on_close: Option<bool>,
+    /// Starting heap in MB for newly created instances
+    pub default_memory_min: Option<u32>,
+    /// Max heap in MB for newly created instances; unset picks a share of system RAM
+    pub default_memory_max: Option<u32>,
+    /// Make all launcher connections over IPv4, for networks with broken IPv6 routes
+    pub force_ipv4: Option<bool>,
+    /// Refresh all saved Microsoft accounts in the background when the launcher starts
+    pub refresh_accounts_on_startup: Option<bool>,
+}
+
+// ----------
+// PrivacySettings
+// Description: The outbound-activity toggles grouped for the privacy panel. Each maps onto
+//              a LauncherSettings field so existing code paths keep reading their own flag.
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PrivacySettings {
+    pub enable_discord: bool,
+    pub enable_update_checks: bool,
+    pub enable_notifications: bool,
+}
+
+const DEFAULT_DOWNLOAD_CONCURRENCY: u32 = 12;
+const DEFAULT_DOWNLOAD_MAX_RETRIES: u32 = 3;
+const DEFAULT_LIVE_OUTPUT_LINES: u32 = 2000;
+const DEFAULT_LAUNCH_CONFIRM_DOWNLOAD_MB: u32 = 200;
+const DEFAULT_LAUNCH_WATCHDOG_SECS: u32 = 90;
+const DEFAULT_MEMORY_MIN_MB: u32 = 512;
+const FALLBACK_MEMORY_MAX_MB: u32 = 4096;
+// Automatic max heap is 40% of total RAM, kept within these bounds
+const AUTO_MEMORY_MAX_FLOOR_MB: u32 = 1024;
+const AUTO_MEMORY_MAX_CAP_MB: u32 = 8192;
+
+// ----------
+// SystemMemory
+// Description: Physical RAM of this machine in MB (0 when it could not be read) and the
+//              max heap new instances get when no default is configured
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+pub struct SystemMemory {
+    pub total_mb: u64,
+    pub available_mb: u64,
+    pub recommended_max_mb: u32,
 }
 
 fn default_update_channel() -> Option<String> {
@@ -52,12 +127,32 @@ impl Default for LauncherSettings {
             enable_instance_animations: Some(true),
             show_fps_counter: Some(false),
             show_instance_editor_tab_icons: Some(false),
+            download_concurrency: Some(DEFAULT_DOWNLOAD_CONCURRENCY),
+            download_max_retries: Some(DEFAULT_DOWNLOAD_MAX_RETRIES),
+            discord_presence_enabled: Some(true),
+            github_token: None,
+            last_view: None,
+            launch_confirm_download_mb: Some(DEFAULT_LAUNCH_CONFIRM_DOWNLOAD_MB),
+            auto_update_check: Some(true),
+            enable_notifications: Some(true),
+            on_game_launch: Some("keep_open".to_string()),
+            launch_watchdog_secs: Some(DEFAULT_LAUNCH_WATCHDOG_SECS),
+            auto_download_java: Some(true),
+            custom_data_dir: None,
+            log_level: None,
+            download_speed_limit_kbps: None,
+            live_output_lines: Some(DEFAULT_LIVE_OUTPUT_LINES),
+            minimize_to_tray_on_close: Some(false),
+            default_memory_min: None,
+            default_memory_max: None,
+            force_ipv4: Some(false),
+            refresh_accounts_on_startup: Some(true),
         }
     }
 }
 
 fn get_settings_path() -> PathBuf {
-    get_minecraft_dir().join("settings.json")
+    get_default_data_dir().join("settings.json")
 }
 
 pub fn load_settings() -> LauncherSettings {
@@ -88,6 +183,38 @@ pub fn save_settings(settings: &LauncherSettings) -> Result<(), String> {
     Ok(())
 }
 
+// Fields that only make sense on the machine they were set on, or hold credentials;
+// they are left out of exports and never taken from an import
+const MACHINE_LOCAL_SETTINGS: [&str; 4] = ["java_path", "custom_data_dir", "last_view", "github_token"];
+
+/// Current settings as JSON without machine-local or secret fields, for carrying to another machine
+pub fn export_portable_settings() -> Result<serde_json::Value, String> {
+    let mut value = serde_json::to_value(load_settings()).map_err(|e| e.to_string())?;
+    if let Some(fields) = value.as_object_mut() {
+        for key in MACHINE_LOCAL_SETTINGS {
+            fields.remove(key);
+        }
+    }
+    Ok(value)
+}
+
+/// Apply exported settings on top of the current ones without saving. Only known, non-null
+/// fields are taken, machine-local ones are kept, and the result has to parse.
+pub fn merge_portable_settings(imported: &serde_json::Value) -> Result<LauncherSettings, String> {
+    let imported = imported
+        .as_object()
+        .ok_or("Settings in the file are not an object")?;
+    let mut merged = serde_json::to_value(load_settings()).map_err(|e| e.to_string())?;
+    let fields = merged.as_object_mut().ok_or("Current settings are not an object")?;
+    for (key, value) in imported {
+        if value.is_null() || MACHINE_LOCAL_SETTINGS.contains(&key.as_str()) || !fields.contains_key(key) {
+            continue;
+        }
+        fields.insert(key.clone(), value.clone());
+    }
+    serde_json::from_value(merged).map_err(|e| format!("Settings in the file are invalid: {}", e))
+}
+
 pub fn set_java_path(path: Option<String>) -> Result<(), String> {
     let mut settings = load_settings();
     settings.java_path = path;
@@ -97,3 +224,163 @@ pub fn set_java_path(path: Option<String>) -> Result<(), String> {
 pub fn get_java_path() -> Option<String> {
     load_settings().java_path
 }
+
+pub fn set_last_view(view: Option<String>) -> Result<(), String> {
+    let mut settings = load_settings();
+    settings.last_view = view.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+    save_settings(&settings)
+}
+
+pub fn get_last_view() -> Option<String> {
+    load_settings().last_view.filter(|v| !v.is_empty())
+}
+
+pub fn get_download_concurrency() -> usize {
+    load_settings()
+        .download_concurrency
+        .unwrap_or(DEFAULT_DOWNLOAD_CONCURRENCY)
+        .clamp(1, 64) as usize
+}
+
+pub fn get_download_max_retries() -> u32 {
+    load_settings()
+        .download_max_retries
+        .unwrap_or(DEFAULT_DOWNLOAD_MAX_RETRIES)
+        .min(10)
+}
+
+pub fn is_discord_presence_enabled() -> bool {
+    load_settings().discord_presence_enabled.unwrap_or(true)
+}
+
+pub fn get_github_token() -> Option<String> {
+    load_settings()
+        .github_token
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+/// Download size above which a launch needs confirmation, None when confirmation is off
+pub fn get_launch_confirm_threshold_bytes() -> Option<u64> {
+    let mb = load_settings()
+        .launch_confirm_download_mb
+        .unwrap_or(DEFAULT_LAUNCH_CONFIRM_DOWNLOAD_MB);
+    (mb > 0).then(|| mb as u64 * 1024 * 1024)
+}
+
+pub fn get_update_channel() -> String {
+    match load_settings().update_channel.as_deref().map(|c| c.trim().to_ascii_lowercase()) {
+        Some(channel) if channel == "prerelease" => channel,
+        _ => "stable".to_string(),
+    }
+}
+
+pub fn is_force_ipv4_enabled() -> bool {
+    load_settings().force_ipv4.unwrap_or(false)
+}
+
+pub fn is_refresh_accounts_on_startup_enabled() -> bool {
+    load_settings().refresh_accounts_on_startup.unwrap_or(true)
+}
+
+pub fn is_auto_update_check_enabled() -> bool {
+    load_settings().auto_update_check.unwrap_or(true)
+}
+
+pub fn get_privacy_settings() -> PrivacySettings {
+    let settings = load_settings();
+    PrivacySettings {
+        enable_discord: settings.discord_presence_enabled.unwrap_or(true),
+        enable_update_checks: settings.auto_update_check.unwrap_or(true),
+        enable_notifications: settings.enable_notifications.unwrap_or(true),
+    }
+}
+
+pub fn save_privacy_settings(privacy: &PrivacySettings) -> Result<(), String> {
+    let mut settings = load_settings();
+    settings.discord_presence_enabled = Some(privacy.enable_discord);
+    settings.auto_update_check = Some(privacy.enable_update_checks);
+    settings.enable_notifications = Some(privacy.enable_notifications);
+    save_settings(&settings)
+}
+
+/// The `on_game_launch` behavior, falling back to "keep_open" for missing or unknown values
+pub fn get_on_game_launch() -> String {
+    match load_settings().on_game_launch.as_deref().map(str::trim) {
+        Some(value @ ("minimize" | "close_to_tray" | "exit")) => value.to_string(),
+        _ => "keep_open".to_string(),
+    }
+}
+
+pub fn is_minimize_to_tray_on_close_enabled() -> bool {
+    load_settings().minimize_to_tray_on_close.unwrap_or(false)
+}
+
+/// Seconds to wait for the game window before warning, or None when the watchdog is off
+pub fn get_launch_watchdog_secs() -> Option<u64> {
+    match load_settings().launch_watchdog_secs.unwrap_or(DEFAULT_LAUNCH_WATCHDOG_SECS) {
+        0 => None,
+        secs => Some(secs.clamp(15, 900) as u64),
+    }
+}
+
+pub fn get_system_memory() -> SystemMemory {
+    let mut system = sysinfo::System::new();
+    system.refresh_memory();
+    let total_mb = system.total_memory() / 1024 / 1024;
+    SystemMemory {
+        total_mb,
+        available_mb: system.available_memory() / 1024 / 1024,
+        recommended_max_mb: auto_memory_max(total_mb),
+    }
+}
+
+/// 40% of total RAM in 512 MB steps, within the floor and cap
+fn auto_memory_max(total_mb: u64) -> u32 {
+    if total_mb == 0 {
+        return FALLBACK_MEMORY_MAX_MB;
+    }
+    let share = (total_mb * 2 / 5).min(u32::MAX as u64) as u32;
+    (share / 512 * 512).clamp(AUTO_MEMORY_MAX_FLOOR_MB, AUTO_MEMORY_MAX_CAP_MB)
+}
+
+/// (min, max) heap in MB for a newly created instance
+pub fn get_default_instance_memory() -> (u32, u32) {
+    let settings = load_settings();
+    let max = settings
+        .default_memory_max
+        .filter(|mb| *mb >= 512)
+        .unwrap_or_else(|| get_system_memory().recommended_max_mb);
+    let min = settings.default_memory_min.unwrap_or(DEFAULT_MEMORY_MIN_MB).clamp(256, max);
+    (min, max)
+}
+
+pub fn is_auto_download_java_enabled() -> bool {
+    load_settings().auto_download_java.unwrap_or(true)
+}
+
+/// Level for the log plugin; read once at startup, so changes apply after a restart
+pub fn get_log_level() -> log::LevelFilter {
+    load_settings()
+        .log_level
+        .as_deref()
+        .and_then(|level| level.trim().parse().ok())
+        .unwrap_or(if cfg!(debug_assertions) { log::LevelFilter::Debug } else { log::LevelFilter::Info })
+}
+
+/// Download cap in bytes per second, or None when downloads are unthrottled
+pub fn get_download_speed_limit() -> Option<u64> {
+    match load_settings().download_speed_limit_kbps.unwrap_or(0) {
+        0 => None,
+        kbps => Some(kbps as u64 * 1024),
+    }
+}
+
+/// How many recent output lines to buffer per running instance, capped so a chatty
+/// modpack can't grow the buffer without bound
+pub fn get_live_output_lines() -> usize {
+    load_settings()
+        .live_output_lines
+        .unwrap_or(DEFAULT_LIVE_OUTPUT_LINES)
+        .min(20_000) as usize
+}
@@ -0,0 +1,260 @@
+use image::{imageops, Rgba, RgbaImage};
+use base64::{engine::general_purpose, Engine as _};
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::minecraft::downloader::{get_account_heads_cache_dir, get_skins_dir};
+
+/// Pixels per skin texel in the rendered preview
+const PREVIEW_SCALE: u32 = 8;
+
+/// Pixels per skin texel in rendered account heads (8x8 face -> 64x64)
+const HEAD_SCALE: u32 = 8;
+
+/// How long a rendered head is served from cache before the skin is fetched again
+const HEAD_CACHE_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+const SESSION_PROFILE_URL: &str = "https://sessionserver.mojang.com/session/minecraft/profile";
+
+// Front-facing composite: 4px arm + 8px body + 4px arm wide, head + body + legs tall
+const COMPOSITE_WIDTH: u32 = 16;
+const COMPOSITE_HEIGHT: u32 = 32;
+
+// ----------
+// SkinPart
+// Description: One front face on the skin texture and where it lands on the composite
+// ----------
+struct SkinPart {
+    src: (u32, u32),
+    overlay: Option<(u32, u32)>,
+    size: (u32, u32),
+    dest: (u32, u32),
+    mirror: bool,
+}
+
+fn get_previews_dir() -> PathBuf {
+    get_skins_dir().join("previews")
+}
+
+/// Alpha-blend a region of the skin onto the composite, optionally mirrored horizontally
+fn blit(skin: &RgbaImage, out: &mut RgbaImage, src: (u32, u32), size: (u32, u32), dest: (u32, u32), mirror: bool) {
+    for y in 0..size.1 {
+        for x in 0..size.0 {
+            let sx = if mirror { src.0 + size.0 - 1 - x } else { src.0 + x };
+            let sy = src.1 + y;
+            if sx >= skin.width() || sy >= skin.height() {
+                continue;
+            }
+            let Rgba([r, g, b, a]) = *skin.get_pixel(sx, sy);
+            if a == 0 {
+                continue;
+            }
+            let target = out.get_pixel_mut(dest.0 + x, dest.1 + y);
+            let alpha = a as f32 / 255.0;
+            let blend = |top: u8, bottom: u8| (top as f32 * alpha + bottom as f32 * (1.0 - alpha)).round() as u8;
+            let out_alpha = (a as f32 + target[3] as f32 * (1.0 - alpha)).round().min(255.0) as u8;
+            *target = Rgba([blend(r, target[0]), blend(g, target[1]), blend(b, target[2]), out_alpha]);
+        }
+    }
+}
+
+fn front_parts(slim: bool, legacy: bool) -> Vec<SkinPart> {
+    let arm_width = if slim { 3 } else { 4 };
+    let mut parts = vec![
+        SkinPart { src: (8, 8), overlay: Some((40, 8)), size: (8, 8), dest: (4, 0), mirror: false },
+        SkinPart { src: (20, 20), overlay: (!legacy).then_some((20, 36)), size: (8, 12), dest: (4, 8), mirror: false },
+        // The player's right side is on the viewer's left
+        SkinPart { src: (44, 20), overlay: (!legacy).then_some((44, 36)), size: (arm_width, 12), dest: (4 - arm_width, 8), mirror: false },
+        SkinPart { src: (4, 20), overlay: (!legacy).then_some((4, 36)), size: (4, 12), dest: (4, 20), mirror: false },
+    ];
+    if legacy {
+        // 64x32 skins have no separate left limbs; the game mirrors the right ones
+        parts.push(SkinPart { src: (44, 20), overlay: None, size: (arm_width, 12), dest: (12, 8), mirror: true });
+        parts.push(SkinPart { src: (4, 20), overlay: None, size: (4, 12), dest: (8, 20), mirror: true });
+    } else {
+        parts.push(SkinPart { src: (36, 52), overlay: Some((52, 52)), size: (arm_width, 12), dest: (12, 8), mirror: false });
+        parts.push(SkinPart { src: (20, 52), overlay: Some((4, 52)), size: (4, 12), dest: (8, 20), mirror: false });
+    }
+    parts
+}
+
+/// Render a front-facing body preview of a skin PNG into the preview cache and return its path.
+/// `variant` is "slim" for 3px arms, anything else renders classic 4px arms.
+pub fn render_skin_preview(file_path: &Path, variant: &str) -> Result<PathBuf, String> {
+    let slim = variant.eq_ignore_ascii_case("slim");
+    let bytes = fs::read(file_path).map_err(|e| format!("Failed to read skin: {}", e))?;
+
+    // Same skin file and variant always map to the same preview
+    let mut hasher = Sha1::new();
+    hasher.update(&bytes);
+    hasher.update(if slim { b"slim" as &[u8] } else { b"classic" });
+    let preview_path = get_previews_dir().join(format!("{:x}.png", hasher.finalize()));
+    if preview_path.exists() {
+        return Ok(preview_path);
+    }
+
+    let skin = image::load_from_memory_with_format(&bytes, image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to decode skin: {}", e))?
+        .to_rgba8();
+    let legacy = match (skin.width(), skin.height()) {
+        (64, 64) => false,
+        (64, 32) => true,
+        (width, height) => return Err(format!("Unsupported skin size {}x{}, expected 64x64 or 64x32", width, height)),
+    };
+
+    let mut composite = RgbaImage::new(COMPOSITE_WIDTH, COMPOSITE_HEIGHT);
+    for part in front_parts(slim, legacy) {
+        blit(&skin, &mut composite, part.src, part.size, part.dest, part.mirror);
+        if let Some(overlay) = part.overlay {
+            blit(&skin, &mut composite, overlay, part.size, part.dest, part.mirror);
+        }
+    }
+
+    let preview = imageops::resize(
+        &composite,
+        COMPOSITE_WIDTH * PREVIEW_SCALE,
+        COMPOSITE_HEIGHT * PREVIEW_SCALE,
+        imageops::FilterType::Nearest,
+    );
+    fs::create_dir_all(get_previews_dir()).map_err(|e| format!("Failed to create preview folder: {}", e))?;
+    preview
+        .save_with_format(&preview_path, image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to save preview: {}", e))?;
+    Ok(preview_path)
+}
+
+// Steve's face as palette indices, used when an account has no fetchable skin
+const DEFAULT_HEAD_PALETTE: [[u8; 4]; 6] = [
+    [47, 31, 15, 255], // hair
+    [36, 24, 8, 255], // hair shadow
+    [182, 137, 108, 255], // skin
+    [170, 125, 102, 255], // skin shadow
+    [255, 255, 255, 255], // eye white
+    [82, 61, 137, 255], // eye
+];
+const DEFAULT_HEAD: [[u8; 8]; 8] = [
+    [0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 2, 2, 2, 2, 2, 2, 0],
+    [2, 2, 2, 2, 2, 2, 2, 2],
+    [2, 4, 5, 2, 2, 5, 4, 2],
+    [2, 2, 2, 1, 1, 2, 2, 2],
+    [2, 2, 3, 3, 3, 3, 2, 2],
+    [2, 2, 3, 2, 2, 3, 2, 2],
+];
+
+fn render_default_head() -> RgbaImage {
+    let face = RgbaImage::from_fn(8, 8, |x, y| Rgba(DEFAULT_HEAD_PALETTE[DEFAULT_HEAD[y as usize][x as usize] as usize]));
+    imageops::resize(&face, 8 * HEAD_SCALE, 8 * HEAD_SCALE, imageops::FilterType::Nearest)
+}
+
+/// Crop the face and hat layer out of a skin texture and scale it up
+fn render_head(skin: &RgbaImage) -> RgbaImage {
+    let mut face = RgbaImage::new(8, 8);
+    blit(skin, &mut face, (8, 8), (8, 8), (0, 0), false);
+    // 64x32 skins still carry the hat layer at the same spot
+    blit(skin, &mut face, (40, 8), (8, 8), (0, 0), false);
+    imageops::resize(&face, 8 * HEAD_SCALE, 8 * HEAD_SCALE, imageops::FilterType::Nearest)
+}
+
+fn is_fresh(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < HEAD_CACHE_TTL)
+}
+
+/// Look up the skin URL for a profile via the session server. `None` means the profile
+/// exists nowhere (offline accounts) or has no custom skin set.
+async fn fetch_skin_url(uuid: &str) -> Result<Option<String>, String> {
+    let response = super::http_client()
+        .get(format!("{}/{}", SESSION_PROFILE_URL, uuid))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach session server: {}", e))?;
+    // Unknown profiles come back as 204 No Content (older API) or 404
+    if response.status() == reqwest::StatusCode::NO_CONTENT || response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(format!("Session server returned {}", response.status()));
+    }
+    let profile: serde_json::Value = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse profile: {}", e))?;
+
+    let Some(encoded) = profile["properties"]
+        .as_array()
+        .and_then(|props| props.iter().find(|p| p["name"] == "textures"))
+        .and_then(|p| p["value"].as_str())
+    else {
+        return Ok(None);
+    };
+    let decoded = general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| format!("Failed to decode textures: {}", e))?;
+    let textures: serde_json::Value =
+        serde_json::from_slice(&decoded).map_err(|e| format!("Failed to parse textures: {}", e))?;
+    Ok(textures["textures"]["SKIN"]["url"].as_str().map(str::to_string))
+}
+
+async fn fetch_head(uuid: &str) -> Result<RgbaImage, String> {
+    let Some(url) = fetch_skin_url(uuid).await? else {
+        return Ok(render_default_head());
+    };
+    let bytes = super::http_client()
+        .get(&url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Failed to download skin: {}", e))?
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read skin: {}", e))?;
+    let skin = image::load_from_memory_with_format(&bytes, image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to decode skin: {}", e))?
+        .to_rgba8();
+    Ok(render_head(&skin))
+}
+
+/// Return a cached 64x64 head avatar for a profile UUID, refreshing it from the session
+/// server once it is older than the cache TTL. Offline accounts and unreachable servers
+/// fall back to the last cached head, or a rendered default when there is none.
+pub async fn account_head(uuid: &str) -> Result<PathBuf, String> {
+    let uuid = uuid.replace('-', "").to_ascii_lowercase();
+    if uuid.len() != 32 || !uuid.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("Invalid UUID: {}", uuid));
+    }
+
+    let cache_dir = get_account_heads_cache_dir();
+    let head_path = cache_dir.join(format!("{}.png", uuid));
+    if is_fresh(&head_path) {
+        return Ok(head_path);
+    }
+
+    let head = if super::is_offline() {
+        Err("Offline".to_string())
+    } else {
+        fetch_head(&uuid).await
+    };
+    fs::create_dir_all(&cache_dir).map_err(|e| format!("Failed to create heads cache folder: {}", e))?;
+    let (head, path) = match head {
+        Ok(head) => (head, head_path),
+        Err(e) => {
+            if head_path.exists() {
+                // A stale head beats a generic one
+                return Ok(head_path);
+            }
+            // Not cached under the UUID so the real skin is retried on the next request
+            log::warn!("Using default head for {}: {}", uuid, e);
+            (render_default_head(), cache_dir.join("default.png"))
+        }
+    };
+    head.save_with_format(&path, image::ImageFormat::Png)
+        .map_err(|e| format!("Failed to save head: {}", e))?;
+    Ok(path)
+}
@@ -132,17 +132,149 @@ pub struct JavaVersion {
     pub major_version: i32,
 }
 
-/// Fetches the version manifest from Mojang's API
+// Reuse the cached manifest without asking Mojang when it was checked this recently
+const MANIFEST_FRESH_SECS: u64 = 600;
+
+fn manifest_cache_path() -> std::path::PathBuf {
+    super::downloader::get_minecraft_dir().join("cache").join("version_manifest.json")
+}
+
+fn manifest_meta_path() -> std::path::PathBuf {
+    super::downloader::get_minecraft_dir().join("cache").join("version_manifest.meta.json")
+}
+
+// ----------
+// ManifestCacheMeta
+// Description: HTTP validators for the cached manifest and when it was last confirmed current
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct ManifestCacheMeta {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    checked_at: u64,
+}
+
+fn load_cached_manifest() -> Option<VersionManifest> {
+    let content = std::fs::read_to_string(manifest_cache_path()).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn load_manifest_meta() -> Option<ManifestCacheMeta> {
+    let content = std::fs::read_to_string(manifest_meta_path()).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn save_manifest_meta(meta: &ManifestCacheMeta) {
+    if let Ok(json) = serde_json::to_string(meta) {
+        let _ = std::fs::write(manifest_meta_path(), json);
+    }
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Unix seconds when the cached manifest was last confirmed current, if there is one
+pub fn manifest_cache_checked_at() -> Option<u64> {
+    load_cached_manifest()?;
+    load_manifest_meta().map(|meta| meta.checked_at).filter(|secs| *secs > 0)
+}
+
+/// Fetches the version manifest from Mojang's API, falling back to the last copy
+/// saved on disk when offline or when the request fails
 pub async fn fetch_version_manifest() -> Result<VersionManifest, Box<dyn Error + Send + Sync>> {
+    fetch_version_manifest_with(false).await
+}
+
+/// Like fetch_version_manifest; `force` skips the freshness window and conditional
+/// request so the whole manifest is downloaded again
+pub async fn fetch_version_manifest_with(force: bool) -> Result<VersionManifest, Box<dyn Error + Send + Sync>> {
+    let cached = load_cached_manifest();
+    if super::is_offline() {
+        if let Some(cached) = cached {
+            return Ok(cached);
+        }
+    }
+
+    let meta = if cached.is_some() { load_manifest_meta() } else { None };
+    if !force {
+        if let (Some(cached), Some(meta)) = (&cached, &meta) {
+            if unix_now().saturating_sub(meta.checked_at) < MANIFEST_FRESH_SECS {
+                return Ok(cached.clone());
+            }
+        }
+    }
+
+    let validators = if force { None } else { meta };
+    match fetch_remote_manifest(validators.as_ref()).await {
+        Ok(RemoteManifest::NotModified) => match cached {
+            Some(cached) => {
+                if let Some(mut meta) = validators {
+                    meta.checked_at = unix_now();
+                    save_manifest_meta(&meta);
+                }
+                Ok(cached)
+            }
+            None => Err("Mojang reported the version manifest unchanged but no cached copy exists".into()),
+        },
+        Ok(RemoteManifest::Fresh { manifest, etag, last_modified }) => {
+            let path = manifest_cache_path();
+            if let Some(parent) = path.parent() {
+                let _ = std::fs::create_dir_all(parent);
+            }
+            if let Ok(json) = serde_json::to_string(&manifest) {
+                if std::fs::write(&path, json).is_ok() {
+                    save_manifest_meta(&ManifestCacheMeta { etag, last_modified, checked_at: unix_now() });
+                }
+            }
+            Ok(manifest)
+        }
+        Err(e) => cached.ok_or(e),
+    }
+}
+
+enum RemoteManifest {
+    NotModified,
+    Fresh {
+        manifest: VersionManifest,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+}
+
+async fn fetch_remote_manifest(validators: Option<&ManifestCacheMeta>) -> Result<RemoteManifest, Box<dyn Error + Send + Sync>> {
     let client = super::http_client();
-    let response = client
+    let mut request = client
         .get(VERSION_MANIFEST_URL)
-        .header("User-Agent", format!("PaletheaLauncher/{}", super::get_launcher_version()))
-        .send()
-        .await?
-        .error_for_status()?;
+        .header("User-Agent", format!("PaletheaLauncher/{}", super::get_launcher_version()));
+    if let Some(meta) = validators {
+        if let Some(etag) = &meta.etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &meta.last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    let response = request.send().await?;
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        return Ok(RemoteManifest::NotModified);
+    }
+    let response = response.error_for_status()?;
+    let header = |name: reqwest::header::HeaderName| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let etag = header(reqwest::header::ETAG);
+    let last_modified = header(reqwest::header::LAST_MODIFIED);
     let manifest: VersionManifest = response.json().await?;
-    Ok(manifest)
+    Ok(RemoteManifest::Fresh { manifest, etag, last_modified })
 }
 
 /// Fetches detailed version information for a specific version
@@ -158,6 +290,29 @@ pub async fn fetch_version_details(version_url: &str) -> Result<VersionDetails,
     Ok(details)
 }
 
+/// Details for a version id, read from the installed version JSON when present and
+/// fetched through the manifest otherwise
+pub async fn load_or_fetch_version_details(version_id: &str) -> Result<VersionDetails, Box<dyn Error + Send + Sync>> {
+    let json_path = super::downloader::get_versions_dir()
+        .join(version_id)
+        .join(format!("{}.json", version_id));
+    if let Ok(details) = load_version_details(&json_path) {
+        return Ok(details);
+    }
+    let manifest = fetch_version_manifest().await?;
+    let info = manifest
+        .versions
+        .iter()
+        .find(|v| v.id == version_id)
+        .ok_or_else(|| format!("Version {} is not in the version manifest", version_id))?;
+    fetch_version_details(&info.url).await
+}
+
+/// Whether a manifest version type is pre-release Beta or Alpha
+pub fn is_old_version_type(version_type: &str) -> bool {
+    matches!(version_type, "old_beta" | "old_alpha")
+}
+
 /// Load version details from a local file
 pub fn load_version_details(path: &std::path::PathBuf) -> Result<VersionDetails, Box<dyn Error + Send + Sync>> {
     let content = std::fs::read_to_string(path)?;
@@ -228,3 +383,21 @@ pub fn library_name_to_path(name: &str) -> String {
     path.push_str(".jar");
     path
 }
+
+/// Up to `limit` entries of `available` that share the longest leading run of version parts
+/// with `requested`, for "did you mean" errors when a loader version doesn't exist
+pub fn nearby_versions(requested: &str, available: &[String], limit: usize) -> Vec<String> {
+    let parts = |v: &str| -> Vec<String> { v.split(['.', '-', '+']).map(str::to_string).collect() };
+    let wanted = parts(requested);
+    let mut scored: Vec<(usize, &String)> = available
+        .iter()
+        .map(|candidate| {
+            let shared = parts(candidate).iter().zip(&wanted).take_while(|(a, b)| a == b).count();
+            (shared, candidate)
+        })
+        .filter(|(shared, _)| *shared > 0)
+        .collect();
+    // Stable sort keeps the source order (usually newest first) among equally close versions
+    scored.sort_by(|a, b| b.0.cmp(&a.0));
+    scored.into_iter().take(limit).map(|(_, v)| v.clone()).collect()
+}
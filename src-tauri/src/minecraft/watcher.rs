@@ -0,0 +1,93 @@
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+
+// Changes arriving within this window are reported as one event
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(500);
+
+/// Content folders in the game directory whose listings the UI shows
+pub const WATCHED_FOLDERS: &[&str] = &["mods", "resourcepacks", "shaderpacks"];
+
+// ----------
+// ModsChangedPayload
+// Description: Emitted as `mods-changed` once a burst of file changes has settled
+// ----------
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ModsChangedPayload {
+    pub instance_id: String,
+    pub folders: Vec<String>, // which of WATCHED_FOLDERS changed
+}
+
+// Dropping a watcher stops it, which also ends its debounce thread
+static WATCHERS: LazyLock<Mutex<HashMap<String, RecommendedWatcher>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Watch an instance's content folders, replacing any watcher already running for it
+pub fn start_watching(app_handle: AppHandle, instance_id: String, game_dir: PathBuf) -> Result<(), String> {
+    let (tx, rx) = mpsc::channel::<String>();
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        let Ok(event) = result else {
+            return;
+        };
+        if !matches!(event.kind, EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(notify::event::ModifyKind::Name(_))) {
+            return;
+        }
+        for path in event.paths {
+            let folder = path
+                .parent()
+                .and_then(|parent| parent.file_name())
+                .map(|name| name.to_string_lossy().to_string());
+            if let Some(folder) = folder.filter(|f| WATCHED_FOLDERS.contains(&f.as_str())) {
+                let _ = tx.send(folder);
+            }
+        }
+    })
+    .map_err(|e| format!("Failed to create folder watcher: {}", e))?;
+
+    for folder in WATCHED_FOLDERS {
+        let dir = game_dir.join(folder);
+        std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to watch {}: {}", dir.display(), e))?;
+    }
+
+    let thread_instance_id = instance_id.clone();
+    std::thread::spawn(move || {
+        // Block until the first change, then keep collecting until the folders go quiet
+        while let Ok(first) = rx.recv() {
+            let mut folders = BTreeSet::from([first]);
+            loop {
+                match rx.recv_timeout(DEBOUNCE_WINDOW) {
+                    Ok(folder) => {
+                        folders.insert(folder);
+                    }
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+            let _ = app_handle.emit("mods-changed", ModsChangedPayload {
+                instance_id: thread_instance_id.clone(),
+                folders: folders.into_iter().collect(),
+            });
+        }
+    });
+
+    if let Ok(mut watchers) = WATCHERS.lock() {
+        watchers.insert(instance_id, watcher);
+    }
+    Ok(())
+}
+
+/// Stop watching an instance. Returns false when no watcher was running.
+pub fn stop_watching(instance_id: &str) -> bool {
+    match WATCHERS.lock() {
+        Ok(mut watchers) => watchers.remove(instance_id).is_some(),
+        Err(_) => false,
+    }
+}
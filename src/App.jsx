@@ -60,13 +60,28 @@ const devError = (...args) => {
   }
 };
 
-const SUPPORTED_LOADER_KEYS = new Set(['fabric', 'forge', 'neoforge']);
+const SUPPORTED_LOADER_KEYS = new Set(['fabric', 'quilt', 'forge', 'neoforge']);
 const DEFAULT_INSTANCE_ICON = '/minecraft_logo.png';
 const CATEGORY_LIST_STORAGE_KEY = 'instance-category-list';
 const CATEGORY_LIST_UPDATED_EVENT = 'instance-category-list-updated';
 const SIDEBAR_STYLE_CACHE_KEY = 'sidebar_style_cache';
 const OPEN_CATEGORY_MANAGER_EVENT = 'open-instance-category-manager';
 const PREFERRED_SERVER_STORAGE_KEY = 'instance-preferred-server-map';
+// Sidebar tabs reopened on startup; anything else saved as the last view is an instance id
+const RESTORABLE_TABS = ['instances', 'settings', 'appearance', 'stats', 'skins', 'updates', 'console'];
+
+// launch_instance asks first when a launch needs a large download; retry once the user agrees
+const invokeLaunchInstance = async (args) => {
+  let result = await invoke('launch_instance', { ...args, confirm: false });
+  if (result?.status === 'confirmation_required') {
+    const sizeMb = Math.ceil(result.bytes / (1024 * 1024));
+    if (!window.confirm(`Launching needs to download about ${sizeMb} MB of game files. Continue?`)) {
+      return null;
+    }
+    result = await invoke('launch_instance', { ...args, confirm: true });
+  }
+  return result?.message ?? null;
+};
 
 const normalizeCategoryName = (value) => (typeof value === 'string' ? value.trim() : '');
 
@@ -225,6 +240,7 @@ function App() {
   const [instances, setInstances] = useState([]);
   const [accounts, setAccounts] = useState([]);
   const [activeAccount, setActiveAccount] = useState(null);
+  const [isOffline, setIsOffline] = useState(false);
   const [isLoading, setIsLoading] = useState(false);
   const [isCreateTaskRunning, setIsCreateTaskRunning] = useState(false);
   const [launchingInstanceId, setLaunchingInstanceId] = useState(null);
@@ -239,6 +255,8 @@ function App() {
   const [loadingTelemetry, setLoadingTelemetry] = useState(EMPTY_DOWNLOAD_TELEMETRY);
   const [notifications, setNotifications] = useState([]);
   const [editingInstanceId, setEditingInstanceId] = useState(null);
+  // Stays false until the startup restore ran, so the initial tab doesn't overwrite the saved view
+  const lastViewRestoredRef = useRef(false);
   const [deletingInstanceIds, setDeletingInstanceIds] = useState([]);
   const [contextMenu, setContextMenu] = useState(null);
   const [showLoginPrompt, setShowLoginPrompt] = useState(false);
@@ -253,6 +271,7 @@ function App() {
   const [skinCache, setSkinCache] = useState({});
   const [logs, setLogs] = useState([]);
   const [launcherSettings, setLauncherSettings] = useState(null);
+  const notificationsEnabledRef = useRef(true);
   const [showAccountManager, setShowAccountManager] = useState(false);
   const [isInitializing, setIsInitializing] = useState(true);
   const [openEditors, setOpenEditors] = useState([]);
@@ -435,6 +454,8 @@ function App() {
   }, []);
 
   const showNotification = useCallback((message, type = 'info') => {
+    // Errors and warnings always show; routine toasts follow the notifications setting
+    if (!notificationsEnabledRef.current && (type === 'success' || type === 'info')) return;
     const id = `toast-${Date.now()}-${notificationCounterRef.current++}`;
     setNotifications((prev) => [...prev, { id, message, type, isLeaving: false }]);
 
@@ -559,6 +580,15 @@ function App() {
     localStorage.setItem(SIDEBAR_STYLE_CACHE_KEY, styleValue);
   }, [launcherSettings?.sidebar_style]);
 
+  useEffect(() => {
+    notificationsEnabledRef.current = launcherSettings?.enable_notifications !== false;
+  }, [launcherSettings?.enable_notifications]);
+
+  useEffect(() => {
+    if (!lastViewRestoredRef.current) return;
+    invoke('set_last_view', { view: editingInstanceId || activeTab }).catch(() => {});
+  }, [activeTab, editingInstanceId]);
+
   useEffect(() => {
     // Load all cached skins on startup
     const cache = {};
@@ -660,6 +690,7 @@ function App() {
     try {
       const result = await invoke('get_instances');
       setInstances(result);
+      return result;
     } catch (error) {
       devError(`[DEBUG] [${getElapsed()}] Failed to load instances:`, error);
     }
@@ -710,7 +741,8 @@ function App() {
       const uiAccounts = savedData.accounts.map(a => ({
         username: a.username,
         isLoggedIn: a.is_microsoft,
-        uuid: a.uuid
+        uuid: a.uuid,
+        needsRelogin: a.needs_relogin
       }));
 
       setAccounts(uiAccounts);
@@ -796,7 +828,28 @@ function App() {
           loadSkinForAccount(activeAcc.is_microsoft, activeAcc.uuid);
         };
 
-        validateAccountAsync();
+        // Refresh the remaining accounts once the active one is settled
+        validateAccountAsync().then(async () => {
+          try {
+            const results = await invoke('refresh_all_accounts', { onStartup: true });
+            const expired = results.filter(r => r.status === 'needs_relogin');
+            if (expired.length > 0) {
+              const names = expired.map(r => r.username).join(', ');
+              showNotification(`Please login again to keep using: ${names}`, 'warning');
+            }
+            if (results.length > 0) {
+              const refreshedData = await invoke('get_saved_accounts');
+              setAccounts((refreshedData.accounts || []).map(a => ({
+                username: a.username,
+                isLoggedIn: a.is_microsoft,
+                uuid: a.uuid,
+                needsRelogin: a.needs_relogin
+              })));
+            }
+          } catch (err) {
+            devLog('Background account refresh failed:', err);
+          }
+        });
       }
     } catch (error) {
       devError('Failed to load accounts:', error);
@@ -828,7 +881,7 @@ function App() {
       );
 
       try {
-        await Promise.race([
+        const [loadedInstances] = await Promise.race([
           Promise.all([
             loadInstances(),
             loadAccounts(),
@@ -837,10 +890,24 @@ function App() {
           ]),
           timeoutPromise
         ]);
+
+        // Reopen the instance or tab the user was last looking at
+        try {
+          const lastView = await invoke('get_last_view');
+          if (RESTORABLE_TABS.includes(lastView)) {
+            setActiveTab(lastView);
+          } else if (lastView && loadedInstances?.some((instance) => instance.id === lastView)) {
+            setEditingInstanceId(lastView);
+          }
+        } catch (error) {
+          devError(`[DEBUG] [${getElapsed()}] Failed to restore last view:`, error);
+        }
+        lastViewRestoredRef.current = true;
         // Short delay to ensure state updates and transitions are smooth
         setTimeout(() => setIsInitializing(false), 500);
       } catch (error) {
         devError(`[DEBUG] [${getElapsed()}] CRITICAL: Initialization halted:`, error);
+        lastViewRestoredRef.current = true;
         setIsInitializing(false);
       }
     };
@@ -1148,9 +1215,11 @@ function App() {
         if (launchHandler) {
           await launchHandler(instanceId);
         } else {
-          const result = await invoke('launch_instance', { instanceId, serverAddress: null });
-          showNotification(result, 'success');
-          loadRunningInstances();
+          const message = await invokeLaunchInstance({ instanceId, serverAddress: null });
+          if (message) {
+            showNotification(message, 'success');
+            loadRunningInstances();
+          }
         }
       } catch (error) {
         showNotification(`Failed to launch: ${error}`, 'error');
@@ -1207,12 +1276,40 @@ function App() {
     }
   }, [isLoading, isCreateTaskRunning]);
 
+  // Offline detection: probe on startup and whenever the OS reports a network change
+  useEffect(() => {
+    const probe = () => {
+      invoke('is_online')
+        .then((online) => setIsOffline(!online))
+        .catch(() => {});
+    };
+    probe();
+    window.addEventListener('online', probe);
+    window.addEventListener('offline', probe);
+    const unlistenOffline = listen('offline-mode-changed', (event) => setIsOffline(Boolean(event.payload)));
+    return () => {
+      window.removeEventListener('online', probe);
+      window.removeEventListener('offline', probe);
+      unlistenOffline.then(fn => fn());
+    };
+  }, []);
+
+  // Offline accounts launching straight into an online-mode server get a heads-up
+  useEffect(() => {
+    const unlistenServerJoin = listen('server-join-warning', (event) => {
+      if (event.payload?.message) showNotification(event.payload.message, 'warning');
+    });
+    return () => {
+      unlistenServerJoin.then(fn => fn());
+    };
+  }, [showNotification]);
+
   // Navigation Guard: Redirect from specialized pages if state changes (eg. logout)
   useEffect(() => {
-    if (activeTab === 'skins' && !activeAccount?.isLoggedIn) {
+    if ((activeTab === 'skins' && (!activeAccount?.isLoggedIn || isOffline)) || (activeTab === 'updates' && isOffline)) {
       setActiveTab('instances');
     }
-  }, [activeAccount, activeTab]);
+  }, [activeAccount, activeTab, isOffline]);
 
   const [hasCheckedWelcome, setHasCheckedWelcome] = useState(false);
 
@@ -1243,14 +1340,14 @@ function App() {
     });
   }, []);
 
-  const handleCloneInstance = useCallback(async (instance) => {
+  const handleCloneInstance = useCallback(async (instance, options = null) => {
     const newName = `${instance.name} (Copy)`;
     setIsLoading(true);
     setLoadingStatus('Cloning instance...');
     setLoadingTelemetry(EMPTY_DOWNLOAD_TELEMETRY);
     transferStatsRef.current = { lastBytes: null, lastTs: 0, speedBps: 0 };
     try {
-      await invoke('clone_instance', { instanceId: instance.id, newName });
+      await invoke('clone_instance', { instanceId: instance.id, newName, options });
       await loadInstances();
       showNotification(`Cloned "${instance.name}" successfully!`, 'success');
     } catch (error) {
@@ -1419,7 +1516,8 @@ function App() {
         // 1. Create the instance
         const newInstance = await invoke('create_instance', {
           name: name || originalName,
-          versionId: mcVersion
+          versionId: mcVersion,
+          lenient: true
         });
         setTaskInstanceId(newInstance.id);
 
@@ -1434,6 +1532,8 @@ function App() {
           setTaskStatus(`Installing ${loader}...`, 20);
           if (loader === 'fabric') {
             await invoke('install_fabric', { instanceId: newInstance.id, loaderVersion: loader_version });
+          } else if (loader === 'quilt') {
+            await invoke('install_quilt', { instanceId: newInstance.id, loaderVersion: loader_version });
           } else if (loader === 'forge') {
             await invoke('install_forge', { instanceId: newInstance.id, loaderVersion: loader_version });
           } else if (loader === 'neoforge') {
@@ -1562,12 +1662,13 @@ function App() {
         await processItems(resourcepacks, 'resourcepack');
         await processItems(shaders, 'shader');
 
-        successMessage = `Share code applied! Instance "${name || originalName}" created.`;
+        successMessage = `Share code applied! Instance "${newInstance.name}" created.`;
       } else if (modLoader === 'modpack') {
         const {
           provider = 'modrinth',
           modpackId,
           versionId: modpackVersionId,
+          fileName: modpackFileName = null,
           modpackName,
           modpackIcon,
           modpackAuthor = null,
@@ -1577,7 +1678,7 @@ function App() {
 
         setTaskStatus(`Creating instance for ${modpackName}...`, 5);
         // We use a placeholder version initially, modpack installer will update it
-        const newInstance = await invoke('create_instance', { name, versionId: 'pending' });
+        const newInstance = await invoke('create_instance', { name, versionId: 'pending', lenient: true });
         const normalizedProvider = provider === 'curseforge' ? 'CurseForge' : 'Modrinth';
         const fallbackModrinthUrl = modpackId
           ? `https://modrinth.com/modpack/${modpackSlug || modpackId}`
@@ -1622,7 +1723,8 @@ function App() {
         } else {
           await invoke('install_modpack', {
             instanceId: attributedInstance.id,
-            versionId: modpackVersionId
+            versionId: modpackVersionId,
+            fileName: modpackFileName
           });
         }
 
@@ -1666,6 +1768,26 @@ function App() {
               console.error('Failed to install Fabric:', fabricError);
               showNotification(`Instance created but Fabric installation failed: ${fabricError}`, 'error');
             }
+          } else if (modLoader === 'quilt') {
+            try {
+              const loaderVersions = modLoaderVersion ? [modLoaderVersion] : await invoke('get_loader_versions', {
+                loader: 'quilt',
+                gameVersion: versionId
+              });
+              const loaderVersion = modLoaderVersion || (loaderVersions && loaderVersions[0]);
+
+              if (loaderVersion) {
+                await invoke('install_quilt', {
+                  instanceId: newInstance.id,
+                  loaderVersion
+                });
+              } else {
+                showNotification(`No Quilt version found for ${versionId}`, 'error');
+              }
+            } catch (quiltError) {
+              console.error('Failed to install Quilt:', quiltError);
+              showNotification(`Instance created but Quilt installation failed: ${quiltError}`, 'error');
+            }
           } else if (modLoader === 'forge') {
             try {
               const loaderVersions = modLoaderVersion ? [modLoaderVersion] : await invoke('get_loader_versions', {
@@ -1984,6 +2106,8 @@ function App() {
     try {
       if (loaderUpdate.loaderKey === 'fabric') {
         await invoke('install_fabric', { instanceId, loaderVersion: loaderUpdate.latestVersion });
+      } else if (loaderUpdate.loaderKey === 'quilt') {
+        await invoke('install_quilt', { instanceId, loaderVersion: loaderUpdate.latestVersion });
       } else if (loaderUpdate.loaderKey === 'forge') {
         await invoke('install_forge', { instanceId, loaderVersion: loaderUpdate.latestVersion });
       } else if (loaderUpdate.loaderKey === 'neoforge') {
@@ -2043,6 +2167,16 @@ function App() {
       transferStatsRef.current = { lastBytes: null, lastTs: 0, speedBps: 0 };
     }
 
+    // Advisory only: the launch carries on while mods are checked in the background
+    invoke('preflight_check', { instanceId })
+      .then((issues) => {
+        if (!issues?.length) return;
+        const names = issues.slice(0, 3).map((issue) => issue.name || issue.filename).join(', ');
+        const more = issues.length > 3 ? ` and ${issues.length - 3} more` : '';
+        showNotification(`These mods may not work with this instance: ${names}${more}. ${issues[0].reason}.`, 'warning');
+      })
+      .catch((error) => console.warn('Mod compatibility check failed:', error));
+
     // Give React a frame to render the overlay before the backend starts heavy preparation
     await new Promise(resolve => setTimeout(resolve, 50));
 
@@ -2075,7 +2209,8 @@ function App() {
         let availableLoaderUpdate = null;
         try {
           const updateRows = await invoke('get_instance_mod_updates', { instanceId });
-          availableModUpdates = Array.isArray(updateRows) ? updateRows : [];
+          // Pinned mods are listed for display only and never offered as updates
+          availableModUpdates = Array.isArray(updateRows) ? updateRows.filter((row) => !row.pinned) : [];
         } catch (scanError) {
           console.error('Failed to scan mod updates before launch:', scanError);
           showNotification('Could not check for mod updates before launch.', 'warning');
@@ -2180,11 +2315,12 @@ function App() {
         ? launchOptions.serverAddress.trim()
         : null;
 
-      const result = await invoke('launch_instance', {
+      const message = await invokeLaunchInstance({
         instanceId,
         serverAddress: normalizedServerAddress,
       });
-      showNotification(result, 'success');
+      if (!message) return;
+      showNotification(message, 'success');
       loadRunningInstances(); // Update running instances immediately
 
       // Keep the 100% state visible for a moment before closing overlay
@@ -2441,6 +2577,26 @@ function App() {
     }
   }, [activeAccount, showNotification]);
 
+  const handleMigrateOfflineUuid = useCallback(async (username) => {
+    try {
+      const result = await invoke('migrate_offline_account_uuid', { username });
+      if (!result.changed) {
+        showNotification(`${username} already uses the standard offline UUID`, 'info');
+        return;
+      }
+      setAccounts(prev => prev.map(a => (a.username === username ? { ...a, uuid: result.uuid } : a)));
+      if (activeAccount?.username === username) {
+        setActiveAccount(prev => ({ ...prev, uuid: result.uuid }));
+      }
+      const movedNote = result.moved_player_files > 0
+        ? ` and carried over ${result.moved_player_files} world player file${result.moved_player_files === 1 ? '' : 's'}`
+        : '';
+      showNotification(`${username} now uses the standard offline UUID${movedNote}`, 'success');
+    } catch (error) {
+      showNotification(`Failed to update UUID: ${error}`, 'error');
+    }
+  }, [activeAccount, showNotification]);
+
   const handleOpenPopoutEditor = useCallback(async (instanceId) => {
     if (editingInstanceId === instanceId) {
       setEditingInstanceId(null);
@@ -2566,6 +2722,38 @@ function App() {
           handleCloneInstance(instance);
         }
         break;
+      case 'cloneSettingsOnly':
+        if (instance) {
+          handleCloneInstance(instance, {
+            copy_worlds: false,
+            copy_mods: false,
+            copy_configs: true,
+            copy_resourcepacks: false,
+            copy_screenshots: false,
+          });
+        }
+        break;
+      case 'moveToTop':
+      case 'moveUp':
+      case 'moveDown':
+        if (instance) {
+          const ids = instances.map(i => i.id);
+          const from = ids.indexOf(instance.id);
+          const to = action === 'moveToTop' ? 0 : action === 'moveUp' ? from - 1 : from + 1;
+          if (from === -1 || to < 0 || to >= ids.length || to === from) break;
+          ids.splice(from, 1);
+          ids.splice(to, 0, instance.id);
+          try {
+            await invoke('reorder_instances', { orderedIds: ids });
+            await loadInstances();
+            if (localStorage.getItem('instance_sort') !== 'custom') {
+              showNotification('Switch the instance sort to Custom to see this order', 'info');
+            }
+          } catch (error) {
+            console.error('Failed to reorder instances:', error);
+          }
+        }
+        break;
       case 'setCategory':
         if (instance) {
           setCategoryEditorModal(buildCategoryPickerModalState(instances, instance));
@@ -2682,6 +2870,44 @@ function App() {
           }
         }
         break;
+      case 'exportCurseForge':
+        if (instance) {
+          try {
+            const defaultName = `${instance.name.replace(/[^a-zA-Z0-9]/g, '_')}-curseforge.zip`;
+            const savePath = await save({
+              defaultPath: defaultName,
+              filters: [{
+                name: 'CurseForge Modpack',
+                extensions: ['zip']
+              }]
+            });
+
+            if (savePath) {
+              setIsLoading(true);
+              setLoadingStatus(`Exporting ${instance.name} for CurseForge...`);
+              setLoadingTelemetry(EMPTY_DOWNLOAD_TELEMETRY);
+              transferStatsRef.current = { lastBytes: null, lastTs: 0, speedBps: 0 };
+              const result = await invoke('export_instance_curseforge', {
+                instanceId: instance.id,
+                destinationPath: savePath
+              });
+              showNotification(
+                `Exported "${instance.name}": ${result.referenced_mods} mod(s) referenced, ${result.bundled_mods} bundled`,
+                'success'
+              );
+            }
+          } catch (error) {
+            showNotification(`Failed to export instance: ${error}`, 'error');
+          } finally {
+            setIsLoading(false);
+            setLoadingStatus('');
+            setLoadingProgress(0);
+            setLoadingCount({ current: 0, total: 0 });
+            setLoadingBytes({ current: 0, total: 0 });
+            setLoadingTelemetry(EMPTY_DOWNLOAD_TELEMETRY);
+          }
+        }
+        break;
       case 'shareCode':
         if (instance) {
           try {
@@ -3275,6 +3501,7 @@ function App() {
           currentSkinTexture={currentSkinUrl}
           skinCache={skinCache}
           launcherSettings={launcherSettings}
+          isOffline={isOffline}
           onOpenAccountManager={() => setShowAccountManager(true)}
           onShowInfo={(config) => {
             setConfirmModal({
@@ -3612,6 +3839,7 @@ function App() {
         onSwitchAccount={handleSwitchAccount}
         onAddAccount={handleAddAccount}
         onRemoveAccount={handleRemoveAccount}
+        onMigrateOfflineUuid={handleMigrateOfflineUuid}
         skinCache={skinCache}
         skinRefreshKey={skinRefreshKey}
       />
@@ -1,5 +1,6 @@
-import { useState, useCallback, memo } from 'react';
-import { User, X, Trash2 } from 'lucide-react';
+import { useState, useCallback, useEffect, memo } from 'react';
+import { invoke, convertFileSrc } from '@tauri-apps/api/core';
+import { User, X, Trash2, Fingerprint } from 'lucide-react';
 import './AccountManagerModal.css';
 
 const STEVE_HEAD_DATA = 'data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAgAAAAICAIAAABLbSncAAAARklEQVQI12NgoAbghLD+I4kwBqOjo+O/f/8YGBj+MzD8Z2D4z8Dwnwmq7P9/BoYL5y8g0/8hHP7/x0b/Y2D4D5b5/58ZAME2EVcxlvGVAAAAAElFTkSuQmCC';
@@ -37,17 +38,36 @@ function AccountManagerModal({
     onSwitchAccount,
     onAddAccount,
     onRemoveAccount,
+    onMigrateOfflineUuid,
     skinCache = {},
     skinRefreshKey
 }) {
     const [failedImages, setFailedImages] = useState({});
+    const [cachedHeads, setCachedHeads] = useState({});
+
+    useEffect(() => {
+        if (!show) return;
+        let cancelled = false;
+        accounts.filter(a => a.isLoggedIn && a.uuid).forEach(async (account) => {
+            try {
+                const path = await invoke('get_account_head', { uuid: account.uuid });
+                if (!cancelled) {
+                    setCachedHeads(prev => ({ ...prev, [account.uuid]: `${convertFileSrc(path)}?t=${skinRefreshKey}` }));
+                }
+            } catch (error) {
+                console.error('Failed to load account head:', error);
+            }
+        });
+        return () => { cancelled = true; };
+    }, [show, accounts, skinRefreshKey]);
 
     const getSkinUrl = useCallback((uuid, isLoggedIn) => {
         if (!isLoggedIn || !uuid) return STEVE_HEAD_DATA;
         if (failedImages[uuid]) return STEVE_HEAD_DATA;
+        if (cachedHeads[uuid]) return cachedHeads[uuid];
         const cleanUuid = uuid.replace(/-/g, '');
         return `https://minotar.net/helm/${cleanUuid}/64.png?t=${skinRefreshKey}`;
-    }, [failedImages, skinRefreshKey]);
+    }, [failedImages, cachedHeads, skinRefreshKey]);
 
     if (!show) return null;
 
@@ -93,7 +113,7 @@ function AccountManagerModal({
                             </div>
                             <div className="account-card-info">
                                 <span className="account-name">{account.username}</span>
-                                <span className="account-type">{account.isLoggedIn ? 'Microsoft' : 'Offline'}</span>
+                                <span className="account-type">{account.isLoggedIn ? (account.needsRelogin ? 'Microsoft · Login again' : 'Microsoft') : 'Offline'}</span>
                             </div>
                             <button
                                 className="remove-card-btn"
@@ -105,6 +125,18 @@ function AccountManagerModal({
                             >
                                 <Trash2 size={16} />
                             </button>
+                            {!account.isLoggedIn && onMigrateOfflineUuid && (
+                                <button
+                                    className="remove-card-btn offline-uuid-btn"
+                                    onClick={(e) => {
+                                        e.stopPropagation();
+                                        onMigrateOfflineUuid(account.username);
+                                    }}
+                                    title="Use the standard offline UUID, so servers and worlds see the same player every time"
+                                >
+                                    <Fingerprint size={16} />
+                                </button>
+                            )}
                         </div>
                     ))}
 
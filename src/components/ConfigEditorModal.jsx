@@ -0,0 +1,146 @@
+import { useState, useEffect, useMemo } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Save, X, Loader2, FileText } from 'lucide-react';
+import './OptionsEditorModal.css';
+
+// ----------
+// Config Editor Modal
+// Description: Lists the files under an instance's config/ folder and offers a plain text editor for mod configs.
+// ----------
+function ConfigEditorModal({ instanceId, onClose, onShowNotification }) {
+    const [configs, setConfigs] = useState([]);
+    const [loading, setLoading] = useState(true);
+    const [filter, setFilter] = useState('');
+    const [selectedPath, setSelectedPath] = useState(null);
+    const [content, setContent] = useState('');
+    const [loadingFile, setLoadingFile] = useState(false);
+    const [dirty, setDirty] = useState(false);
+    const [saving, setSaving] = useState(false);
+
+    useEffect(() => {
+        invoke('list_instance_configs', { instanceId })
+            .then((list) => setConfigs(list || []))
+            .catch((error) => {
+                console.error('Failed to list configs:', error);
+                if (onShowNotification) onShowNotification('Failed to list config files', 'error');
+            })
+            .finally(() => setLoading(false));
+    }, [instanceId, onShowNotification]);
+
+    const filteredConfigs = useMemo(() => {
+        const query = filter.trim().toLowerCase();
+        if (!query) return configs;
+        return configs.filter((config) => config.rel_path.toLowerCase().includes(query));
+    }, [configs, filter]);
+
+    const handleSelect = async (relPath) => {
+        if (relPath === selectedPath) return;
+        if (dirty && !window.confirm('Discard unsaved changes?')) return;
+        setSelectedPath(relPath);
+        setLoadingFile(true);
+        try {
+            setContent(await invoke('read_instance_config', { instanceId, relPath }));
+            setDirty(false);
+        } catch (error) {
+            setContent('');
+            if (onShowNotification) onShowNotification(String(error), 'error');
+        }
+        setLoadingFile(false);
+    };
+
+    const handleSave = async () => {
+        if (!selectedPath) return;
+        setSaving(true);
+        try {
+            await invoke('write_instance_config', { instanceId, relPath: selectedPath, content });
+            setDirty(false);
+            if (onShowNotification) onShowNotification(`${selectedPath} saved`, 'success');
+        } catch (error) {
+            if (onShowNotification) onShowNotification(`Failed to save: ${error}`, 'error');
+        }
+        setSaving(false);
+    };
+
+    return (
+        <div className="options-modal-overlay" onClick={onClose}>
+            <div className="options-modal categorized" onClick={(e) => e.stopPropagation()}>
+                <div className="options-modal-header">
+                    <div className="header-info">
+                        <h3>Mod Configs</h3>
+                        <span>{selectedPath || 'Pick a file from the config folder'}</span>
+                    </div>
+                    <button className="close-btn" onClick={onClose}>
+                        <X size={20} />
+                    </button>
+                </div>
+
+                <div className="options-modal-main">
+                    <div className="options-sidebar">
+                        <input
+                            type="text"
+                            className="option-text-input"
+                            placeholder="Filter files..."
+                            value={filter}
+                            onChange={(e) => setFilter(e.target.value)}
+                        />
+                        {loading ? (
+                            <div className="loading-state">
+                                <Loader2 className="spin" />
+                            </div>
+                        ) : filteredConfigs.map((config) => (
+                            <button
+                                key={config.rel_path}
+                                className={`sidebar-item ${selectedPath === config.rel_path ? 'active' : ''}`}
+                                onClick={() => handleSelect(config.rel_path)}
+                                title={config.rel_path}
+                            >
+                                <FileText size={16} />
+                                <span>{config.rel_path}</span>
+                            </button>
+                        ))}
+                        {!loading && configs.length === 0 && (
+                            <div className="empty-category">
+                                <p>No config files yet. Launch the game once so mods can create them.</p>
+                            </div>
+                        )}
+                    </div>
+
+                    <div className="options-content">
+                        {loadingFile ? (
+                            <div className="loading-state">
+                                <Loader2 className="spin" />
+                            </div>
+                        ) : selectedPath ? (
+                            <textarea
+                                className="option-text-input"
+                                style={{ width: '100%', height: '100%', fontFamily: 'monospace', resize: 'none' }}
+                                spellCheck={false}
+                                value={content}
+                                onChange={(e) => {
+                                    setContent(e.target.value);
+                                    setDirty(true);
+                                }}
+                            />
+                        ) : (
+                            <div className="empty-category">
+                                <p>Select a config file to edit it.</p>
+                            </div>
+                        )}
+                    </div>
+                </div>
+
+                <div className="options-modal-footer">
+                    <div className="footer-left">
+                        <button className="cancel-btn" onClick={onClose}>Close</button>
+                    </div>
+                    <button className="save-btn" onClick={handleSave} disabled={!selectedPath || !dirty || saving}>
+                        {saving ? <Loader2 className="spin" size={18} /> : <Save size={18} />}
+                        <span>{saving ? 'Saving...' : 'Save File'}</span>
+                    </button>
+                </div>
+            </div>
+        </div>
+    );
+}
+
+export default ConfigEditorModal;
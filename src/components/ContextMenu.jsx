@@ -72,12 +72,18 @@ function ContextMenu({ x, y, instance, isEditing = false, onAction }) {
           <button className="context-menu-item" onClick={() => onAction('clone')}>
             Clone Instance
           </button>
+          <button className="context-menu-item" onClick={() => onAction('cloneSettingsOnly')}>
+            Clone Settings Only
+          </button>
           <button className="context-menu-item" onClick={() => onAction('openFolder')}>
             Open Folder
           </button>
           <button className="context-menu-item" onClick={() => onAction('share')}>
             Share (Export .zip)
           </button>
+          <button className="context-menu-item" onClick={() => onAction('exportCurseForge')}>
+            Export for CurseForge
+          </button>
           <button className="context-menu-item" onClick={() => onAction('shareCode')}>
             Copy Share Code
           </button>
@@ -85,6 +91,17 @@ function ContextMenu({ x, y, instance, isEditing = false, onAction }) {
             Create Desktop Shortcut
           </button>
           <div className="context-menu-divider" />
+          <div className="context-menu-label">Custom Order</div>
+          <button className="context-menu-item" onClick={() => onAction('moveToTop')}>
+            Move to Top
+          </button>
+          <button className="context-menu-item" onClick={() => onAction('moveUp')}>
+            Move Up
+          </button>
+          <button className="context-menu-item" onClick={() => onAction('moveDown')}>
+            Move Down
+          </button>
+          <div className="context-menu-divider" />
           <div className="context-menu-label">Category</div>
           <div className="context-menu-category-value">
             {instance.category?.trim() || 'Uncategorized'}
@@ -97,6 +97,8 @@ function CreateInstance({
   const [selectedModpackVersion, setSelectedModpackVersion] = useState(null);
   const [modpackVersions, setModpackVersions] = useState([]);
   const [modpackVersionsLoading, setModpackVersionsLoading] = useState(false);
+  const [modpackFiles, setModpackFiles] = useState([]);
+  const [selectedModpackFile, setSelectedModpackFile] = useState(null);
   const [modpackTotalSize, setModpackTotalSize] = useState(null);
   const [sizeLoading, setSizeLoading] = useState(false);
   const [selectedModrinthCategories, setSelectedModrinthCategories] = useState([]);
@@ -113,6 +115,7 @@ function CreateInstance({
   // Share code state
   const [shareCode, setShareCode] = useState('');
   const [decodedShareData, setDecodedShareData] = useState(null);
+  const [shareValidation, setShareValidation] = useState(null);
   const [isDecoding, setIsDecoding] = useState(false);
   const [decodingError, setDecodingError] = useState('');
 
@@ -130,6 +133,9 @@ function CreateInstance({
 
   // Version loading state
   const [loadingVersions, setLoadingVersions] = useState(true);
+  const [versionsUpdatedAt, setVersionsUpdatedAt] = useState(null);
+  const [versionDetails, setVersionDetails] = useState(null);
+  const [nameError, setNameError] = useState('');
   const [isEntering, setIsEntering] = useState(true);
 
   // 2. Ref Hooks
@@ -156,7 +162,7 @@ function CreateInstance({
   }, [modpackProvider]);
 
   // 3. useMemo hooks
-  const canNextFromName = useMemo(() => (creationMode === 'import' || creationMode === 'share-code') ? true : name.trim().length > 0, [creationMode, name]);
+  const canNextFromName = useMemo(() => (creationMode === 'import' || creationMode === 'share-code') ? true : (name.trim().length > 0 && !nameError), [creationMode, name, nameError]);
   
   const canNextFromVersion = useMemo(() => creationMode === 'version'
     ? !!selectedVersion
@@ -181,11 +187,12 @@ function CreateInstance({
         : !!importZipPath, [creationMode, name, selectedVersion, modLoader, selectedLoaderVersion, selectedModpack, selectedModpackVersion, decodedShareData, importZipPath]);
 
   // 4. useCallback hooks
-  const loadVersions = useCallback(async () => {
+  const loadVersions = useCallback(async (force = false) => {
     setLoadingVersions(true);
     try {
-      const result = await invoke('get_versions');
+      const result = await invoke('get_versions', { force });
       setVersions(result);
+      setVersionsUpdatedAt(await invoke('get_versions_updated_at'));
 
       // Select latest release by default if none selected
       if (!selectedVersion) {
@@ -351,7 +358,7 @@ function CreateInstance({
     setLoaderLoading(false);
   }, [modLoader, selectedVersion]);
 
-  const calculateTotalSize = useCallback(async (modpack, version) => {
+  const calculateTotalSize = useCallback(async (modpack, version, fileName) => {
     if (!modpack || !version) return;
     setSizeLoading(true);
     try {
@@ -360,7 +367,7 @@ function CreateInstance({
           projectId: modpack.project_id,
           fileId: version.id
         })
-        : await invoke('get_modpack_total_size', { versionId: version.id });
+        : await invoke('get_modpack_total_size', { versionId: version.id, fileName });
       setModpackTotalSize(size);
     } catch (error) {
       console.error('Failed to calculate modpack size:', error);
@@ -447,6 +454,7 @@ function CreateInstance({
 
   const handleDecodeShareCode = useCallback(async (code) => {
     setShareCode(code);
+    setShareValidation(null);
     if (!code.trim()) {
       setDecodedShareData(null);
       setDecodingError('');
@@ -459,6 +467,9 @@ function CreateInstance({
       const result = await invoke('decode_instance_share_code', { code: code.trim() });
       setDecodedShareData(result);
       if (!name) setName(result.name);
+      invoke('validate_share_code', { code: code.trim() })
+        .then(setShareValidation)
+        .catch((error) => console.error('Failed to validate share code:', error));
     } catch (error) {
       console.error('Failed to decode share code:', error);
       setDecodingError('Invalid or corrupted share code.');
@@ -494,6 +505,7 @@ function CreateInstance({
           provider: modpackProvider,
           modpackId: selectedModpack.project_id,
           versionId: selectedModpackVersion.id,
+          fileName: selectedModpackFile,
           modpackName: selectedModpack.title,
           modpackIcon: selectedModpack.icon_url,
           modpackAuthor: selectedModpack.author || null,
@@ -518,7 +530,7 @@ function CreateInstance({
         }, selectedJava);
       }
     }
-  }, [isJavaInstalled, selectedJava, creationMode, name, selectedVersion, modLoader, selectedLoaderVersion, selectedModpack, selectedModpackVersion, importZipPath, decodedShareData, onCreate, modpackProvider]);
+  }, [isJavaInstalled, selectedJava, creationMode, name, selectedVersion, modLoader, selectedLoaderVersion, selectedModpack, selectedModpackVersion, selectedModpackFile, importZipPath, decodedShareData, onCreate, modpackProvider]);
 
   // 5. useEffect hooks
   useEffect(() => {
@@ -567,13 +579,33 @@ function CreateInstance({
     }
   }, [selectedVersion, modLoader, loadLoaderVersions]);
 
+  useEffect(() => {
+    setModpackFiles([]);
+    setSelectedModpackFile(null);
+    // Only worth asking when the version carries more than one file to choose from
+    if (modpackProvider !== 'modrinth' || !selectedModpackVersion || (selectedModpackVersion.files?.length || 0) < 2) {
+      return;
+    }
+    let cancelled = false;
+    invoke('get_modpack_files', { versionId: selectedModpackVersion.id })
+      .then((files) => {
+        if (cancelled) return;
+        setModpackFiles(files);
+        setSelectedModpackFile(files.find((f) => f.recommended)?.filename || null);
+      })
+      .catch((error) => console.error('Failed to load modpack files:', error));
+    return () => {
+      cancelled = true;
+    };
+  }, [modpackProvider, selectedModpackVersion]);
+
   useEffect(() => {
     if (selectedModpackVersion) {
-      calculateTotalSize(selectedModpack, selectedModpackVersion);
+      calculateTotalSize(selectedModpack, selectedModpackVersion, selectedModpackFile);
     } else {
       setModpackTotalSize(null);
     }
-  }, [selectedModpack, selectedModpackVersion, calculateTotalSize]);
+  }, [selectedModpack, selectedModpackVersion, selectedModpackFile, calculateTotalSize]);
 
   useEffect(() => {
     const checkJava = async () => {
@@ -603,6 +635,33 @@ function CreateInstance({
     };
   }, []);
 
+  useEffect(() => {
+    if (creationMode === 'import' || creationMode === 'share-code' || !name.trim()) {
+      setNameError('');
+      return;
+    }
+    let cancelled = false;
+    invoke('validate_instance_name', { name })
+      .then(() => { if (!cancelled) setNameError(''); })
+      .catch(error => { if (!cancelled) setNameError(String(error)); });
+    return () => { cancelled = true; };
+  }, [creationMode, name]);
+
+  useEffect(() => {
+    if (creationMode !== 'version' || !selectedVersion) {
+      setVersionDetails(null);
+      return;
+    }
+    let cancelled = false;
+    invoke('get_version_details', { versionId: selectedVersion })
+      .then(details => { if (!cancelled) setVersionDetails(details); })
+      .catch(error => {
+        console.error('Failed to load version details:', error);
+        if (!cancelled) setVersionDetails(null);
+      });
+    return () => { cancelled = true; };
+  }, [creationMode, selectedVersion]);
+
   useEffect(() => {
     // When selected version changes, update recommended java
     let mcVersion = null;
@@ -616,10 +675,13 @@ function CreateInstance({
       mcVersion = importInfo.version_id;
     }
 
-    if (mcVersion) {
+    // The version metadata knows the real requirement; the version-number guess covers the rest
+    if (creationMode === 'version' && versionDetails?.id === selectedVersion && versionDetails.required_java) {
+      setSelectedJava(versionDetails.required_java);
+    } else if (mcVersion) {
       setSelectedJava(getRecommendedJava(mcVersion));
     }
-  }, [selectedVersion, selectedModpackVersion, decodedShareData, importInfo, creationMode, getRecommendedJava]);
+  }, [selectedVersion, selectedModpackVersion, decodedShareData, importInfo, creationMode, getRecommendedJava, versionDetails]);
 
   const isPage = mode === 'page';
   const instanceHeaderStyleRaw = launcherSettings?.instance_header_style;
@@ -691,6 +753,7 @@ function CreateInstance({
                 disabled={creationMode === 'import' || creationMode === 'share-code'}
                 autoFocus
               />
+              {nameError && <div className="import-name-hint instance-name-error">{nameError}</div>}
             </div>
 
             <div className="form-group">
@@ -769,8 +832,9 @@ function CreateInstance({
             versions={versions}
             selectedVersion={selectedVersion}
             onSelect={setSelectedVersion}
-            onRefresh={loadVersions}
+            onRefresh={() => loadVersions(true)}
             loading={loadingVersions}
+            updatedAt={versionsUpdatedAt}
           />
         )}
 
@@ -874,6 +938,17 @@ function CreateInstance({
                       <span className="stat-label">Shaders</span>
                     </div>
                   </div>
+                  {shareValidation && (shareValidation.unavailable_count + shareValidation.incompatible_count) > 0 && (
+                    <div className="preview-warning">
+                      {shareValidation.unavailable_count + shareValidation.incompatible_count} of {shareValidation.items.length} items aren't available for Minecraft {shareValidation.version}
+                      {shareValidation.loader !== 'vanilla' ? ` (${shareValidation.loader})` : ''}.
+                    </div>
+                  )}
+                  {shareValidation && shareValidation.unchecked_count > 0 && (
+                    <div className="preview-warning">
+                      {shareValidation.unchecked_count} CurseForge {shareValidation.unchecked_count === 1 ? 'item' : 'items'} couldn't be checked and will be tried during install.
+                    </div>
+                  )}
                 </div>
               )}
             </div>
@@ -1015,6 +1090,11 @@ function CreateInstance({
                   name: 'Fabric',
                   icon: <img src="https://flintmc.net/brand/modification/81bae1feee32c1c794d63719ef123d0b.png" alt="Fabric" className="loader-logo-img fabric" />
                 },
+                {
+                  id: 'quilt',
+                  name: 'Quilt',
+                  icon: <img src="https://quiltmc.org/favicon.png" alt="Quilt" className="loader-logo-img quilt" />
+                },
                 {
                   id: 'forge',
                   name: 'Forge',
@@ -1135,6 +1215,22 @@ function CreateInstance({
                 )}
               </div>
             </div>
+
+            {modpackFiles.length > 1 && (
+              <div className="modpack-version-section form-group">
+                <label className="section-label">Pack File</label>
+                <select
+                  value={selectedModpackFile || ''}
+                  onChange={(e) => setSelectedModpackFile(e.target.value)}
+                >
+                  {modpackFiles.map((f) => (
+                    <option key={f.filename} value={f.filename}>
+                      {f.filename} ({(f.size / 1024).toFixed(0)} KB){f.recommended ? ' - recommended' : ''}
+                    </option>
+                  ))}
+                </select>
+              </div>
+            )}
           </div>
         )}
 
@@ -1156,6 +1252,9 @@ function CreateInstance({
                     creationMode === 'import' ? importInfo?.version_id : ''
                   )}</strong>.
                 </p>
+                {creationMode === 'version' && versionDetails?.id === selectedVersion && versionDetails.java_warning && (
+                  <p className="java-version-warning">{versionDetails.java_warning}</p>
+                )}
               </div>
             </div>
 
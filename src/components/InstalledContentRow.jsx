@@ -1,4 +1,5 @@
-import { Check, Info, RefreshCcw, Trash2 } from 'lucide-react';
+import { convertFileSrc } from '@tauri-apps/api/core';
+import { Check, Info, Pin, PinOff, RefreshCcw, Trash2 } from 'lucide-react';
 
 function getProviderClass(provider) {
   const normalized = String(provider || '').toLowerCase();
@@ -20,6 +21,7 @@ function InstalledContentRow({
   onToggleSelect,
   onInfoAction,
   onToggleEnabled,
+  onTogglePinned,
   onDelete,
   infoTitle = 'Open project info',
   deleteTitle = 'Delete item',
@@ -33,6 +35,8 @@ function InstalledContentRow({
   const providerClass = getProviderClass(displayPlatform);
   const canOpenInfo = typeof onInfoAction === 'function';
   const isEnabled = item.enabled ?? true;
+  // Packs without provider metadata fall back to the pack.png extracted by the backend
+  const iconSrc = item.icon_url || (item.icon_path ? convertFileSrc(item.icon_path) : null);
 
   return (
     <div
@@ -63,8 +67,8 @@ function InstalledContentRow({
           </div>
         </div>
 
-        {item.icon_url ? (
-          <img src={item.icon_url} alt="" className="mod-icon-small" referrerPolicy="no-referrer" />
+        {iconSrc ? (
+          <img src={iconSrc} alt="" className="mod-icon-small" referrerPolicy="no-referrer" />
         ) : (
           <div className="mod-icon-placeholder">📦</div>
         )}
@@ -81,7 +85,7 @@ function InstalledContentRow({
           }}
         >
           <div className="item-grid-primary">
-            <h4>{displayName}</h4>
+            <h4 title={item.description || undefined}>{displayName}</h4>
             <span className="item-grid-author">
               <span className="item-grid-author-prefix">by </span>
               <span className="item-grid-author-name">{item.author || authorFallback}</span>
@@ -96,6 +100,17 @@ function InstalledContentRow({
               {showUpdateBadge && (
                 <span className="update-available-tag pulse">Update Available</span>
               )}
+              {item.pinned && (
+                <span className="pinned-tag" title="Skipped by update checks">Pinned</span>
+              )}
+              {item.loader_mismatch && (
+                <span
+                  className="loader-mismatch-tag"
+                  title={`Made for ${(item.loaders || []).join(', ')}; it will not load with this instance's loader`}
+                >
+                  Wrong Loader
+                </span>
+              )}
             </div>
             <span className={`item-grid-platform ${providerClass}`.trim()}>{displayPlatform}</span>
           </div>
@@ -113,6 +128,19 @@ function InstalledContentRow({
           }}
           title={disableMutatingActions ? mutatingActionsDisabledTitle : (isEnabled ? 'Disable item' : 'Enable item')}
         />
+        {typeof onTogglePinned === 'function' && (
+          <button
+            className="update-btn-simple"
+            onClick={(event) => {
+              event.stopPropagation();
+              onTogglePinned(item);
+            }}
+            title={item.pinned ? 'Unpin (allow updates)' : 'Pin to this version'}
+            disabled={isUpdating}
+          >
+            {item.pinned ? <PinOff size={14} /> : <Pin size={14} />}
+          </button>
+        )}
         <button
           className="update-btn-simple"
           onClick={(event) => {
@@ -386,7 +386,14 @@ function InstanceConsole({ instance, onInstanceUpdated, onShowNotification, clea
     const requestId = ++latestLoadRequestIdRef.current;
     if (showLoading) setLoading(true);
     try {
-      const logContent = await invoke('get_instance_log', { instanceId: instance.id });
+      let logContent = await invoke('get_instance_log', { instanceId: instance.id });
+      if (typeof logContent !== 'string' || logContent.trim().length === 0) {
+        // latest.log may not exist yet, or the game died before writing it; show the captured process output instead
+        const liveOutput = await invoke('get_instance_live_output', { instanceId: instance.id }).catch(() => []);
+        if (liveOutput.length > 0) {
+          logContent = liveOutput.map((entry) => entry.line).join('\n');
+        }
+      }
 
       if (requestId !== latestLoadRequestIdRef.current || isClearingRef.current) {
         if (showLoading) setLoading(false);
@@ -132,6 +132,16 @@ function InstanceEditor({
     };
   }, [instanceId]);
 
+  useEffect(() => {
+    invoke('start_watching_instance', { instanceId }).catch((error) => {
+      console.error('Failed to watch instance folders:', error);
+    });
+
+    return () => {
+      invoke('stop_watching_instance', { instanceId }).catch(() => {});
+    };
+  }, [instanceId]);
+
   useEffect(() => {
     const unlisten = listen('refresh-instances', () => {
       loadInstance();
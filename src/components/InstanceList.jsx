@@ -20,7 +20,7 @@ const OPEN_CATEGORY_MANAGER_EVENT = 'open-instance-category-manager';
 const INSTANCE_HEADER_DOCK_OPEN_KEY = 'instance_header_center_dock_open';
 const INSTANCE_HEADER_STYLE_CACHE_KEY = 'instance_header_style_cache';
 const CENTER_DOCK_ANIMATION_MS = 380;
-const SORT_OPTIONS = new Set(['name', 'age', 'playtime']);
+const SORT_OPTIONS = new Set(['name', 'age', 'playtime', 'custom']);
 const toCategoryKey = (value) => (typeof value === 'string' ? value.trim().toLowerCase() : '');
 
 function SkinHead2D({ src, size = 28 }) {
@@ -438,6 +438,8 @@ function InstanceList({
         }
         case 'playtime':
           return (b.playtime_seconds || 0) - (a.playtime_seconds || 0);
+        // get_instances already returns the saved manual order
+        case 'custom':
         default:
           return 0;
       }
@@ -1769,6 +1771,15 @@ function InstanceList({
         >
           <Clock size={16} />
         </button>
+        <button
+          type="button"
+          className={`instance-controls-filter-btn ${sortBy === 'custom' ? 'active' : ''}`}
+          onClick={() => handleSortChange({ target: { value: 'custom' } })}
+          title="Custom Order"
+          aria-label="Custom Order"
+        >
+          <GripVertical size={16} />
+        </button>
         <div className="p-dropdown instance-category-filter-dropdown" ref={categoryFilterRef}>
           <button
             type="button"
@@ -1912,6 +1923,7 @@ function InstanceList({
                         {sortBy === 'name' && 'Name'}
                         {sortBy === 'age' && 'Creation Date'}
                         {sortBy === 'playtime' && 'Playtime'}
+                        {sortBy === 'custom' && 'Custom'}
                       </span>
                       <ChevronDown size={14} className={`trigger-icon ${isSortOpen ? 'flip' : ''}`} />
                     </button>
@@ -1948,6 +1960,16 @@ function InstanceList({
                           <span className="item-label">Playtime</span>
                           {sortBy === 'playtime' && <Check size={14} className="selected-icon" />}
                         </div>
+                        <div
+                          className={`p-dropdown-item ${sortBy === 'custom' ? 'selected' : ''}`}
+                          onClick={() => {
+                            handleSortChange({ target: { value: 'custom' } });
+                            setIsSortOpen(false);
+                          }}
+                        >
+                          <span className="item-label">Custom</span>
+                          {sortBy === 'custom' && <Check size={14} className="selected-icon" />}
+                        </div>
                       </div>
                     )}
                   </div>
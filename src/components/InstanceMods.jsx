@@ -1,6 +1,7 @@
 import { useState, useEffect, useRef, useCallback, useMemo, memo } from 'react';
 import { createPortal } from 'react-dom';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { Trash2, RefreshCcw, Plus, Upload, Copy, Code, Loader2, ChevronDown, Check, ListFilterPlus, Play, Square, X, TriangleAlert, ShieldCheck, Wand2 } from 'lucide-react';
 import { open } from '@tauri-apps/plugin-dialog';
 import ConfirmModal from './ConfirmModal';
@@ -157,6 +158,9 @@ function InstanceMods({
   const [versionModal, setVersionModal] = useState({ show: false, project: null, updateMod: null });
   const [showAddModal, setShowAddModal] = useState(false);
   const [shareCodeInput, setShareCodeInput] = useState('');
+  const [modUrlInput, setModUrlInput] = useState('');
+  const [modUrlName, setModUrlName] = useState('');
+  const [installingFromUrl, setInstallingFromUrl] = useState(false);
   const [applyingCode, setApplyingCode] = useState(false);
   const [applyProgress, setApplyProgress] = useState(0);
   const [applyStatus, setApplyStatus] = useState('');
@@ -234,6 +238,18 @@ function InstanceMods({
     setLoading(false);
   }, [instance.id]);
 
+  useEffect(() => {
+    const unlisten = listen('mods-changed', (event) => {
+      if (event.payload?.instance_id === instance.id && event.payload.folders?.includes('mods')) {
+        loadInstalledMods();
+      }
+    });
+
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [instance.id, loadInstalledMods]);
+
   const requestSourceChoice = useCallback((bothCount, scopeLabel = 'selected files') => {
     return new Promise((resolve) => {
       sourceChoiceResolverRef.current = resolve;
@@ -261,6 +277,16 @@ function InstanceMods({
     if (isResolvingManualMods) return;
     setIsResolvingManualMods(true);
     try {
+      // Exact hash matches first; only what's left goes through the name search
+      let identified = 0;
+      if (!filenames) {
+        const hashResult = await invoke('identify_mods_by_hash', { instanceId: instance.id });
+        identified = hashResult.updated;
+        if (identified > 0) {
+          await loadInstalledMods();
+        }
+      }
+
       const previewResult = await invoke('resolve_manual_modrinth_metadata', {
         instanceId: instance.id,
         fileType: 'mod',
@@ -269,7 +295,11 @@ function InstanceMods({
       });
 
       if (previewResult.scanned === 0) {
-        onShowNotification?.('No manual mod files available to check.', 'info');
+        if (identified > 0) {
+          onShowNotification?.(`Identified ${identified} mod file${identified === 1 ? '' : 's'} by hash.`, 'success');
+        } else {
+          onShowNotification?.('No manual mod files available to check.', 'info');
+        }
         return;
       }
 
@@ -777,6 +807,27 @@ function InstanceMods({
     }
   }, [isModMutationLocked, notifyModMutationLocked, instance.id, loadInstalledMods]);
 
+  const handleTogglePinned = useCallback(async (mod) => {
+    try {
+      await invoke('set_mod_pinned', {
+        instanceId: instance.id,
+        filename: mod.filename,
+        pinned: !mod.pinned
+      });
+      if (!mod.pinned && mod.project_id) {
+        setUpdatesFound((prev) => {
+          const next = { ...prev };
+          delete next[mod.project_id];
+          return next;
+        });
+      }
+      await loadInstalledMods();
+    } catch (error) {
+      console.error('Failed to change mod pin:', error);
+      onShowNotification?.(`Failed to pin mod: ${error}`, 'error');
+    }
+  }, [instance.id, loadInstalledMods, onShowNotification]);
+
   const scanConflicts = useCallback(async ({ silent = false } = {}) => {
     setScanningConflicts(true);
     try {
@@ -973,6 +1024,29 @@ function InstanceMods({
     }
   }, [instance.id, loadInstalledMods, onShowNotification]);
 
+  const handleInstallFromUrl = useCallback(async () => {
+    const url = modUrlInput.trim();
+    if (!url || installingFromUrl) return;
+    setInstallingFromUrl(true);
+    try {
+      const filename = await invoke('install_mod_from_url', {
+        instanceId: instance.id,
+        url,
+        name: modUrlName.trim() || null
+      });
+      setModUrlInput('');
+      setModUrlName('');
+      setShowAddModal(false);
+      await loadInstalledMods();
+      onShowNotification?.(`Installed ${filename}`, 'success');
+    } catch (error) {
+      console.error('Failed to install mod from URL:', error);
+      onShowNotification?.(`Failed to install mod: ${error}`, 'error');
+    } finally {
+      setInstallingFromUrl(false);
+    }
+  }, [instance.id, installingFromUrl, loadInstalledMods, modUrlInput, modUrlName, onShowNotification]);
+
   const handleCheckUpdate = useCallback((mod) => {
     if (!mod.project_id) return;
     setVersionModal({
@@ -1023,7 +1097,7 @@ function InstanceMods({
   }, [installedMods, instance.id, onShowNotification]);
 
   const handleUpdateAll = useCallback(async () => {
-    const modsToUpdate = installedMods.filter(m => updatesFound[m.project_id]);
+    const modsToUpdate = installedMods.filter(m => !m.pinned && updatesFound[m.project_id]);
     if (modsToUpdate.length === 0) return;
 
     onShowConfirm({
@@ -1441,15 +1515,20 @@ function InstanceMods({
 
     setLoading(true);
     try {
-      for (const filename of selectedMods) {
+      const filenames = selectedMods.filter((filename) => {
         const mod = installedMods.find(m => m.filename === filename);
-        if (mod && mod.enabled !== enable) {
-          await invoke('toggle_instance_mod', { instanceId: instance.id, filename });
-        }
-      }
+        return mod && mod.enabled !== enable;
+      });
+      // The backend applies the batch all-or-nothing and reports per-file results
+      const results = await invoke('toggle_mods', { instanceId: instance.id, filenames, enabled: enable });
+      const failed = results.filter(r => !r.success);
       setSelectedMods([]);
       await loadInstalledMods();
-      onShowNotification(`Successfully ${enable ? 'enabled' : 'disabled'} ${selectedMods.length} mods.`, 'success');
+      if (failed.length > 0) {
+        onShowNotification(`Failed to ${enable ? 'enable' : 'disable'} mods: ${failed[0].requested} (${failed[0].error})`, 'error');
+      } else {
+        onShowNotification(`Successfully ${enable ? 'enabled' : 'disabled'} ${selectedMods.length} mods.`, 'success');
+      }
     } catch (error) {
       console.error('Failed to toggle mods:', error);
       onShowNotification('Failed to toggle some mods.', 'error');
@@ -1676,6 +1755,37 @@ function InstanceMods({
                     Adding mods from code will automatically download them from Modrinth or CurseForge.
                   </p>
                 </div>
+
+                <div className="code-input-container">
+                  <label style={{ fontSize: '12px', color: 'var(--text-muted)', fontWeight: 600, textTransform: 'uppercase' }}>Download From URL</label>
+                  <div className="code-input-wrapper">
+                    <input
+                      type="text"
+                      className="code-input"
+                      placeholder="https://github.com/.../releases/download/.../mod.jar"
+                      value={modUrlInput}
+                      onChange={(event) => setModUrlInput(event.target.value)}
+                      disabled={installingFromUrl}
+                    />
+                  </div>
+                  <div className="code-input-wrapper">
+                    <input
+                      type="text"
+                      className="code-input"
+                      placeholder="Mod name (optional)"
+                      value={modUrlName}
+                      onChange={(event) => setModUrlName(event.target.value)}
+                      disabled={installingFromUrl}
+                    />
+                    <button
+                      className="apply-btn"
+                      onClick={handleInstallFromUrl}
+                      disabled={installingFromUrl || !modUrlInput.trim()}
+                    >
+                      {installingFromUrl ? '...' : 'Install'}
+                    </button>
+                  </div>
+                </div>
               </>
             )}
           </div>
@@ -1880,6 +1990,7 @@ function InstanceMods({
                           onToggleSelect={handleToggleSelect}
                           onInfoAction={() => handleCheckUpdate(mod)}
                           onToggleEnabled={() => handleToggle(mod)}
+                          onTogglePinned={handleTogglePinned}
                           onDelete={() => handleDelete(mod)}
                           infoTitle="Open project info"
                           deleteTitle="Delete mod"
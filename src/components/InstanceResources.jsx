@@ -1,6 +1,7 @@
 import { useState, useEffect, useRef, useCallback, useMemo, memo } from 'react';
 import { createPortal } from 'react-dom';
 import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { Trash2, RefreshCcw, Plus, Upload, Loader2, ChevronDown, Check, ListFilterPlus, Settings2, X, Wand2, Copy, Code } from 'lucide-react';
 import { open } from '@tauri-apps/plugin-dialog';
 import ConfirmModal from './ConfirmModal';
@@ -296,6 +297,19 @@ function InstanceResources({
     loadResources();
   }, [loadResources]);
 
+  useEffect(() => {
+    const unlisten = listen('mods-changed', (event) => {
+      const folders = event.payload?.folders || [];
+      if (event.payload?.instance_id === instance.id && (folders.includes('resourcepacks') || folders.includes('shaderpacks'))) {
+        loadResources();
+      }
+    });
+
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, [instance.id, loadResources]);
+
   useEffect(() => {
     const loadCurseForgeKeyStatus = async () => {
       try {
@@ -148,6 +148,44 @@ function drawEditorOperation(ctx, operation) {
   );
 }
 
+// Prefer the capture time from the game's filename over the file's modified date
+function getCaptureTime(screenshot) {
+  if (screenshot?.captured_at) return screenshot.captured_at * 1000;
+  return screenshot?.date ? new Date(screenshot.date).getTime() : 0;
+}
+
+const ScreenshotThumbnail = memo(function ScreenshotThumbnail({ instanceId, screenshot, className, onClick }) {
+  const [thumbnailPath, setThumbnailPath] = useState(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    setThumbnailPath(null);
+    invoke('get_screenshot_thumbnail', { instanceId, filename: screenshot.filename })
+      .then((path) => {
+        if (!cancelled) setThumbnailPath(path);
+      })
+      .catch(() => {
+        if (!cancelled) setThumbnailPath(screenshot.path);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [instanceId, screenshot.filename, screenshot.path, screenshot.date]);
+
+  if (!thumbnailPath) {
+    return <div className={className} onClick={onClick} />;
+  }
+  return (
+    <img
+      src={convertFileSrc(thumbnailPath)}
+      alt={screenshot.filename}
+      className={className}
+      onClick={onClick}
+      loading="lazy"
+    />
+  );
+});
+
 function InstanceScreenshots({ instance, onShowNotification, isScrolled }) {
   const [screenshots, setScreenshots] = useState([]);
   const [loading, setLoading] = useState(true);
@@ -197,9 +235,7 @@ function InstanceScreenshots({ instance, onShowNotification, isScrolled }) {
 
   const sortedScreenshots = useMemo(() => {
     return [...screenshots].sort((left, right) => {
-      const leftTime = left?.date ? new Date(left.date).getTime() : 0;
-      const rightTime = right?.date ? new Date(right.date).getTime() : 0;
-      return rightTime - leftTime;
+      return getCaptureTime(right) - getCaptureTime(left);
     });
   }, [screenshots]);
 
@@ -615,9 +651,10 @@ function InstanceScreenshots({ instance, onShowNotification, isScrolled }) {
     }
   };
 
-  const formatDate = (dateStr) => {
-    if (!dateStr) return 'Unknown';
-    return new Date(dateStr).toLocaleDateString(undefined, {
+  const formatDate = (screenshot) => {
+    const time = getCaptureTime(screenshot);
+    if (!time) return 'Unknown';
+    return new Date(time).toLocaleDateString(undefined, {
       year: 'numeric',
       month: 'short',
       day: 'numeric',
@@ -1137,7 +1174,7 @@ function InstanceScreenshots({ instance, onShowNotification, isScrolled }) {
             <div className="screenshot-editor-stage">
               <div className="screenshot-editor-meta">
                 <span className="screenshot-filename" title={editorScreenshot.filename}>{editorScreenshot.filename}</span>
-                <span className="screenshot-date">{formatDate(editorScreenshot.date)}</span>
+                <span className="screenshot-date">{formatDate(editorScreenshot)}</span>
               </div>
               {editorLoading && <div className="screenshot-editor-loading">Loading image...</div>}
               <canvas
@@ -1164,11 +1201,11 @@ function InstanceScreenshots({ instance, onShowNotification, isScrolled }) {
                 className="screenshot-timeline-thumb-btn"
                 onClick={() => setSelectedImage(ss)}
               >
-                <img src={convertFileSrc(ss.path)} alt={ss.filename} className="screenshot-timeline-thumb" />
+                <ScreenshotThumbnail instanceId={instance.id} screenshot={ss} className="screenshot-timeline-thumb" />
               </button>
               <div className="screenshot-timeline-meta">
                 <span className="screenshot-filename" title={ss.filename}>{ss.filename}</span>
-                <span className="screenshot-date">{formatDate(ss.date)}</span>
+                <span className="screenshot-date">{formatDate(ss)}</span>
               </div>
               <div className="screenshot-timeline-actions">
                 <button className="open-btn" onClick={() => setSelectedImage(ss)} title="Open preview">Open</button>
@@ -1194,9 +1231,9 @@ function InstanceScreenshots({ instance, onShowNotification, isScrolled }) {
               onContextMenu={(e) => handleScreenshotContextMenu(e, ss)}
             >
               <div className="screenshot-image-wrapper">
-                <img
-                  src={convertFileSrc(ss.path)}
-                  alt={ss.filename}
+                <ScreenshotThumbnail
+                  instanceId={instance.id}
+                  screenshot={ss}
                   className="screenshot-image"
                   onClick={() => setSelectedImage(ss)}
                 />
@@ -1223,7 +1260,7 @@ function InstanceScreenshots({ instance, onShowNotification, isScrolled }) {
               <div className="screenshot-info">
                 <div className="screenshot-meta">
                   <span className="screenshot-filename">{ss.filename}</span>
-                  <span className="screenshot-date">{formatDate(ss.date)}</span>
+                  <span className="screenshot-date">{formatDate(ss)}</span>
                 </div>
                 <div className="screenshot-card-actions">
                   <button className="edit-btn" onClick={() => handleEditScreenshot(ss)}>
@@ -1261,7 +1298,7 @@ function InstanceScreenshots({ instance, onShowNotification, isScrolled }) {
         >
           <div className="modal-screenshot-info" onClick={e => e.stopPropagation()}>
             <h3>{selectedImage.filename}</h3>
-            <span>{formatDate(selectedImage.date)}</span>
+            <span>{formatDate(selectedImage)}</span>
           </div>
           <img
             src={convertFileSrc(selectedImage.path)}
@@ -1,16 +1,22 @@
 import { useState, useEffect, useCallback, useMemo, useRef } from 'react';
 import { invoke, convertFileSrc } from '@tauri-apps/api/core';
-import { open } from '@tauri-apps/plugin-dialog';
+import { open, save } from '@tauri-apps/plugin-dialog';
 import { join } from '@tauri-apps/api/path';
 import { Box, ChevronDown, ChevronUp, Cpu, Save, Trash2, Check, Minus, Plus, User, X, Server, ServerOff } from 'lucide-react';
 import VersionSelector from './VersionSelector';
 import IconPicker from './IconPicker';
 import OptionsEditorModal from './OptionsEditorModal';
+import ConfigEditorModal from './ConfigEditorModal';
 import SubTabs from './SubTabs';
 
 const STEVE_HEAD_DATA = 'data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAgAAAAICAIAAABLbSncAAAARklEQVQI12NgoAbghLD+I4kwBqOjo+O/f/8YGBj+MzD8Z2D4z8Dwnwmq7P9/BoYL5y8g0/8hHP7/x0b/Y2D4D5b5/58ZAME2EVcxlvGVAAAAAElFTkSuQmCC';
 const ACCOUNT_AVATAR_SIZE = 34;
 const PREFERRED_SERVER_STORAGE_KEY = 'instance-preferred-server-map';
+const RESOLUTION_PRESETS = [
+  { id: '720p', label: '720p (1280x720)', width: 1280, height: 720 },
+  { id: '1080p', label: '1080p (1920x1080)', width: 1920, height: 1080 },
+  { id: '1440p', label: '1440p (2560x1440)', width: 2560, height: 1440 },
+];
 
 function SkinHead2D({ src, size = 28 }) {
   return (
@@ -88,6 +94,19 @@ function InstanceSettings({
   const [javaDownloadError, setJavaDownloadError] = useState('');
   const [memory, setMemory] = useState(instance.memory_max || 4096);
   const [jvmArgs, setJvmArgs] = useState(instance.jvm_args || '');
+  const [wrapperCommand, setWrapperCommand] = useState(instance.wrapper_command || '');
+  const [resolutionWidth, setResolutionWidth] = useState(instance.resolution_width ? String(instance.resolution_width) : '');
+  const [resolutionHeight, setResolutionHeight] = useState(instance.resolution_height ? String(instance.resolution_height) : '');
+  const [demoMode, setDemoMode] = useState(Boolean(instance.demo_mode));
+  const [separateLoaderMods, setSeparateLoaderMods] = useState(Boolean(instance.separate_loader_mods));
+  const [monitors, setMonitors] = useState([]);
+  const [systemMemory, setSystemMemory] = useState(null);
+  const [excludedLibraries, setExcludedLibraries] = useState(instance.excluded_libraries || []);
+  const [newExcludedLibrary, setNewExcludedLibrary] = useState('');
+  const [relocatingData, setRelocatingData] = useState(false);
+  const [copyingLaunchCommand, setCopyingLaunchCommand] = useState(false);
+  const [repairing, setRepairing] = useState(false);
+  const [exportingBundle, setExportingBundle] = useState(false);
   const [preferredAccount, setPreferredAccount] = useState(instance.preferred_account || '');
   const [preferredServer, setPreferredServer] = useState('');
   const [initialPreferredServer, setInitialPreferredServer] = useState('');
@@ -100,6 +119,7 @@ function InstanceSettings({
   const [loadingServers, setLoadingServers] = useState(false);
   const [failedImages, setFailedImages] = useState({});
   const [versions, setVersions] = useState([]);
+  const [versionsUpdatedAt, setVersionsUpdatedAt] = useState(null);
   const [loaderVersions, setLoaderVersions] = useState([]);
   const [loadingLoaders, setLoadingLoaders] = useState(false);
   const [saving, setSaving] = useState(false);
@@ -110,16 +130,22 @@ function InstanceSettings({
   const [showLoaderSelector, setShowLoaderSelector] = useState(false);
   const [showIconPicker, setShowIconPicker] = useState(false);
   const [showOptionsEditor, setShowOptionsEditor] = useState(false);
+  const [showConfigEditor, setShowConfigEditor] = useState(false);
+  const [otherInstances, setOtherInstances] = useState([]);
+  const [optionsSourceId, setOptionsSourceId] = useState('');
+  const [optionsCopyMode, setOptionsCopyMode] = useState('all');
+  const [copyingOptions, setCopyingOptions] = useState(false);
   const [installingLoader, setInstallingLoader] = useState(false);
   const [activeSettingsSubTab, setActiveSettingsSubTab] = useState('general');
   const [settingsSubTabsScrolled, setSettingsSubTabsScrolled] = useState(false);
   const isManagedModpackInstance = Boolean(instance?.modpack_provider || instance?.modpack_project_id);
   const effectiveLaunchUpdateCheck = !isManagedModpackInstance && checkModUpdatesOnLaunch;
 
-  const loadVersions = useCallback(async () => {
+  const loadVersions = useCallback(async (force = false) => {
     try {
-      const vers = await invoke('get_versions');
+      const vers = await invoke('get_versions', { force });
       setVersions(vers);
+      setVersionsUpdatedAt(await invoke('get_versions_updated_at'));
     } catch (error) {
       console.error('Failed to load versions:', error);
     }
@@ -129,6 +155,29 @@ function InstanceSettings({
     loadVersions();
   }, [loadVersions]);
 
+  useEffect(() => {
+    invoke('get_instances')
+      .then((list) => setOtherInstances((list || []).filter((item) => item.id !== instance.id)))
+      .catch((error) => console.error('Failed to load instances for options copy:', error));
+  }, [instance.id]);
+
+  const handleCopyOptions = async () => {
+    if (!optionsSourceId) return;
+    setCopyingOptions(true);
+    try {
+      const copied = await invoke('copy_options_from', {
+        sourceInstanceId: optionsSourceId,
+        targetInstanceId: instance.id,
+        selective: optionsCopyMode === 'keys'
+      });
+      onShowNotification?.(`Copied ${copied.join(', ')}`, 'success');
+    } catch (error) {
+      onShowNotification?.(`Failed to copy options: ${error}`, 'error');
+    } finally {
+      setCopyingOptions(false);
+    }
+  };
+
   const loadAccounts = useCallback(async () => {
     try {
       const data = await invoke('get_saved_accounts');
@@ -216,6 +265,59 @@ function InstanceSettings({
     setCheckModUpdatesOnLaunch(instance.check_mod_updates_on_launch !== false);
   }, [instance.id, instance.check_mod_updates_on_launch]);
 
+  useEffect(() => {
+    invoke('get_monitor_resolutions')
+      .then(setMonitors)
+      .catch((error) => console.error('Failed to read monitor resolutions:', error));
+    invoke('get_system_memory')
+      .then(setSystemMemory)
+      .catch((error) => console.error('Failed to read system memory:', error));
+  }, []);
+
+  useEffect(() => {
+    setExcludedLibraries(instance.excluded_libraries || []);
+  }, [instance.excluded_libraries]);
+
+  const handleSetExcludedLibrary = async (identity, excluded) => {
+    try {
+      const updated = await invoke('set_excluded_library', { instanceId: instance.id, identity, excluded });
+      setExcludedLibraries(updated.excluded_libraries || []);
+      if (excluded) setNewExcludedLibrary('');
+    } catch (error) {
+      onShowNotification?.(`Failed to update excluded libraries: ${error}`, 'error');
+    }
+  };
+
+  const handleRelocateData = async (moveBack) => {
+    let newPath = null;
+    if (!moveBack) {
+      newPath = await open({ directory: true, title: 'Choose where to move this instance\'s game data' });
+      if (!newPath) return;
+    }
+    setRelocatingData(true);
+    try {
+      await invoke('relocate_instance_data', { instanceId: instance.id, newPath });
+      onShowNotification?.(moveBack ? 'Game data moved back' : 'Game data moved', 'success');
+    } catch (error) {
+      onShowNotification?.(`Failed to move game data: ${error}`, 'error');
+    }
+    setRelocatingData(false);
+  };
+
+  const memoryWarning = useMemo(() => {
+    if (!systemMemory?.total_mb) return '';
+    if (memory >= systemMemory.total_mb) {
+      return `More than the ${systemMemory.total_mb} MB installed. The game may fail to start.`;
+    }
+    if (memory > systemMemory.total_mb * 0.75) {
+      return `Leaves little of the ${systemMemory.total_mb} MB installed for your system. Expect slowdowns.`;
+    }
+    if (memory > systemMemory.available_mb) {
+      return `More than the ${systemMemory.available_mb} MB currently free.`;
+    }
+    return '';
+  }, [memory, systemMemory]);
+
   useEffect(() => {
     setActiveSettingsSubTab('general');
     setSettingsSubTabsScrolled(false);
@@ -275,11 +377,16 @@ function InstanceSettings({
       javaPath !== (instance.java_path || '') ||
       memory !== (instance.memory_max || 4096) ||
       jvmArgs !== (instance.jvm_args || '') ||
+      wrapperCommand !== (instance.wrapper_command || '') ||
+      resolutionWidth !== (instance.resolution_width ? String(instance.resolution_width) : '') ||
+      resolutionHeight !== (instance.resolution_height ? String(instance.resolution_height) : '') ||
+      demoMode !== Boolean(instance.demo_mode) ||
+      separateLoaderMods !== Boolean(instance.separate_loader_mods) ||
       preferredAccount !== (instance.preferred_account || '') ||
       preferredServer !== initialPreferredServer ||
       checkModUpdatesOnLaunch !== (instance.check_mod_updates_on_launch !== false);
     setHasChanges(changed);
-  }, [name, versionId, category, modLoader, modLoaderVersion, javaPath, memory, jvmArgs, preferredAccount, preferredServer, initialPreferredServer, checkModUpdatesOnLaunch, instance]);
+  }, [name, versionId, category, modLoader, modLoaderVersion, javaPath, memory, jvmArgs, wrapperCommand, resolutionWidth, resolutionHeight, demoMode, separateLoaderMods, preferredAccount, preferredServer, initialPreferredServer, checkModUpdatesOnLaunch, instance]);
 
   useEffect(() => {
     checkChanges();
@@ -308,19 +415,65 @@ function InstanceSettings({
     };
   }, [instance.id, instance.logo_filename]);
 
+  const primaryMonitor = monitors[0] || null;
+  const fitsAnyMonitor = (width, height) => monitors.length === 0
+    || monitors.some((monitor) => width <= monitor.width && height <= monitor.height);
+
+  const getResolutionPreset = () => {
+    if (!resolutionWidth && !resolutionHeight) return 'default';
+    const width = Number.parseInt(resolutionWidth, 10);
+    const height = Number.parseInt(resolutionHeight, 10);
+    if (primaryMonitor && width === primaryMonitor.width && height === primaryMonitor.height) return 'native';
+    return RESOLUTION_PRESETS.find((preset) => preset.width === width && preset.height === height)?.id || 'custom';
+  };
+
+  const applyResolutionPreset = (presetId) => {
+    if (presetId === 'default') {
+      setResolutionWidth('');
+      setResolutionHeight('');
+      return;
+    }
+    const preset = presetId === 'native' ? primaryMonitor : RESOLUTION_PRESETS.find((entry) => entry.id === presetId);
+    if (preset) {
+      setResolutionWidth(String(preset.width));
+      setResolutionHeight(String(preset.height));
+    }
+  };
+
   const handleSave = useCallback(async () => {
     setSaving(true);
     try {
+      // A new game version needs the game and loader reinstalled before the instance is saved.
+      // The backend then owns version_id and mod_loader_version, so they are not sent again.
+      let baseInstance = instance;
+      let versionFields = { version_id: versionId, mod_loader_version: modLoaderVersion || null };
+      if (versionId !== instance.version_id && modLoader === (instance.mod_loader || 'Vanilla')) {
+        const change = await invoke('change_instance_version', {
+          instanceId: instance.id,
+          newVersionId: versionId,
+          newLoaderVersion: modLoaderVersion && modLoaderVersion !== instance.mod_loader_version ? modLoaderVersion : null,
+        });
+        if (change.incompatible_mods.length > 0) {
+          const names = change.incompatible_mods.map(m => m.name || m.filename).join(', ');
+          onShowNotification?.(`No Minecraft ${versionId} release yet for: ${names}`, 'warning');
+        }
+        baseInstance = change.instance;
+        versionFields = {};
+      }
       const updatedInstance = {
-        ...instance,
+        ...baseInstance,
+        ...versionFields,
         name,
-        version_id: versionId,
         category: category.trim() || null,
         mod_loader: modLoader,
-        mod_loader_version: modLoaderVersion || null,
         java_path: javaPath || null,
         memory_max: memory,
         jvm_args: jvmArgs || null,
+        wrapper_command: wrapperCommand.trim() || null,
+        resolution_width: Number.parseInt(resolutionWidth, 10) || null,
+        resolution_height: Number.parseInt(resolutionHeight, 10) || null,
+        demo_mode: demoMode,
+        separate_loader_mods: separateLoaderMods,
         preferred_account: preferredAccount || null,
         check_mod_updates_on_launch: checkModUpdatesOnLaunch,
       };
@@ -355,9 +508,10 @@ function InstanceSettings({
       }
     } catch (error) {
       console.error('Failed to save:', error);
+      onShowNotification?.(`Failed to save: ${error}`, 'error');
     }
     setSaving(false);
-  }, [instance, name, versionId, category, modLoader, modLoaderVersion, javaPath, memory, jvmArgs, preferredAccount, preferredServer, savedServers, checkModUpdatesOnLaunch, onSave]);
+  }, [instance, name, versionId, category, modLoader, modLoaderVersion, javaPath, memory, jvmArgs, wrapperCommand, resolutionWidth, resolutionHeight, demoMode, separateLoaderMods, preferredAccount, preferredServer, savedServers, checkModUpdatesOnLaunch, onSave, onShowNotification]);
 
   const handleDownloadJava = useCallback(async () => {
     setJavaDownloading(true);
@@ -391,6 +545,49 @@ function InstanceSettings({
     }
   }, [instance.id, onShowNotification]);
 
+  const handleCopyLaunchCommand = useCallback(async () => {
+    setCopyingLaunchCommand(true);
+    try {
+      const result = await invoke('get_launch_command', { instanceId: instance.id });
+      await navigator.clipboard.writeText(result.command_line);
+      onShowNotification?.('Launch command copied to clipboard (access token redacted)', 'success');
+    } catch (error) {
+      console.error('Failed to resolve launch command:', error);
+      onShowNotification?.(`Failed to resolve launch command: ${error}`, 'error');
+    }
+    setCopyingLaunchCommand(false);
+  }, [instance.id, onShowNotification]);
+
+  const handleRepair = useCallback(async () => {
+    setRepairing(true);
+    try {
+      const report = await invoke('repair_instance', { instanceId: instance.id });
+      const summary = report.fixed.length > 0 ? report.fixed.join(', ') : 'No problems found';
+      onShowNotification?.(`Repair finished: ${summary}`, 'success');
+    } catch (error) {
+      console.error('Failed to repair instance:', error);
+      onShowNotification?.(`Failed to repair instance: ${error}`, 'error');
+    }
+    setRepairing(false);
+  }, [instance.id, onShowNotification]);
+
+  const handleExportDebugBundle = useCallback(async () => {
+    try {
+      const destinationPath = await save({
+        defaultPath: `${instance.name.replace(/[^a-zA-Z0-9]/g, '_')}-debug.zip`,
+        filters: [{ name: 'Zip Archive', extensions: ['zip'] }]
+      });
+      if (!destinationPath) return;
+      setExportingBundle(true);
+      await invoke('export_debug_bundle', { destinationPath, instanceId: instance.id });
+      onShowNotification?.('Debug bundle exported (access tokens removed)', 'success');
+    } catch (error) {
+      console.error('Failed to export debug bundle:', error);
+      onShowNotification?.(`Failed to export debug bundle: ${error}`, 'error');
+    }
+    setExportingBundle(false);
+  }, [instance.id, instance.name, onShowNotification]);
+
   const handleChooseLogo = useCallback(() => {
     setShowIconPicker(true);
   }, []);
@@ -528,7 +725,7 @@ function InstanceSettings({
     setShowServerModal(true);
   }, [loadSavedServers]);
 
-  const loaders = ['Vanilla', 'Fabric', 'Forge', 'NeoForge'];
+  const loaders = ['Vanilla', 'Fabric', 'Quilt', 'Forge', 'NeoForge'];
   const settingsSubTabs = [
     { id: 'general', label: 'General' },
     { id: 'versions', label: 'Versions' },
@@ -578,6 +775,70 @@ function InstanceSettings({
               Edit options.txt
             </button>
           </div>
+          <div className="setting-row">
+            <label>Mod Configs</label>
+            <button
+              className="btn btn-secondary"
+              onClick={() => setShowConfigEditor(true)}
+              style={{ flex: 1, padding: '10px 12px', textAlign: 'center' }}
+            >
+              Edit config files
+            </button>
+          </div>
+          {!instance.game_directory && (
+            <div className="setting-row">
+              <label>Game Data Location</label>
+              <div style={{ display: 'flex', gap: '8px', flex: 1 }}>
+                <button
+                  className="btn btn-secondary"
+                  onClick={() => handleRelocateData(false)}
+                  disabled={relocatingData}
+                  style={{ flex: 1, padding: '10px 12px', textAlign: 'center' }}
+                  title={instance.relocated_game_dir || 'Stored with the launcher\'s other instances'}
+                >
+                  {relocatingData ? 'Moving...' : instance.relocated_game_dir ? 'Move elsewhere' : 'Move to another drive'}
+                </button>
+                {instance.relocated_game_dir && (
+                  <button
+                    className="btn btn-secondary"
+                    onClick={() => handleRelocateData(true)}
+                    disabled={relocatingData}
+                    style={{ padding: '10px 12px' }}
+                  >
+                    Move back
+                  </button>
+                )}
+              </div>
+            </div>
+          )}
+          {otherInstances.length > 0 && (
+            <div className="setting-row">
+              <label>Copy Options From</label>
+              <div style={{ display: 'flex', gap: '8px', flex: 1 }}>
+                <select
+                  value={optionsSourceId}
+                  onChange={(e) => setOptionsSourceId(e.target.value)}
+                  style={{ flex: 1 }}
+                >
+                  <option value="">Select instance...</option>
+                  {otherInstances.map((item) => (
+                    <option key={item.id} value={item.id}>{item.name}</option>
+                  ))}
+                </select>
+                <select value={optionsCopyMode} onChange={(e) => setOptionsCopyMode(e.target.value)}>
+                  <option value="all">All settings</option>
+                  <option value="keys">Keybinds only</option>
+                </select>
+                <button
+                  className="btn btn-secondary"
+                  onClick={handleCopyOptions}
+                  disabled={!optionsSourceId || copyingOptions}
+                >
+                  {copyingOptions ? 'Copying...' : 'Copy'}
+                </button>
+              </div>
+            </div>
+          )}
           <div className="setting-row">
             <label>Launch Account</label>
             <div className="launch-account-control">
@@ -714,7 +975,8 @@ function InstanceSettings({
                   onSelect={(vid) => {
                     setVersionId(vid);
                   }}
-                  onRefresh={loadVersions}
+                  onRefresh={() => loadVersions(true)}
+                  updatedAt={versionsUpdatedAt}
                 />
               </div>
             )}
@@ -892,6 +1154,11 @@ function InstanceSettings({
               </div>
             </div>
           </div>
+          {memoryWarning && (
+            <p className="setting-hint" style={{ marginTop: '-12px', marginBottom: '12px', color: '#f59e0b', fontSize: '11px', lineHeight: '1.4' }}>
+              {memoryWarning}
+            </p>
+          )}
           <div className="setting-row">
             <label>JVM Arguments</label>
             <input
@@ -901,6 +1168,160 @@ function InstanceSettings({
               placeholder="-XX:+UseG1GC"
             />
           </div>
+          <div className="setting-row">
+            <label>Wrapper Command</label>
+            <input
+              type="text"
+              value={wrapperCommand}
+              onChange={(e) => setWrapperCommand(e.target.value)}
+              placeholder="gamemoderun, prime-run, mangohud"
+            />
+          </div>
+          <div className="setting-row">
+            <label>Exclude Library</label>
+            <input
+              type="text"
+              value={newExcludedLibrary}
+              onChange={(e) => setNewExcludedLibrary(e.target.value)}
+              onKeyDown={(e) => {
+                if (e.key === 'Enter' && newExcludedLibrary.trim()) {
+                  handleSetExcludedLibrary(newExcludedLibrary.trim(), true);
+                }
+              }}
+              placeholder="org.ow2.asm:asm"
+            />
+            <button
+              className="btn btn-secondary"
+              onClick={() => handleSetExcludedLibrary(newExcludedLibrary.trim(), true)}
+              disabled={!newExcludedLibrary.trim()}
+              style={{ padding: '10px 12px' }}
+            >
+              Exclude
+            </button>
+          </div>
+          {excludedLibraries.map((identity) => (
+            <div className="setting-row" key={identity}>
+              <label />
+              <span style={{ flex: 1, fontFamily: 'monospace', fontSize: '12px' }}>{identity}</span>
+              <button
+                className="btn btn-secondary"
+                onClick={() => handleSetExcludedLibrary(identity, false)}
+                style={{ padding: '6px 10px' }}
+              >
+                Include
+              </button>
+            </div>
+          ))}
+          <p className="setting-hint" style={{ marginTop: '-12px', marginBottom: '12px', color: 'var(--text-secondary)', fontSize: '11px', lineHeight: '1.4' }}>
+            Excluded libraries are left off the classpath at launch without being deleted. Use this to test whether a bundled library is causing a crash.
+          </p>
+          <div className="setting-row">
+            <label>Resolution</label>
+            <select
+              value={getResolutionPreset()}
+              onChange={(e) => applyResolutionPreset(e.target.value)}
+            >
+              <option value="default">Game default (854x480)</option>
+              {primaryMonitor && (
+                <option value="native">Native ({primaryMonitor.width}x{primaryMonitor.height})</option>
+              )}
+              {RESOLUTION_PRESETS.map((preset) => (
+                <option key={preset.id} value={preset.id} disabled={!fitsAnyMonitor(preset.width, preset.height)}>
+                  {preset.label}
+                </option>
+              ))}
+              <option value="custom">Custom</option>
+            </select>
+            <input
+              type="number"
+              min="1"
+              value={resolutionWidth}
+              onChange={(e) => setResolutionWidth(e.target.value)}
+              placeholder="Width"
+              style={{ maxWidth: '90px' }}
+            />
+            <input
+              type="number"
+              min="1"
+              value={resolutionHeight}
+              onChange={(e) => setResolutionHeight(e.target.value)}
+              placeholder="Height"
+              style={{ maxWidth: '90px' }}
+            />
+          </div>
+          <div className="setting-row">
+            <label>Demo Mode</label>
+            <div className="launch-account-control">
+              <button
+                type="button"
+                className={`instance-setting-toggle-btn ${demoMode ? 'enabled' : ''}`}
+                onClick={() => setDemoMode((prev) => !prev)}
+              >
+                <span className={`instance-setting-toggle ${demoMode ? 'enabled' : ''}`} />
+                <div className="instance-setting-toggle-copy">
+                  <span className="instance-setting-toggle-title">
+                    Launch in demo mode
+                  </span>
+                  <span className="instance-setting-toggle-sub">
+                    Only available with offline accounts; Microsoft accounts are refused
+                  </span>
+                </div>
+              </button>
+            </div>
+          </div>
+          <div className="setting-row">
+            <label>Mods per Loader</label>
+            <div className="launch-account-control">
+              <button
+                type="button"
+                className={`instance-setting-toggle-btn ${separateLoaderMods ? 'enabled' : ''}`}
+                onClick={() => setSeparateLoaderMods((prev) => !prev)}
+              >
+                <span className={`instance-setting-toggle ${separateLoaderMods ? 'enabled' : ''}`} />
+                <div className="instance-setting-toggle-copy">
+                  <span className="instance-setting-toggle-title">
+                    Keep mods separate for each loader
+                  </span>
+                  <span className="instance-setting-toggle-sub">
+                    Switching loaders sets the current mods aside in mods-&lt;loader&gt; and brings back that loader&apos;s own. Existing mods stay with the current loader.
+                  </span>
+                </div>
+              </button>
+            </div>
+          </div>
+          <div className="setting-row">
+            <label>Launch Command</label>
+            <button
+              className="btn btn-secondary"
+              onClick={handleCopyLaunchCommand}
+              disabled={copyingLaunchCommand}
+              style={{ flex: 1, padding: '10px 12px', textAlign: 'center' }}
+            >
+              {copyingLaunchCommand ? 'Resolving...' : 'Copy launch command'}
+            </button>
+          </div>
+          <div className="setting-row">
+            <label>Repair</label>
+            <button
+              className="btn btn-secondary"
+              onClick={handleRepair}
+              disabled={repairing}
+              style={{ flex: 1, padding: '10px 12px', textAlign: 'center' }}
+            >
+              {repairing ? 'Repairing...' : 'Fix common launch problems'}
+            </button>
+          </div>
+          <div className="setting-row">
+            <label>Bug Report</label>
+            <button
+              className="btn btn-secondary"
+              onClick={handleExportDebugBundle}
+              disabled={exportingBundle}
+              style={{ flex: 1, padding: '10px 12px', textAlign: 'center' }}
+            >
+              {exportingBundle ? 'Exporting...' : 'Export debug bundle'}
+            </button>
+          </div>
         </div>
       </div>
       <div className="settings-footer-bar">
@@ -929,6 +1350,13 @@ function InstanceSettings({
           onShowNotification={onShowNotification}
         />
       )}
+      {showConfigEditor && (
+        <ConfigEditorModal
+          instanceId={instance.id}
+          onClose={() => setShowConfigEditor(false)}
+          onShowNotification={onShowNotification}
+        />
+      )}
       {showServerModal && (
         <div className="instance-account-modal-overlay" onClick={() => setShowServerModal(false)}>
           <div className="instance-account-modal" onClick={(e) => e.stopPropagation()}>
@@ -781,6 +781,16 @@ function InstanceWorlds({
     }
   };
 
+  const getDifficultyName = (difficulty) => {
+    switch (difficulty) {
+      case 0: return 'Peaceful';
+      case 1: return 'Easy';
+      case 2: return 'Normal';
+      case 3: return 'Hard';
+      default: return null;
+    }
+  };
+
   const renderAddWorldModal = () => {
     if (!showAddWorldModal) return null;
 
@@ -1044,9 +1054,22 @@ function InstanceWorlds({
                         )}
                       </div>
                       <div className="world-meta">
-                        <span>{getGameModeIcon(world.game_mode)}</span>
+                        <span>{world.hardcore ? 'Hardcore' : getGameModeIcon(world.game_mode)}</span>
+                        {!world.hardcore && getDifficultyName(world.difficulty) && (
+                          <span>{getDifficultyName(world.difficulty)}</span>
+                        )}
+                        {world.day_count != null && <span>Day {world.day_count}</span>}
                         <span>{formatSize(world.size)}</span>
                         <span>Last played: {formatDate(world.last_played)}</span>
+                        {world.seed != null && (
+                          <span
+                            className="world-seed"
+                            title="Click to copy seed"
+                            onClick={() => navigator.clipboard?.writeText(world.seed)}
+                          >
+                            Seed: {world.seed}
+                          </span>
+                        )}
                       </div>
                     </div>
                     <div className="world-actions">
@@ -435,20 +435,8 @@ function ModVersionModal({
           if (latestVersion.dependencies && latestVersion.dependencies.length > 0) {
             setLoadingDeps(true);
             try {
-              const projectIds = latestVersion.dependencies
-                .map((dep) => dep.project_id)
-                .filter(Boolean);
-
-              if (projectIds.length > 0) {
-                const depProjects = await invoke('get_modrinth_projects', { projectIds });
-                const enrichedDeps = depProjects.map((depProject) => {
-                  const depInfo = latestVersion.dependencies.find((dep) => dep.project_id === (depProject.project_id || depProject.id));
-                  return { ...depProject, dependency_type: depInfo?.dependency_type };
-                });
-                setDependencies(enrichedDeps);
-              } else {
-                setDependencies([]);
-              }
+              const detailed = await invoke('get_version_dependencies_detailed', { versionId: latestVersion.id });
+              setDependencies(detailed.filter((dep) => dep.project_id));
             } catch (depErr) {
               console.error('Failed to load dependencies:', depErr);
             } finally {
@@ -442,8 +442,18 @@ function ProjectDetailsEntityModal({
                 </div>
                 <p className="dep-description">{dependency.description || 'No description.'}</p>
                 <div className="dep-meta">
-                  <span>by {dependency.author || 'Unknown'}</span>
-                  <span className="dot">|</span>
+                  {dependency.author && (
+                    <>
+                      <span>by {dependency.author}</span>
+                      <span className="dot">|</span>
+                    </>
+                  )}
+                  {dependency.version_number && (
+                    <>
+                      <span>{dependency.version_number}</span>
+                      <span className="dot">|</span>
+                    </>
+                  )}
                   <span>{formatNumber(dependency.downloads || 0)} downloads</span>
                 </div>
               </div>
@@ -1,7 +1,7 @@
 import { useState, useEffect, useRef } from 'react';
 import { getVersion } from '@tauri-apps/api/app';
 import { invoke } from '@tauri-apps/api/core';
-import { open } from '@tauri-apps/plugin-dialog';
+import { open, save } from '@tauri-apps/plugin-dialog';
 import ConfirmModal from './ConfirmModal';
 import './Settings.css';
 
@@ -14,7 +14,17 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
   const [loginCode, setLoginCode] = useState(null);
   const [diskUsage, setDiskUsage] = useState(null);
   const [downloadedVersions, setDownloadedVersions] = useState([]);
+  const [javaRuntimes, setJavaRuntimes] = useState([]);
+  const [systemMemory, setSystemMemory] = useState(null);
+  const [connectivity, setConnectivity] = useState(null);
+  const [privacy, setPrivacy] = useState(null);
+  const [isTestingConnection, setIsTestingConnection] = useState(false);
+  const [transferIncludesAccounts, setTransferIncludesAccounts] = useState(false);
+  const [transferPassphrase, setTransferPassphrase] = useState('');
+  const [transferStatus, setTransferStatus] = useState(null);
   const [isCleaning, setIsCleaning] = useState(false);
+  const [isMovingData, setIsMovingData] = useState(false);
+  const [isExportingBundle, setIsExportingBundle] = useState(false);
   const [isLoadingStorage, setIsLoadingStorage] = useState(false);
   const [appVersion, setAppVersion] = useState('0.2.0');
   const [javaDownloadVersion, setJavaDownloadVersion] = useState('21');
@@ -58,6 +68,8 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
       if (cachedVersions) setDownloadedVersions(JSON.parse(cachedVersions));
     }
     getVersion().then(setAppVersion);
+    invoke('get_system_memory').then(setSystemMemory).catch(() => {});
+    invoke('get_privacy_settings').then(setPrivacy).catch(() => {});
   }, []);
 
   useEffect(() => {
@@ -97,6 +109,17 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
     }
   };
 
+  const handleSavePrivacy = async (changes) => {
+    const updated = { ...privacy, ...changes };
+    try {
+      await invoke('save_privacy_settings', { privacy: updated });
+      setPrivacy(updated);
+      onSettingsUpdated();
+    } catch (error) {
+      console.error('Failed to save privacy settings:', error);
+    }
+  };
+
   const handleSaveUsername = () => {
     if (newUsername.trim() && newUsername !== username) {
       onSetUsername(newUsername.trim());
@@ -204,6 +227,9 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
       const versions = await invoke('get_downloaded_versions');
       setDownloadedVersions(versions);
       sessionStorage.setItem('cached_downloaded_versions', JSON.stringify(versions));
+
+      const runtimes = await invoke('list_installed_java_runtimes');
+      setJavaRuntimes(runtimes);
     } catch (error) {
       console.error('Failed to load storage info:', error);
     } finally {
@@ -211,6 +237,89 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
     }
   };
 
+  const handleExportDebugBundle = async () => {
+    try {
+      const destinationPath = await save({
+        defaultPath: 'palethea-debug.zip',
+        filters: [{ name: 'Zip Archive', extensions: ['zip'] }]
+      });
+      if (!destinationPath) return;
+      setIsExportingBundle(true);
+      await invoke('export_debug_bundle', { destinationPath });
+    } catch (error) {
+      console.error('Failed to export debug bundle:', error);
+    } finally {
+      setIsExportingBundle(false);
+    }
+  };
+
+  const handleExportConfig = async () => {
+    setTransferStatus(null);
+    try {
+      const destinationPath = await save({
+        defaultPath: 'palethea-settings.json',
+        filters: [{ name: 'Launcher Config', extensions: ['json'] }]
+      });
+      if (!destinationPath) return;
+      await invoke('export_launcher_config', {
+        destinationPath,
+        includeAccounts: transferIncludesAccounts,
+        passphrase: transferIncludesAccounts ? transferPassphrase : null
+      });
+      setTransferStatus({ error: false, message: transferIncludesAccounts ? 'Exported settings and encrypted accounts.' : 'Exported settings.' });
+    } catch (error) {
+      setTransferStatus({ error: true, message: String(error) });
+    }
+  };
+
+  const handleImportConfig = async () => {
+    setTransferStatus(null);
+    try {
+      const selected = await open({ multiple: false, filters: [{ name: 'Launcher Config', extensions: ['json'] }] });
+      if (!selected) return;
+      const result = await invoke('import_launcher_config', { path: selected, passphrase: transferPassphrase || null });
+      const added = result.accounts?.added.length || 0;
+      const skipped = result.accounts?.skipped.length || 0;
+      let message = 'Imported settings.';
+      if (result.accounts) {
+        message += ` Added ${added} account${added === 1 ? '' : 's'}${skipped > 0 ? `, ${skipped} already here` : ''}.`;
+      }
+      setTransferStatus({ error: false, message });
+      onSettingsUpdated();
+    } catch (error) {
+      setTransferStatus({ error: true, message: String(error) });
+    }
+  };
+
+  const handleMoveDataDirectory = async () => {
+    try {
+      const selected = await open({ directory: true, multiple: false });
+      if (!selected) return;
+      requestConfirm({
+        title: 'Move Data Directory',
+        message: `Move all instances, versions and libraries to ${selected}? This can take a while for large installs.`,
+        confirmText: 'Move',
+        onConfirm: async () => {
+          closeConfirm();
+          setStorageError('');
+          setIsMovingData(true);
+          try {
+            const dir = await invoke('migrate_data_directory', { newPath: selected });
+            setDataDir(dir);
+            sessionStorage.setItem('cached_data_dir', dir);
+            await loadStorageInfo();
+          } catch (error) {
+            console.error('Failed to move data directory:', error);
+            setStorageError(`Failed to move data directory: ${error}`);
+          }
+          setIsMovingData(false);
+        }
+      });
+    } catch (error) {
+      console.error('Failed to select data directory:', error);
+    }
+  };
+
   const requestConfirm = ({ title, message, confirmText = 'Confirm', variant = 'primary', onConfirm }) => {
     setConfirmState({
       show: true,
@@ -268,6 +377,26 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
     });
   };
 
+  const handleDeleteJavaRuntime = async (runtime) => {
+    requestConfirm({
+      title: 'Delete Java Runtime',
+      message: `Are you sure you want to delete ${runtime.vendor} Java ${runtime.version}?`,
+      confirmText: 'Delete',
+      variant: 'danger',
+      onConfirm: async () => {
+        closeConfirm();
+        setStorageError('');
+        try {
+          await invoke('delete_java_runtime', { path: runtime.path });
+          await loadStorageInfo();
+        } catch (error) {
+          console.error('Failed to delete Java runtime:', error);
+          setStorageError(`Failed to delete Java runtime: ${error}`);
+        }
+      }
+    });
+  };
+
   const formatSize = (bytes) => {
     if (!bytes) return '0 B';
     const units = ['B', 'KB', 'MB', 'GB', 'TB'];
@@ -425,8 +554,304 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
               Set a custom Java path, or leave empty to auto-detect.
             </p>
           </div>
+
+          <div className="setting-item">
+            <label>Missing Java</label>
+            <select
+              value={launcherSettings?.auto_download_java === false ? 'ask' : 'download'}
+              onChange={async (e) => {
+                const updated = {
+                  ...launcherSettings,
+                  auto_download_java: e.target.value === 'download'
+                };
+                await invoke('save_settings', { newSettings: updated });
+                onSettingsUpdated();
+              }}
+            >
+              <option value="download">Download it automatically on launch</option>
+              <option value="ask">Stop the launch and let me install it</option>
+            </select>
+            <p className="setting-hint">
+              Used when no installed Java meets the version the game requires.
+            </p>
+          </div>
+        </section>
+
+        <section className="settings-section">
+          <h2>Launcher</h2>
+          <div className="setting-item">
+            <label>When a Game Starts</label>
+            <select
+              value={launcherSettings?.on_game_launch || 'keep_open'}
+              onChange={async (e) => {
+                const updated = {
+                  ...launcherSettings,
+                  on_game_launch: e.target.value
+                };
+                await invoke('save_settings', { newSettings: updated });
+                onSettingsUpdated();
+              }}
+            >
+              <option value="keep_open">Keep the launcher open</option>
+              <option value="minimize">Minimize the launcher</option>
+              <option value="close_to_tray">Close to the system tray</option>
+              <option value="exit">Exit the launcher</option>
+            </select>
+            <p className="setting-hint">
+              Exiting leaves the game running; its playtime is counted the next time the launcher opens.
+            </p>
+          </div>
+
+          <div className="setting-item">
+            <label>Default Memory for New Instances</label>
+            <select
+              value={launcherSettings?.default_memory_max ? String(launcherSettings.default_memory_max) : 'auto'}
+              onChange={async (e) => {
+                const updated = {
+                  ...launcherSettings,
+                  default_memory_max: e.target.value === 'auto' ? null : parseInt(e.target.value, 10)
+                };
+                await invoke('save_settings', { newSettings: updated });
+                onSettingsUpdated();
+              }}
+            >
+              <option value="auto">
+                Automatic{systemMemory?.recommended_max_mb ? ` (${systemMemory.recommended_max_mb} MB)` : ''}
+              </option>
+              {[2048, 3072, 4096, 6144, 8192, 12288, 16384].map((mb) => (
+                <option key={mb} value={String(mb)}>{mb} MB</option>
+              ))}
+            </select>
+            <p className="setting-hint">
+              {systemMemory?.total_mb
+                ? `Automatic uses about 40% of this machine's ${systemMemory.total_mb} MB, up to 8 GB. `
+                : 'Automatic uses about 40% of system memory, up to 8 GB. '}
+              Existing instances keep their own setting.
+            </p>
+          </div>
+
+          <div className="setting-item">
+            <label>Closing the Window</label>
+            <select
+              value={launcherSettings?.minimize_to_tray_on_close ? 'tray' : 'ask'}
+              onChange={async (e) => {
+                const updated = {
+                  ...launcherSettings,
+                  minimize_to_tray_on_close: e.target.value === 'tray'
+                };
+                await invoke('save_settings', { newSettings: updated });
+                onSettingsUpdated();
+              }}
+            >
+              <option value="ask">Ask before exiting while games run</option>
+              <option value="tray">Keep running in the tray while games run</option>
+            </select>
+            <p className="setting-hint">
+              From the tray, Open Palethea brings the window back and Exit quits the launcher. With no games running the window always closes.
+            </p>
+          </div>
+
+          <div className="setting-item">
+            <label>Log Level</label>
+            <select
+              value={launcherSettings?.log_level || 'default'}
+              onChange={async (e) => {
+                const updated = {
+                  ...launcherSettings,
+                  log_level: e.target.value === 'default' ? null : e.target.value
+                };
+                await invoke('save_settings', { newSettings: updated });
+                onSettingsUpdated();
+              }}
+            >
+              <option value="default">Default</option>
+              <option value="error">Errors only</option>
+              <option value="warn">Warnings</option>
+              <option value="info">Info</option>
+              <option value="debug">Debug</option>
+              <option value="trace">Trace</option>
+            </select>
+            <p className="setting-hint">
+              Takes effect after restarting the launcher.
+            </p>
+          </div>
+
+          <div className="setting-item">
+            <label>Download Speed Limit</label>
+            <select
+              value={String(launcherSettings?.download_speed_limit_kbps || 0)}
+              onChange={async (e) => {
+                const updated = {
+                  ...launcherSettings,
+                  download_speed_limit_kbps: Number(e.target.value)
+                };
+                await invoke('save_settings', { newSettings: updated });
+                onSettingsUpdated();
+              }}
+            >
+              <option value="0">Unlimited</option>
+              <option value="512">512 KB/s</option>
+              <option value="1024">1 MB/s</option>
+              <option value="2560">2.5 MB/s</option>
+              <option value="5120">5 MB/s</option>
+              <option value="10240">10 MB/s</option>
+              <option value="25600">25 MB/s</option>
+            </select>
+            <p className="setting-hint">
+              Shared by all downloads at once, so parallel installs split the limit.
+            </p>
+          </div>
+
+          <div className="setting-item">
+            <label>Network Protocol</label>
+            <select
+              value={launcherSettings?.force_ipv4 ? 'ipv4' : 'auto'}
+              onChange={async (e) => {
+                const updated = {
+                  ...launcherSettings,
+                  force_ipv4: e.target.value === 'ipv4'
+                };
+                await invoke('save_settings', { newSettings: updated });
+                onSettingsUpdated();
+              }}
+            >
+              <option value="auto">Automatic</option>
+              <option value="ipv4">IPv4 only</option>
+            </select>
+            <p className="setting-hint">
+              Use IPv4 only if downloads stall on a network with broken IPv6.
+            </p>
+            <button
+              className="btn btn-secondary"
+              disabled={isTestingConnection}
+              onClick={async () => {
+                setIsTestingConnection(true);
+                try {
+                  setConnectivity(await invoke('diagnose_connectivity'));
+                } catch (error) {
+                  console.error('Failed to test connection:', error);
+                }
+                setIsTestingConnection(false);
+              }}
+            >
+              {isTestingConnection ? 'Testing...' : 'Test Connection'}
+            </button>
+            {connectivity && connectivity.map(host => (
+              <p key={host.url} className="setting-hint">
+                {host.url.replace('https://', '')}: IPv4 {host.ipv4_ms != null ? `${host.ipv4_ms} ms` : 'failed'}, IPv6 {host.ipv6_ms != null ? `${host.ipv6_ms} ms` : 'failed'}
+              </p>
+            ))}
+          </div>
+
+          <div className="setting-item">
+            <label>Game Output Buffer</label>
+            <select
+              value={String(launcherSettings?.live_output_lines ?? 2000)}
+              onChange={async (e) => {
+                const updated = {
+                  ...launcherSettings,
+                  live_output_lines: Number(e.target.value)
+                };
+                await invoke('save_settings', { newSettings: updated });
+                onSettingsUpdated();
+              }}
+            >
+              <option value="0">Off</option>
+              <option value="500">Last 500 lines</option>
+              <option value="2000">Last 2,000 lines</option>
+              <option value="5000">Last 5,000 lines</option>
+              <option value="10000">Last 10,000 lines</option>
+            </select>
+            <p className="setting-hint">
+              Recent game output kept in memory while an instance runs, so the console can show it before latest.log exists. Cleared when the game exits.
+            </p>
+          </div>
+
+          <div className="setting-item">
+            <label>Debug Bundle</label>
+            <button className="btn btn-secondary" onClick={handleExportDebugBundle} disabled={isExportingBundle}>
+              {isExportingBundle ? 'Exporting...' : 'Export logs for a bug report'}
+            </button>
+            <p className="setting-hint">
+              Zips launcher logs and system info into one file. Access tokens are removed.
+            </p>
+          </div>
+
+          <div className="setting-item">
+            <label>Transfer Settings</label>
+            <select
+              value={transferIncludesAccounts ? 'accounts' : 'settings'}
+              onChange={(e) => setTransferIncludesAccounts(e.target.value === 'accounts')}
+            >
+              <option value="settings">Settings only</option>
+              <option value="accounts">Settings and accounts</option>
+            </select>
+            <div className="input-group">
+              <input
+                type="password"
+                value={transferPassphrase}
+                onChange={(e) => setTransferPassphrase(e.target.value)}
+                placeholder="Passphrase for accounts"
+              />
+              <button className="btn btn-secondary" onClick={handleExportConfig}>Export</button>
+              <button className="btn btn-secondary" onClick={handleImportConfig}>Import</button>
+            </div>
+            <p className="setting-hint">
+              Carries settings to another machine. Accounts are encrypted with the passphrase, which is needed again to import them. Java and data folder paths stay machine-specific.
+            </p>
+            {transferStatus && (
+              <p className="setting-hint" style={transferStatus.error ? { color: '#f56565' } : undefined}>{transferStatus.message}</p>
+            )}
+          </div>
         </section>
 
+        {privacy && (
+          <section className="settings-section">
+            <h2>Privacy</h2>
+            <div className="setting-item">
+              <label>Discord Activity</label>
+              <select
+                value={privacy.enable_discord ? 'on' : 'off'}
+                onChange={(e) => handleSavePrivacy({ enable_discord: e.target.value === 'on' })}
+              >
+                <option value="on">Show what I'm playing</option>
+                <option value="off">Off</option>
+              </select>
+              <p className="setting-hint">
+                Shares the running instance and Minecraft version with a local Discord client.
+              </p>
+            </div>
+
+            <div className="setting-item">
+              <label>Update Checks</label>
+              <select
+                value={privacy.enable_update_checks ? 'auto' : 'manual'}
+                onChange={(e) => handleSavePrivacy({ enable_update_checks: e.target.value === 'auto' })}
+              >
+                <option value="auto">Check GitHub automatically</option>
+                <option value="manual">Only when I check</option>
+              </select>
+              <p className="setting-hint">
+                Checking by hand from the Updates page still works when this is off.
+              </p>
+            </div>
+
+            <div className="setting-item">
+              <label>Notifications</label>
+              <select
+                value={privacy.enable_notifications ? 'all' : 'important'}
+                onChange={(e) => handleSavePrivacy({ enable_notifications: e.target.value === 'all' })}
+              >
+                <option value="all">All</option>
+                <option value="important">Errors and warnings only</option>
+              </select>
+              <p className="setting-hint">
+                Routine pop-ups such as finished downloads are skipped when limited.
+              </p>
+            </div>
+          </section>
+        )}
+
         <section className="settings-section">
           <h2>Storage</h2>
           {isLoadingStorage ? (
@@ -438,8 +863,11 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
             <>
               <div className="setting-item">
                 <label>Data Directory</label>
-                <div className="info-box">
-                  <span className="info-text">{dataDir}</span>
+                <div className="input-group">
+                  <input type="text" value={dataDir} readOnly />
+                  <button className="btn btn-secondary" onClick={handleMoveDataDirectory} disabled={isMovingData}>
+                    {isMovingData ? 'Moving...' : 'Move'}
+                  </button>
                 </div>
               </div>
 
@@ -528,6 +956,49 @@ function Settings({ username, onSetUsername, isLoggedIn, onLogin, onLogout, laun
               </div>
             )}
           </div>
+
+          <div className="downloaded-versions">
+            <h3>Java Runtimes</h3>
+            {javaRuntimes.length === 0 ? (
+              <p className="no-data">No Java runtimes downloaded yet.</p>
+            ) : (
+              <div className="version-management-list">
+                {javaRuntimes.map(runtime => (
+                  <div key={runtime.path} className="version-management-item">
+                    <div className="version-details">
+                      <span className="version-id">{runtime.vendor} Java {runtime.version}{runtime.in_use ? ' (in use)' : ''}</span>
+                      <span className="version-size">{formatSize(runtime.size)}</span>
+                    </div>
+                    <button
+                      className="btn-icon delete"
+                      onClick={() => handleDeleteJavaRuntime(runtime)}
+                      disabled={runtime.in_use}
+                      title={runtime.in_use ? 'Used by an instance or the global Java path' : 'Delete runtime'}
+                    >
+                      <svg
+                        className="delete-svg"
+                        viewBox="0 0 24 24"
+                        width="18"
+                        height="18"
+                        stroke="#f87171"
+                        strokeWidth="2"
+                        strokeLinecap="round"
+                        strokeLinejoin="round"
+                        fill="none"
+                        aria-hidden="true"
+                      >
+                        <path d="M3 6h18" />
+                        <path d="M8 6V4h8v2" />
+                        <path d="M10 11v6" />
+                        <path d="M14 11v6" />
+                        <path d="M6 6l1 14h10l1-14" />
+                      </svg>
+                    </button>
+                  </div>
+                ))}
+              </div>
+            )}
+          </div>
         </>
       )}
     </section>
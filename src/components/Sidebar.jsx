@@ -64,6 +64,7 @@ function Sidebar({
   currentSkinTexture,
   skinCache = {},
   launcherSettings,
+  isOffline = false,
   onOpenAccountManager,
   onShowInfo
 }) {
@@ -158,7 +159,8 @@ function Sidebar({
           }}
         />
         {SIDEBAR_TABS.map((tab) => {
-          const isDisabled = tab.id === 'skins' && !activeAccount?.isLoggedIn;
+          const isOfflineDisabled = isOffline && (tab.id === 'skins' || tab.id === 'updates');
+          const isDisabled = isOfflineDisabled || (tab.id === 'skins' && !activeAccount?.isLoggedIn);
           return (
             <button
               key={tab.id}
@@ -181,7 +183,9 @@ function Sidebar({
                     e.stopPropagation();
                     onShowInfo({
                       title: 'Page Disabled',
-                      message: 'This page is disabled because you are not logged in. A Microsoft account is required to manage and sync Minecraft skins.',
+                      message: isOfflineDisabled
+                        ? 'This page needs an internet connection. It will come back once the launcher is online again.'
+                        : 'This page is disabled because you are not logged in. A Microsoft account is required to manage and sync Minecraft skins.',
                       confirmText: 'Got it',
                       variant: 'info'
                     });
@@ -1,7 +1,8 @@
 import { useState, useEffect } from 'react';
 import { invoke, convertFileSrc } from '@tauri-apps/api/core';
 import { sep } from '@tauri-apps/api/path';
-import { BarChart3, Clock, Rocket, Box, Trophy, Star, Calendar, Gamepad2, TrendingUp } from 'lucide-react';
+import { save } from '@tauri-apps/plugin-dialog';
+import { BarChart3, Clock, Rocket, Box, Trophy, Star, Calendar, Gamepad2, TrendingUp, Download } from 'lucide-react';
 import './Stats.css';
 
 const Stats = () => {
@@ -12,6 +13,7 @@ const Stats = () => {
     const [loading, setLoading] = useState(!sessionStorage.getItem('cached_stats'));
     const [logoMap, setLogoMap] = useState({});
     const [activityView, setActivityView] = useState('week');
+    const [exporting, setExporting] = useState(false);
 
     useEffect(() => {
         const fetchStats = async () => {
@@ -66,6 +68,22 @@ const Stats = () => {
         return () => { cancelled = true; };
     }, [stats?.top_instances]);
 
+    const handleExportCsv = async () => {
+        try {
+            const destinationPath = await save({
+                defaultPath: 'palethea_playtime.csv',
+                filters: [{ name: 'CSV', extensions: ['csv'] }]
+            });
+            if (!destinationPath) return;
+            setExporting(true);
+            await invoke('export_stats_csv', { destinationPath });
+        } catch (error) {
+            console.error('Failed to export playtime sessions:', error);
+        } finally {
+            setExporting(false);
+        }
+    };
+
     const formatPlaytime = (seconds) => {
         if (!seconds) return '0m';
         const hours = Math.floor(seconds / 3600);
@@ -145,6 +163,9 @@ const Stats = () => {
         <div className="stats-container">
             <header className="stats-header page-header">
                 <p className="page-subtitle">Your gameplay statistics across all instances.</p>
+                <button className="activity-toggle-btn" onClick={handleExportCsv} disabled={exporting}>
+                    <Download size={14} /> {exporting ? 'Exporting...' : 'Export CSV'}
+                </button>
             </header>
 
             <div className="stats-sections">
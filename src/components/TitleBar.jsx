@@ -2,6 +2,7 @@ import { useState, useEffect, useCallback, memo, useRef, useMemo } from 'react';
 import { getCurrentWindow } from '@tauri-apps/api/window';
 import { sep } from '@tauri-apps/api/path';
 import { invoke, convertFileSrc } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { X, Minus, Maximize, Minimize2, Terminal, ChevronDown, Square, List, Shirt, BarChart3, RefreshCcw, Wallpaper, Settings, Download, Trash2, Play, User, Tag } from 'lucide-react';
 import { clampProgress, formatBytes, formatSpeed } from '../utils/downloadTelemetry';
 import './TitleBar.css';
@@ -65,6 +66,7 @@ function TitleBar({
   const [isDownloadClosing, setIsDownloadClosing] = useState(false);
   const [logoMap, setLogoMap] = useState({});
   const [failedAccountHeads, setFailedAccountHeads] = useState({});
+  const [installTasks, setInstallTasks] = useState([]);
   const dropdownRef = useRef(null);
   const downloadRef = useRef(null);
   const appWindow = useMemo(() => getCurrentWindow(), []);
@@ -118,6 +120,29 @@ function TitleBar({
     settings: { label: 'Settings', icon: Settings },
   };
 
+  useEffect(() => {
+    invoke('get_install_queue').then(setInstallTasks).catch(() => {});
+    const unlisten = listen('install-queue-progress', (event) => {
+      setInstallTasks(Array.isArray(event.payload) ? event.payload : []);
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  const activeInstallTasks = useMemo(
+    () => installTasks.filter((task) => task.status === 'queued' || task.status === 'downloading'),
+    [installTasks]
+  );
+
+  const handleCancelInstall = async (taskId) => {
+    try {
+      await invoke('cancel_install', { taskId });
+    } catch (error) {
+      console.error('Failed to cancel install:', error);
+    }
+  };
+
   const currentTabInfo = TABS_CONFIG[activeTab];
   const editingInstance = editingInstanceId ? instances.find(i => i.id === editingInstanceId) : null;
   const showTitlebarLocation = launcherSettings?.titlebar_location_next_to_logo !== false;
@@ -294,7 +319,9 @@ function TitleBar({
         title="Download Queue"
       >
         <Download size={18} />
-        {downloadQueue.length > 0 && <span className="download-count">{downloadQueue.length}</span>}
+        {downloadQueue.length + activeInstallTasks.length > 0 && (
+          <span className="download-count">{downloadQueue.length + activeInstallTasks.length}</span>
+        )}
       </button>
 
       {showDownloadDropdown && (
@@ -316,7 +343,7 @@ function TitleBar({
             )}
           </div>
           <div className="dropdown-list">
-            {downloadQueue.length === 0 && downloadHistory.length === 0 ? (
+            {downloadQueue.length === 0 && activeInstallTasks.length === 0 && downloadHistory.length === 0 ? (
               <div className="dropdown-empty">No downloads</div>
             ) : (
               <>
@@ -352,8 +379,46 @@ function TitleBar({
                   </div>
                 ))}
 
+                {/* Queued content installs */}
+                {activeInstallTasks.map((task) => (
+                  <div key={`task-${task.task_id}`} className="running-item download-item">
+                    <div className="running-item-left">
+                      <div className="running-item-logo">
+                        <div className="logo-fallback">📦</div>
+                      </div>
+                      <div className="running-item-info">
+                        <div className="running-item-name">{task.label}</div>
+                        <div className="running-item-time">{task.status === 'queued' ? 'Queued' : 'Downloading...'}</div>
+                        {task.total_bytes > 0 && (
+                          <>
+                            <div className="download-item-progress">
+                              <div
+                                className="download-item-progress-fill"
+                                style={{ width: `${clampProgress((task.downloaded_bytes / task.total_bytes) * 100)}%` }}
+                              />
+                            </div>
+                            <div className="download-item-metrics">
+                              <span>{formatBytes(task.downloaded_bytes)}/{formatBytes(task.total_bytes)}</span>
+                            </div>
+                          </>
+                        )}
+                      </div>
+                    </div>
+                    <button
+                      className="stop-instance-btn"
+                      onClick={(e) => {
+                        e.stopPropagation();
+                        handleCancelInstall(task.task_id);
+                      }}
+                      title="Cancel"
+                    >
+                      <X size={14} />
+                    </button>
+                  </div>
+                ))}
+
                 {/* Separator if both exist */}
-                {downloadQueue.length > 0 && downloadHistory.length > 0 && (
+                {downloadQueue.length + activeInstallTasks.length > 0 && downloadHistory.length > 0 && (
                   <div className="download-separator">
                     <div className="separator-line"></div>
                     <span>Recent</span>
@@ -99,27 +99,60 @@ function Updates() {
     setUpdateChannel(newChannel);
     await saveUpdateChannel(newChannel);
     // Re-check updates with new channel
-    checkUpdates(newChannel, true);
+    checkUpdates(newChannel);
   };
 
   // ----------
-  // checkUpdates
-  // Description: Check for available updates using custom version comparison logic
-  //              that properly handles prerelease versions and downgrade opportunities
+  // autoCheckForUpdate
+  // Description: Background check on open. Goes through check_for_update so the
+  //              auto-check setting and channel filtering are applied by the backend
   // ----------
-  const checkUpdates = async (channel = updateChannel, force = false) => {
+  const autoCheckForUpdate = async () => {
     // Throttle automatic checks (only allow if 30s have passed)
-    if (!force) {
-      const lastCheck = localStorage.getItem('last_update_check');
-      const now = Date.now();
-      if (lastCheck && now - parseInt(lastCheck) < 30000) {
-        if (import.meta.env.DEV) {
-          invoke('log_event', { level: 'debug', message: 'Skipping throttled update check' }).catch(() => {});
-        }
-        return;
+    const lastCheck = localStorage.getItem('last_update_check');
+    if (lastCheck && Date.now() - parseInt(lastCheck) < 30000) {
+      if (import.meta.env.DEV) {
+        invoke('log_event', { level: 'debug', message: 'Skipping throttled update check' }).catch(() => {});
+      }
+      return;
+    }
+
+    setIsChecking(true);
+    setError(null);
+    try {
+      const result = await invoke('check_for_update', { manual: false });
+      if (!result.checked) return;
+      localStorage.setItem('last_update_check', Date.now().toString());
+
+      if (result.update_available && result.latest) {
+        const latest = result.latest;
+        const info = {
+          version: latest.tag_name.replace(/^v/, ''),
+          body: latest.body,
+          date: latest.published_at,
+          isPrerelease: latest.prerelease,
+          htmlUrl: latest.html_url,
+        };
+        setUpdateInfo(info);
+        localStorage.setItem('cached_update_info', JSON.stringify(info));
+      } else {
+        setUpdateInfo(null);
+        localStorage.removeItem('cached_update_info');
       }
+    } catch (err) {
+      await invoke('log_event', { level: 'error', message: `Update check failed: ${err.toString()}` });
+      console.error('Failed to check updates:', err);
+    } finally {
+      setIsChecking(false);
     }
+  };
 
+  // ----------
+  // checkUpdates
+  // Description: User-initiated check. Lists all releases using custom version comparison
+  //              logic that properly handles prerelease versions and downgrade opportunities
+  // ----------
+  const checkUpdates = async (channel = updateChannel) => {
     setIsChecking(true);
     localStorage.setItem('last_update_check', Date.now().toString());
     setError(null);
@@ -335,7 +368,7 @@ function Updates() {
 
   useEffect(() => {
     loadSettings().then(() => {
-      checkUpdates();
+      autoCheckForUpdate();
     });
   }, []);
 
@@ -361,7 +394,7 @@ function Updates() {
         <div className="updates-actions">
           <button
             className={`btn ${isChecking ? 'btn-secondary' : 'btn-primary'}`}
-            onClick={() => checkUpdates(updateChannel, true)}
+            onClick={() => checkUpdates(updateChannel)}
             disabled={isChecking || isDownloading}
           >
             {isChecking ? 'Checking...' : 'Check for updates'}
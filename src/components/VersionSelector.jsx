@@ -2,12 +2,21 @@
 import { useState, useEffect } from 'react';
 import './VersionSelector.css';
 
+function formatUpdatedAgo(updatedAt) {
+    const seconds = Math.max(0, Math.floor(Date.now() / 1000) - updatedAt);
+    if (seconds < 60) return 'just now';
+    if (seconds < 3600) return `${Math.floor(seconds / 60)}m ago`;
+    if (seconds < 86400) return `${Math.floor(seconds / 3600)}h ago`;
+    return `${Math.floor(seconds / 86400)}d ago`;
+}
+
 function VersionSelector({
     versions,
     selectedVersion,
     onSelect,
     onRefresh,
     loading,
+    updatedAt = null,
     title = "Select Version",
     showFilters = true
 }) {
@@ -93,7 +102,11 @@ function VersionSelector({
                             onChange={(e) => setSearchQuery(e.target.value)}
                         />
                         {onRefresh && (
-                            <button className="refresh-btn" onClick={onRefresh}>
+                            <button
+                                className="refresh-btn"
+                                onClick={onRefresh}
+                                title={updatedAt ? `Versions last updated ${formatUpdatedAgo(updatedAt)}. Click to refresh.` : 'Refresh'}
+                            >
                                 <svg width="14" height="14" viewBox="0 0 24 24" fill="none" stroke="currentColor" strokeWidth="2"><path d="M23 4v6h-6" /><path d="M20.49 15a9 9 0 1 1-2.12-9.36L23 10" /></svg>
                             </button>
                         )}